@@ -18,5 +18,12 @@ pub const DEFAULT_RELEASES_COLLECTIONS: [(&str, SetRule); 1] = [(
         max_changes_per_user: None,
         version: None,
         rate_config: None,
+        indexes: None,
+        default_ttl_ns: None,
+        max_revisions: None,
+        trash_retention_ns: None,
+        max_items_per_user: None,
+        max_bytes_per_user: None,
+        json_schema: None,
     },
 )];
@@ -124,8 +124,9 @@ impl CdnCommitAssetsStrategy for CdnCommitAssets {
         full_path: &FullPath,
         asset: &Asset,
         _rule: &Rule,
-    ) {
+    ) -> Result<(), String> {
         insert_asset(full_path, asset);
+        Ok(())
     }
 
     fn insert_asset_encoding(
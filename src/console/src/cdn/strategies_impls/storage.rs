@@ -12,7 +12,9 @@ use junobuild_cdn::storage::errors::{
     JUNO_CDN_STORAGE_ERROR_CANNOT_INSERT_ASSET_ENCODING_UNKNOWN_REFERENCE_ID,
     JUNO_CDN_STORAGE_ERROR_CANNOT_INSERT_ASSET_UNKNOWN_REFERENCE_ID,
 };
-use junobuild_collections::assert::stores::{assert_create_permission, assert_permission};
+use junobuild_collections::assert::stores::{
+    assert_create_permission, assert_permission, no_roles,
+};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Permission, Rule};
 use junobuild_shared::segments::controllers::controller_can_write;
@@ -65,7 +67,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_create_permission(permission, caller, controllers)
+        assert_create_permission(permission, caller, controllers, no_roles)
     }
 
     fn assert_update_permission(
@@ -76,7 +78,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_permission(permission, owner, caller, controllers)
+        assert_permission(permission, owner, caller, controllers, no_roles)
     }
 
     fn assert_list_permission(
@@ -87,7 +89,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_permission(permission, owner, caller, controllers)
+        assert_permission(permission, owner, caller, controllers, no_roles)
     }
 
     fn invoke_assert_upload_asset(
@@ -109,6 +111,20 @@ impl StorageAssertionsStrategy for StorageAssertions {
         // No pre-assertions on the console
         Ok(())
     }
+
+    fn assert_storage_items_usage(
+        &self,
+        _caller: &Principal,
+        _controllers: &Controllers,
+        _collection: &CollectionKey,
+        _rule: &Rule,
+        _current: &Option<Asset>,
+        _encoding_type: &EncodingType,
+        _encoding: &AssetEncoding,
+    ) -> Result<(), String> {
+        // No pre-assertions on the console
+        Ok(())
+    }
 }
 
 pub struct StorageState;
@@ -159,8 +175,9 @@ impl StorageStateStrategy for StorageState {
         full_path: &FullPath,
         asset: &Asset,
         _rule: &Rule,
-    ) {
-        insert_asset(full_path, asset)
+    ) -> Result<(), String> {
+        insert_asset(full_path, asset);
+        Ok(())
     }
 
     fn insert_asset_encoding(
@@ -179,8 +196,8 @@ impl StorageStateStrategy for StorageState {
         _collection: &CollectionKey,
         full_path: &FullPath,
         _rule: &Rule,
-    ) -> Option<Asset> {
-        delete_asset(full_path)
+    ) -> Result<Option<Asset>, String> {
+        Ok(delete_asset(full_path))
     }
 
     fn init_certified_assets(&self) {
@@ -64,6 +64,47 @@ export type AuthenticationError =
 export interface AuthenticationRules {
 	allowed_callers: Array<Principal>;
 }
+export interface CanisterOutputCertifiedMessages {
+	messages: Array<CanisterOutputMessage>;
+	cert: Uint8Array;
+	tree: Uint8Array;
+	is_end_of_queue: boolean;
+}
+export interface CanisterOutputMessage {
+	key: string;
+	content: Uint8Array;
+	client_key: ClientKey;
+}
+export interface CanisterWsCloseArguments {
+	client_key: ClientKey;
+}
+export interface CanisterWsGetMessagesArguments {
+	nonce: bigint;
+}
+export interface CanisterWsMessageArguments {
+	msg: WebsocketMessage;
+}
+export interface CanisterWsOpenArguments {
+	gateway_principal: Principal;
+	client_nonce: bigint;
+}
+export interface ClientKey {
+	client_principal: Principal;
+	client_nonce: bigint;
+}
+export interface CollectionMigration {
+	memory: Memory;
+	status: CollectionMigrationStatus;
+	updated_at: bigint;
+	deleted: bigint;
+	created_at: bigint;
+	copied: bigint;
+	last_key: [] | [string];
+}
+export type CollectionMigrationStatus =
+	| { Cleaning: null }
+	| { Copying: null }
+	| { Completed: null };
 export type CollectionType = { Db: null } | { Storage: null };
 export interface CommitBatch {
 	batch_id: bigint;
@@ -97,6 +138,23 @@ export interface CustomDomain {
 	version: [] | [bigint];
 	bn_id: [] | [string];
 }
+export interface DataFieldMatcher {
+	path: string;
+	predicate: DataPredicate;
+}
+export type DataMatcher =
+	| { Or: Array<DataMatcher> }
+	| { And: Array<DataMatcher> }
+	| { Field: DataFieldMatcher };
+export type DataPredicate =
+	| { In: Array<DataValue> }
+	| { Equal: DataValue }
+	| { GreaterThan: DataValue }
+	| { LessThan: DataValue }
+	| { NotEqual: DataValue }
+	| { StartsWith: string }
+	| { Exists: boolean };
+export type DataValue = { Bool: boolean } | { Null: null } | { Text: string } | { Number: number };
 export interface DbConfig {
 	updated_at: [] | [bigint];
 	created_at: [] | [bigint];
@@ -126,12 +184,81 @@ export interface DepositCyclesArgs {
 }
 export interface Doc {
 	updated_at: bigint;
+	restorable_until: [] | [bigint];
 	owner: Principal;
 	data: Uint8Array;
 	description: [] | [string];
 	created_at: bigint;
 	version: [] | [bigint];
+	expires_at: [] | [bigint];
+}
+export interface DocAccess {
+	readers: Array<DocGrant>;
+	updated_at: bigint;
+	created_at: bigint;
+	version: [] | [bigint];
+	writers: Array<DocGrant>;
+}
+export interface DocGrant {
+	user: Principal;
+	expires_at: [] | [bigint];
+}
+export type DocPatch = { Json: Uint8Array } | { Merge: Uint8Array };
+export interface DocRevision {
+	updated_at: bigint;
+	owner: Principal;
+	data: Uint8Array;
+	description: [] | [string];
+	version: bigint;
+	caller: Principal;
+}
+export interface DocsBatchError {
+	key: string;
+	collection: string;
+	error: string;
+	index: bigint;
+}
+export type DocsBatchOperation =
+	| {
+			Set: {
+				doc: SetDoc;
+				key: string;
+				collection: string;
+			};
+	  }
+	| {
+			AssertVersion: {
+				key: string;
+				collection: string;
+				version: [] | [bigint];
+			};
+	  }
+	| { Delete: {
+		doc: DelDoc;
+		key: string;
+		collection: string;
+	} };
+export type DocsBatchOperationResult = { Set: Doc } | { AssertVersion: null } | { Delete: null };
+export interface ExportCollectionArgs {
+	collection: string;
+	cursor: [] | [ExportCursor];
+	collection_type: CollectionType;
+	format: ExportFormat;
+}
+export interface ExportCollectionResult {
+	cursor: [] | [ExportCursor];
+	data: Uint8Array;
+}
+export interface ExportContentCursor {
+	chunk_index: bigint;
+	offset: bigint;
+	encoding_type: string;
+}
+export interface ExportCursor {
+	key: string;
+	content: [] | [ExportContentCursor];
 }
+export type ExportFormat = { Cbor: null } | { JsonLines: null };
 export type GetDelegationArgs = { OpenId: OpenIdGetDelegationArgs };
 export type GetDelegationError =
 	| { JwtFindProvider: JwtFindProviderError }
@@ -164,6 +291,27 @@ export interface HttpResponse {
 	streaming_strategy: [] | [StreamingStrategy];
 	status_code: number;
 }
+export interface ImportCollectionArgs {
+	collection: string;
+	data: Uint8Array;
+	collection_type: CollectionType;
+	dry_run: boolean;
+	format: ExportFormat;
+}
+export interface ImportCollectionResult {
+	imported: bigint;
+	conflicts: Array<ImportConflict>;
+}
+export interface ImportConflict {
+	key: string;
+	imported_version: [] | [bigint];
+	current_version: [] | [bigint];
+}
+export type IndexMatcher =
+	| { Equal: DataValue }
+	| { Between: [DataValue, DataValue] }
+	| { GreaterThan: DataValue }
+	| { LessThan: DataValue };
 export interface InitAssetKey {
 	token: [] | [string];
 	collection: string;
@@ -192,11 +340,21 @@ export type JwtVerifyError =
 	| { BadClaim: string }
 	| { BadSig: string }
 	| { NoKeyForKid: null };
+export interface ListChangesResults {
+	last_sequence: bigint;
+	items: Array<NotificationMessage>;
+}
+export interface ListIndexMatcher {
+	value: IndexMatcher;
+	path: string;
+}
 export interface ListMatcher {
 	key: [] | [string];
 	updated_at: [] | [TimestampMatcher];
+	data: [] | [DataMatcher];
 	description: [] | [string];
 	created_at: [] | [TimestampMatcher];
+	index: [] | [ListIndexMatcher];
 }
 export interface ListOrder {
 	field: ListOrderField;
@@ -243,6 +401,13 @@ export interface ListResults_1 {
 	items: Array<[string, Doc]>;
 	items_length: bigint;
 }
+export interface ListResults_2 {
+	matches_pages: [] | [bigint];
+	matches_length: bigint;
+	items_page: [] | [bigint];
+	items: Array<[string, DocRevision]>;
+	items_length: bigint;
+}
 export interface ListRulesMatcher {
 	include_system: boolean;
 }
@@ -259,6 +424,22 @@ export interface MemorySize {
 	stable: bigint;
 	heap: bigint;
 }
+export interface NotificationDoc {
+	updated_at: bigint;
+	data: Uint8Array;
+	description: [] | [string];
+	version: [] | [bigint];
+}
+export interface NotificationMessage {
+	doc: [] | [NotificationDoc];
+	key: string;
+	collection: string;
+	type: string;
+	timestamp: bigint;
+	caller: string;
+	doc_omitted: boolean;
+	sequence: [] | [bigint];
+}
 export interface OpenIdGetDelegationArgs {
 	jwt: string;
 	session_key: Uint8Array;
@@ -279,10 +460,16 @@ export interface OpenIdProviderDelegationConfig {
 	targets: [] | [Array<Principal>];
 	max_time_to_live: [] | [bigint];
 }
+export interface PatchDoc {
+	version: [] | [bigint];
+	patch: DocPatch;
+}
 export type Permission =
 	| { Controllers: null }
 	| { Private: null }
+	| { Roles: Array<string> }
 	| { Public: null }
+	| { Principals: Array<Principal> }
 	| { Managed: null };
 export type PrepareDelegationError =
 	| {
@@ -323,17 +510,31 @@ export interface RateConfig {
 	max_tokens: bigint;
 	time_per_token_ns: bigint;
 }
+export interface RestoreDocRevision {
+	version: [] | [bigint];
+	revision: bigint;
+}
+export type Result = { Ok: Array<DocsBatchOperationResult> } | { Err: DocsBatchError };
+export type Result_1 = { Ok: null } | { Err: string };
+export type Result_2 = { Ok: CanisterOutputCertifiedMessages } | { Err: string };
 export interface Rule {
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
+	max_items_per_user: [] | [number];
 	updated_at: bigint;
 	max_size: [] | [bigint];
+	trash_retention_ns: [] | [bigint];
+	default_ttl_ns: [] | [bigint];
 	read: Permission;
 	created_at: bigint;
+	json_schema: [] | [string];
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
+	max_revisions: [] | [number];
 	rate_config: [] | [RateConfig];
 	write: Permission;
+	indexes: [] | [Array<string>];
+	max_bytes_per_user: [] | [bigint];
 	max_changes_per_user: [] | [number];
 }
 export interface SegmentsDeploymentOptions {
@@ -364,16 +565,29 @@ export interface SetDoc {
 	data: Uint8Array;
 	description: [] | [string];
 	version: [] | [bigint];
+	expires_at: [] | [bigint];
+}
+export interface SetDocAccess {
+	readers: Array<DocGrant>;
+	version: [] | [bigint];
+	writers: Array<DocGrant>;
 }
 export interface SetRule {
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
+	max_items_per_user: [] | [number];
 	max_size: [] | [bigint];
+	trash_retention_ns: [] | [bigint];
+	default_ttl_ns: [] | [bigint];
 	read: Permission;
+	json_schema: [] | [string];
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
+	max_revisions: [] | [number];
 	rate_config: [] | [RateConfig];
 	write: Permission;
+	indexes: [] | [Array<string>];
+	max_bytes_per_user: [] | [bigint];
 	max_changes_per_user: [] | [number];
 }
 export interface SetStorageConfig {
@@ -385,6 +599,15 @@ export interface SetStorageConfig {
 	raw_access: [] | [StorageConfigRawAccess];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
+export interface SetWsConfig {
+	message_rate_config: [] | [RateConfig];
+	max_number_of_returned_messages: [] | [bigint];
+	version: [] | [bigint];
+	gateway_principals: Array<Principal>;
+	send_ack_interval_ms: [] | [bigint];
+	require_user: [] | [boolean];
+	subscription_rate_config: [] | [RateConfig];
+}
 export interface SignedDelegation {
 	signature: Uint8Array;
 	delegation: Delegation;
@@ -430,6 +653,10 @@ export type TimestampMatcher =
 	| { Between: [bigint, bigint] }
 	| { GreaterThan: bigint }
 	| { LessThan: bigint };
+export interface TransferOwnership {
+	owner: Principal;
+	version: [] | [bigint];
+}
 export interface UploadChunk {
 	content: Uint8Array;
 	batch_id: bigint;
@@ -438,7 +665,47 @@ export interface UploadChunk {
 export interface UploadChunkResult {
 	chunk_id: bigint;
 }
+export interface UserPurge {
+	status: UserPurgeStatus;
+	updated_at: bigint;
+	deleted_docs: bigint;
+	collection: [] | [string];
+	created_at: bigint;
+	deleted_assets: bigint;
+	last_key: [] | [string];
+}
+export type UserPurgeStatus =
+	| { Docs: null }
+	| { User: null }
+	| { Shares: null }
+	| { Assets: null }
+	| { Trash: null }
+	| { Completed: null };
+export interface UserUsageData {
+	items_count: number;
+	changes_count: number;
+	items_bytes: bigint;
+}
+export interface WebsocketMessage {
+	sequence_num: bigint;
+	content: Uint8Array;
+	client_key: ClientKey;
+	timestamp: bigint;
+	is_service_message: boolean;
+}
+export interface WsConfig {
+	message_rate_config: [] | [RateConfig];
+	updated_at: [] | [bigint];
+	max_number_of_returned_messages: [] | [bigint];
+	created_at: [] | [bigint];
+	version: [] | [bigint];
+	gateway_principals: Array<Principal>;
+	send_ack_interval_ms: [] | [bigint];
+	require_user: [] | [boolean];
+	subscription_rate_config: [] | [RateConfig];
+}
 export interface _SERVICE {
+	apply_docs_batch: ActorMethod<[Array<DocsBatchOperation>], Result>;
 	authenticate: ActorMethod<[AuthenticationArgs], AuthenticateResultResponse>;
 	commit_asset_upload: ActorMethod<[CommitBatch], undefined>;
 	commit_proposal: ActorMethod<[CommitProposal], null>;
@@ -462,22 +729,27 @@ export interface _SERVICE {
 	del_rule: ActorMethod<[CollectionType, string, DelRule], undefined>;
 	delete_proposal_assets: ActorMethod<[DeleteProposalAssets], undefined>;
 	deposit_cycles: ActorMethod<[DepositCyclesArgs], undefined>;
+	export_collection: ActorMethod<[ExportCollectionArgs], ExportCollectionResult>;
 	get_asset: ActorMethod<[string, string], [] | [AssetNoContent]>;
 	get_auth_config: ActorMethod<[], [] | [AuthenticationConfig]>;
 	get_config: ActorMethod<[], Config>;
 	get_db_config: ActorMethod<[], [] | [DbConfig]>;
 	get_delegation: ActorMethod<[GetDelegationArgs], GetDelegationResultResponse>;
 	get_doc: ActorMethod<[string, string], [] | [Doc]>;
+	get_doc_access: ActorMethod<[string, string], [] | [DocAccess]>;
 	get_many_assets: ActorMethod<[Array<[string, string]>], Array<[string, [] | [AssetNoContent]]>>;
 	get_many_docs: ActorMethod<[Array<[string, string]>], Array<[string, [] | [Doc]]>>;
 	get_proposal: ActorMethod<[bigint], [] | [Proposal]>;
 	get_rule: ActorMethod<[CollectionType, string], [] | [Rule]>;
 	get_storage_config: ActorMethod<[], StorageConfig>;
+	get_user_usage: ActorMethod<[CollectionType, string], [] | [UserUsageData]>;
+	get_ws_config: ActorMethod<[], [] | [WsConfig]>;
 	http_request: ActorMethod<[HttpRequest], HttpResponse>;
 	http_request_streaming_callback: ActorMethod<
 		[StreamingCallbackToken],
 		StreamingCallbackHttpResponse
 	>;
+	import_collection: ActorMethod<[ImportCollectionArgs], ImportCollectionResult>;
 	init_asset_upload: ActorMethod<[InitAssetKey], InitUploadResult>;
 	init_proposal: ActorMethod<[ProposalType], [bigint, Proposal]>;
 	init_proposal_asset_upload: ActorMethod<[InitAssetKey, bigint], InitUploadResult>;
@@ -486,26 +758,45 @@ export interface _SERVICE {
 		Array<[string, InitUploadResult]>
 	>;
 	list_assets: ActorMethod<[string, ListParams], ListResults>;
+	list_changes: ActorMethod<[bigint, [] | [Array<string>], [] | [bigint]], ListChangesResults>;
 	list_controllers: ActorMethod<[], Array<[Principal, Controller]>>;
 	list_custom_domains: ActorMethod<[], Array<[string, CustomDomain]>>;
+	list_doc_revisions: ActorMethod<[string, string, ListParams], ListResults_2>;
 	list_docs: ActorMethod<[string, ListParams], ListResults_1>;
 	list_proposals: ActorMethod<[ListProposalsParams], ListProposalResults>;
 	list_rules: ActorMethod<[CollectionType, ListRulesParams], ListRulesResults>;
+	list_shared_docs: ActorMethod<[string, ListParams], ListResults_1>;
+	list_trashed_docs: ActorMethod<[string, ListParams], ListResults_1>;
 	memory_size: ActorMethod<[], MemorySize>;
+	migrate_collection_memory: ActorMethod<[CollectionType, string, Memory], CollectionMigration>;
+	patch_doc: ActorMethod<[string, string, PatchDoc], Doc>;
+	purge_user_data: ActorMethod<[Principal], UserPurge>;
 	reject_proposal: ActorMethod<[CommitProposal], null>;
+	restore_doc: ActorMethod<[string, string], Doc>;
+	restore_doc_revision: ActorMethod<[string, string, RestoreDocRevision], Doc>;
 	set_asset_token: ActorMethod<[string, string, [] | [string]], undefined>;
 	set_auth_config: ActorMethod<[SetAuthenticationConfig], AuthenticationConfig>;
 	set_controllers: ActorMethod<[SetControllersArgs], Array<[Principal, Controller]>>;
 	set_custom_domain: ActorMethod<[string, [] | [string]], undefined>;
 	set_db_config: ActorMethod<[SetDbConfig], DbConfig>;
 	set_doc: ActorMethod<[string, string, SetDoc], Doc>;
+	set_doc_access: ActorMethod<[string, string, SetDocAccess], DocAccess>;
 	set_many_docs: ActorMethod<[Array<[string, string, SetDoc]>], Array<[string, Doc]>>;
 	set_rule: ActorMethod<[CollectionType, string, SetRule], Rule>;
 	set_storage_config: ActorMethod<[SetStorageConfig], StorageConfig>;
+	set_ws_config: ActorMethod<[SetWsConfig], WsConfig>;
 	submit_proposal: ActorMethod<[bigint], [bigint, Proposal]>;
 	switch_storage_system_memory: ActorMethod<[], undefined>;
+	transfer_asset_ownership: ActorMethod<[string, string, TransferOwnership], AssetNoContent>;
+	transfer_doc_ownership: ActorMethod<[string, string, TransferOwnership], Doc>;
 	upload_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
 	upload_proposal_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
+	ws_close: ActorMethod<[CanisterWsCloseArguments], Result_1>;
+	ws_get_gateway_url: ActorMethod<[], string>;
+	ws_get_messages: ActorMethod<[CanisterWsGetMessagesArguments], Result_2>;
+	ws_message: ActorMethod<[CanisterWsMessageArguments], Result_1>;
+	ws_open: ActorMethod<[CanisterWsOpenArguments], Result_1>;
+	ws_stats: ActorMethod<[], Array<[string, string, bigint]>>;
 }
 export declare const idlFactory: IDL.InterfaceFactory;
 export declare const init: (args: { IDL: typeof IDL }) => IDL.Type[];
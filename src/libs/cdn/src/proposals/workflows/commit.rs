@@ -165,7 +165,7 @@ fn copy_committed_assets(
                 &rule,
             );
 
-            cdn_commit_assets.insert_asset(&key.collection, &key.full_path, &asset, &rule);
+            cdn_commit_assets.insert_asset(&key.collection, &key.full_path, &asset, &rule)?;
         }
     }
 
@@ -43,7 +43,7 @@ pub trait CdnCommitAssetsStrategy {
         full_path: &FullPath,
         asset: &Asset,
         rule: &Rule,
-    );
+    ) -> Result<(), String>;
 
     fn insert_asset_encoding(
         &self,
@@ -1,3 +1,4 @@
 pub mod collection;
+pub mod roles;
 pub mod rules;
 pub mod stores;
@@ -0,0 +1,48 @@
+use crate::constants::core::{MAX_ROLES, MAX_ROLE_NAME_LENGTH};
+use crate::errors::JUNO_COLLECTIONS_ERROR_INVALID_ROLES;
+use crate::types::rules::RoleName;
+
+/// Asserts a list of roles - either granted to a user or required by a permission - is not empty,
+/// not too long and contains only named roles.
+pub fn assert_roles(roles: &[RoleName]) -> Result<(), String> {
+    let valid = !roles.is_empty()
+        && roles.len() <= MAX_ROLES
+        && roles
+            .iter()
+            .all(|role| !role.trim().is_empty() && role.chars().count() <= MAX_ROLE_NAME_LENGTH);
+
+    if !valid {
+        return Err(format!(
+            "{JUNO_COLLECTIONS_ERROR_INVALID_ROLES} ({MAX_ROLES}, {MAX_ROLE_NAME_LENGTH})"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_named_roles() {
+        let roles = vec!["admin".to_string(), "team".to_string()];
+
+        assert!(assert_roles(&roles).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_list_and_blank_names() {
+        assert!(assert_roles(&[]).is_err());
+        assert!(assert_roles(&["admin".to_string(), " ".to_string()]).is_err());
+    }
+
+    #[test]
+    fn rejects_too_many_or_too_long_roles() {
+        let too_many: Vec<RoleName> = (0..=MAX_ROLES).map(|i| format!("role-{i}")).collect();
+        assert!(assert_roles(&too_many).is_err());
+
+        let too_long = vec!["a".repeat(MAX_ROLE_NAME_LENGTH + 1)];
+        assert!(assert_roles(&too_long).is_err());
+    }
+}
@@ -1,15 +1,29 @@
 use crate::assert::collection::{is_not_system_collection, is_system_collection};
-use crate::constants::core::SYS_COLLECTION_PREFIX;
+use crate::assert::roles::assert_roles;
+use crate::constants::core::{
+    MAX_INDEXES_PER_COLLECTION, MAX_JSON_SCHEMA_LENGTH, MAX_PERMISSION_PRINCIPALS,
+    MAX_REVISIONS_PER_DOC, SYS_COLLECTION_PREFIX,
+};
 use crate::errors::{
-    JUNO_COLLECTIONS_ERROR_DELETE_PREFIX_RESERVED,
+    JUNO_COLLECTIONS_ERROR_DELETE_PREFIX_RESERVED, JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED,
+    JUNO_COLLECTIONS_ERROR_INVALID_DEFAULT_TTL, JUNO_COLLECTIONS_ERROR_INVALID_INDEX,
+    JUNO_COLLECTIONS_ERROR_INVALID_JSON_SCHEMA, JUNO_COLLECTIONS_ERROR_INVALID_MAX_REVISIONS,
+    JUNO_COLLECTIONS_ERROR_INVALID_PERMISSION_PRINCIPALS,
+    JUNO_COLLECTIONS_ERROR_INVALID_TRASH_RETENTION,
+    JUNO_COLLECTIONS_ERROR_JSON_SCHEMA_NOT_SUPPORTED,
     JUNO_COLLECTIONS_ERROR_MODIFY_RESERVED_COLLECTION, JUNO_COLLECTIONS_ERROR_PREFIX_RESERVED,
     JUNO_COLLECTIONS_ERROR_RATE_CONFIG_ENABLED, JUNO_COLLECTIONS_ERROR_RESERVED_NAME,
+    JUNO_COLLECTIONS_ERROR_REVISIONS_NOT_SUPPORTED, JUNO_COLLECTIONS_ERROR_TOO_MANY_INDEXES,
+    JUNO_COLLECTIONS_ERROR_TRASH_NOT_SUPPORTED, JUNO_COLLECTIONS_ERROR_TTL_NOT_SUPPORTED,
 };
+use crate::schema::assert::assert_schema;
 use crate::types::core::CollectionKey;
 use crate::types::interface::SetRule;
-use crate::types::rules::{Memory, Rule, Rules};
+use crate::types::rules::{Memory, Permission, Rule, Rules};
+use candid::Principal;
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::types::state::Version;
+use junobuild_shared::utils::principal_not_anonymous;
 
 pub fn assert_memory(current_rule: Option<&Rule>, memory: &Option<Memory>) -> Result<(), String> {
     // Validate memory type does not change
@@ -77,6 +91,35 @@ pub fn assert_mutable_permissions(
     Ok(())
 }
 
+pub fn assert_permissions(user_rule: &SetRule) -> Result<(), String> {
+    assert_permission(&user_rule.read)?;
+    assert_permission(&user_rule.write)
+}
+
+fn assert_permission(permission: &Permission) -> Result<(), String> {
+    match permission {
+        Permission::Principals(principals) => assert_permission_principals(principals),
+        Permission::Roles(roles) => assert_roles(roles),
+        _ => Ok(()),
+    }
+}
+
+fn assert_permission_principals(principals: &[Principal]) -> Result<(), String> {
+    let valid = !principals.is_empty()
+        && principals.len() <= MAX_PERMISSION_PRINCIPALS
+        && principals
+            .iter()
+            .all(|principal| principal_not_anonymous(*principal));
+
+    if !valid {
+        return Err(format!(
+            "{JUNO_COLLECTIONS_ERROR_INVALID_PERMISSION_PRINCIPALS} ({MAX_PERMISSION_PRINCIPALS})"
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn assert_write_version(
     current_rule: Option<&Rule>,
     version: &Option<Version>,
@@ -115,6 +158,13 @@ pub fn assert_system_collection_set_permission(
         || current_rule.mutable_permissions != user_rule.mutable_permissions
         || current_rule.max_size != user_rule.max_size
         || current_rule.max_capacity != user_rule.max_capacity
+        || current_rule.indexes != user_rule.indexes
+        || current_rule.default_ttl_ns != user_rule.default_ttl_ns
+        || current_rule.max_revisions != user_rule.max_revisions
+        || current_rule.trash_retention_ns != user_rule.trash_retention_ns
+        || current_rule.max_items_per_user != user_rule.max_items_per_user
+        || current_rule.max_bytes_per_user != user_rule.max_bytes_per_user
+        || current_rule.json_schema != user_rule.json_schema
     {
         return Err(format!(
             "{JUNO_COLLECTIONS_ERROR_MODIFY_RESERVED_COLLECTION} ({collection})"
@@ -128,6 +178,126 @@ pub fn assert_system_collection_set_permission(
     Ok(())
 }
 
+pub fn assert_indexes(user_rule: &SetRule) -> Result<(), String> {
+    let Some(indexes) = &user_rule.indexes else {
+        return Ok(());
+    };
+
+    if indexes.len() > MAX_INDEXES_PER_COLLECTION {
+        return Err(format!(
+            "{JUNO_COLLECTIONS_ERROR_TOO_MANY_INDEXES} ({MAX_INDEXES_PER_COLLECTION})"
+        ));
+    }
+
+    for (i, path) in indexes.iter().enumerate() {
+        let valid = !path.is_empty() && path.split('.').all(|segment| !segment.is_empty());
+
+        // Each path can only be indexed once
+        if !valid || indexes[..i].contains(path) {
+            return Err(format!("{JUNO_COLLECTIONS_ERROR_INVALID_INDEX} ({path})"));
+        }
+    }
+
+    Ok(())
+}
+
+// Assets have no data to index.
+pub fn assert_storage_indexes(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule
+        .indexes
+        .as_ref()
+        .is_some_and(|indexes| !indexes.is_empty())
+    {
+        return Err(JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_default_ttl(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.default_ttl_ns == Some(0) {
+        return Err(JUNO_COLLECTIONS_ERROR_INVALID_DEFAULT_TTL.to_string());
+    }
+
+    Ok(())
+}
+
+// Assets do not expire.
+pub fn assert_storage_default_ttl(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.default_ttl_ns.is_some() {
+        return Err(JUNO_COLLECTIONS_ERROR_TTL_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_max_revisions(user_rule: &SetRule) -> Result<(), String> {
+    let Some(max_revisions) = user_rule.max_revisions else {
+        return Ok(());
+    };
+
+    if max_revisions == 0 || max_revisions > MAX_REVISIONS_PER_DOC {
+        return Err(format!(
+            "{JUNO_COLLECTIONS_ERROR_INVALID_MAX_REVISIONS} ({MAX_REVISIONS_PER_DOC})"
+        ));
+    }
+
+    Ok(())
+}
+
+// Assets are not versioned.
+pub fn assert_storage_revisions(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.max_revisions.is_some() {
+        return Err(JUNO_COLLECTIONS_ERROR_REVISIONS_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_trash_retention(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.trash_retention_ns == Some(0) {
+        return Err(JUNO_COLLECTIONS_ERROR_INVALID_TRASH_RETENTION.to_string());
+    }
+
+    Ok(())
+}
+
+// Deleted assets are not kept.
+pub fn assert_storage_trash(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.trash_retention_ns.is_some() {
+        return Err(JUNO_COLLECTIONS_ERROR_TRASH_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_json_schema(user_rule: &SetRule) -> Result<(), String> {
+    let Some(json_schema) = &user_rule.json_schema else {
+        return Ok(());
+    };
+
+    if json_schema.len() > MAX_JSON_SCHEMA_LENGTH {
+        return Err(format!(
+            "{JUNO_COLLECTIONS_ERROR_INVALID_JSON_SCHEMA} (exceeds {MAX_JSON_SCHEMA_LENGTH} bytes)"
+        ));
+    }
+
+    let schema: serde_json::Value = serde_json::from_str(json_schema)
+        .map_err(|err| format!("{JUNO_COLLECTIONS_ERROR_INVALID_JSON_SCHEMA} ({err})"))?;
+
+    assert_schema(&schema)
+        .map_err(|err| format!("{JUNO_COLLECTIONS_ERROR_INVALID_JSON_SCHEMA} ({err})"))
+}
+
+// Assets have no data to validate.
+pub fn assert_storage_json_schema(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.json_schema.is_some() {
+        return Err(JUNO_COLLECTIONS_ERROR_JSON_SCHEMA_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
 pub fn assert_system_collection_delete_permission(
     collection: &CollectionKey,
 ) -> Result<(), String> {
@@ -1,4 +1,4 @@
-use crate::types::rules::Permission;
+use crate::types::rules::{Permission, RoleName};
 use candid::Principal;
 use junobuild_shared::segments::controllers::controller_can_write;
 use junobuild_shared::types::state::{Controllers, UserId};
@@ -9,8 +9,16 @@ pub fn assert_permission(
     owner: Principal,
     caller: Principal,
     controllers: &Controllers,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
-    assert_permission_with(permission, owner, caller, controllers, controller_can_write)
+    assert_permission_with(
+        permission,
+        owner,
+        caller,
+        controllers,
+        controller_can_write,
+        has_role,
+    )
 }
 
 pub fn assert_permission_with(
@@ -19,6 +27,7 @@ pub fn assert_permission_with(
     caller: Principal,
     controllers: &Controllers,
     is_allowed_controller: fn(UserId, &Controllers) -> bool,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
     match permission {
         Permission::Public => true,
@@ -27,6 +36,8 @@ pub fn assert_permission_with(
             assert_caller(caller, owner) || controller_can_write(caller, controllers)
         }
         Permission::Controllers => is_allowed_controller(caller, controllers),
+        Permission::Principals(principals) => assert_principals(caller, principals),
+        Permission::Roles(roles) => assert_roles(caller, roles, has_role),
     }
 }
 
@@ -36,8 +47,15 @@ pub fn assert_create_permission(
     permission: &Permission,
     caller: Principal,
     controllers: &Controllers,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
-    assert_create_permission_with(permission, caller, controllers, controller_can_write)
+    assert_create_permission_with(
+        permission,
+        caller,
+        controllers,
+        controller_can_write,
+        has_role,
+    )
 }
 
 pub fn assert_create_permission_with(
@@ -45,15 +63,23 @@ pub fn assert_create_permission_with(
     caller: Principal,
     controllers: &Controllers,
     is_allowed_controller: fn(UserId, &Controllers) -> bool,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
     match permission {
         Permission::Public => true,
         Permission::Private => assert_not_anonymous(caller),
         Permission::Managed => assert_not_anonymous(caller),
         Permission::Controllers => is_allowed_controller(caller, controllers),
+        Permission::Principals(principals) => assert_principals(caller, principals),
+        Permission::Roles(roles) => assert_roles(caller, roles, has_role),
     }
 }
 
+/// For stores that do not manage roles - e.g. the Console. No one is granted a role.
+pub fn no_roles(_user: UserId, _roles: &[RoleName]) -> bool {
+    false
+}
+
 fn assert_caller(caller: Principal, owner: Principal) -> bool {
     principal_not_anonymous_and_equal(caller, owner)
 }
@@ -62,6 +88,18 @@ fn assert_not_anonymous(caller: Principal) -> bool {
     principal_not_anonymous(caller)
 }
 
+fn assert_principals(caller: Principal, principals: &[Principal]) -> bool {
+    assert_not_anonymous(caller) && principals.contains(&caller)
+}
+
+fn assert_roles(
+    caller: Principal,
+    roles: &[RoleName],
+    has_role: fn(UserId, &[RoleName]) -> bool,
+) -> bool {
+    assert_not_anonymous(caller) && has_role(caller, roles)
+}
+
 pub fn public_permission(permission: &Permission) -> bool {
     matches!(permission, Permission::Public)
 }
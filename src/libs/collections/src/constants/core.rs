@@ -1 +1,13 @@
 pub const SYS_COLLECTION_PREFIX: char = '#';
+
+pub const MAX_INDEXES_PER_COLLECTION: usize = 10;
+
+pub const MAX_REVISIONS_PER_DOC: u32 = 100;
+
+pub const MAX_JSON_SCHEMA_LENGTH: usize = 65_536;
+
+pub const MAX_PERMISSION_PRINCIPALS: usize = 100;
+
+pub const MAX_ROLES: usize = 20;
+
+pub const MAX_ROLE_NAME_LENGTH: usize = 64;
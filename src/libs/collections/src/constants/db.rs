@@ -8,6 +8,7 @@ pub const COLLECTION_LOG_KEY: &str = "#log";
 pub const COLLECTION_USER_USAGE_KEY: &str = "#user-usage";
 pub const COLLECTION_USER_WEBAUTHN_KEY: &str = "#user-webauthn";
 pub const COLLECTION_USER_WEBAUTHN_INDEX_KEY: &str = "#user-webauthn-index";
+pub const COLLECTION_USER_ROLE_KEY: &str = "#user-role";
 
 const COLLECTION_USER_DEFAULT_RULE: SetRule = SetRule {
     read: Managed,
@@ -20,6 +21,13 @@ const COLLECTION_USER_DEFAULT_RULE: SetRule = SetRule {
     max_changes_per_user: None,
     version: None,
     rate_config: Some(DEFAULT_RATE_CONFIG),
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
 };
 
 pub const COLLECTION_LOG_DEFAULT_RULE: SetRule = SetRule {
@@ -32,6 +40,13 @@ pub const COLLECTION_LOG_DEFAULT_RULE: SetRule = SetRule {
     max_changes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
 };
 
 pub const COLLECTION_USER_USAGE_DEFAULT_RULE: SetRule = SetRule {
@@ -44,6 +59,13 @@ pub const COLLECTION_USER_USAGE_DEFAULT_RULE: SetRule = SetRule {
     max_changes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
 };
 
 pub const COLLECTION_USER_WEBAUTHN_DEFAULT_RULE: SetRule = SetRule {
@@ -60,6 +82,13 @@ pub const COLLECTION_USER_WEBAUTHN_DEFAULT_RULE: SetRule = SetRule {
     max_changes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
 };
 
 pub const COLLECTION_USER_WEBAUTHN_INDEX_DEFAULT_RULE: SetRule = SetRule {
@@ -74,9 +103,36 @@ pub const COLLECTION_USER_WEBAUTHN_INDEX_DEFAULT_RULE: SetRule = SetRule {
     max_changes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
 };
 
-pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 5] = [
+pub const COLLECTION_USER_ROLE_DEFAULT_RULE: SetRule = SetRule {
+    // The roles of the users are granted by the controllers.
+    read: Controllers,
+    write: Controllers,
+    memory: Some(Memory::Stable),
+    mutable_permissions: Some(false),
+    max_size: None,
+    max_capacity: None,
+    max_changes_per_user: None,
+    version: None,
+    rate_config: None,
+    indexes: None,
+    default_ttl_ns: None,
+    max_revisions: None,
+    trash_retention_ns: None,
+    max_items_per_user: None,
+    max_bytes_per_user: None,
+    json_schema: None,
+};
+
+pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 6] = [
     (COLLECTION_USER_KEY, COLLECTION_USER_DEFAULT_RULE),
     (COLLECTION_LOG_KEY, COLLECTION_LOG_DEFAULT_RULE),
     (
@@ -91,4 +147,5 @@ pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 5] = [
         COLLECTION_USER_WEBAUTHN_INDEX_KEY,
         COLLECTION_USER_WEBAUTHN_INDEX_DEFAULT_RULE,
     ),
+    (COLLECTION_USER_ROLE_KEY, COLLECTION_USER_ROLE_DEFAULT_RULE),
 ];
@@ -18,3 +18,39 @@ pub const JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_EMPTY: &str = "juno.collections.
 pub const JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_FOUND: &str = "juno.collections.error.not_found";
 // Collection starts with {}, a reserved prefix
 pub const JUNO_COLLECTIONS_ERROR_PREFIX_RESERVED: &str = "juno.collections.error.prefix_reserved";
+// Index path "{}" is invalid.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_INDEX: &str = "juno.collections.error.invalid_index";
+// A collection can declare at most {} indexes.
+pub const JUNO_COLLECTIONS_ERROR_TOO_MANY_INDEXES: &str = "juno.collections.error.too_many_indexes";
+// Indexes are only supported in the Datastore.
+pub const JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED: &str =
+    "juno.collections.error.indexes_not_supported";
+// The default time-to-live of the documents must be greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_DEFAULT_TTL: &str =
+    "juno.collections.error.invalid_default_ttl";
+// A time-to-live is only supported in the Datastore.
+pub const JUNO_COLLECTIONS_ERROR_TTL_NOT_SUPPORTED: &str =
+    "juno.collections.error.ttl_not_supported";
+// The number of revisions kept per document must be between 1 and {}.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_MAX_REVISIONS: &str =
+    "juno.collections.error.invalid_max_revisions";
+// Revisions are only supported in the Datastore.
+pub const JUNO_COLLECTIONS_ERROR_REVISIONS_NOT_SUPPORTED: &str =
+    "juno.collections.error.revisions_not_supported";
+// The retention of the trashed documents must be greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_TRASH_RETENTION: &str =
+    "juno.collections.error.invalid_trash_retention";
+// A trash is only supported in the Datastore.
+pub const JUNO_COLLECTIONS_ERROR_TRASH_NOT_SUPPORTED: &str =
+    "juno.collections.error.trash_not_supported";
+// The JSON Schema is not valid JSON, too large or uses unsupported keywords.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_JSON_SCHEMA: &str =
+    "juno.collections.error.invalid_json_schema";
+// A JSON Schema is only supported in the Datastore.
+pub const JUNO_COLLECTIONS_ERROR_JSON_SCHEMA_NOT_SUPPORTED: &str =
+    "juno.collections.error.json_schema_not_supported";
+// A permission must list between 1 and {} principals, none of them anonymous.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_PERMISSION_PRINCIPALS: &str =
+    "juno.collections.error.invalid_permission_principals";
+// Between 1 and {} roles must be listed, each named with at most {} characters.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_ROLES: &str = "juno.collections.error.invalid_roles";
@@ -54,6 +54,13 @@ impl Rule {
             updated_at,
             version: Some(version),
             rate_config: user_rule.rate_config.clone(),
+            indexes: user_rule.indexes.clone(),
+            default_ttl_ns: user_rule.default_ttl_ns,
+            max_revisions: user_rule.max_revisions,
+            trash_retention_ns: user_rule.trash_retention_ns,
+            max_items_per_user: user_rule.max_items_per_user,
+            max_bytes_per_user: user_rule.max_bytes_per_user,
+            json_schema: user_rule.json_schema.clone(),
         }
     }
 
@@ -82,6 +89,13 @@ impl Rule {
                     updated_at,
                     version: Some(version),
                     rate_config: user_rule.rate_config.clone(),
+                    indexes: current_rule.indexes.clone(),
+                    default_ttl_ns: current_rule.default_ttl_ns,
+                    max_revisions: current_rule.max_revisions,
+                    trash_retention_ns: current_rule.trash_retention_ns,
+                    max_items_per_user: current_rule.max_items_per_user,
+                    max_bytes_per_user: current_rule.max_bytes_per_user,
+                    json_schema: current_rule.json_schema.clone(),
                 };
 
                 Ok(rule)
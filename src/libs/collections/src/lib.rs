@@ -3,6 +3,7 @@ pub mod constants;
 mod errors;
 mod impls;
 pub mod msg;
+pub mod schema;
 pub mod store;
 pub mod types;
 pub mod utils;
@@ -0,0 +1,219 @@
+use crate::schema::constants::{SCHEMA_ANNOTATIONS, SCHEMA_KEYWORDS, SCHEMA_TYPES};
+use crate::schema::types::SchemaError;
+use crate::schema::utils::{pointer_push, resolve_ref};
+use serde_json::{Map, Value};
+
+/// Asserts a schema only uses the supported keywords - a subset of the draft 2020-12 - with valid
+/// values, and that its references resolve. The pointer of the error is the location in the schema.
+pub fn assert_schema(schema: &Value) -> Result<(), SchemaError> {
+    assert_subschema(schema, schema, "")
+}
+
+fn assert_subschema(root: &Value, schema: &Value, pointer: &str) -> Result<(), SchemaError> {
+    let schema = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(schema) => schema,
+        _ => return Err(error(pointer, "a schema must be an object or a boolean")),
+    };
+
+    for (keyword, value) in schema {
+        let keyword_pointer = pointer_push(pointer, keyword);
+
+        if SCHEMA_ANNOTATIONS.contains(&keyword.as_str()) {
+            continue;
+        }
+
+        if !SCHEMA_KEYWORDS.contains(&keyword.as_str()) {
+            return Err(error(
+                pointer,
+                &format!("unsupported keyword \"{keyword}\""),
+            ));
+        }
+
+        match keyword.as_str() {
+            "$ref" => {
+                let reference = value
+                    .as_str()
+                    .ok_or_else(|| error(&keyword_pointer, "must be a string"))?;
+
+                if resolve_ref(root, reference).is_none() {
+                    return Err(error(
+                        &keyword_pointer,
+                        &format!("unresolved reference \"{reference}\""),
+                    ));
+                }
+            }
+            "$defs" | "properties" => {
+                for (name, subschema) in as_object(value, &keyword_pointer)? {
+                    assert_subschema(root, subschema, &pointer_push(&keyword_pointer, name))?;
+                }
+            }
+            "type" => assert_type(value, &keyword_pointer)?,
+            "enum" => {
+                as_array(value, &keyword_pointer)?;
+            }
+            "multipleOf" => assert_keyword(
+                value.as_f64().is_some_and(|multiple_of| multiple_of > 0.0),
+                &keyword_pointer,
+                "must be a number greater than 0",
+            )?,
+            "maximum" | "exclusiveMaximum" | "minimum" | "exclusiveMinimum" => {
+                assert_keyword(value.is_number(), &keyword_pointer, "must be a number")?
+            }
+            "maxLength" | "minLength" | "maxItems" | "minItems" | "maxProperties"
+            | "minProperties" => assert_keyword(
+                value.as_u64().is_some(),
+                &keyword_pointer,
+                "must be a non-negative integer",
+            )?,
+            "uniqueItems" => {
+                assert_keyword(value.is_boolean(), &keyword_pointer, "must be a boolean")?
+            }
+            "items" | "additionalProperties" | "not" => {
+                assert_subschema(root, value, &keyword_pointer)?;
+            }
+            "prefixItems" | "allOf" | "anyOf" | "oneOf" => {
+                let subschemas = as_array(value, &keyword_pointer)?;
+
+                assert_keyword(
+                    !subschemas.is_empty(),
+                    &keyword_pointer,
+                    "must not be empty",
+                )?;
+
+                for (index, subschema) in subschemas.iter().enumerate() {
+                    assert_subschema(
+                        root,
+                        subschema,
+                        &pointer_push(&keyword_pointer, &index.to_string()),
+                    )?;
+                }
+            }
+            "required" => {
+                let names = as_array(value, &keyword_pointer)?;
+
+                assert_keyword(
+                    names.iter().all(|name| name.is_string()),
+                    &keyword_pointer,
+                    "must be an array of strings",
+                )?;
+            }
+            // "const" accepts any value
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+fn assert_type(value: &Value, pointer: &str) -> Result<(), SchemaError> {
+    let is_type = |value: &Value| {
+        value
+            .as_str()
+            .is_some_and(|name| SCHEMA_TYPES.contains(&name))
+    };
+
+    let valid = match value {
+        Value::Array(types) => !types.is_empty() && types.iter().all(is_type),
+        _ => is_type(value),
+    };
+
+    if !valid {
+        return Err(error(
+            pointer,
+            &format!(
+                "must be one of - or an array of - {}",
+                SCHEMA_TYPES.join(", ")
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn assert_keyword(valid: bool, pointer: &str, message: &str) -> Result<(), SchemaError> {
+    if !valid {
+        return Err(error(pointer, message));
+    }
+
+    Ok(())
+}
+
+fn as_object<'a>(value: &'a Value, pointer: &str) -> Result<&'a Map<String, Value>, SchemaError> {
+    value
+        .as_object()
+        .ok_or_else(|| error(pointer, "must be an object"))
+}
+
+fn as_array<'a>(value: &'a Value, pointer: &str) -> Result<&'a Vec<Value>, SchemaError> {
+    value
+        .as_array()
+        .ok_or_else(|| error(pointer, "must be an array"))
+}
+
+fn error(pointer: &str, message: &str) -> SchemaError {
+    SchemaError {
+        pointer: pointer.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn accepts_supported_schema() {
+        let schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Article",
+            "type": "object",
+            "properties": {
+                "title": { "type": "string", "minLength": 1 },
+                "tags": { "type": "array", "items": { "type": "string" }, "uniqueItems": true },
+                "author": { "$ref": "#/$defs/author" }
+            },
+            "required": ["title"],
+            "additionalProperties": false,
+            "$defs": {
+                "author": { "type": ["string", "null"] }
+            }
+        });
+
+        assert_eq!(assert_schema(&schema), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unsupported_keyword() {
+        let schema = json!({
+            "properties": {
+                "name": { "type": "string", "pattern": "^a" }
+            }
+        });
+
+        let err = assert_schema(&schema).unwrap_err();
+
+        assert_eq!(err.pointer, "/properties/name");
+        assert_eq!(err.message, "unsupported keyword \"pattern\"");
+    }
+
+    #[test]
+    fn rejects_invalid_keyword_value() {
+        let err = assert_schema(&json!({ "type": "text" })).unwrap_err();
+        assert_eq!(err.pointer, "/type");
+
+        let err = assert_schema(&json!({ "minLength": -1 })).unwrap_err();
+        assert_eq!(err.pointer, "/minLength");
+
+        let err = assert_schema(&json!({ "anyOf": [] })).unwrap_err();
+        assert_eq!(err.pointer, "/anyOf");
+    }
+
+    #[test]
+    fn rejects_unresolved_reference() {
+        let err = assert_schema(&json!({ "$ref": "#/$defs/unknown" })).unwrap_err();
+
+        assert_eq!(err.pointer, "/$ref");
+    }
+}
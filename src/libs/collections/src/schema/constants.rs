@@ -0,0 +1,48 @@
+pub const SCHEMA_TYPES: [&str; 7] = [
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+// The keywords of the draft 2020-12 that are validated.
+pub const SCHEMA_KEYWORDS: [&str; 26] = [
+    "$ref",
+    "$defs",
+    "type",
+    "enum",
+    "const",
+    "multipleOf",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "items",
+    "prefixItems",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "properties",
+    "required",
+    "additionalProperties",
+    "maxProperties",
+    "minProperties",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+];
+
+// The keywords that only annotate a schema. They are accepted but have no effect on the validation.
+pub const SCHEMA_ANNOTATIONS: [&str; 11] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+    "format",
+];
@@ -0,0 +1,5 @@
+pub mod assert;
+mod constants;
+pub mod types;
+mod utils;
+pub mod validate;
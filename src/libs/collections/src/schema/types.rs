@@ -0,0 +1,15 @@
+use std::fmt::{Display, Formatter};
+
+/// A value that does not match a schema. `pointer` is the JSON pointer (RFC 6901) of the value -
+/// empty for the root of the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaError {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at \"{}\"", self.message, self.pointer)
+    }
+}
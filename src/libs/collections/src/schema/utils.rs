@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+/// Appends a reference token - a property name or an array index - to a JSON pointer.
+pub fn pointer_push(pointer: &str, token: &str) -> String {
+    format!("{pointer}/{}", token.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Resolves a reference to a location within the root schema - e.g. `#/$defs/address`.
+pub fn resolve_ref<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+
+    root.pointer(pointer)
+}
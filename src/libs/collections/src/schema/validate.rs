@@ -0,0 +1,473 @@
+use crate::schema::types::SchemaError;
+use crate::schema::utils::{pointer_push, resolve_ref};
+use serde_json::{Map, Value};
+
+// The maximal number of nested schemas - including references - applied to a value. It prevents
+// recursive references from never ending.
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+/// Validates data against a schema asserted with `assert_schema`. The pointer of the error is the
+/// location of the invalid value in the data.
+pub fn validate_data(schema: &Value, data: &Value) -> Result<(), SchemaError> {
+    validate_subschema(schema, schema, data, "", 0)
+}
+
+fn validate_subschema(
+    root: &Value,
+    schema: &Value,
+    value: &Value,
+    pointer: &str,
+    depth: usize,
+) -> Result<(), SchemaError> {
+    if depth > MAX_SCHEMA_DEPTH {
+        return Err(error(pointer, "exceeds the maximal depth of the schema"));
+    }
+
+    let schema = match schema {
+        Value::Bool(false) => return Err(error(pointer, "is not allowed")),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(|reference| reference.as_str()) {
+        if let Some(subschema) = resolve_ref(root, reference) {
+            validate_subschema(root, subschema, value, pointer, depth + 1)?;
+        }
+    }
+
+    validate_type(schema, value, pointer)?;
+    validate_values(schema, value, pointer)?;
+
+    match value {
+        Value::Number(_) => validate_number(schema, value, pointer)?,
+        Value::String(text) => validate_string(schema, text, pointer)?,
+        Value::Array(items) => validate_array(root, schema, items, pointer, depth)?,
+        Value::Object(properties) => validate_object(root, schema, properties, pointer, depth)?,
+        Value::Null | Value::Bool(_) => (),
+    }
+
+    validate_combinations(root, schema, value, pointer, depth)
+}
+
+fn validate_type(
+    schema: &Map<String, Value>,
+    value: &Value,
+    pointer: &str,
+) -> Result<(), SchemaError> {
+    let Some(expected) = schema.get("type") else {
+        return Ok(());
+    };
+
+    let types: Vec<&str> = match expected {
+        Value::Array(types) => types.iter().filter_map(|name| name.as_str()).collect(),
+        _ => expected.as_str().into_iter().collect(),
+    };
+
+    if !types.iter().any(|name| is_type(value, name)) {
+        return Err(error(
+            pointer,
+            &format!("must be of type {}", types.join(" or ")),
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().is_some_and(|number| number.fract() == 0.0)
+        }
+        "string" => value.is_string(),
+        _ => false,
+    }
+}
+
+fn validate_values(
+    schema: &Map<String, Value>,
+    value: &Value,
+    pointer: &str,
+) -> Result<(), SchemaError> {
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        if !values.contains(value) {
+            return Err(error(pointer, "must be one of the allowed values"));
+        }
+    }
+
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(error(pointer, "must be equal to the constant"));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_number(
+    schema: &Map<String, Value>,
+    value: &Value,
+    pointer: &str,
+) -> Result<(), SchemaError> {
+    let Some(number) = value.as_f64() else {
+        return Ok(());
+    };
+
+    let limit = |keyword: &str| schema.get(keyword).and_then(|limit| limit.as_f64());
+
+    if let Some(multiple_of) = limit("multipleOf") {
+        // Integers are compared exactly, other numbers are subject to the precision of floats
+        let is_multiple = match (
+            value.as_i64(),
+            schema.get("multipleOf").and_then(|m| m.as_i64()),
+        ) {
+            (Some(integer), Some(multiple_of)) => integer % multiple_of == 0,
+            _ => (number / multiple_of).fract() == 0.0,
+        };
+
+        if !is_multiple {
+            return Err(error(
+                pointer,
+                &format!("must be a multiple of {multiple_of}"),
+            ));
+        }
+    }
+
+    if let Some(maximum) = limit("maximum") {
+        if number > maximum {
+            return Err(error(
+                pointer,
+                &format!("must be less than or equal to {maximum}"),
+            ));
+        }
+    }
+
+    if let Some(maximum) = limit("exclusiveMaximum") {
+        if number >= maximum {
+            return Err(error(pointer, &format!("must be less than {maximum}")));
+        }
+    }
+
+    if let Some(minimum) = limit("minimum") {
+        if number < minimum {
+            return Err(error(
+                pointer,
+                &format!("must be greater than or equal to {minimum}"),
+            ));
+        }
+    }
+
+    if let Some(minimum) = limit("exclusiveMinimum") {
+        if number <= minimum {
+            return Err(error(pointer, &format!("must be greater than {minimum}")));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_string(
+    schema: &Map<String, Value>,
+    text: &str,
+    pointer: &str,
+) -> Result<(), SchemaError> {
+    let length = text.chars().count() as u64;
+
+    if let Some(max_length) = schema.get("maxLength").and_then(|max| max.as_u64()) {
+        if length > max_length {
+            return Err(error(
+                pointer,
+                &format!("must not have more than {max_length} characters"),
+            ));
+        }
+    }
+
+    if let Some(min_length) = schema.get("minLength").and_then(|min| min.as_u64()) {
+        if length < min_length {
+            return Err(error(
+                pointer,
+                &format!("must have at least {min_length} characters"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_array(
+    root: &Value,
+    schema: &Map<String, Value>,
+    items: &[Value],
+    pointer: &str,
+    depth: usize,
+) -> Result<(), SchemaError> {
+    let length = items.len() as u64;
+
+    if let Some(max_items) = schema.get("maxItems").and_then(|max| max.as_u64()) {
+        if length > max_items {
+            return Err(error(
+                pointer,
+                &format!("must not have more than {max_items} items"),
+            ));
+        }
+    }
+
+    if let Some(min_items) = schema.get("minItems").and_then(|min| min.as_u64()) {
+        if length < min_items {
+            return Err(error(
+                pointer,
+                &format!("must have at least {min_items} items"),
+            ));
+        }
+    }
+
+    if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+        for (index, item) in items.iter().enumerate() {
+            if items[..index].contains(item) {
+                return Err(error(
+                    &pointer_push(pointer, &index.to_string()),
+                    "must not be a duplicate item",
+                ));
+            }
+        }
+    }
+
+    // The items that are not validated by "prefixItems" are validated by "items"
+    let prefix_items: &[Value] = schema
+        .get("prefixItems")
+        .and_then(|prefix_items| prefix_items.as_array())
+        .map(|prefix_items| prefix_items.as_slice())
+        .unwrap_or(&[]);
+
+    for (index, item) in items.iter().enumerate() {
+        let subschema = match prefix_items.get(index) {
+            Some(subschema) => Some(subschema),
+            None => schema.get("items"),
+        };
+
+        if let Some(subschema) = subschema {
+            validate_subschema(
+                root,
+                subschema,
+                item,
+                &pointer_push(pointer, &index.to_string()),
+                depth + 1,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_object(
+    root: &Value,
+    schema: &Map<String, Value>,
+    properties: &Map<String, Value>,
+    pointer: &str,
+    depth: usize,
+) -> Result<(), SchemaError> {
+    let length = properties.len() as u64;
+
+    if let Some(max_properties) = schema.get("maxProperties").and_then(|max| max.as_u64()) {
+        if length > max_properties {
+            return Err(error(
+                pointer,
+                &format!("must not have more than {max_properties} properties"),
+            ));
+        }
+    }
+
+    if let Some(min_properties) = schema.get("minProperties").and_then(|min| min.as_u64()) {
+        if length < min_properties {
+            return Err(error(
+                pointer,
+                &format!("must have at least {min_properties} properties"),
+            ));
+        }
+    }
+
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(|name| name.as_str()) {
+            if !properties.contains_key(name) {
+                return Err(error(&pointer_push(pointer, name), "is required"));
+            }
+        }
+    }
+
+    let schema_properties = schema
+        .get("properties")
+        .and_then(|schema_properties| schema_properties.as_object());
+
+    for (name, property) in properties {
+        let property_pointer = pointer_push(pointer, name);
+
+        let subschema =
+            match schema_properties.and_then(|schema_properties| schema_properties.get(name)) {
+                Some(subschema) => Some(subschema),
+                None => schema.get("additionalProperties"),
+            };
+
+        if let Some(subschema) = subschema {
+            validate_subschema(root, subschema, property, &property_pointer, depth + 1)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_combinations(
+    root: &Value,
+    schema: &Map<String, Value>,
+    value: &Value,
+    pointer: &str,
+    depth: usize,
+) -> Result<(), SchemaError> {
+    let subschemas = |keyword: &str| {
+        schema
+            .get(keyword)
+            .and_then(|subschemas| subschemas.as_array())
+    };
+
+    let is_valid =
+        |subschema: &Value| validate_subschema(root, subschema, value, pointer, depth + 1).is_ok();
+
+    if let Some(subschemas) = subschemas("allOf") {
+        for subschema in subschemas {
+            validate_subschema(root, subschema, value, pointer, depth + 1)?;
+        }
+    }
+
+    if let Some(subschemas) = subschemas("anyOf") {
+        if !subschemas.iter().any(is_valid) {
+            return Err(error(
+                pointer,
+                "must match at least one schema of \"anyOf\"",
+            ));
+        }
+    }
+
+    if let Some(subschemas) = subschemas("oneOf") {
+        if subschemas
+            .iter()
+            .filter(|subschema| is_valid(subschema))
+            .count()
+            != 1
+        {
+            return Err(error(pointer, "must match exactly one schema of \"oneOf\""));
+        }
+    }
+
+    if let Some(subschema) = schema.get("not") {
+        if is_valid(subschema) {
+            return Err(error(pointer, "must not match the schema of \"not\""));
+        }
+    }
+
+    Ok(())
+}
+
+fn error(pointer: &str, message: &str) -> SchemaError {
+    SchemaError {
+        pointer: pointer.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string", "minLength": 1, "maxLength": 10 },
+                "rating": { "type": "integer", "minimum": 0, "maximum": 5 },
+                "tags": {
+                    "type": "array",
+                    "items": { "enum": ["news", "blog"] },
+                    "uniqueItems": true
+                },
+                "author": { "$ref": "#/$defs/author" }
+            },
+            "required": ["title"],
+            "additionalProperties": false,
+            "$defs": {
+                "author": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "email": { "type": ["string", "null"] }
+                    },
+                    "required": ["name"]
+                }
+            }
+        })
+    }
+
+    fn pointer(data: Value) -> String {
+        validate_data(&schema(), &data).unwrap_err().pointer
+    }
+
+    #[test]
+    fn accepts_valid_data() {
+        let data = json!({
+            "title": "Hello",
+            "rating": 4,
+            "tags": ["news", "blog"],
+            "author": { "name": "Ada", "email": null }
+        });
+
+        assert_eq!(validate_data(&schema(), &data), Ok(()));
+    }
+
+    #[test]
+    fn points_to_invalid_values() {
+        assert_eq!(pointer(json!([])), "");
+        assert_eq!(pointer(json!({})), "/title");
+        assert_eq!(pointer(json!({ "title": "" })), "/title");
+        assert_eq!(pointer(json!({ "title": "Hi", "rating": 4.5 })), "/rating");
+        assert_eq!(pointer(json!({ "title": "Hi", "rating": 6 })), "/rating");
+        assert_eq!(
+            pointer(json!({ "title": "Hi", "tags": ["news", "news"] })),
+            "/tags/1"
+        );
+        assert_eq!(
+            pointer(json!({ "title": "Hi", "tags": ["news", "other"] })),
+            "/tags/1"
+        );
+        assert_eq!(
+            pointer(json!({ "title": "Hi", "author": {} })),
+            "/author/name"
+        );
+        assert_eq!(pointer(json!({ "title": "Hi", "a/b": true })), "/a~1b");
+    }
+
+    #[test]
+    fn validates_combinations() {
+        let schema = json!({
+            "oneOf": [{ "type": "integer" }, { "type": "number", "minimum": 10 }],
+            "not": { "const": 42 }
+        });
+
+        assert!(validate_data(&schema, &json!(1)).is_ok());
+        assert!(validate_data(&schema, &json!(10.5)).is_ok());
+        assert!(validate_data(&schema, &json!(12)).is_err());
+        assert!(validate_data(&schema, &json!(1.5)).is_err());
+    }
+
+    #[test]
+    fn stops_recursive_references() {
+        let schema =
+            json!({ "$defs": { "loop": { "$ref": "#/$defs/loop" } }, "$ref": "#/$defs/loop" });
+
+        assert!(validate_data(&schema, &json!(null)).is_err());
+    }
+}
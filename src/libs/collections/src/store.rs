@@ -1,7 +1,9 @@
 use crate::assert::rules::{
-    assert_memory, assert_mutable_permissions, assert_storage_reserved_collection,
-    assert_system_collection_delete_permission, assert_system_collection_set_permission,
-    assert_write_version,
+    assert_default_ttl, assert_indexes, assert_json_schema, assert_max_revisions, assert_memory,
+    assert_mutable_permissions, assert_permissions, assert_storage_default_ttl,
+    assert_storage_indexes, assert_storage_json_schema, assert_storage_reserved_collection,
+    assert_storage_revisions, assert_storage_trash, assert_system_collection_delete_permission,
+    assert_system_collection_set_permission, assert_trash_retention, assert_write_version,
 };
 use crate::constants::core::SYS_COLLECTION_PREFIX;
 use crate::types::core::CollectionKey;
@@ -34,8 +36,20 @@ pub fn set_rule(
 
     if storage_checks {
         assert_storage_reserved_collection(&collection, rules)?;
+        assert_storage_indexes(&user_rule)?;
+        assert_storage_default_ttl(&user_rule)?;
+        assert_storage_revisions(&user_rule)?;
+        assert_storage_trash(&user_rule)?;
+        assert_storage_json_schema(&user_rule)?;
     }
 
+    assert_indexes(&user_rule)?;
+    assert_default_ttl(&user_rule)?;
+    assert_max_revisions(&user_rule)?;
+    assert_trash_retention(&user_rule)?;
+    assert_json_schema(&user_rule)?;
+    assert_permissions(&user_rule)?;
+
     assert_memory(current_rule, &user_rule.memory)?;
     assert_mutable_permissions(current_rule, &user_rule)?;
 
@@ -10,7 +10,7 @@ pub mod core {
 
 pub mod rules {
     use crate::types::core::CollectionKey;
-    use candid::CandidType;
+    use candid::{CandidType, Principal};
     use junobuild_shared::memory::serializers::deserialize_default_as_true;
     use junobuild_shared::rate::types::RateConfig;
     use junobuild_shared::types::state::Timestamp;
@@ -20,6 +20,12 @@ pub mod rules {
 
     pub type Rules = HashMap<CollectionKey, Rule>;
 
+    /// A dotted JSON path in the data of the documents - e.g. `status` or `author.name`.
+    pub type IndexPath = String;
+
+    /// The name of a role granted to users - e.g. `admin` or `team`.
+    pub type RoleName = String;
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct Rule {
         pub read: Permission,
@@ -34,6 +40,13 @@ pub mod rules {
         pub updated_at: Timestamp,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub indexes: Option<Vec<IndexPath>>,
+        pub default_ttl_ns: Option<u64>,
+        pub max_revisions: Option<u32>,
+        pub trash_retention_ns: Option<u64>,
+        pub max_items_per_user: Option<u32>,
+        pub max_bytes_per_user: Option<u64>,
+        pub json_schema: Option<String>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Default, Clone, PartialEq)]
@@ -56,12 +69,16 @@ pub mod rules {
         Managed,
         // The controllers - and only these - can rule the document
         Controllers,
+        // The listed principals - and only these - can rule the document
+        Principals(Vec<Principal>),
+        // The users granted at least one of the listed roles - and only these - can rule the document
+        Roles(Vec<RoleName>),
     }
 }
 
 pub mod interface {
     use crate::types::core::CollectionKey;
-    use crate::types::rules::{Memory, Permission, Rule};
+    use crate::types::rules::{IndexPath, Memory, Permission, Rule};
     use candid::CandidType;
     use junobuild_shared::rate::types::RateConfig;
     use junobuild_shared::types::state::Version;
@@ -78,6 +95,13 @@ pub mod interface {
         pub max_changes_per_user: Option<u32>,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub indexes: Option<Vec<IndexPath>>,
+        pub default_ttl_ns: Option<u64>,
+        pub max_revisions: Option<u32>,
+        pub trash_retention_ns: Option<u64>,
+        pub max_items_per_user: Option<u32>,
+        pub max_bytes_per_user: Option<u64>,
+        pub json_schema: Option<String>,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
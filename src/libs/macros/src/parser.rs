@@ -24,6 +24,7 @@ pub enum Hook {
     OnDeleteAsset,
     OnDeleteManyAssets,
     OnDeleteFilteredAssets,
+    OnTransferAssetOwnership,
     OnInit,
     OnInitSync,
     OnPostUpgrade,
@@ -48,6 +49,7 @@ fn map_hook_name(hook: Hook) -> String {
         Hook::OnDeleteAsset => "juno_on_delete_asset".to_string(),
         Hook::OnDeleteManyAssets => "juno_on_delete_many_assets".to_string(),
         Hook::OnDeleteFilteredAssets => "juno_on_delete_filtered_assets".to_string(),
+        Hook::OnTransferAssetOwnership => "juno_on_transfer_asset_ownership".to_string(),
         Hook::OnInit => "juno_on_init".to_string(),
         Hook::OnPostUpgrade => "juno_on_post_upgrade".to_string(),
         Hook::OnInitSync => "juno_on_init_sync".to_string(),
@@ -73,6 +75,9 @@ fn map_hook_collections(hook: Hook) -> Option<String> {
         Hook::OnDeleteFilteredAssets => {
             Some("juno_on_delete_filtered_assets_collections".to_string())
         }
+        Hook::OnTransferAssetOwnership => {
+            Some("juno_on_transfer_asset_ownership_collections".to_string())
+        }
         Hook::AssertSetDoc => Some("juno_assert_set_doc_collections".to_string()),
         Hook::AssertDeleteDoc => Some("juno_assert_delete_doc_collections".to_string()),
         Hook::AssertUploadAsset => Some("juno_assert_upload_asset_collections".to_string()),
@@ -92,6 +97,7 @@ fn map_hook_type(hook: &Hook) -> Option<String> {
         Hook::OnDeleteAsset => Some("OnDeleteAssetContext".to_string()),
         Hook::OnDeleteManyAssets => Some("OnDeleteManyAssetsContext".to_string()),
         Hook::OnDeleteFilteredAssets => Some("OnDeleteFilteredAssetsContext".to_string()),
+        Hook::OnTransferAssetOwnership => Some("OnTransferAssetOwnershipContext".to_string()),
         Hook::AssertSetDoc => Some("AssertSetDocContext".to_string()),
         Hook::AssertDeleteDoc => Some("AssertDeleteDocContext".to_string()),
         Hook::AssertUploadAsset => Some("AssertUploadAssetContext".to_string()),
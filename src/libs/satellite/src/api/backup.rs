@@ -0,0 +1,16 @@
+use crate::backup::store::{export_collection_store, import_collection_store};
+use crate::backup::types::interface::{
+    ExportCollectionArgs, ExportCollectionResult, ImportCollectionArgs, ImportCollectionResult,
+};
+use crate::caller;
+use junobuild_shared::ic::UnwrapOrTrap;
+
+pub fn export_collection(args: ExportCollectionArgs) -> ExportCollectionResult {
+    export_collection_store(&args).unwrap_or_trap()
+}
+
+pub fn import_collection(args: ImportCollectionArgs) -> ImportCollectionResult {
+    let caller = caller();
+
+    import_collection_store(caller, &args).unwrap_or_trap()
+}
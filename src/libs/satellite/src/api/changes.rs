@@ -0,0 +1,16 @@
+use crate::caller;
+use crate::changes::store::list_changes_store;
+use crate::changes::types::interface::ListChangesResults;
+use crate::changes::types::state::ChangeSequence;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::ic::UnwrapOrTrap;
+
+pub fn list_changes(
+    since: ChangeSequence,
+    collections: Option<Vec<CollectionKey>>,
+    limit: Option<usize>,
+) -> ListChangesResults {
+    let caller = caller();
+
+    list_changes_store(caller, since, &collections, limit).unwrap_or_trap()
+}
@@ -10,6 +10,11 @@ use crate::db::store::{
 use crate::db::types::config::DbConfig;
 use crate::db::types::interface::SetDbConfig;
 use crate::types::interface::Config;
+use crate::ws::store::{
+    get_config_store as get_ws_config_store, set_config_store as set_ws_config_store,
+};
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::SetWsConfig;
 use junobuild_auth::state::types::config::AuthenticationConfig;
 use junobuild_auth::state::types::interface::SetAuthenticationConfig;
 use junobuild_shared::ic::UnwrapOrTrap;
@@ -24,11 +29,13 @@ pub fn get_config() -> Config {
     let storage = get_storage_config_store();
     let db = get_db_config_store();
     let authentication = get_auth_config_store();
+    let ws = get_ws_config_store();
 
     Config {
         storage,
         db,
         authentication,
+        ws,
     }
 }
 
@@ -56,6 +63,18 @@ pub fn get_db_config() -> Option<DbConfig> {
     get_db_config_store()
 }
 
+// ---------------------------------------------------------
+// WebSocket config
+// ---------------------------------------------------------
+
+pub fn set_ws_config(config: SetWsConfig) -> WsConfig {
+    set_ws_config_store(&config).unwrap_or_trap()
+}
+
+pub fn get_ws_config() -> Option<WsConfig> {
+    get_ws_config_store()
+}
+
 // ---------------------------------------------------------
 // Storage config
 // ---------------------------------------------------------
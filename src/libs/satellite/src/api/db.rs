@@ -1,14 +1,23 @@
+use crate::db::store::internal_delete_collection_docs_store;
 use crate::hooks::db::{
     invoke_on_delete_doc, invoke_on_delete_filtered_docs, invoke_on_delete_many_docs,
     invoke_on_set_doc, invoke_on_set_many_docs,
 };
+use crate::types::interface::TransferOwnership;
 use crate::user::internal_hooks::{
     on_delete_many_users, on_delete_user, on_set_many_users, on_set_user,
 };
+use crate::ws::notify::{
+    notify_delete_doc, notify_delete_many_docs, notify_set_doc, notify_set_many_docs,
+};
 use crate::{
-    caller, count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_doc_store, list_docs_store, set_doc_store, DelDoc, Doc,
-    DocContext, DocUpsert, SetDoc,
+    apply_docs_batch_store, caller, count_collection_docs_store, count_docs_store,
+    delete_doc_store, delete_filtered_docs_store, get_doc_access_store, get_doc_store,
+    list_doc_revisions_store, list_docs_store, list_shared_docs_store, list_trashed_docs_store,
+    patch_doc_store, restore_doc_revision_store, restore_doc_store, set_doc_access_store,
+    set_doc_store, transfer_doc_ownership_store, DelDoc, Doc, DocAccess, DocContext, DocRevision,
+    DocUpsert, DocsBatchChanges, DocsBatchError, DocsBatchOperation, DocsBatchOperationResult,
+    PatchDoc, RestoreDocRevision, SetDoc, SetDocAccess,
 };
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::ic::UnwrapOrTrap;
@@ -24,6 +33,66 @@ pub fn set_doc(collection: CollectionKey, key: Key, doc: SetDoc) -> Doc {
 
     invoke_on_set_doc(&caller, &doc);
 
+    notify_set_doc(&caller, &doc);
+
+    doc.data.after
+}
+
+pub fn patch_doc(collection: CollectionKey, key: Key, doc: PatchDoc) -> Doc {
+    let caller = caller();
+
+    let doc = patch_doc_store(caller, collection, key, doc).unwrap_or_trap();
+
+    on_set_user(&doc).unwrap_or_trap();
+
+    invoke_on_set_doc(&caller, &doc);
+
+    notify_set_doc(&caller, &doc);
+
+    doc.data.after
+}
+
+pub fn list_doc_revisions(
+    collection: CollectionKey,
+    key: Key,
+    filter: ListParams,
+) -> ListResults<DocRevision> {
+    let caller = caller();
+
+    list_doc_revisions_store(caller, collection, key, &filter).unwrap_or_trap()
+}
+
+pub fn restore_doc_revision(collection: CollectionKey, key: Key, doc: RestoreDocRevision) -> Doc {
+    let caller = caller();
+
+    let doc = restore_doc_revision_store(caller, collection, key, doc).unwrap_or_trap();
+
+    on_set_user(&doc).unwrap_or_trap();
+
+    invoke_on_set_doc(&caller, &doc);
+
+    notify_set_doc(&caller, &doc);
+
+    doc.data.after
+}
+
+pub fn list_trashed_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
+    let caller = caller();
+
+    list_trashed_docs_store(caller, collection, &filter).unwrap_or_trap()
+}
+
+pub fn restore_doc(collection: CollectionKey, key: Key) -> Doc {
+    let caller = caller();
+
+    let doc = restore_doc_store(caller, collection, key).unwrap_or_trap();
+
+    on_set_user(&doc).unwrap_or_trap();
+
+    invoke_on_set_doc(&caller, &doc);
+
+    notify_set_doc(&caller, &doc);
+
     doc.data.after
 }
 
@@ -41,6 +110,8 @@ pub fn del_doc(collection: CollectionKey, key: Key, doc: DelDoc) {
     on_delete_user(&deleted_doc).unwrap_or_trap();
 
     invoke_on_delete_doc(&caller, &deleted_doc);
+
+    notify_delete_doc(&caller, &deleted_doc);
 }
 
 pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
@@ -49,6 +120,41 @@ pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<D
     list_docs_store(caller, collection, &filter).unwrap_or_trap()
 }
 
+pub fn list_shared_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
+    let caller = caller();
+
+    list_shared_docs_store(caller, collection, &filter).unwrap_or_trap()
+}
+
+pub fn set_doc_access(collection: CollectionKey, key: Key, access: SetDocAccess) -> DocAccess {
+    let caller = caller();
+
+    set_doc_access_store(caller, collection, key, access).unwrap_or_trap()
+}
+
+pub fn transfer_doc_ownership(
+    collection: CollectionKey,
+    key: Key,
+    value: TransferOwnership,
+) -> Doc {
+    let caller = caller();
+
+    let doc = transfer_doc_ownership_store(caller, collection, key, value).unwrap_or_trap();
+
+    // The hooks receive the document before and after the transfer - i.e. with its previous and new owner.
+    invoke_on_set_doc(&caller, &doc);
+
+    notify_set_doc(&caller, &doc);
+
+    doc.data.after
+}
+
+pub fn get_doc_access(collection: CollectionKey, key: Key) -> Option<DocAccess> {
+    let caller = caller();
+
+    get_doc_access_store(caller, collection, key).unwrap_or_trap()
+}
+
 pub fn count_docs(collection: CollectionKey, filter: ListParams) -> usize {
     let caller = caller();
 
@@ -82,6 +188,8 @@ pub fn set_many_docs(docs: Vec<(CollectionKey, Key, SetDoc)>) -> Vec<(Key, Doc)>
 
     invoke_on_set_many_docs(&caller, &hook_payload);
 
+    notify_set_many_docs(&caller, &hook_payload);
+
     results
 }
 
@@ -98,6 +206,31 @@ pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
     on_delete_many_users(&results).unwrap_or_trap();
 
     invoke_on_delete_many_docs(&caller, &results);
+
+    notify_delete_many_docs(&caller, &results);
+}
+
+pub fn apply_docs_batch(
+    operations: Vec<DocsBatchOperation>,
+) -> Result<Vec<DocsBatchOperationResult>, DocsBatchError> {
+    let caller = caller();
+
+    let DocsBatchChanges {
+        results,
+        set_docs,
+        deleted_docs,
+    } = apply_docs_batch_store(caller, operations)?;
+
+    on_set_many_users(&set_docs).unwrap_or_trap();
+    on_delete_many_users(&deleted_docs).unwrap_or_trap();
+
+    invoke_on_set_many_docs(&caller, &set_docs);
+    invoke_on_delete_many_docs(&caller, &deleted_docs);
+
+    notify_set_many_docs(&caller, &set_docs);
+    notify_delete_many_docs(&caller, &deleted_docs);
+
+    Ok(results)
 }
 
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
@@ -106,10 +239,16 @@ pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
     let results = delete_filtered_docs_store(caller, collection, &filter).unwrap_or_trap();
 
     invoke_on_delete_filtered_docs(&caller, &results);
+
+    notify_delete_many_docs(&caller, &results);
 }
 
 pub fn del_docs(collection: CollectionKey) {
-    delete_docs_store(&collection).unwrap_or_trap();
+    let caller = caller();
+
+    let results = internal_delete_collection_docs_store(&collection).unwrap_or_trap();
+
+    notify_delete_many_docs(&caller, &results);
 }
 
 pub fn count_collection_docs(collection: CollectionKey) -> usize {
@@ -1,8 +1,13 @@
 pub mod auth;
+pub mod backup;
 pub mod cdn;
+pub mod changes;
 pub mod config;
 pub mod controllers;
 pub mod db;
 pub mod http;
 pub mod rules;
 pub mod storage;
+pub mod usage;
+pub mod user;
+pub mod ws;
@@ -1,14 +1,16 @@
+use crate::rules::migrate_memory::migrate_collection_memory as migrate_memory;
 use crate::rules::store::{
     del_rule_db, del_rule_storage, get_rule_db, get_rule_storage, list_rules_db,
     list_rules_storage, set_rule_db, set_rule_storage,
 };
 use crate::rules::switch_memory::switch_storage_memory;
+use crate::rules::types::state::CollectionMigration;
 use crate::types::state::CollectionType;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{
     DelRule, ListRulesParams, ListRulesResults, SetRule,
 };
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::ic::UnwrapOrTrap;
 
 pub fn get_rule(collection_type: &CollectionType, collection: &CollectionKey) -> Option<Rule> {
@@ -42,3 +44,11 @@ pub fn del_rule(collection_type: CollectionType, collection: CollectionKey, rule
 pub fn switch_storage_system_memory() {
     switch_storage_memory().unwrap_or_trap()
 }
+
+pub fn migrate_collection_memory(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    memory: &Memory,
+) -> CollectionMigration {
+    migrate_memory(collection_type, collection, memory).unwrap_or_trap()
+}
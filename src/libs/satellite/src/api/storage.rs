@@ -1,12 +1,17 @@
-use crate::assets::storage::store::{commit_batch_store, create_batch_store, create_chunk_store};
+use crate::assets::storage::store::{
+    commit_batch_store, create_batch_store, create_chunk_store,
+    internal_delete_collection_assets_store,
+};
 use crate::hooks::storage::{
     invoke_on_delete_asset, invoke_on_delete_filtered_assets, invoke_on_delete_many_assets,
-    invoke_upload_asset,
+    invoke_on_transfer_asset_ownership, invoke_upload_asset,
 };
+use crate::types::interface::TransferOwnership;
+use crate::ws::notify::{notify_delete_asset, notify_delete_many_assets, notify_upload_asset};
 use crate::{
     caller, count_assets_store, count_collection_assets_store, delete_asset_store,
-    delete_assets_store, delete_filtered_assets_store, get_asset_store, list_assets_store,
-    set_asset_token_store,
+    delete_filtered_assets_store, get_asset_store, list_assets_store, set_asset_token_store,
+    transfer_asset_ownership_store,
 };
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::ic::UnwrapOrTrap;
@@ -39,6 +44,8 @@ pub fn commit_asset_upload(commit: CommitBatch) {
     let asset = commit_batch_store(caller, commit).unwrap_or_trap();
 
     invoke_upload_asset(&caller, &asset);
+
+    notify_upload_asset(&caller, &asset);
 }
 
 pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults<AssetNoContent> {
@@ -58,7 +65,9 @@ pub fn del_asset(collection: CollectionKey, full_path: FullPath) {
 
     let asset = delete_asset_store(caller, &collection, full_path).unwrap_or_trap();
 
-    invoke_on_delete_asset(&caller, &asset)
+    invoke_on_delete_asset(&caller, &asset);
+
+    notify_delete_asset(&caller, &asset);
 }
 
 pub fn del_many_assets(assets: Vec<(CollectionKey, String)>) {
@@ -72,6 +81,8 @@ pub fn del_many_assets(assets: Vec<(CollectionKey, String)>) {
     }
 
     invoke_on_delete_many_assets(&caller, &results);
+
+    notify_delete_many_assets(&caller, &results);
 }
 
 pub fn del_filtered_assets(collection: CollectionKey, filter: ListParams) {
@@ -80,10 +91,16 @@ pub fn del_filtered_assets(collection: CollectionKey, filter: ListParams) {
     let results = delete_filtered_assets_store(caller, collection, &filter).unwrap_or_trap();
 
     invoke_on_delete_filtered_assets(&caller, &results);
+
+    notify_delete_many_assets(&caller, &results);
 }
 
 pub fn del_assets(collection: CollectionKey) {
-    delete_assets_store(&collection).unwrap_or_trap();
+    let caller = caller();
+
+    let results = internal_delete_collection_assets_store(&collection).unwrap_or_trap();
+
+    notify_delete_many_assets(&caller, &results);
 }
 
 pub fn count_collection_assets(collection: CollectionKey) -> usize {
@@ -118,3 +135,20 @@ pub fn set_asset_token(collection: CollectionKey, full_path: FullPath, token: As
     // No hook currently available for this function for simplicity reasons,
     // but not against adding one if it proves useful.
 }
+
+pub fn transfer_asset_ownership(
+    collection: CollectionKey,
+    full_path: FullPath,
+    value: TransferOwnership,
+) -> AssetNoContent {
+    let caller = caller();
+
+    let asset =
+        transfer_asset_ownership_store(caller, &collection, &full_path, &value).unwrap_or_trap();
+
+    invoke_on_transfer_asset_ownership(&caller, &asset);
+
+    notify_upload_asset(&caller, &asset.after);
+
+    AssetNoContent::from(&asset.after)
+}
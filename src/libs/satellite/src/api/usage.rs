@@ -0,0 +1,15 @@
+use crate::caller;
+use crate::types::state::CollectionType;
+use crate::user::usage::store::get_usage;
+use crate::user::usage::types::state::UserUsageData;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::ic::UnwrapOrTrap;
+
+pub fn get_user_usage(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+) -> Option<UserUsageData> {
+    let caller = caller();
+
+    get_usage(collection, collection_type, &caller).unwrap_or_trap()
+}
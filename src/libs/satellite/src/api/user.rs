@@ -0,0 +1,28 @@
+use crate::caller;
+use crate::hooks::db::invoke_on_delete_many_docs;
+use crate::hooks::storage::invoke_on_delete_many_assets;
+use crate::user::purge::store::purge_user_data_store;
+use crate::user::purge::types::state::UserPurge;
+use crate::ws::notify::{notify_delete_many_assets, notify_delete_many_docs};
+use junobuild_shared::ic::UnwrapOrTrap;
+use junobuild_shared::types::state::UserId;
+
+pub fn purge_user_data(user_id: &UserId) -> UserPurge {
+    let caller = caller();
+
+    let batch = purge_user_data_store(user_id).unwrap_or_trap();
+
+    if !batch.docs.is_empty() {
+        invoke_on_delete_many_docs(&caller, &batch.docs);
+
+        notify_delete_many_docs(&caller, &batch.docs);
+    }
+
+    if !batch.assets.is_empty() {
+        invoke_on_delete_many_assets(&caller, &batch.assets);
+
+        notify_delete_many_assets(&caller, &batch.assets);
+    }
+
+    batch.purge
+}
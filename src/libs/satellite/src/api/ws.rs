@@ -0,0 +1,21 @@
+use crate::caller;
+use crate::ws::store::{assert_gateway_store, assert_open_store};
+use ic_websocket_cdk::{
+    CanisterWsGetMessagesArguments, CanisterWsGetMessagesResult, CanisterWsOpenArguments,
+    CanisterWsOpenResult,
+};
+
+pub fn ws_open(args: CanisterWsOpenArguments) -> CanisterWsOpenResult {
+    assert_gateway_store(&args.gateway_principal)?;
+    assert_open_store(caller())?;
+
+    ic_websocket_cdk::ws_open(args)
+}
+
+pub fn ws_get_messages(args: CanisterWsGetMessagesArguments) -> CanisterWsGetMessagesResult {
+    let caller = caller();
+
+    assert_gateway_store(&caller)?;
+
+    ic_websocket_cdk::ws_get_messages(args)
+}
@@ -1,4 +1,5 @@
 use crate::assets::constants::CDN_JUNO_RELEASES_COLLECTION_KEY;
+use crate::user::role::assert::has_role;
 use candid::Principal;
 use junobuild_collections::assert::stores::{
     assert_create_permission, assert_create_permission_with, assert_permission,
@@ -37,10 +38,16 @@ pub fn assert_cdn_create_permission(
 ) -> bool {
     // Through a proposal, any controller - including "Submit" - can provide an asset for the #_juno or #dapp collections.
     if collection == CDN_JUNO_RELEASES_COLLECTION_KEY || collection == COLLECTION_ASSET_KEY {
-        return assert_create_permission_with(permission, caller, controllers, is_controller);
+        return assert_create_permission_with(
+            permission,
+            caller,
+            controllers,
+            is_controller,
+            has_role,
+        );
     }
 
-    assert_create_permission(permission, caller, controllers)
+    assert_create_permission(permission, caller, controllers, has_role)
 }
 
 pub fn assert_cdn_update_permission(
@@ -52,8 +59,15 @@ pub fn assert_cdn_update_permission(
 ) -> bool {
     // Through a proposal, any controller - including "Submit" - can provide an update of an asset for the #_juno or #dapp collections.
     if collection == CDN_JUNO_RELEASES_COLLECTION_KEY || collection == COLLECTION_ASSET_KEY {
-        return assert_permission_with(permission, owner, caller, controllers, is_controller);
+        return assert_permission_with(
+            permission,
+            owner,
+            caller,
+            controllers,
+            is_controller,
+            has_role,
+        );
     }
 
-    assert_permission(permission, owner, caller, controllers)
+    assert_permission(permission, owner, caller, controllers, has_role)
 }
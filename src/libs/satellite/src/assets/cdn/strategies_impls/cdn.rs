@@ -123,8 +123,8 @@ impl CdnCommitAssetsStrategy for CdnCommitAssets {
         full_path: &FullPath,
         asset: &Asset,
         rule: &Rule,
-    ) {
-        unsafe_insert_asset(collection, full_path, asset, rule);
+    ) -> Result<(), String> {
+        unsafe_insert_asset(collection, full_path, asset, rule)
     }
 
     fn insert_asset_encoding(
@@ -4,6 +4,7 @@ use crate::assets::cdn::assert::{
     assert_cdn_write_on_dapp_collection, assert_cdn_write_on_system_collection,
 };
 use crate::assets::cdn::strategies_impls::cdn::CdnStable;
+use crate::user::role::assert::has_role;
 use candid::Principal;
 use junobuild_cdn::storage::errors::{
     JUNO_CDN_STORAGE_ERROR_CANNOT_GET_ASSET_UNKNOWN_REFERENCE_ID,
@@ -78,7 +79,7 @@ impl StorageAssertionsStrategy for CdnStorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_permission(permission, owner, caller, controllers)
+        assert_permission(permission, owner, caller, controllers, has_role)
     }
 
     fn invoke_assert_upload_asset(
@@ -100,6 +101,20 @@ impl StorageAssertionsStrategy for CdnStorageAssertions {
         // No pre-assertions when using CDN as access are granted to controllers only.
         Ok(())
     }
+
+    fn assert_storage_items_usage(
+        &self,
+        _caller: &Principal,
+        _controllers: &Controllers,
+        _collection: &CollectionKey,
+        _rule: &Rule,
+        _current: &Option<Asset>,
+        _encoding_type: &EncodingType,
+        _encoding: &AssetEncoding,
+    ) -> Result<(), String> {
+        // No pre-assertions when using CDN as access are granted to controllers only.
+        Ok(())
+    }
 }
 
 pub struct CdnStorageUpload;
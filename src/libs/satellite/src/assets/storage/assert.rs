@@ -1,9 +1,14 @@
 use crate::auth::assert::assert_caller_is_allowed;
 use crate::hooks::storage::invoke_assert_delete_asset;
+use crate::types::interface::TransferOwnership;
+use crate::types::state::CollectionType;
 use crate::types::store::{AssertContext, StoreContext};
 use crate::user::core::assert::{assert_user_is_not_banned, is_known_user};
-use crate::user::usage::assert::increment_and_assert_storage_usage;
+use crate::user::role::assert::has_role;
+use crate::user::usage::assert::{assert_transfer_items_usage, increment_and_assert_storage_usage};
+use crate::user::usage::types::state::UserUsageItem;
 use candid::Principal;
+use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::assert::stores::{
     assert_permission, assert_permission_with, public_permission,
 };
@@ -12,10 +17,13 @@ use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::segments::controllers::{controller_can_write, is_controller};
+use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::state::Controllers;
+use junobuild_shared::utils::principal_not_anonymous;
 use junobuild_storage::errors::{
     JUNO_STORAGE_ERROR_ASSET_NOT_FOUND, JUNO_STORAGE_ERROR_CANNOT_READ_ASSET,
-    JUNO_STORAGE_ERROR_UPLOAD_NOT_ALLOWED,
+    JUNO_STORAGE_ERROR_INVALID_OWNER, JUNO_STORAGE_ERROR_MATCHER_NOT_SUPPORTED,
+    JUNO_STORAGE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED, JUNO_STORAGE_ERROR_UPLOAD_NOT_ALLOWED,
 };
 use junobuild_storage::runtime::increment_and_assert_rate as increment_and_assert_rate_runtime;
 use junobuild_storage::types::config::StorageConfig;
@@ -49,10 +57,27 @@ pub fn assert_list_assets(
         auth_config,
         rule: _,
     }: &AssertContext,
+    filters: &ListParams,
 ) -> Result<(), String> {
     assert_caller_is_allowed(caller, controllers, auth_config)?;
     assert_user_is_not_banned(caller, controllers)?;
 
+    assert_list_matcher(filters)?;
+
+    Ok(())
+}
+
+// Assets have no data, therefore they can be matched neither with an index nor with data predicates.
+fn assert_list_matcher(filters: &ListParams) -> Result<(), String> {
+    let unsupported = filters
+        .matcher
+        .as_ref()
+        .is_some_and(|matcher| matcher.index.is_some() || matcher.data.is_some());
+
+    if unsupported {
+        return Err(JUNO_STORAGE_ERROR_MATCHER_NOT_SUPPORTED.to_string());
+    }
+
     Ok(())
 }
 
@@ -67,10 +92,17 @@ pub fn assert_storage_list_permission(
     // because when used with the CLI, it needs to know which assets are currently deployed in order to only submit those
     // that are different.
     if collection == COLLECTION_ASSET_KEY {
-        return assert_permission_with(permission, owner, caller, controllers, is_controller);
+        return assert_permission_with(
+            permission,
+            owner,
+            caller,
+            controllers,
+            is_controller,
+            has_role,
+        );
     }
 
-    assert_permission(permission, owner, caller, controllers)
+    assert_permission(permission, owner, caller, controllers, has_role)
 }
 
 pub fn assert_create_batch(
@@ -104,6 +136,7 @@ pub fn assert_write_asset(
         asset.key.owner,
         context.caller,
         context.controllers,
+        has_role,
     ) {
         return Err(JUNO_STORAGE_ERROR_ASSET_NOT_FOUND.to_string());
     }
@@ -120,6 +153,59 @@ pub fn assert_write_asset(
     Ok(())
 }
 
+/// The ownership of an asset can be transferred by its owner and the controllers, to a user who
+/// has not reached the quotas of the collection.
+pub fn assert_transfer_asset_ownership(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    &AssertContext { rule, auth_config }: &AssertContext,
+    current_asset: &Asset,
+    value: &TransferOwnership,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    if is_system_collection(collection) {
+        return Err(JUNO_STORAGE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED.to_string());
+    }
+
+    if !assert_permission(
+        &Permission::Managed,
+        current_asset.key.owner,
+        caller,
+        controllers,
+        has_role,
+    ) {
+        return Err(JUNO_STORAGE_ERROR_ASSET_NOT_FOUND.to_string());
+    }
+
+    assert_version(value.version, current_asset.version)?;
+
+    if !principal_not_anonymous(value.owner) {
+        return Err(JUNO_STORAGE_ERROR_INVALID_OWNER.to_string());
+    }
+
+    if value.owner != current_asset.key.owner {
+        assert_transfer_items_usage(
+            value.owner,
+            controllers,
+            collection,
+            &CollectionType::Storage,
+            rule,
+            &UserUsageItem::from_asset(current_asset),
+        )?;
+    }
+
+    increment_and_assert_storage_usage(caller, controllers, collection, rule.max_changes_per_user)?;
+
+    increment_and_assert_rate_runtime(collection, &rule.rate_config)?;
+
+    Ok(())
+}
+
 pub fn assert_delete_asset(context: &StoreContext, asset: &Asset) -> Result<(), String> {
     invoke_assert_delete_asset(&context.caller, asset)
 }
@@ -130,7 +216,7 @@ fn assert_read_permission(
     current_asset: &Asset,
     rule: &Permission,
 ) -> Result<(), String> {
-    if !assert_permission(rule, current_asset.key.owner, caller, controllers) {
+    if !assert_permission(rule, current_asset.key.owner, caller, controllers, has_role) {
         return Err(JUNO_STORAGE_ERROR_CANNOT_READ_ASSET.to_string());
     }
 
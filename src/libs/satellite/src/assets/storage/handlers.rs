@@ -3,6 +3,7 @@ use crate::assets::storage::state::{
 };
 use crate::certification::strategy_impls::StorageCertificate;
 use crate::controllers::store::get_controllers;
+use crate::user::role::assert::has_role;
 use junobuild_collections::assert::stores::assert_permission;
 use junobuild_collections::types::rules::Rule;
 use junobuild_shared::ic::api::id;
@@ -54,7 +55,13 @@ pub fn set_asset_handler(
         // This allows to assert for permission. Useful for collection set as "Private".
         let caller = id();
 
-        if !assert_permission(&rule.write, existing_asset.key.owner, caller, &controllers) {
+        if !assert_permission(
+            &rule.write,
+            existing_asset.key.owner,
+            caller,
+            &controllers,
+            has_role,
+        ) {
             return Err(JUNO_STORAGE_ERROR_SET_NOT_ALLOWED.to_string());
         }
     }
@@ -81,7 +88,7 @@ fn set_asset_handler_impl(
         rule,
     );
 
-    insert_asset(&key.collection, &key.full_path, &asset, rule);
+    insert_asset(&key.collection, &key.full_path, &asset, rule)?;
 
     let config = get_config();
 
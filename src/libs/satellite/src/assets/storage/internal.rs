@@ -1,9 +1,37 @@
-use crate::assets::storage::state::{delete_asset, insert_asset, insert_asset_encoding};
+use crate::assets::storage::state::{
+    copy_assets, delete_asset, delete_assets_from_memory, get_asset, get_assets_from, insert_asset,
+    insert_asset_encoding,
+};
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_storage::types::state::FullPath;
 use junobuild_storage::types::store::{Asset, AssetEncoding};
 
+/// Retrieves an asset directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_asset(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    rule: &Rule,
+) -> Option<Asset> {
+    get_asset(collection, full_path, rule)
+}
+
+/// Retrieves up to `limit` assets ordered by full path, starting at - and including - `start`, directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_assets_from(
+    collection: &CollectionKey,
+    start: &FullPath,
+    limit: usize,
+    rule: &Rule,
+) -> Vec<Asset> {
+    get_assets_from(collection, start, limit, rule)
+}
+
 /// Insert an asset directly into the state.
 ///
 /// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
@@ -13,7 +41,7 @@ pub fn unsafe_insert_asset(
     full_path: &FullPath,
     asset: &Asset,
     rule: &Rule,
-) {
+) -> Result<(), String> {
     insert_asset(collection, full_path, asset, rule)
 }
 
@@ -39,6 +67,33 @@ pub fn unsafe_delete_asset(
     collection: &CollectionKey,
     full_path: &FullPath,
     rule: &Rule,
-) -> Option<Asset> {
+) -> Result<Option<Asset>, String> {
     delete_asset(collection, full_path, rule)
 }
+
+/// Copies the assets ordered by full path, starting at - and including - `start`, with their content to the `target` memory.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_copy_assets(
+    collection: &CollectionKey,
+    start: &FullPath,
+    limit: usize,
+    max_bytes: usize,
+    rule: &Rule,
+    target: &Memory,
+) -> (usize, Option<FullPath>) {
+    copy_assets(collection, start, limit, max_bytes, rule, target)
+}
+
+/// Deletes the assets of a collection, with their content, from the given memory.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_delete_assets_from_memory(
+    collection: &CollectionKey,
+    limit: usize,
+    memory: &Memory,
+) -> usize {
+    delete_assets_from_memory(collection, limit, memory)
+}
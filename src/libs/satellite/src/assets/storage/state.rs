@@ -3,7 +3,10 @@ use crate::assets::storage::types::state::{
     AssetsStable, ContentChunksStable, StableEncodingChunkKey, StableKey,
 };
 use crate::memory::state::STATE;
-use crate::types::state::{StableState, State};
+use crate::rules::store::get_collection_migration;
+use crate::types::state::{CollectionType, StableState, State};
+use crate::user::usage::track::track_storage_usage;
+use crate::user::usage::types::state::UserUsageItem;
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_collections::msg::msg_storage_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
@@ -13,12 +16,14 @@ use junobuild_shared::data::collect::collect_stable_vec;
 use junobuild_shared::memory::serializers::deserialize_from_bytes;
 use junobuild_shared::types::core::{Blob, DomainName};
 use junobuild_shared::types::domain::{CustomDomain, CustomDomains};
+use junobuild_storage::heap_utils::collect_assets_heap;
 use junobuild_storage::stable_utils::insert_asset_encoding_stable;
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::state::{AssetsHeap, FullPath, StorageHeapState};
 use junobuild_storage::types::store::{Asset, AssetEncoding};
 use junobuild_storage::utils::{clone_asset_encoding_content_chunks, insert_encoding_into_asset};
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::ops::RangeBounds;
 // ---------------------------------------------------------
 // Assets
@@ -122,7 +127,79 @@ pub fn insert_asset_encoding(
     asset: &mut Asset,
     rule: &Rule,
 ) {
-    match rule.mem() {
+    insert_asset_encoding_memory(full_path, encoding_type, encoding, asset, &rule.mem())
+}
+
+pub fn insert_asset(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    asset: &Asset,
+    rule: &Rule,
+) -> Result<(), String> {
+    let current_usage = get_asset_usage(collection, full_path, &rule.mem());
+
+    insert_asset_memory(collection, full_path, asset, &rule.mem());
+
+    mirror_insert_asset(collection, full_path, asset, &rule.mem());
+
+    track_storage_usage(
+        collection,
+        full_path,
+        &current_usage,
+        &Some(UserUsageItem::from_asset(asset)),
+    )
+}
+
+pub fn delete_asset(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    rule: &Rule,
+) -> Result<Option<Asset>, String> {
+    let deleted_asset = delete_asset_memory(collection, full_path, &rule.mem());
+
+    mirror_delete_asset(collection, full_path);
+
+    track_storage_usage(
+        collection,
+        full_path,
+        &deleted_asset.as_ref().map(UserUsageItem::from_asset),
+        &None,
+    )?;
+
+    Ok(deleted_asset)
+}
+
+// The heap asset is not cloned given that it contains its content.
+fn get_asset_usage(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    memory: &Memory,
+) -> Option<UserUsageItem> {
+    match memory {
+        Memory::Heap => STATE.with(|state| {
+            state
+                .borrow()
+                .heap
+                .storage
+                .assets
+                .get(full_path)
+                .map(UserUsageItem::from_asset)
+        }),
+        Memory::Stable => STATE
+            .with(|state| get_asset_stable(collection, full_path, &state.borrow().stable.assets))
+            .as_ref()
+            .map(UserUsageItem::from_asset),
+    }
+}
+
+fn insert_asset_encoding_memory(
+    full_path: &FullPath,
+    encoding_type: &str,
+    encoding: &AssetEncoding,
+    asset: &mut Asset,
+    memory: &Memory,
+) {
+    match memory {
         Memory::Heap => insert_encoding_into_asset(encoding_type, encoding, asset),
         Memory::Stable => STATE.with(|state| {
             insert_asset_encoding_stable(
@@ -137,8 +214,13 @@ pub fn insert_asset_encoding(
     }
 }
 
-pub fn insert_asset(collection: &CollectionKey, full_path: &FullPath, asset: &Asset, rule: &Rule) {
-    match rule.mem() {
+fn insert_asset_memory(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    asset: &Asset,
+    memory: &Memory,
+) {
+    match memory {
         Memory::Heap => STATE.with(|state| {
             insert_asset_heap(
                 full_path,
@@ -157,12 +239,12 @@ pub fn insert_asset(collection: &CollectionKey, full_path: &FullPath, asset: &As
     }
 }
 
-pub fn delete_asset(
+fn delete_asset_memory(
     collection: &CollectionKey,
     full_path: &FullPath,
-    rule: &Rule,
+    memory: &Memory,
 ) -> Option<Asset> {
-    match rule.mem() {
+    match memory {
         Memory::Heap => STATE.with(|state| {
             delete_asset_heap(full_path, &mut state.borrow_mut().heap.storage.assets)
         }),
@@ -255,6 +337,49 @@ pub fn count_assets_stable(collection: &CollectionKey, assets: &AssetsStable) ->
     assets.range(filter_assets_range(collection)).count()
 }
 
+/// Returns up to `limit` assets of a collection ordered by full path, starting at - and including - `start`.
+pub fn get_assets_from(
+    collection: &CollectionKey,
+    start: &FullPath,
+    limit: usize,
+    rule: &Rule,
+) -> Vec<Asset> {
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state = state.borrow();
+
+            let mut assets: Vec<(&FullPath, &Asset)> =
+                collect_assets_heap(collection, &state.heap.storage.assets)
+                    .into_iter()
+                    .filter(|(full_path, _)| *full_path >= start)
+                    .collect();
+
+            assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            assets
+                .into_iter()
+                .take(limit)
+                .map(|(_, asset)| asset.clone())
+                .collect()
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let end_key = StableKey {
+                collection: range_collection_end(collection).clone(),
+                full_path: "".to_string(),
+            };
+
+            state
+                .borrow()
+                .stable
+                .assets
+                .range(stable_full_path(collection, start)..end_key)
+                .take(limit)
+                .map(|entry| entry.value().clone())
+                .collect()
+        }),
+    }
+}
+
 fn filter_assets_range(collection: &CollectionKey) -> impl RangeBounds<StableKey> {
     let start_key = StableKey {
         collection: collection.clone(),
@@ -288,6 +413,142 @@ fn stable_encoding_chunk_key(
     }
 }
 
+// ---------------------------------------------------------
+// Migrations
+// ---------------------------------------------------------
+
+/// Copies the assets - with their content - ordered by full path, starting at - and including -
+/// `start`, from the memory of the collection to the `target` memory. At most `limit` assets are
+/// copied and the copy stops once `max_bytes` of content were copied. Returns the number of copied
+/// assets and the full path of the last one.
+pub fn copy_assets(
+    collection: &CollectionKey,
+    start: &FullPath,
+    limit: usize,
+    max_bytes: usize,
+    rule: &Rule,
+    target: &Memory,
+) -> (usize, Option<FullPath>) {
+    let mut copied: usize = 0;
+    let mut copied_bytes: usize = 0;
+    let mut last_full_path: Option<FullPath> = None;
+
+    for asset in get_assets_from(collection, start, limit, rule) {
+        if copied > 0 && copied_bytes >= max_bytes {
+            break;
+        }
+
+        copied_bytes += copy_asset(collection, &asset, &rule.mem(), target);
+        copied += 1;
+        last_full_path = Some(asset.key.full_path.clone());
+    }
+
+    (copied, last_full_path)
+}
+
+/// Deletes the assets of a collection from a memory it no longer uses. The heap is cleared at once,
+/// the stable memory by batches of up to `limit` assets. Returns the number of deleted assets.
+pub fn delete_assets_from_memory(
+    collection: &CollectionKey,
+    limit: usize,
+    memory: &Memory,
+) -> usize {
+    match memory {
+        Memory::Heap => STATE.with(|state| {
+            let assets = &mut state.borrow_mut().heap.storage.assets;
+
+            let length = assets.len();
+            assets.retain(|_, asset| asset.key.collection != *collection);
+
+            length - assets.len()
+        }),
+        Memory::Stable => {
+            let full_paths: Vec<FullPath> = STATE.with(|state| {
+                state
+                    .borrow()
+                    .stable
+                    .assets
+                    .range(filter_assets_range(collection))
+                    .take(limit)
+                    .map(|entry| entry.key().full_path.clone())
+                    .collect()
+            });
+
+            for full_path in &full_paths {
+                delete_asset_memory(collection, full_path, &Memory::Stable);
+            }
+
+            full_paths.len()
+        }
+    }
+}
+
+// While a collection is migrated, the writes on the assets that were already copied are mirrored
+// to the target memory.
+fn mirror_insert_asset(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    asset: &Asset,
+    memory: &Memory,
+) {
+    if let Some(migration) = get_collection_migration(&CollectionType::Storage, collection) {
+        if migration.is_copied(full_path) {
+            copy_asset(collection, asset, memory, &migration.memory);
+        }
+    }
+}
+
+fn mirror_delete_asset(collection: &CollectionKey, full_path: &FullPath) {
+    if let Some(migration) = get_collection_migration(&CollectionType::Storage, collection) {
+        if migration.is_copied(full_path) {
+            delete_asset_memory(collection, full_path, &migration.memory);
+        }
+    }
+}
+
+// Writes an asset, with its content resolved from the source memory, to the target memory and
+// returns the length of the copied content.
+fn copy_asset(
+    collection: &CollectionKey,
+    asset: &Asset,
+    source: &Memory,
+    target: &Memory,
+) -> usize {
+    let full_path = &asset.key.full_path;
+
+    // Chunks of a previous version of the asset are not left behind in stable memory
+    delete_asset_memory(collection, full_path, target);
+
+    let mut copy = Asset {
+        encodings: HashMap::new(),
+        ..asset.clone()
+    };
+
+    let mut copied_bytes: usize = 0;
+
+    for (encoding_type, encoding) in asset.encodings.iter() {
+        let content_chunks: Vec<Blob> = (0..encoding.content_chunks.len())
+            .filter_map(|chunk_index| get_content_chunks(encoding, chunk_index, source))
+            .collect();
+
+        copied_bytes += content_chunks
+            .iter()
+            .map(|chunk| chunk.len())
+            .sum::<usize>();
+
+        let encoding = AssetEncoding {
+            content_chunks,
+            ..encoding.clone()
+        };
+
+        insert_asset_encoding_memory(full_path, encoding_type, &encoding, &mut copy, target);
+    }
+
+    insert_asset_memory(collection, full_path, &copy, target);
+
+    copied_bytes
+}
+
 // ---------------------------------------------------------
 // Rules
 // ---------------------------------------------------------
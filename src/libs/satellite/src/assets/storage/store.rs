@@ -1,6 +1,6 @@
 use crate::assets::storage::assert::{
     assert_create_batch, assert_delete_asset, assert_get_asset, assert_list_assets,
-    assert_set_config, assert_write_asset,
+    assert_set_config, assert_transfer_asset_ownership, assert_write_asset,
 };
 use crate::assets::storage::certified_assets::runtime::init_certified_assets as init_runtime_certified_assets;
 use crate::assets::storage::state::{
@@ -16,6 +16,7 @@ use crate::auth::store::get_config as get_auth_config;
 use crate::certification::strategy_impls::StorageCertificate;
 use crate::controllers::store::get_controllers;
 use crate::memory::state::STATE;
+use crate::types::interface::TransferOwnership;
 use crate::types::store::{AssertContext, StoreContext};
 use candid::Principal;
 use junobuild_collections::msg::msg_storage_collection_not_empty;
@@ -43,7 +44,7 @@ use junobuild_storage::types::interface::{
 };
 use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
 use junobuild_storage::types::state::{AssetAccessToken, FullPath};
-use junobuild_storage::types::store::{Asset, AssetEncoding};
+use junobuild_storage::types::store::{Asset, AssetEncoding, AssetUpsert};
 use junobuild_storage::utils::{
     filter_collection_values, filter_values, get_token_protected_asset, map_asset_no_content,
     should_include_asset_for_deletion,
@@ -128,6 +129,17 @@ pub fn delete_asset_store(
 ///
 /// This function allows you to securely delete multiple assets from a Juno collection's of the Storage.
 pub fn delete_assets_store(collection: &CollectionKey) -> Result<(), String> {
+    internal_delete_collection_assets_store(collection).map(|_| ())
+}
+
+/// Internal variant of `delete_assets_store`.
+///
+/// Deletes every asset of a collection - from the certification as well - and returns the deleted assets.
+///
+/// Useful for notifying the deletion of the assets.
+pub fn internal_delete_collection_assets_store(
+    collection: &CollectionKey,
+) -> Result<Vec<Option<Asset>>, String> {
     let rule = get_state_rule(collection)?;
 
     let full_paths = match rule.mem() {
@@ -343,7 +355,7 @@ fn secure_list_assets_impl(
         auth_config: &auth_config,
     };
 
-    assert_list_assets(context, &assert_context)?;
+    assert_list_assets(context, &assert_context, filters)?;
 
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
@@ -433,7 +445,7 @@ fn delete_asset_impl(
 
             let certificate = &StorageCertificate;
 
-            let deleted = delete_state_asset(context.collection, &full_path, assert_context.rule);
+            let deleted = delete_state_asset(context.collection, &full_path, assert_context.rule)?;
             delete_runtime_certified_asset(&asset, certificate);
 
             // We just removed the rewrite for /404.html in the certification tree therefore if /index.html exists, we want to reintroduce it as rewrite
@@ -456,19 +468,34 @@ fn delete_assets_impl(
     full_paths: &Vec<FullPath>,
     collection: &CollectionKey,
     rule: &Rule,
-) -> Result<(), String> {
+) -> Result<Vec<Option<Asset>>, String> {
+    let mut results: Vec<Option<Asset>> = Vec::new();
+
     for full_path in full_paths {
-        let deleted_asset = delete_state_asset(collection, full_path, rule);
+        let deleted_asset = delete_state_asset(collection, full_path, rule)?;
 
-        match deleted_asset {
-            None => {}
-            Some(deleted_asset) => {
-                delete_runtime_certified_asset(&deleted_asset, &StorageCertificate);
-            }
+        if let Some(deleted_asset) = &deleted_asset {
+            delete_runtime_certified_asset(deleted_asset, &StorageCertificate);
         }
+
+        results.push(deleted_asset);
     }
 
-    Ok(())
+    Ok(results)
+}
+
+/// Internal variant of `delete_assets_store`.
+///
+/// Deletes the assets of the given full paths from a collection and from the certification,
+/// without performing any assertions.
+///
+/// Useful for purging the data owned by a user.
+pub fn internal_delete_assets_store(
+    collection: &CollectionKey,
+    full_paths: &Vec<FullPath>,
+    rule: &Rule,
+) -> Result<Vec<Option<Asset>>, String> {
+    delete_assets_impl(full_paths, collection, rule)
 }
 
 /// Count the number of assets in a collection's store.
@@ -642,7 +669,7 @@ fn set_asset_token_impl(
         full_path,
         &updated_asset,
         assert_context.rule,
-    );
+    )?;
 
     // Protected assets are served with specific certified headers (see token_headers).
     // That is why we update the certification if either those have to be added or removed.
@@ -653,6 +680,82 @@ fn set_asset_token_impl(
     Ok(())
 }
 
+/// Transfer the ownership of an asset in a collection's store.
+///
+/// This function hands over an asset to a new owner, preserving its content, token and creation date.
+/// Only the owner of the asset and the controllers can transfer it. The items and bytes of the asset
+/// are accounted to the new owner in the usage of the collection.
+///
+/// # Parameters
+/// - `caller`: The `Principal` representing the caller initiating the operation.
+/// - `collection`: A reference to the `CollectionKey` representing the collection containing the asset.
+/// - `full_path`: A reference to the `FullPath` identifying the asset to transfer.
+/// - `value`: A reference to the `TransferOwnership` representing the new owner and the expected version.
+///
+/// # Returns
+/// - `Ok(AssetUpsert)`: The asset before and after the transfer, i.e. with its previous and new owner.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn transfer_asset_ownership_store(
+    caller: Principal,
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    value: &TransferOwnership,
+) -> Result<AssetUpsert, String> {
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection,
+    };
+
+    secure_transfer_asset_ownership_impl(&context, full_path, value)
+}
+
+fn secure_transfer_asset_ownership_impl(
+    context: &StoreContext,
+    full_path: &FullPath,
+    value: &TransferOwnership,
+) -> Result<AssetUpsert, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    transfer_asset_ownership_impl(context, &assert_context, full_path, value)
+}
+
+fn transfer_asset_ownership_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    full_path: &FullPath,
+    value: &TransferOwnership,
+) -> Result<AssetUpsert, String> {
+    let asset = get_state_asset(context.collection, full_path, assert_context.rule)
+        .ok_or(JUNO_STORAGE_ERROR_ASSET_NOT_FOUND.to_string())?;
+
+    assert_transfer_asset_ownership(context, assert_context, &asset, value)?;
+
+    let transferred_asset = Asset::transfer(&asset, value.owner);
+
+    // The usage of the asset is moved from its previous owner to the new one. The owner is not part
+    // of the certified headers, therefore the certification remains unchanged.
+    insert_asset(
+        context.collection,
+        full_path,
+        &transferred_asset,
+        assert_context.rule,
+    )?;
+
+    Ok(AssetUpsert {
+        before: Some(asset),
+        after: transferred_asset,
+    })
+}
+
 // ---------------------------------------------------------
 // Upload batch and chunks
 // ---------------------------------------------------------
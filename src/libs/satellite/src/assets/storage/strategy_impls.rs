@@ -6,7 +6,8 @@ use crate::assets::storage::state::{
 };
 use crate::assets::storage::store::{get_content_chunks_store, get_public_asset_store};
 use crate::hooks::storage::invoke_assert_upload_asset;
-use crate::user::usage::assert::increment_and_assert_storage_usage;
+use crate::user::role::assert::has_role;
+use crate::user::usage::assert::{assert_storage_items_usage, increment_and_assert_storage_usage};
 use candid::Principal;
 use junobuild_collections::assert::stores::{assert_create_permission, assert_permission};
 use junobuild_collections::types::core::CollectionKey;
@@ -60,7 +61,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_create_permission(permission, caller, controllers)
+        assert_create_permission(permission, caller, controllers, has_role)
     }
 
     fn assert_update_permission(
@@ -71,7 +72,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &Controllers,
     ) -> bool {
-        assert_permission(permission, owner, caller, controllers)
+        assert_permission(permission, owner, caller, controllers, has_role)
     }
 
     fn assert_list_permission(
@@ -102,6 +103,27 @@ impl StorageAssertionsStrategy for StorageAssertions {
     ) -> Result<(), String> {
         increment_and_assert_storage_usage(*caller, controllers, collection, max_changes_per_user)
     }
+
+    fn assert_storage_items_usage(
+        &self,
+        caller: &Principal,
+        controllers: &Controllers,
+        collection: &CollectionKey,
+        rule: &Rule,
+        current: &Option<Asset>,
+        encoding_type: &EncodingType,
+        encoding: &AssetEncoding,
+    ) -> Result<(), String> {
+        assert_storage_items_usage(
+            *caller,
+            controllers,
+            collection,
+            rule,
+            current,
+            encoding_type,
+            encoding,
+        )
+    }
 }
 
 pub struct StorageState;
@@ -151,7 +173,7 @@ impl StorageStateStrategy for StorageState {
         full_path: &FullPath,
         asset: &Asset,
         rule: &Rule,
-    ) {
+    ) -> Result<(), String> {
         insert_asset(collection, full_path, asset, rule)
     }
 
@@ -171,7 +193,7 @@ impl StorageStateStrategy for StorageState {
         collection: &CollectionKey,
         full_path: &FullPath,
         rule: &Rule,
-    ) -> Option<Asset> {
+    ) -> Result<Option<Asset>, String> {
         delete_asset(collection, full_path, rule)
     }
 
@@ -197,8 +219,7 @@ impl StorageUploadStrategy for StorageUpload {
     }
 
     fn insert_asset(&self, batch: &Batch, asset: &Asset, rule: &Rule) -> Result<(), String> {
-        insert_asset(&batch.key.collection, &batch.key.full_path, asset, rule);
-        Ok(())
+        insert_asset(&batch.key.collection, &batch.key.full_path, asset, rule)
     }
 
     fn get_asset(
@@ -0,0 +1,8 @@
+// The maximal size of a page of an export. It stays below the maximal size of a response, leaving room for its Candid encoding.
+pub const EXPORT_MAX_PAGE_SIZE: usize = 1_500_000;
+
+// The maximal length of the content of an asset exported in a single record. Larger chunks are sliced.
+pub const EXPORT_MAX_CONTENT_LENGTH: usize = 262_144;
+
+// The number of documents or assets read at once while filling a page of an export.
+pub const EXPORT_READ_BATCH: usize = 50;
@@ -0,0 +1,160 @@
+use crate::backup::constants::EXPORT_MAX_PAGE_SIZE;
+use crate::backup::types::interface::ExportFormat;
+use crate::backup::types::records::ExportRecord;
+use crate::errors::backup::JUNO_BACKUP_ERROR_INVALID_DATA;
+use ciborium::{from_reader, into_writer};
+use junobuild_shared::types::core::Blob;
+
+/// Collects the encoded records of a page of an export.
+pub struct ExportWriter {
+    format: ExportFormat,
+    data: Blob,
+}
+
+impl ExportWriter {
+    pub fn new(format: &ExportFormat) -> Self {
+        ExportWriter {
+            format: format.clone(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Appends a record to the page and returns `true`, or returns `false` without appending it if
+    /// the page is full. The first record of a page is always appended so that an export progresses.
+    pub fn write(&mut self, record: &ExportRecord) -> Result<bool, String> {
+        let bytes = encode_record(&self.format, record)?;
+
+        if !self.data.is_empty() && self.data.len() + bytes.len() > EXPORT_MAX_PAGE_SIZE {
+            return Ok(false);
+        }
+
+        self.data.extend(bytes);
+
+        Ok(true)
+    }
+
+    pub fn into_data(self) -> Blob {
+        self.data
+    }
+}
+
+fn encode_record(format: &ExportFormat, record: &ExportRecord) -> Result<Blob, String> {
+    match format {
+        ExportFormat::Cbor => {
+            let mut bytes = vec![];
+            into_writer(record, &mut bytes).map_err(|err| err.to_string())?;
+            Ok(bytes)
+        }
+        ExportFormat::JsonLines => {
+            let mut bytes = serde_json::to_vec(record).map_err(|err| err.to_string())?;
+            bytes.push(b'\n');
+            Ok(bytes)
+        }
+    }
+}
+
+/// Decodes the records of a page of an export.
+pub fn decode_records(format: &ExportFormat, data: &[u8]) -> Result<Vec<ExportRecord>, String> {
+    decode_records_impl(format, data)
+        .map_err(|err| format!("{JUNO_BACKUP_ERROR_INVALID_DATA} ({err})"))
+}
+
+fn decode_records_impl(format: &ExportFormat, data: &[u8]) -> Result<Vec<ExportRecord>, String> {
+    match format {
+        ExportFormat::Cbor => {
+            let mut reader = data;
+            let mut records = Vec::new();
+
+            while !reader.is_empty() {
+                let record: ExportRecord =
+                    from_reader(&mut reader).map_err(|err| err.to_string())?;
+                records.push(record);
+            }
+
+            Ok(records)
+        }
+        ExportFormat::JsonLines => data
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).map_err(|err| err.to_string()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::types::records::{ExportAssetContent, ExportDoc};
+    use crate::db::types::state::Doc;
+    use candid::Principal;
+
+    fn records() -> Vec<ExportRecord> {
+        vec![
+            ExportRecord::Doc(ExportDoc {
+                key: "hello".to_string(),
+                doc: Doc {
+                    owner: Principal::anonymous(),
+                    data: b"{\"a\":1}".to_vec(),
+                    description: Some("world".to_string()),
+                    created_at: 1,
+                    updated_at: 2,
+                    version: Some(3),
+                    expires_at: None,
+                    restorable_until: None,
+                },
+            }),
+            ExportRecord::AssetContent(ExportAssetContent {
+                full_path: "/images/hello.png".to_string(),
+                encoding_type: "identity".to_string(),
+                chunk_index: 0,
+                content: vec![0, 10, 255],
+            }),
+        ]
+    }
+
+    fn roundtrip(format: ExportFormat) {
+        let mut writer = ExportWriter::new(&format);
+
+        for record in records() {
+            assert!(writer.write(&record).unwrap());
+        }
+
+        let decoded = decode_records(&format, &writer.into_data()).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+
+        let ExportRecord::Doc(doc) = &decoded[0] else {
+            panic!("Expected a document");
+        };
+        assert_eq!(doc.key, "hello");
+        assert_eq!(doc.doc.owner, Principal::anonymous());
+        assert_eq!(doc.doc.created_at, 1);
+        assert_eq!(doc.doc.version, Some(3));
+
+        let ExportRecord::AssetContent(content) = &decoded[1] else {
+            panic!("Expected an asset content");
+        };
+        assert_eq!(content.content, vec![0, 10, 255]);
+    }
+
+    #[test]
+    fn roundtrips_cbor() {
+        roundtrip(ExportFormat::Cbor);
+    }
+
+    #[test]
+    fn roundtrips_json_lines() {
+        roundtrip(ExportFormat::JsonLines);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        for format in [ExportFormat::Cbor, ExportFormat::JsonLines] {
+            let result = decode_records(&format, b"not a record");
+
+            assert!(result
+                .unwrap_err()
+                .starts_with(JUNO_BACKUP_ERROR_INVALID_DATA));
+        }
+    }
+}
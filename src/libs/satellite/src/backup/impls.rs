@@ -0,0 +1,66 @@
+use crate::backup::types::records::{ExportAsset, ExportAssetEncoding};
+use crate::backup::types::state::ImportAsset;
+use junobuild_storage::types::store::Asset;
+
+impl From<&Asset> for ExportAsset {
+    fn from(asset: &Asset) -> Self {
+        ExportAsset {
+            key: asset.key.clone(),
+            headers: asset.headers.clone(),
+            encodings: asset
+                .encodings
+                .iter()
+                .map(|(encoding_type, encoding)| {
+                    (
+                        encoding_type.clone(),
+                        ExportAssetEncoding {
+                            modified: encoding.modified,
+                            total_length: encoding.total_length,
+                            sha256: encoding.sha256,
+                            chunks_length: encoding.content_chunks.len(),
+                        },
+                    )
+                })
+                .collect(),
+            created_at: asset.created_at,
+            updated_at: asset.updated_at,
+            version: asset.version,
+        }
+    }
+}
+
+impl From<ExportAsset> for ImportAsset {
+    fn from(asset: ExportAsset) -> Self {
+        let content_chunks = asset
+            .encodings
+            .iter()
+            .map(|(encoding_type, encoding)| {
+                (
+                    encoding_type.clone(),
+                    vec![Vec::new(); encoding.chunks_length],
+                )
+            })
+            .collect();
+
+        ImportAsset {
+            asset,
+            content_chunks,
+        }
+    }
+}
+
+impl ImportAsset {
+    /// Whether the content of all the encodings of the asset was imported.
+    pub fn is_complete(&self) -> bool {
+        self.asset
+            .encodings
+            .iter()
+            .all(|(encoding_type, encoding)| {
+                let length: u128 = self.content_chunks.get(encoding_type).map_or(0, |chunks| {
+                    chunks.iter().map(|chunk| chunk.len() as u128).sum()
+                });
+
+                length >= encoding.total_length
+            })
+    }
+}
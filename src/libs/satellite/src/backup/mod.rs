@@ -0,0 +1,6 @@
+mod constants;
+mod format;
+mod impls;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,22 @@
+use crate::assets::storage::types::state::StableKey;
+use crate::backup::types::state::ImportAsset;
+use crate::memory::state::STATE;
+
+// ---------------------------------------------------------
+// Imported assets
+// ---------------------------------------------------------
+
+pub fn insert_import_asset(key: StableKey, import_asset: ImportAsset) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .backup
+            .import_assets
+            .insert(key, import_asset)
+    });
+}
+
+pub fn delete_import_asset(key: &StableKey) -> Option<ImportAsset> {
+    STATE.with(|state| state.borrow_mut().runtime.backup.import_assets.remove(key))
+}
@@ -0,0 +1,469 @@
+use crate::assets::storage::internal::{
+    unsafe_get_asset, unsafe_get_assets_from, unsafe_insert_asset, unsafe_insert_asset_encoding,
+};
+use crate::assets::storage::store::{get_config_store, get_content_chunks_store};
+use crate::assets::storage::types::state::StableKey;
+use crate::backup::constants::{EXPORT_MAX_CONTENT_LENGTH, EXPORT_READ_BATCH};
+use crate::backup::format::{decode_records, ExportWriter};
+use crate::backup::state::{delete_import_asset, insert_import_asset};
+use crate::backup::types::interface::{
+    ExportCollectionArgs, ExportCollectionResult, ExportContentCursor, ExportCursor,
+    ImportCollectionArgs, ImportCollectionResult, ImportConflict,
+};
+use crate::backup::types::records::{ExportAsset, ExportAssetContent, ExportDoc, ExportRecord};
+use crate::backup::types::state::ImportAsset;
+use crate::certification::strategy_impls::StorageCertificate;
+use crate::db::internal::{unsafe_get_doc, unsafe_get_docs_from, unsafe_insert_doc};
+use crate::db::types::state::Doc;
+use crate::errors::backup::{
+    JUNO_BACKUP_ERROR_ASSET_NOT_STAGED, JUNO_BACKUP_ERROR_COLLECTION_MISMATCH,
+    JUNO_BACKUP_ERROR_INVALID_CONTENT, JUNO_BACKUP_ERROR_INVALID_RECORD,
+};
+use crate::rules::store::{get_rule_db, get_rule_storage};
+use crate::types::state::CollectionType;
+use candid::Principal;
+use ic_cdk::api::time;
+use junobuild_collections::msg::{msg_db_collection_not_found, msg_storage_collection_not_found};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_storage::runtime::update_certified_asset as update_runtime_certified_asset;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, AssetEncoding, EncodingType};
+use std::collections::HashMap;
+
+// ---------------------------------------------------------
+// Export
+// ---------------------------------------------------------
+
+/// Exports a page of the documents or the assets of a collection.
+///
+/// The entries are exported as they are stored - i.e. with their owners, timestamps and versions -
+/// and, for the assets, with their content. Expired documents are skipped.
+///
+/// # Arguments
+/// - `args`: The collection to export, the format of the export and the cursor returned by the
+///   previous page, if any.
+///
+/// # Returns
+/// The page and the cursor from which the export continues, or an error if the collection does not exist.
+pub fn export_collection_store(
+    args: &ExportCollectionArgs,
+) -> Result<ExportCollectionResult, String> {
+    let mut writer = ExportWriter::new(&args.format);
+
+    let cursor = match args.collection_type {
+        CollectionType::Db => export_docs(&args.collection, &args.cursor, &mut writer)?,
+        CollectionType::Storage => export_assets(&args.collection, &args.cursor, &mut writer)?,
+    };
+
+    Ok(ExportCollectionResult {
+        data: writer.into_data(),
+        cursor,
+    })
+}
+
+fn export_docs(
+    collection: &CollectionKey,
+    cursor: &Option<ExportCursor>,
+    writer: &mut ExportWriter,
+) -> Result<Option<ExportCursor>, String> {
+    let rule = get_rule_db(collection).ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+    let now = time();
+
+    let mut start = cursor
+        .as_ref()
+        .map_or_else(String::new, |cursor| cursor.key.clone());
+
+    loop {
+        let docs = unsafe_get_docs_from(collection, &start, EXPORT_READ_BATCH, &rule)?;
+
+        // The smallest key that follows the last one that was read
+        let Some(next_start) = docs.last().map(|(key, _)| format!("{key}\0")) else {
+            return Ok(None);
+        };
+
+        for (key, doc) in docs {
+            if doc.is_expired(now) {
+                continue;
+            }
+
+            let record = ExportRecord::Doc(ExportDoc {
+                key: key.clone(),
+                doc,
+            });
+
+            if !writer.write(&record)? {
+                return Ok(Some(ExportCursor { key, content: None }));
+            }
+        }
+
+        start = next_start;
+    }
+}
+
+fn export_assets(
+    collection: &CollectionKey,
+    cursor: &Option<ExportCursor>,
+    writer: &mut ExportWriter,
+) -> Result<Option<ExportCursor>, String> {
+    let rule =
+        get_rule_storage(collection).ok_or_else(|| msg_storage_collection_not_found(collection))?;
+
+    let mut start = cursor
+        .as_ref()
+        .map_or_else(String::new, |cursor| cursor.key.clone());
+
+    // The export of the first asset resumes within its content if its metadata were already exported
+    let mut resume: Option<(FullPath, ExportContentCursor)> = cursor.as_ref().and_then(|cursor| {
+        cursor
+            .content
+            .clone()
+            .map(|content| (cursor.key.clone(), content))
+    });
+
+    loop {
+        let assets = unsafe_get_assets_from(collection, &start, EXPORT_READ_BATCH, &rule);
+
+        // The smallest full path that follows the last one that was read
+        let Some(next_start) = assets
+            .last()
+            .map(|asset| format!("{}\0", asset.key.full_path))
+        else {
+            return Ok(None);
+        };
+
+        for asset in assets {
+            let from = resume
+                .take()
+                .filter(|(full_path, _)| *full_path == asset.key.full_path)
+                .map(|(_, content)| content);
+
+            if from.is_none() {
+                let record = ExportRecord::Asset(ExportAsset::from(&asset));
+
+                if !writer.write(&record)? {
+                    return Ok(Some(ExportCursor {
+                        key: asset.key.full_path,
+                        content: None,
+                    }));
+                }
+            }
+
+            if let Some(content) = export_asset_content(&asset, &rule.mem(), &from, writer)? {
+                return Ok(Some(ExportCursor {
+                    key: asset.key.full_path,
+                    content: Some(content),
+                }));
+            }
+        }
+
+        start = next_start;
+    }
+}
+
+// Exports the content of an asset, sliced, starting at the given position. Returns the position of
+// the first slice that did not fit in the page, if any.
+fn export_asset_content(
+    asset: &Asset,
+    memory: &Memory,
+    from: &Option<ExportContentCursor>,
+    writer: &mut ExportWriter,
+) -> Result<Option<ExportContentCursor>, String> {
+    let mut encodings: Vec<(&EncodingType, &AssetEncoding)> = asset.encodings.iter().collect();
+    encodings.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (encoding_type, encoding) in encodings {
+        for chunk_index in 0..encoding.content_chunks.len() {
+            if from.as_ref().is_some_and(|from| {
+                (&from.encoding_type, from.chunk_index) > (encoding_type, chunk_index)
+            }) {
+                continue;
+            }
+
+            let content =
+                get_content_chunks_store(encoding, chunk_index, memory).ok_or_else(|| {
+                    format!(
+                        "{} ({})",
+                        JUNO_BACKUP_ERROR_INVALID_CONTENT, asset.key.full_path
+                    )
+                })?;
+
+            for offset in (0..content.len()).step_by(EXPORT_MAX_CONTENT_LENGTH) {
+                let position = ExportContentCursor {
+                    encoding_type: encoding_type.clone(),
+                    chunk_index,
+                    offset,
+                };
+
+                if from.as_ref().is_some_and(|from| *from > position) {
+                    continue;
+                }
+
+                let end = (offset + EXPORT_MAX_CONTENT_LENGTH).min(content.len());
+
+                let record = ExportRecord::AssetContent(ExportAssetContent {
+                    full_path: asset.key.full_path.clone(),
+                    encoding_type: encoding_type.clone(),
+                    chunk_index,
+                    content: content[offset..end].to_vec(),
+                });
+
+                if !writer.write(&record)? {
+                    return Ok(Some(position));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+// ---------------------------------------------------------
+// Import
+// ---------------------------------------------------------
+
+/// Imports a page of an export into a collection.
+///
+/// The documents and assets are written as they were exported - i.e. with their owners, timestamps
+/// and versions - without running the assertions and hooks. Entries whose key already exists in the
+/// collection are not overwritten but reported as conflicts. With `dry_run`, nothing is written.
+///
+/// The imported documents are accounted in the usage of their owners but are neither recorded in
+/// the changes nor counted in the `max_changes_per_user` of the collection, as they are restored
+/// rather than changed.
+///
+/// The content of an asset can span multiple pages. The asset is kept in heap memory and written -
+/// and counted as imported - once all its content was imported. If it is lost in between, e.g. with
+/// an upgrade, the import of its content fails and the asset should be imported again.
+///
+/// # Arguments
+/// - `caller`: The caller of the import, recorded as the author of the revisions of the documents.
+/// - `args`: The collection, the format and the data of the page, and whether it is a dry run.
+///
+/// # Returns
+/// The number of entries that were - or would be - imported and the conflicts, or an error if the
+/// data cannot be decoded or does not match the collection.
+pub fn import_collection_store(
+    caller: Principal,
+    args: &ImportCollectionArgs,
+) -> Result<ImportCollectionResult, String> {
+    let records = decode_records(&args.format, &args.data)?;
+
+    match args.collection_type {
+        CollectionType::Db => import_docs(caller, &args.collection, records, args.dry_run),
+        CollectionType::Storage => import_assets(&args.collection, records, args.dry_run),
+    }
+}
+
+fn import_docs(
+    caller: Principal,
+    collection: &CollectionKey,
+    records: Vec<ExportRecord>,
+    dry_run: bool,
+) -> Result<ImportCollectionResult, String> {
+    let rule = get_rule_db(collection).ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+    let now = time();
+
+    let mut result = ImportCollectionResult {
+        imported: 0,
+        conflicts: Vec::new(),
+    };
+
+    for record in records {
+        let ExportRecord::Doc(ExportDoc { key, doc }) = record else {
+            return Err(JUNO_BACKUP_ERROR_INVALID_RECORD.to_string());
+        };
+
+        let current_doc =
+            unsafe_get_doc(collection, &key, &rule)?.filter(|current| !current.is_expired(now));
+
+        if let Some(current_doc) = current_doc {
+            result.conflicts.push(ImportConflict {
+                key,
+                current_version: current_doc.version,
+                imported_version: doc.version,
+            });
+            continue;
+        }
+
+        if !dry_run {
+            let doc = Doc {
+                restorable_until: None,
+                ..doc
+            };
+
+            // Tracks the usage of the owner, does not record a change
+            unsafe_insert_doc(caller, collection, &key, &doc, &rule)?;
+        }
+
+        result.imported += 1;
+    }
+
+    Ok(result)
+}
+
+fn import_assets(
+    collection: &CollectionKey,
+    records: Vec<ExportRecord>,
+    dry_run: bool,
+) -> Result<ImportCollectionResult, String> {
+    let rule =
+        get_rule_storage(collection).ok_or_else(|| msg_storage_collection_not_found(collection))?;
+
+    let mut result = ImportCollectionResult {
+        imported: 0,
+        conflicts: Vec::new(),
+    };
+
+    for record in records {
+        match record {
+            ExportRecord::Asset(asset) => {
+                if asset.key.collection != *collection {
+                    return Err(format!(
+                        "{} ({})",
+                        JUNO_BACKUP_ERROR_COLLECTION_MISMATCH, asset.key.full_path
+                    ));
+                }
+
+                if let Some(current_asset) =
+                    unsafe_get_asset(collection, &asset.key.full_path, &rule)
+                {
+                    result.conflicts.push(ImportConflict {
+                        key: asset.key.full_path.clone(),
+                        current_version: current_asset.version,
+                        imported_version: asset.version,
+                    });
+                    continue;
+                }
+
+                if dry_run
+                    || write_or_keep_import_asset(collection, ImportAsset::from(asset), &rule)?
+                {
+                    result.imported += 1;
+                }
+            }
+            ExportRecord::AssetContent(content) => {
+                if dry_run {
+                    continue;
+                }
+
+                let key = StableKey {
+                    collection: collection.clone(),
+                    full_path: content.full_path.clone(),
+                };
+
+                let Some(mut import_asset) = delete_import_asset(&key) else {
+                    // The content of an asset that is not imported because of a conflict is skipped
+                    if unsafe_get_asset(collection, &content.full_path, &rule).is_some() {
+                        continue;
+                    }
+
+                    return Err(format!(
+                        "{} ({})",
+                        JUNO_BACKUP_ERROR_ASSET_NOT_STAGED, content.full_path
+                    ));
+                };
+
+                let chunk = import_asset
+                    .content_chunks
+                    .get_mut(&content.encoding_type)
+                    .and_then(|chunks| chunks.get_mut(content.chunk_index))
+                    .ok_or_else(|| {
+                        format!(
+                            "{} ({})",
+                            JUNO_BACKUP_ERROR_INVALID_CONTENT, content.full_path
+                        )
+                    })?;
+
+                chunk.extend(content.content);
+
+                if write_or_keep_import_asset(collection, import_asset, &rule)? {
+                    result.imported += 1;
+                }
+            }
+            ExportRecord::Doc(_) => {
+                return Err(JUNO_BACKUP_ERROR_INVALID_RECORD.to_string());
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// Writes the asset if all its content was imported, otherwise keeps it until the next records.
+// Returns whether the asset was written.
+fn write_or_keep_import_asset(
+    collection: &CollectionKey,
+    import_asset: ImportAsset,
+    rule: &Rule,
+) -> Result<bool, String> {
+    if !import_asset.is_complete() {
+        let key = StableKey {
+            collection: collection.clone(),
+            full_path: import_asset.asset.key.full_path.clone(),
+        };
+
+        insert_import_asset(key, import_asset);
+
+        return Ok(false);
+    }
+
+    write_import_asset(collection, import_asset, rule)?;
+
+    Ok(true)
+}
+
+fn write_import_asset(
+    collection: &CollectionKey,
+    import_asset: ImportAsset,
+    rule: &Rule,
+) -> Result<(), String> {
+    let ImportAsset {
+        asset: exported_asset,
+        content_chunks,
+    } = import_asset;
+
+    let mut asset = Asset {
+        key: exported_asset.key,
+        headers: exported_asset.headers,
+        encodings: HashMap::new(),
+        created_at: exported_asset.created_at,
+        updated_at: exported_asset.updated_at,
+        version: exported_asset.version,
+    };
+
+    for (encoding_type, exported_encoding) in exported_asset.encodings {
+        let chunks = content_chunks
+            .get(&encoding_type)
+            .cloned()
+            .unwrap_or_default();
+
+        let encoding = AssetEncoding::from(&chunks);
+
+        if encoding.total_length != exported_encoding.total_length
+            || encoding.sha256 != exported_encoding.sha256
+        {
+            return Err(format!(
+                "{} ({})",
+                JUNO_BACKUP_ERROR_INVALID_CONTENT, asset.key.full_path
+            ));
+        }
+
+        let encoding = AssetEncoding {
+            modified: exported_encoding.modified,
+            ..encoding
+        };
+
+        let full_path = asset.key.full_path.clone();
+
+        unsafe_insert_asset_encoding(&full_path, &encoding_type, &encoding, &mut asset, rule);
+    }
+
+    unsafe_insert_asset(collection, &asset.key.full_path, &asset, rule)?;
+
+    update_runtime_certified_asset(&asset, &get_config_store(), &StorageCertificate);
+
+    Ok(())
+}
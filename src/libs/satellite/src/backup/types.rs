@@ -0,0 +1,150 @@
+pub mod state {
+    use crate::assets::storage::types::state::StableKey;
+    use crate::backup::types::records::ExportAsset;
+    use junobuild_shared::types::core::Blob;
+    use junobuild_storage::types::store::EncodingType;
+    use std::collections::HashMap;
+
+    pub type ImportAssets = HashMap<StableKey, ImportAsset>;
+
+    #[derive(Default, Clone)]
+    pub struct BackupRuntimeState {
+        pub import_assets: ImportAssets,
+    }
+
+    /// An asset being imported. Its content is collected - possibly over multiple imports - until
+    /// all the content of its encodings was received, at which point the asset is written.
+    #[derive(Clone)]
+    pub struct ImportAsset {
+        pub asset: ExportAsset,
+        pub content_chunks: HashMap<EncodingType, Vec<Blob>>,
+    }
+}
+
+pub mod records {
+    use crate::db::types::state::Doc;
+    use ic_certification::Hash;
+    use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use junobuild_storage::http::types::HeaderField;
+    use junobuild_storage::types::state::FullPath;
+    use junobuild_storage::types::store::{AssetKey, EncodingType};
+    use serde::{Deserialize, Serialize};
+
+    /// An entry of an export. A document is exported as a single record, an asset as a record of
+    /// its metadata followed by the records of its content.
+    #[derive(Serialize, Deserialize, Clone)]
+    pub enum ExportRecord {
+        Doc(ExportDoc),
+        Asset(ExportAsset),
+        AssetContent(ExportAssetContent),
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ExportDoc {
+        pub key: Key,
+        pub doc: Doc,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ExportAsset {
+        pub key: AssetKey,
+        pub headers: Vec<HeaderField>,
+        pub encodings: Vec<(EncodingType, ExportAssetEncoding)>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ExportAssetEncoding {
+        pub modified: Timestamp,
+        pub total_length: u128,
+        pub sha256: Hash,
+        pub chunks_length: usize,
+    }
+
+    /// A slice of a content chunk of an asset. The slices of a chunk are exported in order and
+    /// appended to each other on import.
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ExportAssetContent {
+        pub full_path: FullPath,
+        pub encoding_type: EncodingType,
+        pub chunk_index: usize,
+        pub content: Blob,
+    }
+}
+
+pub mod interface {
+    use crate::types::state::CollectionType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_shared::types::state::Version;
+    use junobuild_storage::types::store::EncodingType;
+    use serde::{Deserialize, Serialize};
+
+    /// The encoding of an export: a sequence of CBOR items or JSON objects separated by new lines.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum ExportFormat {
+        Cbor,
+        JsonLines,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ExportCollectionArgs {
+        pub collection_type: CollectionType,
+        pub collection: CollectionKey,
+        pub format: ExportFormat,
+        pub cursor: Option<ExportCursor>,
+    }
+
+    /// The position from which an export resumes: the key of the next document or asset and, if
+    /// the metadata of that asset was already exported, the position of its next content.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ExportCursor {
+        pub key: Key,
+        pub content: Option<ExportContentCursor>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct ExportContentCursor {
+        pub encoding_type: EncodingType,
+        pub chunk_index: usize,
+        pub offset: usize,
+    }
+
+    /// A page of an export. `cursor` should be provided to the next call to continue the export,
+    /// it is `None` once the whole collection was exported.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ExportCollectionResult {
+        pub data: Blob,
+        pub cursor: Option<ExportCursor>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ImportCollectionArgs {
+        pub collection_type: CollectionType,
+        pub collection: CollectionKey,
+        pub format: ExportFormat,
+        pub data: Blob,
+        pub dry_run: bool,
+    }
+
+    /// `imported` counts the documents and the assets that were - or would be - written. An asset
+    /// whose content spans multiple pages is counted by the page that completes it.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ImportCollectionResult {
+        pub imported: usize,
+        pub conflicts: Vec<ImportConflict>,
+    }
+
+    /// A document or an asset that is not imported because the collection already contains an
+    /// entry with the same key.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ImportConflict {
+        pub key: Key,
+        pub current_version: Option<Version>,
+        pub imported_version: Option<Version>,
+    }
+}
@@ -0,0 +1,50 @@
+use crate::changes::types::state::Change;
+use crate::db::assert::assert_doc_access;
+use crate::db::types::state::DocAccess;
+use crate::rules::store::{get_rule_db, get_rule_storage};
+use crate::types::state::CollectionType;
+use crate::user::role::assert::has_role;
+use candid::Principal;
+use junobuild_collections::assert::stores::assert_permission;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::{Controllers, UserId};
+
+/// Asserts that the caller can read the document or asset of a change, according to the current
+/// read permission of its collection and the owner of the entity - or the access list of the
+/// document.
+pub fn assert_read_change(caller: Principal, controllers: &Controllers, change: &Change) -> bool {
+    assert_read_entity(
+        caller,
+        controllers,
+        &change.kind.collection_type(),
+        &change.collection,
+        &change.key,
+        change.owner,
+    )
+}
+
+/// Asserts that the caller can read a document or asset, as `get_doc_store` respectively
+/// `get_asset_store` do: the read permission of the collection applied to the owner of the entity
+/// or, for a document, its access list.
+pub fn assert_read_entity(
+    caller: Principal,
+    controllers: &Controllers,
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    key: &Key,
+    owner: UserId,
+) -> bool {
+    let rule = match collection_type {
+        CollectionType::Db => get_rule_db(collection),
+        CollectionType::Storage => get_rule_storage(collection),
+    };
+
+    let Some(rule) = rule else {
+        return false;
+    };
+
+    assert_permission(&rule.read, owner, caller, controllers, has_role)
+        || (matches!(collection_type, CollectionType::Db)
+            && assert_doc_access(caller, collection, key, DocAccess::can_read))
+}
@@ -0,0 +1,5 @@
+// The maximal number of changes kept in the change log. When exceeded, the oldest changes are dropped.
+pub const MAX_CHANGES: u64 = 10_000;
+
+// The maximal number of changes read from the change log by a single call to list_changes.
+pub const MAX_LIST_CHANGES: usize = 1_000;
@@ -0,0 +1,84 @@
+use crate::changes::types::state::{Change, ChangeKind};
+use crate::db::types::state::Doc;
+use crate::types::state::CollectionType;
+use ic_cdk::api::time;
+use ic_stable_structures::storable::Bound;
+use ic_stable_structures::Storable;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::memory::serializers::{
+    deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
+};
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use junobuild_storage::types::store::Asset;
+use std::borrow::Cow;
+
+impl Change {
+    pub fn from_doc(
+        kind: ChangeKind,
+        collection: &CollectionKey,
+        key: &Key,
+        doc: &Doc,
+        caller: &UserId,
+    ) -> Self {
+        Change {
+            kind,
+            collection: collection.clone(),
+            key: key.clone(),
+            owner: doc.owner,
+            caller: *caller,
+            description: doc.description.clone(),
+            created_at: doc.created_at,
+            updated_at: doc.updated_at,
+            timestamp: time(),
+        }
+    }
+
+    pub fn from_asset(kind: ChangeKind, asset: &Asset, caller: &UserId) -> Self {
+        Change {
+            kind,
+            collection: asset.key.collection.clone(),
+            key: asset.key.full_path.clone(),
+            owner: asset.key.owner,
+            caller: *caller,
+            description: asset.key.description.clone(),
+            created_at: asset.created_at,
+            updated_at: asset.updated_at,
+            timestamp: time(),
+        }
+    }
+}
+
+impl ChangeKind {
+    pub fn collection_type(&self) -> CollectionType {
+        match self {
+            ChangeKind::DocSet | ChangeKind::DocDeleted => CollectionType::Db,
+            ChangeKind::AssetUploaded | ChangeKind::AssetDeleted => CollectionType::Storage,
+        }
+    }
+
+    pub fn msg_type(&self) -> &'static str {
+        match self {
+            ChangeKind::DocSet => "doc_set",
+            ChangeKind::DocDeleted => "doc_deleted",
+            ChangeKind::AssetUploaded => "asset_uploaded",
+            ChangeKind::AssetDeleted => "asset_deleted",
+        }
+    }
+}
+
+impl Storable for Change {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
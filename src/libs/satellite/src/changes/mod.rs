@@ -0,0 +1,6 @@
+pub mod assert;
+pub mod constants;
+mod impls;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,59 @@
+use crate::changes::types::state::{Change, ChangeSequence, ChangesStable};
+use crate::memory::state::STATE;
+use std::ops::Bound::{Excluded, Unbounded};
+
+pub fn insert_change(sequence: ChangeSequence, change: Change) {
+    STATE.with(|state| insert_change_impl(sequence, change, &mut state.borrow_mut().stable.changes))
+}
+
+pub fn get_changes_after(since: ChangeSequence, limit: usize) -> Vec<(ChangeSequence, Change)> {
+    STATE.with(|state| get_changes_after_impl(since, limit, &state.borrow().stable.changes))
+}
+
+pub fn get_first_sequence() -> Option<ChangeSequence> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .changes
+            .first_key_value()
+            .map(|(sequence, _)| sequence)
+    })
+}
+
+pub fn get_last_sequence() -> Option<ChangeSequence> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .changes
+            .last_key_value()
+            .map(|(sequence, _)| sequence)
+    })
+}
+
+pub fn delete_oldest_changes(max: u64) {
+    STATE.with(|state| delete_oldest_changes_impl(max, &mut state.borrow_mut().stable.changes))
+}
+
+fn insert_change_impl(sequence: ChangeSequence, change: Change, changes: &mut ChangesStable) {
+    changes.insert(sequence, change);
+}
+
+fn get_changes_after_impl(
+    since: ChangeSequence,
+    limit: usize,
+    changes: &ChangesStable,
+) -> Vec<(ChangeSequence, Change)> {
+    changes
+        .range((Excluded(since), Unbounded))
+        .take(limit)
+        .map(|entry| (*entry.key(), entry.value().clone()))
+        .collect()
+}
+
+fn delete_oldest_changes_impl(max: u64, changes: &mut ChangesStable) {
+    while changes.len() > max {
+        changes.pop_first();
+    }
+}
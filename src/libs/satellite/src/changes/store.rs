@@ -0,0 +1,99 @@
+use crate::changes::assert::assert_read_change;
+use crate::changes::constants::{MAX_CHANGES, MAX_LIST_CHANGES};
+use crate::changes::state::{
+    delete_oldest_changes, get_changes_after, get_first_sequence, get_last_sequence, insert_change,
+};
+use crate::changes::types::interface::ListChangesResults;
+use crate::changes::types::state::{Change, ChangeSequence};
+use crate::controllers::store::get_controllers;
+use crate::errors::changes::JUNO_CHANGES_ERROR_RESYNC_REQUIRED;
+use crate::types::hooks::NotificationMessage;
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::state::Controllers;
+
+/// Appends a change to the change log and returns its sequence.
+///
+/// The sequence follows the last recorded change, therefore it keeps increasing across upgrades.
+/// When the log exceeds `MAX_CHANGES`, the oldest changes are dropped.
+pub fn record_change(change: &Change) -> ChangeSequence {
+    let sequence = get_last_sequence().map_or(1, |last| last + 1);
+
+    insert_change(sequence, change.clone());
+
+    delete_oldest_changes(MAX_CHANGES);
+
+    sequence
+}
+
+/// Lists at most `limit` of the changes recorded after the given sequence, in order.
+///
+/// Returns an error if some of the changes that followed `since` have already been dropped from
+/// the log, in which case the caller cannot catch up and should resync its data.
+pub fn list_changes_since(
+    since: ChangeSequence,
+    limit: usize,
+) -> Result<Vec<(ChangeSequence, Change)>, String> {
+    if let Some(first) = get_first_sequence() {
+        if first > since.saturating_add(1) {
+            return Err(format!("{JUNO_CHANGES_ERROR_RESYNC_REQUIRED} ({since})"));
+        }
+    }
+
+    Ok(get_changes_after(since, limit))
+}
+
+/// Lists the changes recorded after `since` - i.e. the events emitted to the WebSocket
+/// subscribers - that the caller is allowed to read.
+///
+/// # Arguments
+/// - `caller`: The caller polling the changes.
+/// - `since`: The sequence after which changes are listed.
+/// - `collections`: Optionally, the collections to which the changes should be limited.
+/// - `limit`: Optionally, the maximal number of changes to read from the log, capped at
+///   `MAX_LIST_CHANGES`.
+///
+/// # Returns
+/// The changes and the sequence to provide as `since` to the next call, or an error if the
+/// changes following `since` are no longer available.
+pub fn list_changes_store(
+    caller: Principal,
+    since: ChangeSequence,
+    collections: &Option<Vec<CollectionKey>>,
+    limit: Option<usize>,
+) -> Result<ListChangesResults, String> {
+    let limit = limit.map_or(MAX_LIST_CHANGES, |limit| limit.min(MAX_LIST_CHANGES));
+
+    // Only the page of the log is read. The changes the caller cannot read count towards the
+    // limit as well, so that a page never scans more than the limit.
+    let changes = list_changes_since(since, limit)?;
+
+    let controllers: Controllers = get_controllers();
+
+    let mut items: Vec<NotificationMessage> = Vec::new();
+    let mut last_sequence = since;
+
+    for (sequence, change) in changes {
+        last_sequence = sequence;
+
+        if !filter_collections(collections, &change)
+            || !assert_read_change(caller, &controllers, &change)
+        {
+            continue;
+        }
+
+        items.push(NotificationMessage::from_change(sequence, &change));
+    }
+
+    Ok(ListChangesResults {
+        items,
+        last_sequence,
+    })
+}
+
+fn filter_collections(collections: &Option<Vec<CollectionKey>>, change: &Change) -> bool {
+    match collections {
+        None => true,
+        Some(collections) => collections.contains(&change.collection),
+    }
+}
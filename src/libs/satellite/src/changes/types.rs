@@ -0,0 +1,57 @@
+pub mod state {
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    /// A monotonically increasing number identifying a change of the satellite.
+    pub type ChangeSequence = u64;
+
+    pub type ChangesStable = StableBTreeMap<ChangeSequence, Change, Memory>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum ChangeKind {
+        DocSet,
+        DocDeleted,
+        AssetUploaded,
+        AssetDeleted,
+    }
+
+    /// A change of a document or an asset, recorded in the bounded change log.
+    ///
+    /// Besides identifying the entity, the change keeps the few metadata - owner, description and
+    /// timestamps - that are needed to evaluate permissions and subscription scopes when the
+    /// change is replayed.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Change {
+        pub kind: ChangeKind,
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub owner: UserId,
+        pub caller: UserId,
+        pub description: Option<String>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub timestamp: Timestamp,
+    }
+}
+
+pub mod interface {
+    use crate::changes::types::state::ChangeSequence;
+    use crate::types::hooks::NotificationMessage;
+    use candid::CandidType;
+    use serde::{Deserialize, Serialize};
+
+    /// The changes returned by `list_changes`.
+    ///
+    /// `last_sequence` is the sequence up to which the change log was read. It should be provided
+    /// as `since` to the next call to continue polling from there.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ListChangesResults {
+        pub items: Vec<NotificationMessage>,
+        pub last_sequence: ChangeSequence,
+    }
+}
@@ -1,45 +1,76 @@
 use crate::auth::assert::assert_caller_is_allowed;
-use crate::db::runtime::increment_and_assert_rate;
+use crate::db::constants::{MAX_DOC_GRANTS, MAX_INDEX_KEYS};
+use crate::db::runtime::{get_json_schema, increment_and_assert_rate};
+use crate::db::state::{get_doc_access as get_state_doc_access, get_index_rebuild};
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::SetDbConfig;
-use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext};
+use crate::db::types::interface::{SetDbConfig, SetDocAccess};
+use crate::db::types::state::{DocAccess, DocAssertDelete, DocAssertSet, DocContext, DocGrant};
 use crate::db::types::store::AssertSetDocOptions;
-use crate::errors::db::{JUNO_DATASTORE_ERROR_CANNOT_READ, JUNO_DATASTORE_ERROR_CANNOT_WRITE};
+use crate::errors::db::{
+    JUNO_DATASTORE_ERROR_CANNOT_READ, JUNO_DATASTORE_ERROR_CANNOT_WRITE,
+    JUNO_DATASTORE_ERROR_DOC_ACCESS_NOT_SUPPORTED, JUNO_DATASTORE_ERROR_EXPIRES_AT_NOT_SUPPORTED,
+    JUNO_DATASTORE_ERROR_INDEX_BUILDING, JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND,
+    JUNO_DATASTORE_ERROR_INDEX_TOO_MANY_KEYS, JUNO_DATASTORE_ERROR_INVALID_DOC_ACCESS,
+    JUNO_DATASTORE_ERROR_INVALID_EXPIRES_AT, JUNO_DATASTORE_ERROR_INVALID_OWNER,
+    JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH, JUNO_DATASTORE_ERROR_MATCHER_NOT_SUPPORTED,
+    JUNO_DATASTORE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED, JUNO_DATASTORE_ERROR_VERSION_MISMATCH,
+};
 use crate::hooks::db::{invoke_assert_delete_doc, invoke_assert_set_doc};
+use crate::types::interface::TransferOwnership;
+use crate::types::state::CollectionType;
 use crate::types::store::{AssertContext, StoreContext};
 use crate::user::core::assert::{
     assert_user_collection_caller_key, assert_user_collection_data,
     assert_user_collection_write_permission, assert_user_is_not_banned,
 };
+use crate::user::role::assert::{
+    assert_user_role_collection_data, assert_user_role_collection_key, has_role,
+};
 use crate::user::usage::assert::{
-    assert_user_usage_collection_data, increment_and_assert_db_usage,
+    assert_db_items_usage, assert_transfer_items_usage, assert_user_usage_collection_data,
+    increment_and_assert_db_usage,
 };
+use crate::user::usage::types::state::UserUsageItem;
 use crate::user::webauthn::assert::{
     assert_user_webauthn_collection_data, assert_user_webauthn_collection_write_permission,
 };
 use crate::{DelDoc, Doc, SetDoc};
 use candid::Principal;
+use ic_cdk::api::time;
+use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::assert::stores::{
     assert_create_permission, assert_permission, public_permission,
 };
-use junobuild_collections::types::rules::Permission;
+use junobuild_collections::schema::validate::validate_data;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{IndexPath, Permission, Rule};
 use junobuild_shared::assert::{assert_description_length, assert_max_memory_size, assert_version};
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::state::{Controllers, Version};
+use junobuild_shared::types::list::ListParams;
+use junobuild_shared::types::state::{Controllers, Timestamp, UserId, Version};
+use junobuild_shared::utils::principal_not_anonymous;
 
 pub fn assert_get_doc(
     &StoreContext {
         caller,
         controllers,
-        collection: _,
+        collection,
     }: &StoreContext,
     &AssertContext { rule, auth_config }: &AssertContext,
+    key: &Key,
     current_doc: &Doc,
 ) -> Result<(), String> {
     assert_caller_is_allowed(caller, controllers, auth_config)?;
     assert_user_is_not_banned(caller, controllers)?;
 
-    assert_read_permission(caller, controllers, current_doc, &rule.read)?;
+    assert_read_permission(
+        caller,
+        controllers,
+        collection,
+        key,
+        current_doc,
+        &rule.read,
+    )?;
 
     Ok(())
 }
@@ -61,7 +92,43 @@ pub fn assert_get_docs(
     Ok(())
 }
 
+/// The access list of a document is read - as the documents - by the callers that are allowed and
+/// not banned.
+pub fn assert_get_doc_access(
+    &StoreContext {
+        caller,
+        controllers,
+        collection: _,
+    }: &StoreContext,
+    &AssertContext {
+        auth_config,
+        rule: _,
+    }: &AssertContext,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    Ok(())
+}
+
 pub fn assert_set_doc(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    config: &Option<DbConfig>,
+    options: &AssertSetDocOptions,
+    key: &Key,
+    value: &SetDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    assert_set_doc_constraints(context, assert_context, config, key, value, current_doc)?;
+
+    assert_set_doc_usage(context, assert_context.rule, options, value, current_doc)?;
+
+    Ok(())
+}
+
+/// The assertions of `assert_set_doc` that do not increment any counter.
+pub fn assert_set_doc_constraints(
     &StoreContext {
         caller,
         controllers,
@@ -69,7 +136,6 @@ pub fn assert_set_doc(
     }: &StoreContext,
     &AssertContext { rule, auth_config }: &AssertContext,
     config: &Option<DbConfig>,
-    options: &AssertSetDocOptions,
     key: &Key,
     value: &SetDoc,
     current_doc: &Option<Doc>,
@@ -84,7 +150,17 @@ pub fn assert_set_doc(
     assert_user_webauthn_collection_data(caller, collection, value)?;
     assert_user_webauthn_collection_write_permission(collection, current_doc)?;
 
-    assert_write_permission(caller, controllers, current_doc, &rule.write)?;
+    assert_user_role_collection_key(collection, key)?;
+    assert_user_role_collection_data(collection, value)?;
+
+    assert_update_permission(
+        caller,
+        controllers,
+        collection,
+        key,
+        current_doc,
+        &rule.write,
+    )?;
 
     assert_memory_size(config)?;
 
@@ -92,8 +168,12 @@ pub fn assert_set_doc(
 
     assert_description_length(&value.description)?;
 
+    assert_expires_at(collection, &value.expires_at)?;
+
     assert_user_usage_collection_data(collection, value)?;
 
+    assert_json_schema(collection, rule, value)?;
+
     invoke_assert_set_doc(
         &caller,
         &DocContext {
@@ -106,6 +186,23 @@ pub fn assert_set_doc(
         },
     )?;
 
+    Ok(())
+}
+
+// The assertions of the usage of the caller and of the rate of the collection, which are incremented.
+fn assert_set_doc_usage(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    rule: &Rule,
+    options: &AssertSetDocOptions,
+    value: &SetDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    assert_db_items_usage(caller, controllers, collection, rule, current_doc, value)?;
+
     increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
 
     if options.with_assert_rate {
@@ -115,7 +212,91 @@ pub fn assert_set_doc(
     Ok(())
 }
 
+/// Asserts the caller can write the document before its data is patched, so that a failing
+/// patch - e.g. a JSON patch `test` operation - cannot be used to probe the data of documents the
+/// caller is not allowed to edit. The patched document is then asserted with `assert_set_doc`.
+///
+/// Likewise used before a revision is restored, so that the existence of revisions cannot be probed.
+pub fn assert_patch_doc(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    &AssertContext { rule, auth_config }: &AssertContext,
+    key: &Key,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    assert_update_permission(
+        caller,
+        controllers,
+        collection,
+        key,
+        current_doc,
+        &rule.write,
+    )?;
+
+    Ok(())
+}
+
+pub fn assert_restore_doc(
+    &StoreContext {
+        caller,
+        controllers,
+        collection: _,
+    }: &StoreContext,
+    &AssertContext {
+        auth_config,
+        rule: _,
+    }: &AssertContext,
+    trashed_doc: &Doc,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    assert_restore_permission(caller, controllers, trashed_doc)?;
+
+    Ok(())
+}
+
+/// Trashed documents can be restored by their owner and the controllers.
+pub fn assert_restore_permission(
+    caller: Principal,
+    controllers: &Controllers,
+    trashed_doc: &Doc,
+) -> Result<(), String> {
+    if !assert_permission(
+        &Permission::Managed,
+        trashed_doc.owner,
+        caller,
+        controllers,
+        has_role,
+    ) {
+        return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
+    }
+
+    Ok(())
+}
+
 pub fn assert_delete_doc(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    key: &Key,
+    value: &DelDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    assert_delete_doc_constraints(context, assert_context, key, value, current_doc)?;
+
+    assert_delete_doc_usage(context, assert_context.rule)?;
+
+    Ok(())
+}
+
+/// The assertions of `assert_delete_doc` that do not increment any counter.
+pub fn assert_delete_doc_constraints(
     &StoreContext {
         caller,
         controllers,
@@ -145,6 +326,18 @@ pub fn assert_delete_doc(
         },
     )?;
 
+    Ok(())
+}
+
+// The assertions of the usage of the caller and of the rate of the collection, which are incremented.
+fn assert_delete_doc_usage(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    rule: &Rule,
+) -> Result<(), String> {
     increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
 
     increment_and_assert_rate(collection, &rule.rate_config)?;
@@ -152,6 +345,284 @@ pub fn assert_delete_doc(
     Ok(())
 }
 
+/// Whether the document was shared with the caller through its access list - `is_granted` being
+/// either `DocAccess::can_read` or `DocAccess::can_write`.
+pub fn assert_doc_access(
+    caller: Principal,
+    collection: &CollectionKey,
+    key: &Key,
+    is_granted: fn(&DocAccess, UserId, Timestamp) -> bool,
+) -> bool {
+    // Documents of system collections cannot be shared
+    if is_system_collection(collection) {
+        return false;
+    }
+
+    get_state_doc_access(collection, key).is_some_and(|access| is_granted(&access, caller, time()))
+}
+
+pub fn assert_set_doc_access(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    &AssertContext { rule, auth_config }: &AssertContext,
+    current_doc: &Option<Doc>,
+    current_access: &Option<DocAccess>,
+    value: &SetDocAccess,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    if is_system_collection(collection) {
+        return Err(JUNO_DATASTORE_ERROR_DOC_ACCESS_NOT_SUPPORTED.to_string());
+    }
+
+    let Some(current_doc) = current_doc else {
+        return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
+    };
+
+    assert_doc_access_permission(caller, controllers, current_doc)?;
+
+    assert_version(
+        value.version,
+        current_access.as_ref().and_then(|access| access.version),
+    )?;
+
+    assert_doc_grants(value)?;
+
+    increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
+
+    increment_and_assert_rate(collection, &rule.rate_config)?;
+
+    Ok(())
+}
+
+/// Only the owner of a document - and the controllers - can read and edit its access list.
+pub fn assert_doc_access_permission(
+    caller: Principal,
+    controllers: &Controllers,
+    current_doc: &Doc,
+) -> Result<(), String> {
+    if !assert_permission(
+        &Permission::Managed,
+        current_doc.owner,
+        caller,
+        controllers,
+        has_role,
+    ) {
+        return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
+    }
+
+    Ok(())
+}
+
+fn assert_doc_grants(value: &SetDocAccess) -> Result<(), String> {
+    let now = time();
+
+    let grants = value.readers.len() + value.writers.len();
+
+    let valid_grants = |grants: &[DocGrant]| {
+        grants.iter().enumerate().all(|(i, grant)| {
+            principal_not_anonymous(grant.user)
+                && !grant.is_expired(now)
+                && !grants[..i].iter().any(|other| other.user == grant.user)
+        })
+    };
+
+    if grants > MAX_DOC_GRANTS || !valid_grants(&value.readers) || !valid_grants(&value.writers) {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INVALID_DOC_ACCESS} ({MAX_DOC_GRANTS})"
+        ));
+    }
+
+    Ok(())
+}
+
+/// The ownership of a document can be transferred by its owner and the controllers, to a user who
+/// has not reached the quotas of the collection.
+pub fn assert_transfer_doc_ownership(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    &AssertContext { rule, auth_config }: &AssertContext,
+    current_doc: &Doc,
+    value: &TransferOwnership,
+) -> Result<(), String> {
+    assert_caller_is_allowed(caller, controllers, auth_config)?;
+    assert_user_is_not_banned(caller, controllers)?;
+
+    if is_system_collection(collection) {
+        return Err(JUNO_DATASTORE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED.to_string());
+    }
+
+    if !assert_permission(
+        &Permission::Managed,
+        current_doc.owner,
+        caller,
+        controllers,
+        has_role,
+    ) {
+        return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
+    }
+
+    assert_version(value.version, current_doc.version)?;
+
+    if !principal_not_anonymous(value.owner) {
+        return Err(JUNO_DATASTORE_ERROR_INVALID_OWNER.to_string());
+    }
+
+    if value.owner != current_doc.owner {
+        assert_transfer_items_usage(
+            value.owner,
+            controllers,
+            collection,
+            &CollectionType::Db,
+            rule,
+            &UserUsageItem::from_doc(current_doc),
+        )?;
+    }
+
+    increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
+
+    increment_and_assert_rate(collection, &rule.rate_config)?;
+
+    Ok(())
+}
+
+/// Asserts the precondition of a batch: with a `version`, the document must exist in that
+/// version, without, the document must not exist.
+pub fn assert_doc_version(
+    current_doc: &Option<Doc>,
+    version: &Option<Version>,
+) -> Result<(), String> {
+    let current_version = current_doc
+        .as_ref()
+        .map(|doc| doc.version.unwrap_or_default());
+
+    if current_version != *version {
+        let expected = version.map_or("none".to_string(), |version| version.to_string());
+        let current = current_version.map_or("none".to_string(), |version| version.to_string());
+
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_VERSION_MISMATCH} ({expected} - {current})"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn assert_index(
+    collection: &CollectionKey,
+    rule: &Rule,
+    path: &IndexPath,
+) -> Result<(), String> {
+    let indexed = rule
+        .indexes
+        .as_ref()
+        .is_some_and(|indexes| indexes.contains(path));
+
+    if !indexed {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND} ({collection}: {path})"
+        ));
+    }
+
+    // The entries of the index are incomplete until the documents were all indexed
+    if get_index_rebuild(collection).is_some() {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INDEX_BUILDING} ({collection}: {path})"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn assert_index_keys(
+    collection: &CollectionKey,
+    path: &IndexPath,
+    keys: &[Key],
+) -> Result<(), String> {
+    if keys.len() > MAX_INDEX_KEYS {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INDEX_TOO_MANY_KEYS} ({collection}: {path})"
+        ));
+    }
+
+    Ok(())
+}
+
+// The revisions are not indexed.
+pub fn assert_list_doc_revisions(filters: &ListParams) -> Result<(), String> {
+    assert_not_indexed(filters)
+}
+
+// The trash is not indexed.
+pub fn assert_list_trashed_docs(filters: &ListParams) -> Result<(), String> {
+    assert_not_indexed(filters)
+}
+
+fn assert_not_indexed(filters: &ListParams) -> Result<(), String> {
+    let index = filters
+        .matcher
+        .as_ref()
+        .is_some_and(|matcher| matcher.index.is_some());
+
+    if index {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_MATCHER_NOT_SUPPORTED} (index)"
+        ));
+    }
+
+    Ok(())
+}
+
+fn assert_expires_at(
+    collection: &CollectionKey,
+    expires_at: &Option<Timestamp>,
+) -> Result<(), String> {
+    let Some(expires_at) = expires_at else {
+        return Ok(());
+    };
+
+    if is_system_collection(collection) {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_EXPIRES_AT_NOT_SUPPORTED} ({collection})"
+        ));
+    }
+
+    if *expires_at <= time() {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INVALID_EXPIRES_AT} ({expires_at})"
+        ));
+    }
+
+    Ok(())
+}
+
+// The schema was asserted when the rule was set.
+fn assert_json_schema(
+    collection: &CollectionKey,
+    rule: &Rule,
+    value: &SetDoc,
+) -> Result<(), String> {
+    let Some(json_schema) = &rule.json_schema else {
+        return Ok(());
+    };
+
+    let schema = get_json_schema(collection, json_schema)?;
+
+    let data: serde_json::Value = serde_json::from_slice(&value.data).map_err(|err| {
+        format!("{JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH} (data is not valid JSON: {err})")
+    })?;
+
+    validate_data(&schema, &data)
+        .map_err(|err| format!("{JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH} ({err})"))
+}
+
 fn assert_memory_size(config: &Option<DbConfig>) -> Result<(), String> {
     match config {
         None => Ok(()),
@@ -162,16 +633,36 @@ fn assert_memory_size(config: &Option<DbConfig>) -> Result<(), String> {
 fn assert_read_permission(
     caller: Principal,
     controllers: &Controllers,
+    collection: &CollectionKey,
+    key: &Key,
     current_doc: &Doc,
     rule: &Permission,
 ) -> Result<(), String> {
-    if !assert_permission(rule, current_doc.owner, caller, controllers) {
+    if !assert_permission(rule, current_doc.owner, caller, controllers, has_role)
+        && !assert_doc_access(caller, collection, key, DocAccess::can_read)
+    {
         return Err(JUNO_DATASTORE_ERROR_CANNOT_READ.to_string());
     }
 
     Ok(())
 }
 
+// Besides the permission of the collection, the writers of an existing document can update it.
+fn assert_update_permission(
+    caller: Principal,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    key: &Key,
+    current_doc: &Option<Doc>,
+    rule: &Permission,
+) -> Result<(), String> {
+    if current_doc.is_some() && assert_doc_access(caller, collection, key, DocAccess::can_write) {
+        return Ok(());
+    }
+
+    assert_write_permission(caller, controllers, current_doc, rule)
+}
+
 fn assert_write_permission(
     caller: Principal,
     controllers: &Controllers,
@@ -182,12 +673,12 @@ fn assert_write_permission(
     if !public_permission(rule) {
         match current_doc {
             None => {
-                if !assert_create_permission(rule, caller, controllers) {
+                if !assert_create_permission(rule, caller, controllers, has_role) {
                     return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
                 }
             }
             Some(current_doc) => {
-                if !assert_permission(rule, current_doc.owner, caller, controllers) {
+                if !assert_permission(rule, current_doc.owner, caller, controllers, has_role) {
                     return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
                 }
             }
@@ -234,3 +725,85 @@ fn assert_config_version(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::state::{delete_index_rebuild, insert_index_rebuild};
+    use crate::db::types::state::{IndexRebuild, IndexRebuildStatus};
+
+    fn rule(indexes: Option<Vec<IndexPath>>) -> Rule {
+        Rule {
+            read: Permission::Managed,
+            write: Permission::Managed,
+            mutable_permissions: Some(true),
+            memory: None,
+            max_size: None,
+            max_capacity: None,
+            max_changes_per_user: None,
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+            rate_config: None,
+            indexes,
+            default_ttl_ns: None,
+            max_revisions: None,
+            trash_retention_ns: None,
+            max_items_per_user: None,
+            max_bytes_per_user: None,
+            json_schema: None,
+        }
+    }
+
+    #[test]
+    fn rejects_path_not_indexed() {
+        let collection = "index_not_found".to_string();
+
+        let result = assert_index(&collection, &rule(None), &"status".to_string());
+        assert!(result.is_err_and(|err| err.starts_with(JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND)));
+
+        let result = assert_index(
+            &collection,
+            &rule(Some(vec!["rank".to_string()])),
+            &"status".to_string(),
+        );
+        assert!(result.is_err_and(|err| err.starts_with(JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND)));
+    }
+
+    #[test]
+    fn rejects_index_being_rebuilt() {
+        let collection = "index_building".to_string();
+        let rule = rule(Some(vec!["status".to_string()]));
+        let path = "status".to_string();
+
+        insert_index_rebuild(
+            &collection,
+            &IndexRebuild {
+                status: IndexRebuildStatus::Indexing,
+                last_key: None,
+                created_at: 0,
+                updated_at: 0,
+            },
+        );
+
+        let result = assert_index(&collection, &rule, &path);
+        assert!(result.is_err_and(|err| err.starts_with(JUNO_DATASTORE_ERROR_INDEX_BUILDING)));
+
+        delete_index_rebuild(&collection);
+
+        assert!(assert_index(&collection, &rule, &path).is_ok());
+    }
+
+    #[test]
+    fn rejects_too_many_index_keys() {
+        let collection = "index_keys".to_string();
+        let path = "status".to_string();
+
+        let keys: Vec<Key> = (0..MAX_INDEX_KEYS).map(|i| i.to_string()).collect();
+        assert!(assert_index_keys(&collection, &path, &keys).is_ok());
+
+        let keys: Vec<Key> = (0..=MAX_INDEX_KEYS).map(|i| i.to_string()).collect();
+        let result = assert_index_keys(&collection, &path, &keys);
+        assert!(result.is_err_and(|err| err.starts_with(JUNO_DATASTORE_ERROR_INDEX_TOO_MANY_KEYS)));
+    }
+}
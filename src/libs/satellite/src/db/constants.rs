@@ -0,0 +1,11 @@
+// The maximum number of expired documents removed per sweep. Remaining ones are removed by the sweeps that follow.
+pub const EXPIRED_DOCS_SWEEP_BATCH: usize = 100;
+
+// The maximum number of grants - readers and writers - of the access list of a document.
+pub const MAX_DOC_GRANTS: usize = 100;
+
+// The number of index entries deleted - or documents indexed - per batch of the rebuild of the indexes of a collection.
+pub const INDEX_REBUILD_BATCH: usize = 500;
+
+// The maximum number of documents read with an index match. A narrower match is required beyond it.
+pub const MAX_INDEX_KEYS: usize = 10_000;
@@ -0,0 +1,156 @@
+use crate::db::types::state::IndexValue;
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::types::list::{DataFieldMatcher, DataMatcher, DataPredicate};
+use serde_json::Value;
+
+/// Decodes the data of a document as JSON. Data that is not valid JSON is ignored.
+pub fn decode_data(data: &Blob) -> Option<Value> {
+    serde_json::from_slice::<Value>(data).ok()
+}
+
+/// Resolves a dotted path - e.g. `author.name` - within a JSON value.
+pub fn resolve_path<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(json, |current, segment| current.as_object()?.get(segment))
+}
+
+pub fn filter_data(matcher: &DataMatcher, data: &Blob) -> bool {
+    match decode_data(data) {
+        None => false,
+        Some(json) => matches(matcher, &json),
+    }
+}
+
+fn matches(matcher: &DataMatcher, json: &Value) -> bool {
+    match matcher {
+        DataMatcher::Field(field) => matches_field(field, json),
+        DataMatcher::And(matchers) => matchers.iter().all(|matcher| matches(matcher, json)),
+        DataMatcher::Or(matchers) => matchers.iter().any(|matcher| matches(matcher, json)),
+    }
+}
+
+fn matches_field(DataFieldMatcher { path, predicate }: &DataFieldMatcher, json: &Value) -> bool {
+    let value = resolve_path(json, path);
+
+    match predicate {
+        DataPredicate::Exists(exists) => value.is_some() == *exists,
+        DataPredicate::NotEqual(expected) => {
+            scalar(value).is_none_or(|value| value != IndexValue::from(expected))
+        }
+        DataPredicate::StartsWith(prefix) => value
+            .and_then(Value::as_str)
+            .is_some_and(|value| value.starts_with(prefix.as_str())),
+        DataPredicate::Equal(expected) => {
+            scalar(value).is_some_and(|value| value == IndexValue::from(expected))
+        }
+        DataPredicate::In(expected) => scalar(value).is_some_and(|value| {
+            expected
+                .iter()
+                .any(|expected| value == IndexValue::from(expected))
+        }),
+        DataPredicate::GreaterThan(bound) => {
+            let bound = IndexValue::from(bound);
+            scalar(value).is_some_and(|value| value.is_same_type(&bound) && value > bound)
+        }
+        DataPredicate::LessThan(bound) => {
+            let bound = IndexValue::from(bound);
+            scalar(value).is_some_and(|value| value.is_same_type(&bound) && value < bound)
+        }
+    }
+}
+
+// Scalars are compared as they are indexed, which orders the numbers naturally.
+fn scalar(value: Option<&Value>) -> Option<IndexValue> {
+    value.and_then(IndexValue::from_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use junobuild_shared::types::list::DataValue;
+
+    fn field(path: &str, predicate: DataPredicate) -> DataMatcher {
+        DataMatcher::Field(DataFieldMatcher {
+            path: path.to_string(),
+            predicate,
+        })
+    }
+
+    fn data() -> Blob {
+        br#"{"status":"done","priority":3,"author":{"name":"Ada"}}"#.to_vec()
+    }
+
+    #[test]
+    fn matches_predicates() {
+        let data = data();
+
+        assert!(filter_data(
+            &field(
+                "status",
+                DataPredicate::Equal(DataValue::Text("done".to_string()))
+            ),
+            &data
+        ));
+        assert!(filter_data(
+            &field(
+                "priority",
+                DataPredicate::GreaterThan(DataValue::Number(2.0))
+            ),
+            &data
+        ));
+        assert!(!filter_data(
+            &field(
+                "priority",
+                DataPredicate::LessThan(DataValue::Text("4".to_string()))
+            ),
+            &data
+        ));
+        assert!(filter_data(
+            &field("author.name", DataPredicate::StartsWith("Ad".to_string())),
+            &data
+        ));
+        assert!(filter_data(
+            &field("missing", DataPredicate::NotEqual(DataValue::Null)),
+            &data
+        ));
+        assert!(filter_data(
+            &field("author", DataPredicate::Exists(true)),
+            &data
+        ));
+        assert!(filter_data(
+            &field(
+                "priority",
+                DataPredicate::In(vec![DataValue::Number(1.0), DataValue::Number(3.0)])
+            ),
+            &data
+        ));
+    }
+
+    #[test]
+    fn combines_matchers() {
+        let data = data();
+
+        let done = field(
+            "status",
+            DataPredicate::Equal(DataValue::Text("done".to_string())),
+        );
+        let urgent = field(
+            "priority",
+            DataPredicate::GreaterThan(DataValue::Number(5.0)),
+        );
+
+        assert!(!filter_data(
+            &DataMatcher::And(vec![done.clone(), urgent.clone()]),
+            &data
+        ));
+        assert!(filter_data(&DataMatcher::Or(vec![done, urgent]), &data));
+    }
+
+    #[test]
+    fn does_not_match_invalid_json() {
+        assert!(!filter_data(
+            &field("status", DataPredicate::Exists(false)),
+            &b"not json".to_vec()
+        ));
+    }
+}
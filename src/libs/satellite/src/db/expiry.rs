@@ -0,0 +1,81 @@
+use crate::db::constants::EXPIRED_DOCS_SWEEP_BATCH;
+use crate::db::runtime::{reset_expiry_sweep, schedule_expiry_sweep};
+use crate::db::state::{
+    delete_doc as delete_state_doc, delete_expiry, get_doc as get_state_doc, get_expired_entries,
+    get_next_expiry, get_rule as get_state_rule, get_trashed_doc, purge_trashed_doc,
+};
+use crate::db::types::state::{Doc, DocContext, ExpiryKey};
+use crate::hooks::db::invoke_on_delete_doc;
+use crate::ws::notify::notify_delete_doc;
+use ic_cdk::api::time;
+use junobuild_shared::ic::api::id;
+
+/// Schedules the sweep of the documents that expire next - e.g. after an upgrade, given that
+/// timers are not preserved.
+pub fn init_expiry_sweep() {
+    if let Some(expires_at) = get_next_expiry() {
+        schedule_expiry_sweep(expires_at);
+    }
+}
+
+/// Removes a batch of expired documents - and of trashed documents whose retention elapsed - and
+/// schedules the next sweep.
+///
+/// The removals are performed on behalf of the satellite, which is therefore the caller
+/// provided to the `on_delete_doc` hooks.
+pub fn sweep_expired_docs() {
+    reset_expiry_sweep();
+
+    let now = time();
+
+    for entry in get_expired_entries(now, EXPIRED_DOCS_SWEEP_BATCH) {
+        let deleted_doc = delete_expired_doc(&entry).or_else(|| purge_expired_trashed_doc(&entry));
+
+        // The entry is dropped as well if the document was deleted, overwritten, restored or its
+        // collection removed in the meantime
+        delete_expiry(&entry);
+
+        if let Some(deleted_doc) = deleted_doc {
+            let context = DocContext {
+                collection: entry.collection,
+                key: entry.key,
+                data: Some(deleted_doc),
+            };
+
+            invoke_on_delete_doc(&id(), &context);
+
+            notify_delete_doc(&id(), &context);
+        }
+    }
+
+    init_expiry_sweep();
+}
+
+fn delete_expired_doc(entry: &ExpiryKey) -> Option<Doc> {
+    let rule = get_state_rule(&entry.collection).ok()?;
+
+    let current_doc = get_state_doc(&entry.collection, &entry.key, &rule).ok()??;
+
+    if current_doc.expires_at != Some(entry.expires_at) {
+        return None;
+    }
+
+    delete_state_doc(&entry.collection, &entry.key, &rule).ok()?
+}
+
+// The purged document is provided to the hooks without `restorable_until`, as it cannot be
+// restored anymore - i.e. it is a hard delete.
+fn purge_expired_trashed_doc(entry: &ExpiryKey) -> Option<Doc> {
+    let trashed_doc = get_trashed_doc(&entry.collection, &entry.key)?;
+
+    if trashed_doc.restorable_until != Some(entry.expires_at) {
+        return None;
+    }
+
+    let purged_doc = purge_trashed_doc(&entry.collection, &entry.key)?;
+
+    Some(Doc {
+        restorable_until: None,
+        ..purged_doc
+    })
+}
@@ -1,51 +1,86 @@
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::SetDbConfig;
-use crate::db::types::state::{DbHeapState, Doc, StableKey};
+use crate::db::types::interface::{DocsBatchError, DocsBatchOperation, SetDbConfig, SetDocAccess};
+use crate::db::types::state::{
+    DbHeapState, Doc, DocAccess, DocGrant, DocRevision, ExpiryKey, IndexKey, IndexRebuild,
+    IndexRebuildStatus, IndexValue, RevisionKey, ShareKey, StableKey,
+};
+use crate::db::types::store::DocsBatchPending;
+use crate::errors::db::JUNO_DATASTORE_ERROR_BATCH_OPERATION_FAILED;
+use crate::user::usage::types::state::UserUsagePending;
 use crate::SetDoc;
 use candid::Principal;
 use ic_cdk::api::time;
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::Storable;
 use junobuild_collections::constants::db::DEFAULT_DB_COLLECTIONS;
+use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
-use junobuild_shared::data::version::next_version;
+use junobuild_shared::data::version::{next_version, next_version_from};
 use junobuild_shared::memory::serializers::{
     deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
 };
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::list::DataValue;
 use junobuild_shared::types::state::{Timestamp, UserId, Version};
 use junobuild_shared::types::state::{Timestamped, Versioned};
+use serde_json::Value;
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::mem::discriminant;
 
 impl Default for DbHeapState {
     fn default() -> Self {
+        let mut state = DbHeapState {
+            db: HashMap::new(),
+            rules: HashMap::new(),
+            config: None,
+            index_rebuilds: HashMap::new(),
+        };
+
+        state.init_system_collections();
+
+        state
+    }
+}
+
+impl DbHeapState {
+    /// Creates the system collections that do not exist yet - e.g. when the Satellite is upgraded
+    /// to a version that introduces a new one. Existing collections and their rules are kept as they are.
+    pub fn init_system_collections(&mut self) {
         let now = time();
 
-        DbHeapState {
-            db: HashMap::from(
-                DEFAULT_DB_COLLECTIONS
-                    .map(|(collection, _rules)| (collection.to_owned(), BTreeMap::new())),
-            ),
-            rules: HashMap::from(DEFAULT_DB_COLLECTIONS.map(|(collection, rule)| {
-                (
-                    collection.to_owned(),
-                    Rule {
-                        read: rule.read,
-                        write: rule.write,
-                        memory: Some(rule.memory.unwrap_or(Memory::Stable)),
-                        mutable_permissions: Some(rule.mutable_permissions.unwrap_or(false)),
-                        max_size: rule.max_size,
-                        max_capacity: rule.max_capacity,
-                        max_changes_per_user: rule.max_changes_per_user,
-                        created_at: now,
-                        updated_at: now,
-                        version: rule.version,
-                        rate_config: rule.rate_config,
-                    },
-                )
-            })),
-            config: None,
+        for (collection, rule) in DEFAULT_DB_COLLECTIONS {
+            if self.rules.contains_key(collection) {
+                continue;
+            }
+
+            self.db.insert(collection.to_owned(), BTreeMap::new());
+
+            self.rules.insert(
+                collection.to_owned(),
+                Rule {
+                    read: rule.read,
+                    write: rule.write,
+                    memory: Some(rule.memory.unwrap_or(Memory::Stable)),
+                    mutable_permissions: Some(rule.mutable_permissions.unwrap_or(false)),
+                    max_size: rule.max_size,
+                    max_capacity: rule.max_capacity,
+                    max_changes_per_user: rule.max_changes_per_user,
+                    created_at: now,
+                    updated_at: now,
+                    version: rule.version,
+                    rate_config: rule.rate_config,
+                    indexes: rule.indexes,
+                    default_ttl_ns: rule.default_ttl_ns,
+                    max_revisions: rule.max_revisions,
+                    trash_retention_ns: rule.trash_retention_ns,
+                    max_items_per_user: rule.max_items_per_user,
+                    max_bytes_per_user: rule.max_bytes_per_user,
+                    json_schema: rule.json_schema,
+                },
+            );
         }
     }
 }
@@ -101,7 +136,12 @@ impl Storable for StableKey {
 }
 
 impl Doc {
-    pub fn prepare(caller: Principal, current_doc: &Option<Doc>, user_doc: SetDoc) -> Self {
+    pub fn prepare(
+        caller: Principal,
+        current_doc: &Option<Doc>,
+        user_doc: SetDoc,
+        default_ttl_ns: Option<u64>,
+    ) -> Self {
         let now = time();
 
         let created_at: Timestamp = match current_doc {
@@ -118,6 +158,10 @@ impl Doc {
 
         let updated_at: Timestamp = now;
 
+        let expires_at: Option<Timestamp> = user_doc
+            .expires_at
+            .or_else(|| default_ttl_ns.map(|ttl| now.saturating_add(ttl)));
+
         Doc {
             owner,
             data: user_doc.data,
@@ -125,8 +169,24 @@ impl Doc {
             created_at,
             updated_at,
             version: Some(version),
+            expires_at,
+            restorable_until: None,
         }
     }
+
+    /// The document is handed over to its new owner as is - i.e. its creation date is preserved.
+    pub fn transfer(current_doc: &Doc, owner: UserId) -> Self {
+        Doc {
+            owner,
+            updated_at: time(),
+            version: Some(next_version_from(current_doc)),
+            ..current_doc.clone()
+        }
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
 }
 
 impl Versioned for Doc {
@@ -162,3 +222,402 @@ impl Versioned for DbConfig {
         self.version
     }
 }
+
+impl Storable for IndexKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for ExpiryKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for RevisionKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for DocRevision {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+// A revision is written once, therefore it is created and updated at the same time.
+impl Timestamped for DocRevision {
+    fn created_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    fn cmp_updated_at(&self, other: &Self) -> Ordering {
+        self.updated_at.cmp(&other.updated_at)
+    }
+
+    fn cmp_created_at(&self, other: &Self) -> Ordering {
+        self.updated_at.cmp(&other.updated_at)
+    }
+}
+
+impl DocRevision {
+    pub fn from_doc(doc: &Doc, caller: UserId) -> Self {
+        Self {
+            owner: doc.owner,
+            data: doc.data.clone(),
+            description: doc.description.clone(),
+            version: doc.version.unwrap_or_default(),
+            updated_at: doc.updated_at,
+            caller,
+        }
+    }
+}
+
+impl Storable for DocAccess {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for ShareKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl DocAccess {
+    pub fn prepare(current_access: &Option<DocAccess>, user_access: SetDocAccess) -> Self {
+        let now = time();
+
+        let created_at: Timestamp = match current_access {
+            None => now,
+            Some(current_access) => current_access.created_at,
+        };
+
+        let version = next_version(current_access);
+
+        DocAccess {
+            readers: user_access.readers,
+            writers: user_access.writers,
+            created_at,
+            updated_at: now,
+            version: Some(version),
+        }
+    }
+
+    /// The writers can read the document as well.
+    pub fn can_read(&self, user: UserId, now: Timestamp) -> bool {
+        Self::is_granted(&self.readers, user, now) || self.can_write(user, now)
+    }
+
+    pub fn can_write(&self, user: UserId, now: Timestamp) -> bool {
+        Self::is_granted(&self.writers, user, now)
+    }
+
+    /// The users - readers and writers - the document is shared with, including those whose grant expired.
+    pub fn users(&self) -> Vec<UserId> {
+        let mut users: Vec<UserId> = self
+            .readers
+            .iter()
+            .chain(self.writers.iter())
+            .map(|grant| grant.user)
+            .collect();
+
+        users.sort();
+        users.dedup();
+
+        users
+    }
+
+    /// The access list without the grants of the user - e.g. the new owner of the document - or
+    /// `None` if the user was not granted any access.
+    pub fn revoke(&self, user: UserId) -> Option<Self> {
+        if !self.users().contains(&user) {
+            return None;
+        }
+
+        let without_user = |grants: &[DocGrant]| -> Vec<DocGrant> {
+            grants
+                .iter()
+                .filter(|grant| grant.user != user)
+                .cloned()
+                .collect()
+        };
+
+        Some(DocAccess {
+            readers: without_user(&self.readers),
+            writers: without_user(&self.writers),
+            created_at: self.created_at,
+            updated_at: time(),
+            version: Some(next_version_from(self)),
+        })
+    }
+
+    fn is_granted(grants: &[DocGrant], user: UserId, now: Timestamp) -> bool {
+        grants
+            .iter()
+            .any(|grant| grant.user == user && !grant.is_expired(now))
+    }
+}
+
+impl Versioned for DocAccess {
+    fn version(&self) -> Option<Version> {
+        self.version
+    }
+}
+
+impl DocGrant {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+impl IndexRebuild {
+    pub fn init() -> Self {
+        let now = time();
+
+        IndexRebuild {
+            status: IndexRebuildStatus::Cleaning,
+            last_key: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl IndexValue {
+    /// Only scalar values are indexed. Arrays and objects are skipped.
+    pub fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null => Some(IndexValue::Null),
+            Value::Bool(value) => Some(IndexValue::Bool(*value)),
+            Value::Number(value) => value.as_f64().map(IndexValue::from_number),
+            Value::String(value) => Some(IndexValue::Text(value.clone())),
+            Value::Array(_) | Value::Object(_) => None,
+        }
+    }
+
+    // Flips the bits of the IEEE 754 representation so that the unsigned integers are sorted
+    // like the floats: negative numbers are inverted, positive numbers get their sign bit set.
+    fn from_number(value: f64) -> Self {
+        // -0.0 and 0.0 are the same value
+        let value = if value == 0.0 { 0.0 } else { value };
+
+        let bits = value.to_bits();
+
+        let ordered = if bits >> 63 == 1 {
+            !bits
+        } else {
+            bits | (1 << 63)
+        };
+
+        IndexValue::Number(ordered)
+    }
+
+    pub fn is_same_type(&self, other: &IndexValue) -> bool {
+        discriminant(self) == discriminant(other)
+    }
+
+    /// The lowest value of the same type, from which a range scan of that type starts.
+    pub fn type_min(&self) -> Self {
+        match self {
+            IndexValue::Null => IndexValue::Null,
+            IndexValue::Bool(_) => IndexValue::Bool(false),
+            IndexValue::Number(_) => IndexValue::Number(0),
+            IndexValue::Text(_) => IndexValue::Text("".to_string()),
+        }
+    }
+}
+
+impl From<&DataValue> for IndexValue {
+    fn from(value: &DataValue) -> Self {
+        match value {
+            DataValue::Null => IndexValue::Null,
+            DataValue::Bool(value) => IndexValue::Bool(*value),
+            DataValue::Number(value) => IndexValue::from_number(*value),
+            DataValue::Text(value) => IndexValue::Text(value.clone()),
+        }
+    }
+}
+
+impl DocsBatchOperation {
+    pub fn collection(&self) -> &CollectionKey {
+        match self {
+            DocsBatchOperation::Set { collection, .. }
+            | DocsBatchOperation::Delete { collection, .. }
+            | DocsBatchOperation::AssertVersion { collection, .. } => collection,
+        }
+    }
+
+    pub fn key(&self) -> &Key {
+        match self {
+            DocsBatchOperation::Set { key, .. }
+            | DocsBatchOperation::Delete { key, .. }
+            | DocsBatchOperation::AssertVersion { key, .. } => key,
+        }
+    }
+}
+
+impl DocsBatchError {
+    pub fn from_operation(index: usize, operation: &DocsBatchOperation, error: String) -> Self {
+        DocsBatchError {
+            index,
+            collection: operation.collection().clone(),
+            key: operation.key().clone(),
+            error,
+        }
+    }
+}
+
+impl DocsBatchPending {
+    pub fn usage(&mut self, user: UserId, collection: &CollectionKey) -> &mut UserUsagePending {
+        self.usages.entry((user, collection.clone())).or_default()
+    }
+}
+
+impl Display for DocsBatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} (operation {}, {} {}): {}",
+            JUNO_DATASTORE_ERROR_BATCH_OPERATION_FAILED,
+            self.index,
+            self.collection,
+            self.key,
+            self.error
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u8) -> UserId {
+        Principal::from_slice(&[id])
+    }
+
+    fn access(readers: Vec<DocGrant>, writers: Vec<DocGrant>) -> DocAccess {
+        DocAccess {
+            readers,
+            writers,
+            created_at: 0,
+            updated_at: 0,
+            version: Some(1),
+        }
+    }
+
+    #[test]
+    fn writers_can_read_but_readers_cannot_write() {
+        let access = access(
+            vec![DocGrant {
+                user: user(1),
+                expires_at: None,
+            }],
+            vec![DocGrant {
+                user: user(2),
+                expires_at: None,
+            }],
+        );
+
+        assert!(access.can_read(user(1), 10));
+        assert!(!access.can_write(user(1), 10));
+
+        assert!(access.can_read(user(2), 10));
+        assert!(access.can_write(user(2), 10));
+
+        assert!(!access.can_read(user(3), 10));
+    }
+
+    #[test]
+    fn expired_grants_are_ignored() {
+        let access = access(
+            vec![DocGrant {
+                user: user(1),
+                expires_at: Some(10),
+            }],
+            vec![],
+        );
+
+        assert!(access.can_read(user(1), 9));
+        assert!(!access.can_read(user(1), 10));
+    }
+
+    #[test]
+    fn users_are_deduplicated() {
+        let grant = DocGrant {
+            user: user(1),
+            expires_at: None,
+        };
+
+        let access = access(vec![grant.clone()], vec![grant]);
+
+        assert_eq!(access.users(), vec![user(1)]);
+    }
+}
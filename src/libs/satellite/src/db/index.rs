@@ -0,0 +1,83 @@
+use crate::db::data::{decode_data, resolve_path};
+use crate::db::types::state::IndexValue;
+use junobuild_collections::types::rules::IndexPath;
+use junobuild_shared::types::core::Blob;
+
+/// Extracts the values of the indexed paths from the data of a document.
+///
+/// Paths that do not resolve to a scalar value - missing, array or object - are not indexed.
+/// Data that is not valid JSON has no indexed values.
+pub fn index_values(data: &Blob, paths: &[IndexPath]) -> Vec<(IndexPath, IndexValue)> {
+    if paths.is_empty() {
+        return Vec::new();
+    }
+
+    let Some(json) = decode_data(data) else {
+        return Vec::new();
+    };
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            resolve_path(&json, path)
+                .and_then(IndexValue::from_json)
+                .map(|value| (path.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use junobuild_shared::types::list::DataValue;
+
+    fn number(value: f64) -> IndexValue {
+        IndexValue::from(&DataValue::Number(value))
+    }
+
+    #[test]
+    fn resolves_nested_scalar_values() {
+        let data = br#"{"status":"done","author":{"name":"Ada","age":36},"tags":["a"]}"#.to_vec();
+
+        let paths = vec![
+            "status".to_string(),
+            "author.name".to_string(),
+            "author.age".to_string(),
+            "tags".to_string(),
+            "missing".to_string(),
+        ];
+
+        let values = index_values(&data, &paths);
+
+        assert_eq!(values.len(), 3);
+        assert!(values.contains(&("status".to_string(), IndexValue::Text("done".to_string()))));
+        assert!(values.contains(&(
+            "author.name".to_string(),
+            IndexValue::Text("Ada".to_string())
+        )));
+        assert!(values.contains(&("author.age".to_string(), number(36.0))));
+    }
+
+    #[test]
+    fn ignores_invalid_json() {
+        let values = index_values(&b"not json".to_vec(), &["status".to_string()]);
+
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn orders_numbers_naturally() {
+        let sorted = [-1000.5, -1.0, -0.5, 0.0, 0.25, 1.0, 42.0, 1e10];
+
+        for pair in sorted.windows(2) {
+            assert!(
+                number(pair[0]) < number(pair[1]),
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+
+        assert!(number(-0.0) == number(0.0));
+    }
+}
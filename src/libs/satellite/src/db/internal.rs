@@ -1,10 +1,13 @@
 use crate::db::state::{
-    delete_doc as delete_state_doc, get_doc as get_state_doc, insert_doc as insert_state_doc,
+    copy_docs as copy_state_docs, delete_doc as delete_state_doc,
+    delete_docs_from_memory as delete_state_docs_from_memory, get_doc as get_state_doc,
+    get_doc_access, get_docs_from as get_state_docs_from, get_shared_keys_from,
+    get_trashed_docs_from, insert_doc as insert_state_doc, insert_doc_access, purge_trashed_doc,
 };
 use crate::db::types::state::{Doc, DocUpsert};
 use crate::SetDoc;
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::UserId;
 
@@ -20,6 +23,19 @@ pub fn unsafe_get_doc(
     get_state_doc(collection, key, rule)
 }
 
+/// Retrieves up to `limit` documents ordered by key, starting at - and including - `start`, directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_docs_from(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+    rule: &Rule,
+) -> Result<Vec<(Key, Doc)>, String> {
+    get_state_docs_from(collection, start, limit, rule)
+}
+
 /// Inserts or updates a document directly in the state.
 ///
 /// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
@@ -33,9 +49,9 @@ pub fn unsafe_set_doc(
 ) -> Result<DocUpsert, String> {
     let current_doc = get_state_doc(collection, key, rule)?;
 
-    let doc: Doc = Doc::prepare(caller, &current_doc, value);
+    let doc: Doc = Doc::prepare(caller, &current_doc, value, rule.default_ttl_ns);
 
-    let (_evicted_doc, after) = insert_state_doc(collection, key, &doc, rule)?;
+    let (_evicted_doc, after) = insert_state_doc(collection, key, &doc, rule, &caller)?;
 
     Ok(DocUpsert {
         before: current_doc,
@@ -43,6 +59,25 @@ pub fn unsafe_set_doc(
     })
 }
 
+/// Inserts a document as it is - i.e. keeping its owner, timestamps and version - directly in the state.
+///
+/// The usage of the owner is tracked. The insertion is neither recorded in the changes nor counted
+/// in the `max_changes_per_user` of the collection.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_insert_doc(
+    caller: UserId,
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    rule: &Rule,
+) -> Result<Doc, String> {
+    let (_evicted_doc, after) = insert_state_doc(collection, key, doc, rule, &caller)?;
+
+    Ok(after)
+}
+
 /// Delete a document directly from the state.
 ///
 /// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
@@ -54,3 +89,74 @@ pub fn unsafe_delete_doc(
 ) -> Result<Option<Doc>, String> {
     delete_state_doc(collection, key, rule)
 }
+
+/// Retrieves up to `limit` trashed documents ordered by key, starting at - and including - `start`, directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_trashed_docs_from(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+) -> Vec<(Key, Doc)> {
+    get_trashed_docs_from(collection, start, limit)
+}
+
+/// Permanently deletes a trashed document, its revisions and access list directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_purge_trashed_doc(collection: &CollectionKey, key: &Key) -> Option<Doc> {
+    purge_trashed_doc(collection, key)
+}
+
+/// Retrieves up to `limit` keys of the documents shared with a user ordered by key, starting at - and including - `start`, directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_shared_keys_from(
+    user: &UserId,
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+) -> Vec<Key> {
+    get_shared_keys_from(user, collection, start, limit)
+}
+
+/// Removes the grants of a user from the access list of a document directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_revoke_doc_access(collection: &CollectionKey, key: &Key, user: UserId) {
+    if let Some(access) =
+        get_doc_access(collection, key).and_then(|current_access| current_access.revoke(user))
+    {
+        insert_doc_access(collection, key, &access);
+    }
+}
+
+/// Copies up to `limit` documents ordered by key, starting at - and including - `start`, to the `target` memory.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_copy_docs(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+    rule: &Rule,
+    target: &Memory,
+) -> Result<(usize, Option<Key>), String> {
+    copy_state_docs(collection, start, limit, rule, target)
+}
+
+/// Deletes the documents of a collection from the given memory.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_delete_docs_from_memory(
+    collection: &CollectionKey,
+    limit: usize,
+    memory: &Memory,
+) -> usize {
+    delete_state_docs_from_memory(collection, limit, memory)
+}
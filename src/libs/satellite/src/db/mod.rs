@@ -1,6 +1,12 @@
 mod assert;
+mod constants;
+mod data;
+pub mod expiry;
 pub mod impls;
+mod index;
 pub mod internal;
+mod patch;
+pub mod rebuild;
 pub mod runtime;
 mod state;
 pub mod store;
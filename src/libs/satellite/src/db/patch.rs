@@ -0,0 +1,273 @@
+use crate::db::types::interface::DocPatch;
+use crate::errors::db::JUNO_DATASTORE_ERROR_INVALID_PATCH;
+use junobuild_shared::types::core::Blob;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// An operation of an RFC 6902 JSON patch.
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum JsonPatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// Applies a patch to the data of a document - or to `null` if the document does not exist yet -
+/// and returns the patched data.
+///
+/// Both the data and the patch are expected to be JSON encoded.
+pub fn apply_patch(data: Option<&Blob>, patch: &DocPatch) -> Result<Blob, String> {
+    apply_patch_impl(data, patch)
+        .map_err(|err| format!("{JUNO_DATASTORE_ERROR_INVALID_PATCH} ({err})"))
+}
+
+fn apply_patch_impl(data: Option<&Blob>, patch: &DocPatch) -> Result<Blob, String> {
+    let mut target: Value = match data {
+        None => Value::Null,
+        Some(data) => {
+            serde_json::from_slice(data).map_err(|err| format!("data is not valid JSON: {err}"))?
+        }
+    };
+
+    match patch {
+        DocPatch::Merge(patch) => {
+            let patch: Value = serde_json::from_slice(patch)
+                .map_err(|err| format!("patch is not valid JSON: {err}"))?;
+
+            merge_patch(&mut target, &patch);
+        }
+        DocPatch::Json(patch) => {
+            let operations: Vec<JsonPatchOperation> = serde_json::from_slice(patch)
+                .map_err(|err| format!("patch is not a valid JSON patch: {err}"))?;
+
+            json_patch(&mut target, &operations)?;
+        }
+    }
+
+    serde_json::to_vec(&target).map_err(|err| err.to_string())
+}
+
+// ---------------------------------------------------------
+// RFC 7396 - JSON merge patch
+// ---------------------------------------------------------
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------
+// RFC 6902 - JSON patch
+// ---------------------------------------------------------
+
+fn json_patch(target: &mut Value, operations: &[JsonPatchOperation]) -> Result<(), String> {
+    for operation in operations {
+        match operation {
+            JsonPatchOperation::Add { path, value } => add(target, path, value.clone())?,
+            JsonPatchOperation::Remove { path } => {
+                remove(target, path)?;
+            }
+            JsonPatchOperation::Replace { path, value } => {
+                let current = target
+                    .pointer_mut(path)
+                    .ok_or_else(|| format!("path not found: {path}"))?;
+                *current = value.clone();
+            }
+            JsonPatchOperation::Move { from, path } => {
+                if path.starts_with(&format!("{from}/")) {
+                    return Err(format!("cannot move {from} into one of its children"));
+                }
+
+                let value = remove(target, from)?;
+                add(target, path, value)?;
+            }
+            JsonPatchOperation::Copy { from, path } => {
+                let value = target
+                    .pointer(from)
+                    .cloned()
+                    .ok_or_else(|| format!("path not found: {from}"))?;
+                add(target, path, value)?;
+            }
+            JsonPatchOperation::Test { path, value } => {
+                if target.pointer(path) != Some(value) {
+                    return Err(format!("test failed: {path}"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn add(target: &mut Value, path: &str, value: Value) -> Result<(), String> {
+    if path.is_empty() {
+        *target = value;
+        return Ok(());
+    }
+
+    let (parent, token) = split_pointer(path)?;
+
+    match target.pointer_mut(parent) {
+        Some(Value::Object(map)) => {
+            map.insert(token, value);
+            Ok(())
+        }
+        Some(Value::Array(array)) => {
+            let index = if token == "-" {
+                array.len()
+            } else {
+                array_index(&token, array.len() + 1)
+                    .ok_or_else(|| format!("invalid array index: {path}"))?
+            };
+
+            array.insert(index, value);
+            Ok(())
+        }
+        _ => Err(format!("path not found: {path}")),
+    }
+}
+
+fn remove(target: &mut Value, path: &str) -> Result<Value, String> {
+    let (parent, token) = split_pointer(path)?;
+
+    let removed = match target.pointer_mut(parent) {
+        Some(Value::Object(map)) => map.remove(&token),
+        Some(Value::Array(array)) => {
+            array_index(&token, array.len()).map(|index| array.remove(index))
+        }
+        _ => None,
+    };
+
+    removed.ok_or_else(|| format!("path not found: {path}"))
+}
+
+// Splits a JSON pointer into the pointer of its parent and its last - unescaped - reference token.
+fn split_pointer(path: &str) -> Result<(&str, String), String> {
+    let (parent, token) = path
+        .rsplit_once('/')
+        .ok_or_else(|| format!("invalid path: {path}"))?;
+
+    Ok((parent, token.replace("~1", "/").replace("~0", "~")))
+}
+
+// An index of an array, without leading zeros, lower than the upper bound.
+fn array_index(token: &str, upper_bound: usize) -> Option<usize> {
+    let index = token.parse::<usize>().ok()?;
+
+    (index.to_string() == token && index < upper_bound).then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn patch(data: Value, patch: DocPatch) -> Result<Value, String> {
+        let data = serde_json::to_vec(&data).unwrap();
+        let patched = apply_patch(Some(&data), &patch)?;
+        Ok(serde_json::from_slice(&patched).unwrap())
+    }
+
+    fn encode(value: Value) -> Blob {
+        serde_json::to_vec(&value).unwrap()
+    }
+
+    #[test]
+    fn applies_merge_patch() {
+        let result = patch(
+            json!({"title": "Hello", "author": {"name": "Ada", "age": 36}, "tags": ["a"]}),
+            DocPatch::Merge(encode(
+                json!({"title": "Hi", "author": {"age": null}, "tags": ["b"]}),
+            )),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            json!({"title": "Hi", "author": {"name": "Ada"}, "tags": ["b"]})
+        );
+    }
+
+    #[test]
+    fn merge_patch_creates_missing_data() {
+        let patched =
+            apply_patch(None, &DocPatch::Merge(encode(json!({"a": 1, "b": null})))).unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<Value>(&patched).unwrap(),
+            json!({"a": 1})
+        );
+    }
+
+    #[test]
+    fn applies_json_patch() {
+        let result = patch(
+            json!({"a": {"b": 1}, "list": [1, 2], "c~d": true}),
+            DocPatch::Json(encode(json!([
+                {"op": "test", "path": "/a/b", "value": 1},
+                {"op": "add", "path": "/list/1", "value": 3},
+                {"op": "add", "path": "/list/-", "value": 4},
+                {"op": "replace", "path": "/a/b", "value": 2},
+                {"op": "copy", "from": "/a", "path": "/e"},
+                {"op": "move", "from": "/c~0d", "path": "/f"},
+                {"op": "remove", "path": "/list/0"}
+            ]))),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            json!({"a": {"b": 2}, "list": [3, 2, 4], "e": {"b": 2}, "f": true})
+        );
+    }
+
+    #[test]
+    fn rejects_failing_json_patch() {
+        let data = json!({"a": 1, "list": [1]});
+
+        for operations in [
+            json!([{"op": "test", "path": "/a", "value": 2}]),
+            json!([{"op": "remove", "path": "/missing"}]),
+            json!([{"op": "replace", "path": "/missing", "value": 1}]),
+            json!([{"op": "add", "path": "/list/2", "value": 1}]),
+            json!([{"op": "add", "path": "/list/01", "value": 1}]),
+            json!([{"op": "unknown", "path": "/a"}]),
+        ] {
+            let result = patch(data.clone(), DocPatch::Json(encode(operations)));
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let result = apply_patch(
+            Some(&b"not json".to_vec()),
+            &DocPatch::Merge(encode(json!({}))),
+        );
+
+        assert!(result
+            .unwrap_err()
+            .starts_with(JUNO_DATASTORE_ERROR_INVALID_PATCH));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::db::constants::INDEX_REBUILD_BATCH;
+use crate::db::runtime::{reset_index_rebuild, schedule_index_rebuild};
+use crate::db::state::{
+    delete_collection_indexes_batch, delete_index_rebuild, get_docs_from as get_state_docs_from,
+    get_index_rebuild, get_index_rebuilds, get_rule as get_state_rule, insert_docs_indexes,
+    insert_index_rebuild,
+};
+use crate::db::types::state::{IndexRebuild, IndexRebuildStatus};
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Rule;
+
+/// Starts - or restarts from scratch - the rebuild of the indexes of a collection, e.g. after its
+/// rule declared other indexed paths.
+pub fn start_index_rebuild(collection: &CollectionKey) {
+    insert_index_rebuild(collection, &IndexRebuild::init());
+
+    schedule_index_rebuild();
+}
+
+/// Schedules the rebuilds that are not over - e.g. after an upgrade, given that timers are not
+/// preserved.
+pub fn init_index_rebuilds() {
+    if !get_index_rebuilds().is_empty() {
+        schedule_index_rebuild();
+    }
+}
+
+/// Processes a batch of the rebuild of the indexes of each collection and schedules the next one
+/// until every rebuild is over.
+pub fn rebuild_indexes_batch() {
+    reset_index_rebuild();
+
+    for collection in get_index_rebuilds() {
+        let rebuild = get_index_rebuild(&collection);
+        let rule = get_state_rule(&collection).ok();
+
+        // The rebuild is dropped as well if the collection was removed in the meantime
+        let next = match (rebuild, rule) {
+            (Some(rebuild), Some(rule)) => match rebuild_batch(&collection, &rule, rebuild) {
+                Ok(next) => next,
+                Err(err) => {
+                    // The rebuild is kept as it is and the batch retried with the next one
+                    ic_cdk::println!(
+                        "❌ Failed to rebuild the indexes of {}: {}",
+                        collection,
+                        err
+                    );
+                    continue;
+                }
+            },
+            _ => None,
+        };
+
+        match next {
+            Some(rebuild) => insert_index_rebuild(&collection, &rebuild),
+            None => delete_index_rebuild(&collection),
+        }
+    }
+
+    init_index_rebuilds();
+}
+
+// Returns the progress of the rebuild, None once it is over or an error if the batch should be
+// retried.
+fn rebuild_batch(
+    collection: &CollectionKey,
+    rule: &Rule,
+    rebuild: IndexRebuild,
+) -> Result<Option<IndexRebuild>, String> {
+    match rebuild.status {
+        IndexRebuildStatus::Cleaning => {
+            let deleted = delete_collection_indexes_batch(collection, INDEX_REBUILD_BATCH);
+
+            // Everything was deleted, the documents are indexed from the next batch on
+            let status = if deleted < INDEX_REBUILD_BATCH {
+                IndexRebuildStatus::Indexing
+            } else {
+                IndexRebuildStatus::Cleaning
+            };
+
+            Ok(Some(IndexRebuild {
+                status,
+                updated_at: time(),
+                ..rebuild
+            }))
+        }
+        IndexRebuildStatus::Indexing => {
+            if rule
+                .indexes
+                .as_ref()
+                .is_none_or(|indexes| indexes.is_empty())
+            {
+                return Ok(None);
+            }
+
+            // Resume right after the last indexed key
+            let start = rebuild
+                .last_key
+                .as_ref()
+                .map_or_else(String::new, |last_key| format!("{last_key}\0"));
+
+            let docs = get_state_docs_from(collection, &start, INDEX_REBUILD_BATCH, rule)?;
+
+            insert_docs_indexes(collection, &docs, rule);
+
+            if docs.len() < INDEX_REBUILD_BATCH {
+                return Ok(None);
+            }
+
+            Ok(Some(IndexRebuild {
+                last_key: docs.last().map(|(key, _)| key.clone()),
+                updated_at: time(),
+                ..rebuild
+            }))
+        }
+    }
+}
@@ -1,7 +1,17 @@
+use crate::db::expiry::sweep_expired_docs;
+use crate::db::rebuild::rebuild_indexes_batch;
+use crate::db::types::state::ExpiryTimer;
+use crate::errors::db::JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH;
 use crate::memory::state::STATE;
+use ic_cdk::api::time;
+use ic_cdk_timers::{clear_timer, set_timer};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::rate::types::RateConfig;
-use junobuild_shared::rate::utils::increment_and_assert_rate_store;
+use junobuild_shared::rate::utils::{assert_rate_store_many, increment_and_assert_rate_store};
+use junobuild_shared::types::state::Timestamp;
+use serde_json::Value;
+use std::rc::Rc;
+use std::time::Duration;
 
 // ---------------------------------------------------------
 // Rates
@@ -19,3 +29,128 @@ pub fn increment_and_assert_rate(
         )
     })
 }
+
+/// Asserts that `count` tokens of the rate of the collection are available, without consuming them.
+pub fn assert_rate(
+    collection: &CollectionKey,
+    config: &Option<RateConfig>,
+    count: usize,
+) -> Result<(), String> {
+    STATE.with(|state| {
+        assert_rate_store_many(
+            collection,
+            config,
+            count,
+            &state.borrow().runtime.db.rate_tokens,
+        )
+    })
+}
+
+// ---------------------------------------------------------
+// Expiries
+// ---------------------------------------------------------
+
+/// Schedules a sweep of the expired documents at `expires_at`, unless a sweep is already
+/// scheduled earlier.
+pub fn schedule_expiry_sweep(expires_at: Timestamp) {
+    let scheduled = STATE.with(|state| state.borrow().runtime.db.expiry_timer.clone());
+
+    if let Some(scheduled) = scheduled {
+        if scheduled.expires_at <= expires_at {
+            return;
+        }
+
+        clear_timer(scheduled.timer_id);
+    }
+
+    let delay = Duration::from_nanos(expires_at.saturating_sub(time()));
+
+    let timer_id = set_timer(delay, async {
+        sweep_expired_docs();
+    });
+
+    STATE.with(|state| {
+        state.borrow_mut().runtime.db.expiry_timer = Some(ExpiryTimer {
+            expires_at,
+            timer_id,
+        })
+    });
+}
+
+/// Forgets the scheduled sweep once it has been executed.
+pub fn reset_expiry_sweep() {
+    STATE.with(|state| state.borrow_mut().runtime.db.expiry_timer = None);
+}
+
+// ---------------------------------------------------------
+// Indexes
+// ---------------------------------------------------------
+
+/// Schedules the next batch of the rebuilds of the indexes, unless it is already scheduled.
+pub fn schedule_index_rebuild() {
+    let scheduled = STATE.with(|state| state.borrow().runtime.db.index_rebuild_timer.is_some());
+
+    if scheduled {
+        return;
+    }
+
+    let timer_id = set_timer(Duration::ZERO, async {
+        rebuild_indexes_batch();
+    });
+
+    STATE.with(|state| state.borrow_mut().runtime.db.index_rebuild_timer = Some(timer_id));
+}
+
+/// Forgets the scheduled batch once it has been executed.
+pub fn reset_index_rebuild() {
+    STATE.with(|state| state.borrow_mut().runtime.db.index_rebuild_timer = None);
+}
+
+// ---------------------------------------------------------
+// JSON schemas
+// ---------------------------------------------------------
+
+/// Returns the parsed JSON schema of the collection - parsed and cached on first use.
+pub fn get_json_schema(collection: &CollectionKey, json_schema: &str) -> Result<Rc<Value>, String> {
+    let cached = STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .db
+            .json_schemas
+            .get(collection)
+            .cloned()
+    });
+
+    if let Some(schema) = cached {
+        return Ok(schema);
+    }
+
+    let schema: Rc<Value> = Rc::new(
+        serde_json::from_str(json_schema)
+            .map_err(|err| format!("{JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH} ({err})"))?,
+    );
+
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .db
+            .json_schemas
+            .insert(collection.clone(), schema.clone())
+    });
+
+    Ok(schema)
+}
+
+/// Drops the cached JSON schema of the collection, e.g. when its rule is set.
+pub fn reset_json_schema(collection: &CollectionKey) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .db
+            .json_schemas
+            .remove(collection)
+    });
+}
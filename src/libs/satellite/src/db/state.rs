@@ -1,14 +1,27 @@
+use crate::db::index::index_values;
+use crate::db::runtime::schedule_expiry_sweep;
 use crate::db::types::config::DbConfig;
-use crate::db::types::state::{Collection, DbHeap, DbHeapState, DbStable, Doc, StableKey};
+use crate::db::types::state::{
+    Collection, DbAccessStable, DbHeap, DbHeapState, DbIndexesStable, DbRevisionsStable,
+    DbSharesStable, DbStable, DbTrashStable, Doc, DocAccess, DocRevision, ExpiryKey, IndexKey,
+    IndexRebuild, IndexValue, RevisionKey, ShareKey, StableKey,
+};
 use crate::memory::state::STATE;
+use crate::rules::store::get_collection_migration;
+use crate::types::state::CollectionType;
+use crate::user::usage::track::track_db_usage;
+use crate::user::usage::types::state::UserUsageItem;
+use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::msg::msg_db_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_collections::types::rules::{IndexPath, Memory, Rule};
 use junobuild_collections::utils::range_collection_end;
 use junobuild_shared::data::collect::collect_stable_vec;
 use junobuild_shared::types::core::Key;
+use junobuild_shared::types::list::IndexMatcher;
+use junobuild_shared::types::state::{Timestamp, UserId, Version};
 use std::collections::BTreeMap;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
 // ---------------------------------------------------------
 // Collections
 // ---------------------------------------------------------
@@ -40,6 +53,24 @@ pub fn delete_collection(
     collection: &CollectionKey,
     memory: &Option<Memory>,
 ) -> Result<(), String> {
+    STATE.with(|state| {
+        delete_collection_indexes(collection, &mut state.borrow_mut().stable.db_indexes)
+    });
+
+    delete_index_rebuild(collection);
+
+    STATE.with(|state| {
+        delete_collection_revisions(collection, &mut state.borrow_mut().stable.db_revisions)
+    });
+
+    STATE
+        .with(|state| delete_collection_trash(collection, &mut state.borrow_mut().stable.db_trash));
+
+    STATE.with(|state| {
+        let stable = &mut state.borrow_mut().stable;
+        delete_collection_access(collection, &mut stable.db_access, &mut stable.db_shares)
+    });
+
     match memory.clone().unwrap_or_default() {
         Memory::Heap => STATE
             .with(|state| delete_collection_heap(collection, &mut state.borrow_mut().heap.db.db)),
@@ -111,8 +142,72 @@ pub fn insert_doc(
     key: &Key,
     doc: &Doc,
     rule: &Rule,
+    caller: &UserId,
 ) -> Result<(Option<(Key, Doc)>, Doc), String> {
-    match rule.mem() {
+    let indexes = rule_indexes(rule);
+
+    // The entries of the current document - and its usage - are replaced by those of the new one
+    let current_doc = if indexes.is_empty() && is_system_collection(collection) {
+        None
+    } else {
+        get_doc(collection, key, rule)?
+    };
+
+    let (evicted_doc, after) = insert_doc_memory(collection, key, doc, rule)?;
+
+    track_db_usage(
+        collection,
+        key,
+        &current_doc.as_ref().map(UserUsageItem::from_doc),
+        &Some(UserUsageItem::from_doc(&after)),
+    )?;
+
+    if let Some((evicted_key, evicted_doc)) = &evicted_doc {
+        track_db_usage(
+            collection,
+            evicted_key,
+            &Some(UserUsageItem::from_doc(evicted_doc)),
+            &None,
+        )?;
+    }
+
+    if !indexes.is_empty() {
+        if let Some(current_doc) = &current_doc {
+            delete_doc_indexes(collection, key, current_doc, indexes);
+        }
+
+        if let Some((evicted_key, evicted_doc)) = &evicted_doc {
+            delete_doc_indexes(collection, evicted_key, evicted_doc, indexes);
+        }
+
+        insert_doc_indexes(collection, key, &after, indexes);
+    }
+
+    insert_doc_expiry(collection, key, &after);
+
+    if let Some((evicted_key, _)) = &evicted_doc {
+        delete_doc_revisions(collection, evicted_key);
+        delete_doc_access(collection, evicted_key);
+    }
+
+    // A document set with the key of a trashed document replaces it for good
+    if delete_trashed_doc(collection, key).is_some() {
+        delete_doc_revisions(collection, key);
+        delete_doc_access(collection, key);
+    }
+
+    insert_doc_revision(collection, key, &after, caller, rule.max_revisions);
+
+    Ok((evicted_doc, after))
+}
+
+fn insert_doc_memory(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    rule: &Rule,
+) -> Result<(Option<(Key, Doc)>, Doc), String> {
+    let (evicted_doc, after) = match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             insert_doc_heap(
                 collection,
@@ -131,7 +226,15 @@ pub fn insert_doc(
                 &mut state.borrow_mut().stable.db,
             )
         }),
+    }?;
+
+    mirror_insert_doc(collection, key, &after)?;
+
+    if let Some((evicted_key, _)) = &evicted_doc {
+        mirror_delete_doc(collection, evicted_key)?;
     }
+
+    Ok((evicted_doc, after))
 }
 
 pub fn delete_doc(
@@ -139,13 +242,72 @@ pub fn delete_doc(
     key: &Key,
     rule: &Rule,
 ) -> Result<Option<Doc>, String> {
-    match rule.mem() {
+    let deleted_doc = remove_doc(collection, key, rule)?;
+
+    if deleted_doc.is_some() {
+        delete_doc_revisions(collection, key);
+        delete_doc_access(collection, key);
+    }
+
+    Ok(deleted_doc)
+}
+
+/// Moves a document to the trash of its collection, from which it can be restored until
+/// `restorable_until`. Its revisions and access list are kept until it is purged.
+pub fn trash_doc(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+    restorable_until: Timestamp,
+) -> Result<Option<Doc>, String> {
+    let Some(deleted_doc) = remove_doc(collection, key, rule)? else {
+        return Ok(None);
+    };
+
+    let trashed_doc = Doc {
+        restorable_until: Some(restorable_until),
+        ..deleted_doc
+    };
+
+    STATE.with(|state| {
+        state.borrow_mut().stable.db_trash.insert(
+            StableKey {
+                collection: collection.clone(),
+                key: key.clone(),
+            },
+            trashed_doc.clone(),
+        )
+    });
+
+    insert_expiry(collection, key, restorable_until);
+
+    Ok(Some(trashed_doc))
+}
+
+fn remove_doc(collection: &CollectionKey, key: &Key, rule: &Rule) -> Result<Option<Doc>, String> {
+    let deleted_doc = match rule.mem() {
         Memory::Heap => {
             STATE.with(|state| delete_doc_heap(collection, key, &mut state.borrow_mut().heap.db.db))
         }
         Memory::Stable => STATE
             .with(|state| delete_doc_stable(collection, key, &mut state.borrow_mut().stable.db)),
+    }?;
+
+    mirror_delete_doc(collection, key)?;
+
+    if let Some(deleted_doc) = &deleted_doc {
+        delete_doc_indexes(collection, key, deleted_doc, rule_indexes(rule));
+        delete_doc_expiry(collection, key, deleted_doc);
+
+        track_db_usage(
+            collection,
+            key,
+            &Some(UserUsageItem::from_doc(deleted_doc)),
+            &None,
+        )?;
     }
+
+    Ok(deleted_doc)
 }
 
 // Get
@@ -205,17 +367,104 @@ pub fn get_docs_heap<'a>(
     }
 }
 
-pub fn count_docs_heap(collection: &CollectionKey, db: &DbHeap) -> Result<usize, String> {
+/// Returns up to `limit` documents of a collection ordered by key, starting at - and including - `start`.
+pub fn get_docs_from(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+    rule: &Rule,
+) -> Result<Vec<(Key, Doc)>, String> {
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state = state.borrow();
+
+            let col = state
+                .heap
+                .db
+                .db
+                .get(collection)
+                .ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+            Ok(col
+                .range(start.clone()..)
+                .take(limit)
+                .map(|(key, doc)| (key.clone(), doc.clone()))
+                .collect())
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let end_key = StableKey {
+                collection: range_collection_end(collection).clone(),
+                key: "".to_string(),
+            };
+
+            Ok(state
+                .borrow()
+                .stable
+                .db
+                .range(stable_key(collection, start)..end_key)
+                .take(limit)
+                .map(|entry| (entry.key().key.clone(), entry.value().clone()))
+                .collect())
+        }),
+    }
+}
+
+pub fn get_docs_by_keys_stable(
+    collection: &CollectionKey,
+    keys: &[Key],
+    db: &DbStable,
+) -> Vec<(StableKey, Doc)> {
+    keys.iter()
+        .filter_map(|key| {
+            let stable_key = stable_key(collection, key);
+            db.get(&stable_key).map(|doc| (stable_key, doc))
+        })
+        .collect()
+}
+
+pub fn get_docs_by_keys_heap<'a>(
+    collection: &CollectionKey,
+    keys: &[Key],
+    db: &'a DbHeap,
+) -> Result<Vec<(&'a Key, &'a Doc)>, String> {
+    let col = db.get(collection);
+
+    match col {
+        None => Err(msg_db_collection_not_found(collection)),
+        Some(col) => {
+            let items = keys
+                .iter()
+                .filter_map(|key| col.get_key_value(key))
+                .collect();
+            Ok(items)
+        }
+    }
+}
+
+// Documents that are expired but not yet swept are not counted.
+pub fn count_docs_heap(
+    collection: &CollectionKey,
+    db: &DbHeap,
+    now: Timestamp,
+) -> Result<usize, String> {
     let col = db.get(collection);
 
     match col {
         None => Err(msg_db_collection_not_found(collection)),
-        Some(col) => Ok(col.len()),
+        Some(col) => Ok(col.values().filter(|doc| !doc.is_expired(now)).count()),
     }
 }
 
-pub fn count_docs_stable(collection: &CollectionKey, db: &DbStable) -> Result<usize, String> {
-    let length = db.range(filter_docs_range(collection)).count();
+// Documents that are expired but not yet swept are not counted.
+pub fn count_docs_stable(
+    collection: &CollectionKey,
+    db: &DbStable,
+    now: Timestamp,
+) -> Result<usize, String> {
+    let length = db
+        .range(filter_docs_range(collection))
+        .filter(|entry| !entry.value().is_expired(now))
+        .count();
 
     Ok(length)
 }
@@ -256,7 +505,7 @@ fn limit_docs_stable_capacity(
     db: &mut DbStable,
 ) -> Result<Option<(Key, Doc)>, String> {
     if let Some(max_capacity) = max_capacity {
-        let col_length = count_docs_stable(collection, db)?;
+        let col_length = db.range(filter_docs_range(collection)).count();
 
         if col_length >= max_capacity as usize {
             let last_item = db.range(filter_docs_range(collection)).next();
@@ -339,6 +588,639 @@ fn stable_key(collection: &CollectionKey, key: &Key) -> StableKey {
     }
 }
 
+// ---------------------------------------------------------
+// Indexes
+// ---------------------------------------------------------
+
+fn rule_indexes(rule: &Rule) -> &[IndexPath] {
+    rule.indexes.as_deref().unwrap_or(&[])
+}
+
+fn insert_doc_indexes(collection: &CollectionKey, key: &Key, doc: &Doc, indexes: &[IndexPath]) {
+    let entries = index_keys(collection, key, doc, indexes);
+
+    STATE.with(|state| {
+        let db_indexes = &mut state.borrow_mut().stable.db_indexes;
+
+        for entry in entries {
+            db_indexes.insert(entry, ());
+        }
+    })
+}
+
+fn delete_doc_indexes(collection: &CollectionKey, key: &Key, doc: &Doc, indexes: &[IndexPath]) {
+    let entries = index_keys(collection, key, doc, indexes);
+
+    STATE.with(|state| {
+        let db_indexes = &mut state.borrow_mut().stable.db_indexes;
+
+        for entry in entries {
+            db_indexes.remove(&entry);
+        }
+    })
+}
+
+fn index_keys(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    indexes: &[IndexPath],
+) -> Vec<IndexKey> {
+    index_values(&doc.data, indexes)
+        .into_iter()
+        .map(|(path, value)| IndexKey {
+            collection: collection.clone(),
+            path,
+            value,
+            key: key.clone(),
+        })
+        .collect()
+}
+
+/// Deletes up to `limit` index entries of a collection and returns the number of deleted entries.
+pub fn delete_collection_indexes_batch(collection: &CollectionKey, limit: usize) -> usize {
+    STATE.with(|state| {
+        let db_indexes = &mut state.borrow_mut().stable.db_indexes;
+
+        let entries: Vec<IndexKey> = db_indexes
+            .range(collection_indexes_start(collection)..)
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| entry.collection == *collection)
+            .take(limit)
+            .collect();
+
+        for entry in &entries {
+            db_indexes.remove(entry);
+        }
+
+        entries.len()
+    })
+}
+
+/// Indexes the documents of a collection according to its rule.
+pub fn insert_docs_indexes(collection: &CollectionKey, docs: &[(Key, Doc)], rule: &Rule) {
+    let indexes = rule_indexes(rule);
+
+    if indexes.is_empty() {
+        return;
+    }
+
+    for (key, doc) in docs {
+        insert_doc_indexes(collection, key, doc, indexes);
+    }
+}
+
+pub fn get_index_rebuild(collection: &CollectionKey) -> Option<IndexRebuild> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .index_rebuilds
+            .get(collection)
+            .cloned()
+    })
+}
+
+pub fn get_index_rebuilds() -> Vec<CollectionKey> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .index_rebuilds
+            .keys()
+            .cloned()
+            .collect()
+    })
+}
+
+pub fn insert_index_rebuild(collection: &CollectionKey, rebuild: &IndexRebuild) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .db
+            .index_rebuilds
+            .insert(collection.clone(), rebuild.clone())
+    });
+}
+
+pub fn delete_index_rebuild(collection: &CollectionKey) {
+    STATE.with(|state| state.borrow_mut().heap.db.index_rebuilds.remove(collection));
+}
+
+fn collection_indexes_start(collection: &CollectionKey) -> IndexKey {
+    IndexKey {
+        collection: collection.clone(),
+        path: "".to_string(),
+        value: IndexValue::Null,
+        key: "".to_string(),
+    }
+}
+
+fn delete_collection_indexes(collection: &CollectionKey, db_indexes: &mut DbIndexesStable) {
+    let entries: Vec<IndexKey> = db_indexes
+        .range(collection_indexes_start(collection)..)
+        .map(|entry| entry.key().clone())
+        .take_while(|entry| entry.collection == *collection)
+        .collect();
+
+    for entry in entries {
+        db_indexes.remove(&entry);
+    }
+}
+
+/// Returns the keys of the documents whose value at the indexed path matches, in the order of the index.
+///
+/// At most `limit` keys are returned, the caller is expected to assert whether more documents match.
+pub fn get_index_keys(
+    collection: &CollectionKey,
+    path: &IndexPath,
+    matcher: &IndexMatcher,
+    limit: usize,
+) -> Vec<Key> {
+    let (start, bounds) = index_bounds(matcher);
+
+    let start_key = IndexKey {
+        collection: collection.clone(),
+        path: path.clone(),
+        value: start.clone(),
+        key: "".to_string(),
+    };
+
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_indexes
+            .range(start_key..)
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| {
+                entry.collection == *collection
+                    && entry.path == *path
+                    && entry.value.is_same_type(&start)
+                    && !is_beyond_upper_bound(&bounds.1, &entry.value)
+            })
+            .filter(|entry| bounds.contains(&entry.value))
+            .map(|entry| entry.key)
+            .take(limit)
+            .collect()
+    })
+}
+
+// The value from which the index is scanned and the bounds the values must be within.
+fn index_bounds(matcher: &IndexMatcher) -> (IndexValue, (Bound<IndexValue>, Bound<IndexValue>)) {
+    match matcher {
+        IndexMatcher::Equal(value) => {
+            let value = IndexValue::from(value);
+            (
+                value.clone(),
+                (Bound::Included(value.clone()), Bound::Included(value)),
+            )
+        }
+        IndexMatcher::GreaterThan(value) => {
+            let value = IndexValue::from(value);
+            (value.clone(), (Bound::Excluded(value), Bound::Unbounded))
+        }
+        IndexMatcher::LessThan(value) => {
+            let value = IndexValue::from(value);
+            (value.type_min(), (Bound::Unbounded, Bound::Excluded(value)))
+        }
+        IndexMatcher::Between(start, end) => {
+            let start = IndexValue::from(start);
+            (
+                start.clone(),
+                (
+                    Bound::Included(start),
+                    Bound::Included(IndexValue::from(end)),
+                ),
+            )
+        }
+    }
+}
+
+fn is_beyond_upper_bound(upper: &Bound<IndexValue>, value: &IndexValue) -> bool {
+    match upper {
+        Bound::Included(upper) => value > upper,
+        Bound::Excluded(upper) => value >= upper,
+        Bound::Unbounded => false,
+    }
+}
+
+// ---------------------------------------------------------
+// Expiries
+// ---------------------------------------------------------
+
+// Entries of documents that were overwritten or evicted are not removed here. They are dropped
+// when swept, as the expiry of the document they point to does not match anymore.
+fn insert_doc_expiry(collection: &CollectionKey, key: &Key, doc: &Doc) {
+    if let Some(expires_at) = doc.expires_at {
+        insert_expiry(collection, key, expires_at);
+    }
+}
+
+fn insert_expiry(collection: &CollectionKey, key: &Key, expires_at: Timestamp) {
+    let entry = ExpiryKey {
+        expires_at,
+        collection: collection.clone(),
+        key: key.clone(),
+    };
+
+    STATE.with(|state| state.borrow_mut().stable.db_expiries.insert(entry, ()));
+
+    schedule_expiry_sweep(expires_at);
+}
+
+fn delete_doc_expiry(collection: &CollectionKey, key: &Key, doc: &Doc) {
+    let Some(expires_at) = doc.expires_at else {
+        return;
+    };
+
+    delete_expiry(&ExpiryKey {
+        expires_at,
+        collection: collection.clone(),
+        key: key.clone(),
+    });
+}
+
+pub fn delete_expiry(entry: &ExpiryKey) {
+    STATE.with(|state| state.borrow_mut().stable.db_expiries.remove(entry));
+}
+
+/// Returns - at most `limit` - entries of the expiry queue that are due at `now`, the earliest first.
+pub fn get_expired_entries(now: Timestamp, limit: usize) -> Vec<ExpiryKey> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_expiries
+            .iter()
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| entry.expires_at <= now)
+            .take(limit)
+            .collect()
+    })
+}
+
+pub fn get_next_expiry() -> Option<Timestamp> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_expiries
+            .iter()
+            .next()
+            .map(|entry| entry.key().expires_at)
+    })
+}
+
+// ---------------------------------------------------------
+// Trash
+// ---------------------------------------------------------
+
+pub fn get_trashed_doc(collection: &CollectionKey, key: &Key) -> Option<Doc> {
+    STATE.with(|state| {
+        state.borrow().stable.db_trash.get(&StableKey {
+            collection: collection.clone(),
+            key: key.clone(),
+        })
+    })
+}
+
+/// Returns the trashed documents of a collection, including those awaiting to be purged.
+pub fn get_trashed_docs(collection: &CollectionKey) -> Vec<(Key, Doc)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_trash
+            .range(filter_docs_range(collection))
+            .map(|entry| (entry.key().key.clone(), entry.value().clone()))
+            .collect()
+    })
+}
+
+/// Returns up to `limit` trashed documents of a collection ordered by key, starting at - and including - `start`.
+pub fn get_trashed_docs_from(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+) -> Vec<(Key, Doc)> {
+    let end_key = StableKey {
+        collection: range_collection_end(collection).clone(),
+        key: "".to_string(),
+    };
+
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_trash
+            .range(stable_key(collection, start)..end_key)
+            .take(limit)
+            .map(|entry| (entry.key().key.clone(), entry.value().clone()))
+            .collect()
+    })
+}
+
+/// Moves a trashed document back to its collection as `doc` - i.e. the trashed document without
+/// `restorable_until`.
+pub fn restore_trashed_doc(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    rule: &Rule,
+    caller: &UserId,
+) -> Result<Option<Doc>, String> {
+    if delete_trashed_doc(collection, key).is_none() {
+        return Ok(None);
+    }
+
+    let (_evicted_doc, after) = insert_doc(collection, key, doc, rule, caller)?;
+
+    Ok(Some(after))
+}
+
+/// Permanently deletes a trashed document, its revisions and access list.
+pub fn purge_trashed_doc(collection: &CollectionKey, key: &Key) -> Option<Doc> {
+    let purged_doc = delete_trashed_doc(collection, key)?;
+
+    delete_doc_revisions(collection, key);
+    delete_doc_access(collection, key);
+
+    Some(purged_doc)
+}
+
+// The entry of the expiry queue is not removed here. It is dropped when swept.
+fn delete_trashed_doc(collection: &CollectionKey, key: &Key) -> Option<Doc> {
+    STATE.with(|state| {
+        state.borrow_mut().stable.db_trash.remove(&StableKey {
+            collection: collection.clone(),
+            key: key.clone(),
+        })
+    })
+}
+
+fn delete_collection_trash(collection: &CollectionKey, db_trash: &mut DbTrashStable) {
+    let keys: Vec<StableKey> = db_trash
+        .range(filter_docs_range(collection))
+        .map(|entry| entry.key().clone())
+        .collect();
+
+    for key in keys {
+        db_trash.remove(&key);
+    }
+}
+
+// ---------------------------------------------------------
+// Revisions
+// ---------------------------------------------------------
+
+// Without revisions configured - e.g. after the rule was updated - the revisions kept so far are dropped.
+fn insert_doc_revision(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    caller: &UserId,
+    max_revisions: Option<u32>,
+) {
+    let Some(max_revisions) = max_revisions else {
+        delete_doc_revisions(collection, key);
+        return;
+    };
+
+    let revision = DocRevision::from_doc(doc, *caller);
+
+    STATE.with(|state| {
+        let db_revisions = &mut state.borrow_mut().stable.db_revisions;
+
+        db_revisions.insert(
+            RevisionKey {
+                collection: collection.clone(),
+                key: key.clone(),
+                version: revision.version,
+            },
+            revision,
+        );
+
+        let entries = doc_revision_keys(collection, key, db_revisions);
+
+        // The oldest revisions come first
+        let excess = entries.len().saturating_sub(max_revisions as usize);
+
+        for entry in entries.into_iter().take(excess) {
+            db_revisions.remove(&entry);
+        }
+    })
+}
+
+fn delete_doc_revisions(collection: &CollectionKey, key: &Key) {
+    STATE.with(|state| {
+        let db_revisions = &mut state.borrow_mut().stable.db_revisions;
+
+        for entry in doc_revision_keys(collection, key, db_revisions) {
+            db_revisions.remove(&entry);
+        }
+    })
+}
+
+fn delete_collection_revisions(collection: &CollectionKey, db_revisions: &mut DbRevisionsStable) {
+    let start = RevisionKey {
+        collection: collection.clone(),
+        key: "".to_string(),
+        version: 0,
+    };
+
+    let entries: Vec<RevisionKey> = db_revisions
+        .range(start..)
+        .map(|entry| entry.key().clone())
+        .take_while(|entry| entry.collection == *collection)
+        .collect();
+
+    for entry in entries {
+        db_revisions.remove(&entry);
+    }
+}
+
+fn doc_revision_keys(
+    collection: &CollectionKey,
+    key: &Key,
+    db_revisions: &DbRevisionsStable,
+) -> Vec<RevisionKey> {
+    db_revisions
+        .range(doc_revisions_range(collection, key))
+        .map(|entry| entry.key().clone())
+        .collect()
+}
+
+fn doc_revisions_range(collection: &CollectionKey, key: &Key) -> impl RangeBounds<RevisionKey> {
+    let start = RevisionKey {
+        collection: collection.clone(),
+        key: key.clone(),
+        version: Version::MIN,
+    };
+
+    let end = RevisionKey {
+        collection: collection.clone(),
+        key: key.clone(),
+        version: Version::MAX,
+    };
+
+    start..=end
+}
+
+/// Returns the revisions of a document, the oldest first.
+pub fn get_doc_revisions(collection: &CollectionKey, key: &Key) -> Vec<DocRevision> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_revisions
+            .range(doc_revisions_range(collection, key))
+            .map(|entry| entry.value().clone())
+            .collect()
+    })
+}
+
+pub fn get_doc_revision(
+    collection: &CollectionKey,
+    key: &Key,
+    version: Version,
+) -> Option<DocRevision> {
+    STATE.with(|state| {
+        state.borrow().stable.db_revisions.get(&RevisionKey {
+            collection: collection.clone(),
+            key: key.clone(),
+            version,
+        })
+    })
+}
+
+// ---------------------------------------------------------
+// Access
+// ---------------------------------------------------------
+
+pub fn get_doc_access(collection: &CollectionKey, key: &Key) -> Option<DocAccess> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_access
+            .get(&stable_key(collection, key))
+    })
+}
+
+/// Sets the access list of a document and replaces the entries of the index of the shared documents.
+pub fn insert_doc_access(collection: &CollectionKey, key: &Key, access: &DocAccess) {
+    STATE.with(|state| {
+        let stable = &mut state.borrow_mut().stable;
+
+        let current_access = stable
+            .db_access
+            .insert(stable_key(collection, key), access.clone());
+
+        if let Some(current_access) = current_access {
+            delete_shares(collection, key, &current_access, &mut stable.db_shares);
+        }
+
+        for user in access.users() {
+            stable
+                .db_shares
+                .insert(share_key(user, collection, key), ());
+        }
+    })
+}
+
+fn delete_doc_access(collection: &CollectionKey, key: &Key) {
+    STATE.with(|state| {
+        let stable = &mut state.borrow_mut().stable;
+
+        if let Some(access) = stable.db_access.remove(&stable_key(collection, key)) {
+            delete_shares(collection, key, &access, &mut stable.db_shares);
+        }
+    })
+}
+
+fn delete_collection_access(
+    collection: &CollectionKey,
+    db_access: &mut DbAccessStable,
+    db_shares: &mut DbSharesStable,
+) {
+    let entries: Vec<(StableKey, DocAccess)> = db_access
+        .range(filter_docs_range(collection))
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect();
+
+    for (stable_key, access) in entries {
+        delete_shares(collection, &stable_key.key, &access, db_shares);
+        db_access.remove(&stable_key);
+    }
+}
+
+fn delete_shares(
+    collection: &CollectionKey,
+    key: &Key,
+    access: &DocAccess,
+    db_shares: &mut DbSharesStable,
+) {
+    for user in access.users() {
+        db_shares.remove(&share_key(user, collection, key));
+    }
+}
+
+/// Returns the keys of the documents of a collection shared with a user, including those whose
+/// grant expired.
+pub fn get_shared_keys(user: &UserId, collection: &CollectionKey) -> Vec<Key> {
+    let start = ShareKey {
+        user: *user,
+        collection: collection.clone(),
+        key: "".to_string(),
+    };
+
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_shares
+            .range(start..)
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| entry.user == *user && entry.collection == *collection)
+            .map(|entry| entry.key)
+            .collect()
+    })
+}
+
+/// Returns up to `limit` keys of the documents of a collection shared with a user ordered by key,
+/// starting at - and including - `start`.
+pub fn get_shared_keys_from(
+    user: &UserId,
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+) -> Vec<Key> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_shares
+            .range(share_key(*user, collection, start)..)
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| entry.user == *user && entry.collection == *collection)
+            .take(limit)
+            .map(|entry| entry.key)
+            .collect()
+    })
+}
+
+fn share_key(user: UserId, collection: &CollectionKey, key: &Key) -> ShareKey {
+    ShareKey {
+        user,
+        collection: collection.clone(),
+        key: key.clone(),
+    }
+}
+
 // ---------------------------------------------------------
 // Rules
 // ---------------------------------------------------------
@@ -357,6 +1239,120 @@ pub fn get_rule(collection: &CollectionKey) -> Result<Rule, String> {
     }
 }
 
+// ---------------------------------------------------------
+// Migrations
+// ---------------------------------------------------------
+
+/// Copies up to `limit` documents ordered by key, starting at - and including - `start`, from the
+/// memory of the collection to the `target` memory. Returns the number of copied documents and the
+/// key of the last one.
+pub fn copy_docs(
+    collection: &CollectionKey,
+    start: &Key,
+    limit: usize,
+    rule: &Rule,
+    target: &Memory,
+) -> Result<(usize, Option<Key>), String> {
+    let docs = get_docs_from(collection, start, limit, rule)?;
+
+    for (key, doc) in &docs {
+        insert_doc_into_memory(collection, key, doc, target)?;
+    }
+
+    Ok((docs.len(), docs.last().map(|(key, _)| key.clone())))
+}
+
+/// Deletes the documents of a collection from a memory it no longer uses. The heap is cleared at
+/// once, the stable memory by batches of up to `limit` documents. Returns the number of deleted
+/// documents.
+pub fn delete_docs_from_memory(collection: &CollectionKey, limit: usize, memory: &Memory) -> usize {
+    match memory {
+        Memory::Heap => STATE.with(|state| {
+            state
+                .borrow_mut()
+                .heap
+                .db
+                .db
+                .remove(collection)
+                .map_or(0, |col| col.len())
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let db = &mut state.borrow_mut().stable.db;
+
+            let keys: Vec<StableKey> = db
+                .range(filter_docs_range(collection))
+                .take(limit)
+                .map(|entry| entry.key().clone())
+                .collect();
+
+            for key in &keys {
+                db.remove(key);
+            }
+
+            keys.len()
+        }),
+    }
+}
+
+// While a collection is migrated, the writes on the documents that were already copied are
+// mirrored to the target memory. Indexes, expiries, revisions and trash do not depend on the memory.
+fn mirror_insert_doc(collection: &CollectionKey, key: &Key, doc: &Doc) -> Result<(), String> {
+    if let Some(migration) = get_collection_migration(&CollectionType::Db, collection) {
+        if migration.is_copied(key) {
+            insert_doc_into_memory(collection, key, doc, &migration.memory)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn mirror_delete_doc(collection: &CollectionKey, key: &Key) -> Result<(), String> {
+    if let Some(migration) = get_collection_migration(&CollectionType::Db, collection) {
+        if migration.is_copied(key) {
+            match migration.memory {
+                Memory::Heap => STATE.with(|state| {
+                    delete_doc_heap(collection, key, &mut state.borrow_mut().heap.db.db)
+                }),
+                Memory::Stable => STATE.with(|state| {
+                    delete_doc_stable(collection, key, &mut state.borrow_mut().stable.db)
+                }),
+            }?;
+        }
+    }
+
+    Ok(())
+}
+
+fn insert_doc_into_memory(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    memory: &Memory,
+) -> Result<(), String> {
+    match memory {
+        Memory::Heap => STATE.with(|state| {
+            insert_doc_heap(
+                collection,
+                key,
+                doc,
+                None,
+                &mut state.borrow_mut().heap.db.db,
+            )
+        }),
+        Memory::Stable => STATE.with(|state| {
+            insert_doc_stable(
+                collection,
+                key,
+                doc,
+                None,
+                &mut state.borrow_mut().stable.db,
+            )
+        }),
+    }?;
+
+    Ok(())
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
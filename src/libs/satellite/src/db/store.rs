@@ -1,30 +1,56 @@
 use crate::auth::store::get_config as get_auth_config;
 use crate::controllers::store::get_controllers;
 use crate::db::assert::{
-    assert_delete_doc, assert_get_doc, assert_get_docs, assert_set_config, assert_set_doc,
+    assert_delete_doc, assert_delete_doc_constraints, assert_doc_access,
+    assert_doc_access_permission, assert_doc_version, assert_get_doc, assert_get_doc_access,
+    assert_get_docs, assert_index, assert_index_keys, assert_list_doc_revisions,
+    assert_list_trashed_docs, assert_patch_doc, assert_restore_doc, assert_restore_permission,
+    assert_set_config, assert_set_doc, assert_set_doc_access, assert_set_doc_constraints,
+    assert_transfer_doc_ownership,
 };
+use crate::db::constants::MAX_INDEX_KEYS;
+use crate::db::patch::apply_patch;
+use crate::db::rebuild::start_index_rebuild;
+use crate::db::runtime::{assert_rate, increment_and_assert_rate};
 use crate::db::state::{
     count_docs_heap, count_docs_stable, delete_collection as delete_state_collection,
-    delete_doc as delete_state_doc, get_config, get_doc as get_state_doc, get_docs_heap,
-    get_docs_stable, get_rule as get_state_rule, init_collection as init_state_collection,
-    insert_config, insert_doc as insert_state_doc,
-    is_collection_empty as is_state_collection_empty,
+    delete_doc as delete_state_doc, get_config, get_doc as get_state_doc,
+    get_doc_access as get_state_doc_access, get_doc_revision as get_state_doc_revision,
+    get_doc_revisions as get_state_doc_revisions, get_docs_by_keys_heap, get_docs_by_keys_stable,
+    get_docs_heap, get_docs_stable, get_index_keys, get_rule as get_state_rule, get_shared_keys,
+    get_trashed_doc, get_trashed_docs, init_collection as init_state_collection, insert_config,
+    insert_doc as insert_state_doc, insert_doc_access as insert_state_doc_access,
+    is_collection_empty as is_state_collection_empty, restore_trashed_doc,
+    trash_doc as trash_state_doc,
 };
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::{DelDoc, SetDbConfig, SetDoc};
-use crate::db::types::state::{Doc, DocContext, DocUpsert};
-use crate::db::types::store::AssertSetDocOptions;
-use crate::db::utils::filter_values;
+use crate::db::types::interface::{
+    DelDoc, DocsBatchError, DocsBatchOperation, DocsBatchOperationResult, PatchDoc,
+    RestoreDocRevision, SetDbConfig, SetDoc, SetDocAccess,
+};
+use crate::db::types::state::{Doc, DocAccess, DocContext, DocRevision, DocUpsert};
+use crate::db::types::store::{AssertSetDocOptions, DocsBatchChanges, DocsBatchPending};
+use crate::db::utils::{filter_revisions, filter_trashed_docs, filter_values};
+use crate::errors::db::{
+    JUNO_DATASTORE_ERROR_CANNOT_WRITE, JUNO_DATASTORE_ERROR_NOT_IN_TRASH,
+    JUNO_DATASTORE_ERROR_RESTORE_CONFLICT, JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND,
+};
 use crate::memory::state::STATE;
+use crate::types::interface::TransferOwnership;
 use crate::types::store::{AssertContext, StoreContext};
+use crate::user::usage::assert::{
+    assert_db_pending_changes_usage, assert_db_pending_items_usage, increment_db_usage,
+};
+use crate::user::usage::types::state::UserUsageItem;
 use candid::Principal;
+use ic_cdk::api::time;
 use junobuild_collections::msg::msg_db_collection_not_empty;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::data::list::list_values;
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::list::{ListParams, ListResults};
-use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::types::list::{ListIndexMatcher, ListParams, ListResults};
+use junobuild_shared::types::state::{Controllers, Timestamp, UserId};
 // ---------------------------------------------------------
 // Collection
 // ---------------------------------------------------------
@@ -112,12 +138,12 @@ fn get_doc_impl(
     assert_context: &AssertContext,
     key: Key,
 ) -> Result<Option<Doc>, String> {
-    let value = get_state_doc(context.collection, &key, assert_context.rule)?;
+    let value = get_current_doc(context.collection, &key, assert_context.rule)?;
 
     match value {
         None => Ok(None),
         Some(value) => {
-            if assert_get_doc(context, assert_context, &value).is_err() {
+            if assert_get_doc(context, assert_context, &key, &value).is_err() {
                 return Ok(None);
             }
 
@@ -130,67 +156,755 @@ fn get_doc_impl(
 // Insert
 // ---------------------------------------------------------
 
-/// Set a document in a collection's store.
+/// Set a document in a collection's store.
+///
+/// This function sets a document in a collection's store based on the specified parameters.
+/// It returns a `Result<DocContext<DocUpsert>, String>` where `Ok(DocContext)` indicates successful
+/// insertion or update of the document, or an error message as `Err(String)` if the operation encounters
+/// issues.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection in which to set the document.
+/// - `key`: A `Key` identifying the document to be set.
+/// - `value`: An instance of `SetDoc` representing the document to be inserted or updated.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: Indicates successful insertion or update of the document.
+/// - `Err(String)`: An error message if the operation fails.
+///
+/// This function allows you to securely insert or update documents in a Juno collection's store.
+pub fn set_doc_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: SetDoc,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: true,
+    };
+
+    let data = secure_set_doc(&context, &config, &assert_options, key.clone(), value)?;
+
+    Ok(DocContext {
+        key,
+        collection,
+        data,
+    })
+}
+
+/// Internal variant of `set_doc_store`.
+///
+/// Performs a secure insert or update of a document in the specified collection,
+/// using custom assertion options.
+///
+/// Useful for the authentication flow using prepare delegation.
+pub fn internal_set_doc_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: SetDoc,
+    assert_options: &AssertSetDocOptions,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let data = secure_set_doc(&context, &config, assert_options, key.clone(), value)?;
+
+    Ok(DocContext {
+        key,
+        collection,
+        data,
+    })
+}
+
+fn secure_set_doc(
+    context: &StoreContext,
+    config: &Option<DbConfig>,
+    assert_options: &AssertSetDocOptions,
+    key: Key,
+    value: SetDoc,
+) -> Result<DocUpsert, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    set_doc_impl(context, &assert_context, config, assert_options, key, value)
+}
+
+fn set_doc_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    config: &Option<DbConfig>,
+    assert_options: &AssertSetDocOptions,
+    key: Key,
+    value: SetDoc,
+) -> Result<DocUpsert, String> {
+    let current_doc = get_current_doc(context.collection, &key, assert_context.rule)?;
+
+    assert_set_doc(
+        context,
+        assert_context,
+        config,
+        assert_options,
+        &key,
+        &value,
+        &current_doc,
+    )?;
+
+    let doc: Doc = Doc::prepare(
+        context.caller,
+        &current_doc,
+        value,
+        assert_context.rule.default_ttl_ns,
+    );
+
+    let (_evicted_doc, after) = insert_state_doc(
+        context.collection,
+        &key,
+        &doc,
+        assert_context.rule,
+        &context.caller,
+    )?;
+
+    Ok(DocUpsert {
+        before: current_doc,
+        after,
+    })
+}
+
+// ---------------------------------------------------------
+// Patch
+// ---------------------------------------------------------
+
+/// Patch the data of a document in a collection's store.
+///
+/// This function applies an RFC 7396 JSON merge patch or an RFC 6902 JSON patch to the data of a document -
+/// or to `null` if the document does not exist yet - and sets the result with the same assertions as
+/// `set_doc_store`. It returns a `Result<DocContext<DocUpsert>, String>` where `Ok(DocContext)` indicates
+/// successful update of the document, or an error message as `Err(String)` if the operation encounters issues.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection in which to patch the document.
+/// - `key`: A `Key` identifying the document to be patched.
+/// - `value`: An instance of `PatchDoc` representing the patch and the optional expected version.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: Indicates successful patch of the document.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn patch_doc_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: PatchDoc,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let data = secure_patch_doc(&context, &config, key.clone(), value)?;
+
+    Ok(DocContext {
+        key,
+        collection,
+        data,
+    })
+}
+
+fn secure_patch_doc(
+    context: &StoreContext,
+    config: &Option<DbConfig>,
+    key: Key,
+    value: PatchDoc,
+) -> Result<DocUpsert, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let current_doc = get_current_doc(context.collection, &key, &rule)?;
+
+    assert_patch_doc(context, &assert_context, &key, &current_doc)?;
+
+    let data = apply_patch(current_doc.as_ref().map(|doc| &doc.data), &value.patch)?;
+
+    let set_doc = SetDoc {
+        data,
+        description: current_doc.as_ref().and_then(|doc| doc.description.clone()),
+        // Without version, the patch applies to the current version of the document
+        version: value
+            .version
+            .or(current_doc.as_ref().and_then(|doc| doc.version)),
+        expires_at: current_doc.as_ref().and_then(|doc| doc.expires_at),
+    };
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: true,
+    };
+
+    set_doc_impl(
+        context,
+        &assert_context,
+        config,
+        &assert_options,
+        key,
+        set_doc,
+    )
+}
+
+// ---------------------------------------------------------
+// Trash
+// ---------------------------------------------------------
+
+/// List the trashed documents of a collection.
+///
+/// When a collection is configured with a trash retention, deleted documents are moved to its trash instead
+/// of being removed. This function returns the trashed documents the caller can restore - i.e. those it owns,
+/// or all of them for a controller - that are still within their retention window.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller requesting the trashed documents.
+/// - `collection`: A `CollectionKey` representing the collection of the trash.
+/// - `filters`: A reference to `ListParams` to filter, order and paginate the trashed documents. Index matchers are not supported.
+///
+/// # Returns
+/// - `Ok(ListResults<Doc>)`: The keys and the trashed documents.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn list_trashed_docs_store(
+    caller: UserId,
+    collection: CollectionKey,
+    filters: &ListParams,
+) -> Result<ListResults<Doc>, String> {
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    assert_get_docs(&context, &assert_context)?;
+    assert_list_trashed_docs(filters)?;
+
+    let now = time();
+
+    let docs: Vec<(Key, Doc)> = get_trashed_docs(&collection)
+        .into_iter()
+        .filter(|(_, doc)| {
+            is_restorable(doc, now) && assert_restore_permission(caller, &controllers, doc).is_ok()
+        })
+        .collect();
+
+    let matches = filter_trashed_docs(&docs, filters)?;
+
+    Ok(list_values(&matches, filters))
+}
+
+/// Restore a trashed document of a collection.
+///
+/// This function moves a trashed document back to its collection - with its data, owner and version - as long
+/// as its retention window has not elapsed. Only the owner of the document and the controllers can restore it.
+/// It returns a `Result<DocContext<DocUpsert>, String>` where `Ok(DocContext)` indicates successful restore of
+/// the document, or an error message as `Err(String)` if the operation encounters issues.
+///
+/// The restored document goes through the same assertions as a new document set with `set_doc_store` - e.g.
+/// quotas, JSON Schema, rate and `assert_set_doc` hooks. It keeps its `expires_at`, therefore it cannot be
+/// restored once expired. It cannot be restored over a document set with the same key since it was deleted.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the trashed document.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: Indicates successful restore of the document.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn restore_doc_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let data = secure_restore_doc(&context, &config, key.clone())?;
+
+    Ok(DocContext {
+        key,
+        collection,
+        data,
+    })
+}
+
+fn secure_restore_doc(
+    context: &StoreContext,
+    config: &Option<DbConfig>,
+    key: Key,
+) -> Result<DocUpsert, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let not_in_trash = || {
+        format!(
+            "{JUNO_DATASTORE_ERROR_NOT_IN_TRASH} ({} {key})",
+            context.collection
+        )
+    };
+
+    let now = time();
+
+    let trashed_doc = get_trashed_doc(context.collection, &key)
+        .filter(|doc| is_restorable(doc, now))
+        .ok_or_else(not_in_trash)?;
+
+    assert_restore_doc(context, &assert_context, &trashed_doc)?;
+
+    // The trashed document would otherwise overwrite the document set since it was deleted
+    if get_current_doc(context.collection, &key, &rule)?.is_some() {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_RESTORE_CONFLICT} ({} {key})",
+            context.collection
+        ));
+    }
+
+    let set_doc = SetDoc {
+        data: trashed_doc.data.clone(),
+        description: trashed_doc.description.clone(),
+        version: None,
+        expires_at: trashed_doc.expires_at,
+    };
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: true,
+    };
+
+    assert_set_doc(
+        context,
+        &assert_context,
+        config,
+        &assert_options,
+        &key,
+        &set_doc,
+        &None,
+    )?;
+
+    let doc = Doc {
+        restorable_until: None,
+        ..trashed_doc
+    };
+
+    let after = restore_trashed_doc(context.collection, &key, &doc, &rule, &context.caller)?
+        .ok_or_else(not_in_trash)?;
+
+    Ok(DocUpsert {
+        before: None,
+        after,
+    })
+}
+
+// Trashed documents whose retention elapsed are purged by the next expiry sweep. Those that expired
+// in the meantime cannot be restored either.
+fn is_restorable(trashed_doc: &Doc, now: Timestamp) -> bool {
+    !trashed_doc.is_expired(now)
+        && trashed_doc
+            .restorable_until
+            .is_some_and(|restorable_until| now < restorable_until)
+}
+
+// ---------------------------------------------------------
+// Revisions
+// ---------------------------------------------------------
+
+/// List the revisions of a document in a collection's store.
+///
+/// This function retrieves the revisions kept for a document - the oldest first, unless ordered otherwise -
+/// if the collection is configured to keep revisions. Each revision is keyed by its version. The same read
+/// permissions as `get_doc_store` apply: if the document does not exist or the caller is not allowed to
+/// read it, no revisions are returned.
+///
+/// The revisions are deleted with their document - i.e. once a document is deleted, or purged from the
+/// trash, its revisions cannot be listed nor restored anymore.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller requesting the revisions.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+/// - `filters`: A reference to `ListParams` to filter, order and paginate the revisions. Index matchers are not supported.
+///
+/// # Returns
+/// - `Ok(ListResults<DocRevision>)`: The revisions of the document.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn list_doc_revisions_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    filters: &ListParams,
+) -> Result<ListResults<DocRevision>, String> {
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    assert_list_doc_revisions(filters)?;
+
+    let doc = get_doc_impl(&context, &assert_context, key.clone())?;
+
+    if doc.is_none() {
+        return Ok(ListResults::default());
+    }
+
+    let revisions: Vec<(Key, DocRevision)> = get_state_doc_revisions(&collection, &key)
+        .into_iter()
+        .map(|revision| (revision.version.to_string(), revision))
+        .collect();
+
+    let matches = filter_revisions(&revisions, filters)?;
+
+    Ok(list_values(&matches, filters))
+}
+
+/// Restore a revision of a document in a collection's store.
+///
+/// This function sets the data and description of a revision as a new version of the document, with the
+/// same assertions as `set_doc_store`. It returns a `Result<DocContext<DocUpsert>, String>` where
+/// `Ok(DocContext)` indicates successful restore of the revision, or an error message as `Err(String)`
+/// if the operation encounters issues.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+/// - `value`: An instance of `RestoreDocRevision` representing the revision to restore and the optional expected version.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: Indicates successful restore of the revision.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn restore_doc_revision_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: RestoreDocRevision,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let data = secure_restore_doc_revision(&context, &config, key.clone(), value)?;
+
+    Ok(DocContext {
+        key,
+        collection,
+        data,
+    })
+}
+
+fn secure_restore_doc_revision(
+    context: &StoreContext,
+    config: &Option<DbConfig>,
+    key: Key,
+    value: RestoreDocRevision,
+) -> Result<DocUpsert, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let current_doc = get_current_doc(context.collection, &key, &rule)?;
+
+    // Restoring a revision discloses its data, therefore the caller must be allowed to read the document
+    if let Some(current_doc) = &current_doc {
+        assert_get_doc(context, &assert_context, &key, current_doc)?;
+    }
+
+    assert_patch_doc(context, &assert_context, &key, &current_doc)?;
+
+    let revision =
+        get_state_doc_revision(context.collection, &key, value.revision).ok_or_else(|| {
+            format!(
+                "{JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND} ({} {key} {})",
+                context.collection, value.revision
+            )
+        })?;
+
+    let set_doc = SetDoc {
+        data: revision.data,
+        description: revision.description,
+        // Without version, the revision is restored over the current version of the document
+        version: value
+            .version
+            .or(current_doc.as_ref().and_then(|doc| doc.version)),
+        expires_at: current_doc.as_ref().and_then(|doc| doc.expires_at),
+    };
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: true,
+    };
+
+    set_doc_impl(
+        context,
+        &assert_context,
+        config,
+        &assert_options,
+        key,
+        set_doc,
+    )
+}
+
+// ---------------------------------------------------------
+// Access
+// ---------------------------------------------------------
+
+/// Set the access list of a document in a collection's store.
+///
+/// This function replaces the readers and writers a document is shared with, on top of the permissions of
+/// its collection. Only the owner of the document and the controllers can edit its access list. The access
+/// list is kept until the document is permanently deleted.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+/// - `value`: An instance of `SetDocAccess` representing the readers, writers and the optional expected version.
+///
+/// # Returns
+/// - `Ok(DocAccess)`: The access list of the document.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn set_doc_access_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: SetDocAccess,
+) -> Result<DocAccess, String> {
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let current_doc = get_current_doc(&collection, &key, &rule)?;
+    let current_access = get_state_doc_access(&collection, &key);
+
+    assert_set_doc_access(
+        &context,
+        &assert_context,
+        &current_doc,
+        &current_access,
+        &value,
+    )?;
+
+    let access = DocAccess::prepare(&current_access, value);
+
+    insert_state_doc_access(&collection, &key, &access);
+
+    Ok(access)
+}
+
+/// Get the access list of a document in a collection's store.
+///
+/// Only the owner of the document and the controllers can read its access list. If the document does not
+/// exist, was never shared or the caller is not allowed to read the access list, `None` is returned.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller requesting the access list.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+///
+/// # Returns
+/// - `Ok(Option<DocAccess>)`: The access list of the document, if any.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn get_doc_access_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+) -> Result<Option<DocAccess>, String> {
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    assert_get_doc_access(&context, &assert_context)?;
+
+    let current_doc = get_current_doc(&collection, &key, &rule)?;
+
+    match current_doc {
+        Some(current_doc)
+            if assert_doc_access_permission(caller, &controllers, &current_doc).is_ok() =>
+        {
+            Ok(get_state_doc_access(&collection, &key))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// List the documents of a collection shared with the caller.
 ///
-/// This function sets a document in a collection's store based on the specified parameters.
-/// It returns a `Result<DocContext<DocUpsert>, String>` where `Ok(DocContext)` indicates successful
-/// insertion or update of the document, or an error message as `Err(String)` if the operation encounters
-/// issues.
+/// This function retrieves - across owners - the documents whose access list grants the caller to read or
+/// write them, applying the specified filter criteria as `list_docs_store` does.
 ///
 /// # Parameters
-/// - `caller`: The `UserId` representing the caller initiating the operation.
-/// - `collection`: A `CollectionKey` representing the collection in which to set the document.
-/// - `key`: A `Key` identifying the document to be set.
-/// - `value`: An instance of `SetDoc` representing the document to be inserted or updated.
+/// - `caller`: The `Principal` representing the caller the documents are shared with.
+/// - `collection`: A `CollectionKey` representing the collection from which to list the documents.
+/// - `filter`: A reference to `ListParams` containing the filter criteria for listing the documents.
 ///
 /// # Returns
-/// - `Ok(DocContext<DocUpsert>)`: Indicates successful insertion or update of the document.
+/// - `Ok(ListResults<Doc>)`: Contains the list of shared documents matching the filter criteria.
 /// - `Err(String)`: An error message if the operation fails.
-///
-/// This function allows you to securely insert or update documents in a Juno collection's store.
-pub fn set_doc_store(
-    caller: UserId,
+pub fn list_shared_docs_store(
+    caller: Principal,
     collection: CollectionKey,
-    key: Key,
-    value: SetDoc,
-) -> Result<DocContext<DocUpsert>, String> {
+    filter: &ListParams,
+) -> Result<ListResults<Doc>, String> {
     let controllers: Controllers = get_controllers();
-    let config = get_config();
 
-    let context = StoreContext {
+    let context: StoreContext = StoreContext {
         caller,
-        controllers: &controllers,
         collection: &collection,
+        controllers: &controllers,
     };
 
-    let assert_options = AssertSetDocOptions {
-        with_assert_rate: true,
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
     };
 
-    let data = secure_set_doc(&context, &config, &assert_options, key.clone(), value)?;
+    assert_get_docs(&context, &assert_context)?;
 
-    Ok(DocContext {
-        key,
-        collection,
-        data,
-    })
+    // Grants that expired are skipped, even if the permission of the collection allows reading the documents
+    let keys: Vec<Key> = get_shared_keys(&caller, &collection)
+        .into_iter()
+        .filter(|key| assert_doc_access(caller, &collection, key, DocAccess::can_read))
+        .collect();
+
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state_ref = state.borrow();
+            let docs = get_docs_by_keys_heap(&collection, &keys, &state_ref.heap.db.db)?;
+            get_docs_impl(&docs, &context, filter, &rule)
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let stable = get_docs_by_keys_stable(&collection, &keys, &state.borrow().stable.db);
+            let docs: Vec<(&Key, &Doc)> = stable.iter().map(|(key, doc)| (&key.key, doc)).collect();
+            get_docs_impl(&docs, &context, filter, &rule)
+        }),
+    }
 }
 
-/// Internal variant of `set_doc_store`.
+// ---------------------------------------------------------
+// Ownership
+// ---------------------------------------------------------
+
+/// Transfer the ownership of a document in a collection's store.
 ///
-/// Performs a secure insert or update of a document in the specified collection,
-/// using custom assertion options.
+/// This function hands over a document to a new owner, preserving its data, creation date and revisions.
+/// Only the owner of the document and the controllers can transfer it. The items and bytes of the document
+/// are accounted to the new owner in the usage of the collection.
 ///
-/// Useful for the authentication flow using prepare delegation.
-pub fn internal_set_doc_store(
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the operation.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+/// - `value`: An instance of `TransferOwnership` representing the new owner and the expected version.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: The document before and after the transfer.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn transfer_doc_ownership_store(
     caller: UserId,
     collection: CollectionKey,
     key: Key,
-    value: SetDoc,
-    assert_options: &AssertSetDocOptions,
+    value: TransferOwnership,
 ) -> Result<DocContext<DocUpsert>, String> {
     let controllers: Controllers = get_controllers();
-    let config = get_config();
 
     let context = StoreContext {
         caller,
@@ -198,7 +912,7 @@ pub fn internal_set_doc_store(
         collection: &collection,
     };
 
-    let data = secure_set_doc(&context, &config, assert_options, key.clone(), value)?;
+    let data = secure_transfer_doc_ownership(&context, key.clone(), value)?;
 
     Ok(DocContext {
         key,
@@ -207,12 +921,10 @@ pub fn internal_set_doc_store(
     })
 }
 
-fn secure_set_doc(
+fn secure_transfer_doc_ownership(
     context: &StoreContext,
-    config: &Option<DbConfig>,
-    assert_options: &AssertSetDocOptions,
     key: Key,
-    value: SetDoc,
+    value: TransferOwnership,
 ) -> Result<DocUpsert, String> {
     let rule = get_state_rule(context.collection)?;
     let auth_config = get_auth_config();
@@ -222,36 +934,27 @@ fn secure_set_doc(
         auth_config: &auth_config,
     };
 
-    set_doc_impl(context, &assert_context, config, assert_options, key, value)
-}
-
-fn set_doc_impl(
-    context: &StoreContext,
-    assert_context: &AssertContext,
-    config: &Option<DbConfig>,
-    assert_options: &AssertSetDocOptions,
-    key: Key,
-    value: SetDoc,
-) -> Result<DocUpsert, String> {
-    let current_doc = get_state_doc(context.collection, &key, assert_context.rule)?;
+    let current_doc = get_current_doc(context.collection, &key, &rule)?
+        .ok_or_else(|| JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string())?;
 
-    assert_set_doc(
-        context,
-        assert_context,
-        config,
-        assert_options,
-        &key,
-        &value,
-        &current_doc,
-    )?;
+    assert_transfer_doc_ownership(context, &assert_context, &current_doc, &value)?;
 
-    let doc: Doc = Doc::prepare(context.caller, &current_doc, value);
+    let doc = Doc::transfer(&current_doc, value.owner);
 
+    // The usage of the document is moved from its previous owner to the new one.
     let (_evicted_doc, after) =
-        insert_state_doc(context.collection, &key, &doc, assert_context.rule)?;
+        insert_state_doc(context.collection, &key, &doc, &rule, &context.caller)?;
+
+    // The new owner does not need a grant anymore - it would otherwise still be listed among
+    // the users the document is shared with.
+    if let Some(access) = get_state_doc_access(context.collection, &key)
+        .and_then(|current_access| current_access.revoke(value.owner))
+    {
+        insert_state_doc_access(context.collection, &key, &access);
+    }
 
     Ok(DocUpsert {
-        before: current_doc,
+        before: Some(current_doc),
         after,
     })
 }
@@ -336,28 +1039,72 @@ fn secure_get_docs(
 
     assert_get_docs(&context, &assert_context)?;
 
+    let index = filter
+        .matcher
+        .as_ref()
+        .and_then(|matcher| matcher.index.as_ref());
+
+    if let Some(index) = index {
+        return get_indexed_docs(&context, filter, &rule, index);
+    }
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
             let docs = get_docs_heap(&collection, &state_ref.heap.db.db)?;
-            get_docs_impl(&docs, caller, controllers, filter, &rule)
+            get_docs_impl(&docs, &context, filter, &rule)
         }),
         Memory::Stable => STATE.with(|state| {
             let stable = get_docs_stable(&collection, &state.borrow().stable.db)?;
             let docs: Vec<(&Key, &Doc)> = stable.iter().map(|(key, doc)| (&key.key, doc)).collect();
-            get_docs_impl(&docs, caller, controllers, filter, &rule)
+            get_docs_impl(&docs, &context, filter, &rule)
+        }),
+    }
+}
+
+// Only the documents found in the index are read, the other criteria are then applied as for a scan.
+fn get_indexed_docs(
+    context: &StoreContext,
+    filter: &ListParams,
+    rule: &Rule,
+    ListIndexMatcher { path, value }: &ListIndexMatcher,
+) -> Result<ListResults<Doc>, String> {
+    assert_index(context.collection, rule, path)?;
+
+    // One more key than the maximum is read to assert whether the match is too wide
+    let keys = get_index_keys(context.collection, path, value, MAX_INDEX_KEYS + 1);
+
+    assert_index_keys(context.collection, path, &keys)?;
+
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state_ref = state.borrow();
+            let docs = get_docs_by_keys_heap(context.collection, &keys, &state_ref.heap.db.db)?;
+            get_docs_impl(&docs, context, filter, rule)
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let stable =
+                get_docs_by_keys_stable(context.collection, &keys, &state.borrow().stable.db);
+            let docs: Vec<(&Key, &Doc)> = stable.iter().map(|(key, doc)| (&key.key, doc)).collect();
+            get_docs_impl(&docs, context, filter, rule)
         }),
     }
 }
 
 fn get_docs_impl<'a>(
     docs: &[(&'a Key, &'a Doc)],
-    caller: Principal,
-    controllers: &Controllers,
+    context: &StoreContext,
     filters: &ListParams,
     rule: &Rule,
 ) -> Result<ListResults<Doc>, String> {
-    let matches = filter_values(caller, controllers, &rule.read, docs, filters)?;
+    let matches = filter_values(
+        context.caller,
+        context.controllers,
+        context.collection,
+        &rule.read,
+        docs,
+        filters,
+    )?;
 
     let results = list_values(&matches, filters);
 
@@ -389,6 +1136,10 @@ fn get_docs_impl<'a>(
 ///
 /// This function allows you to securely delete documents from a Juno collection's store, returning
 /// relevant context information or error messages.
+///
+/// # Note
+/// If the collection keeps a trash, the document is moved to the trash with its revisions. Otherwise,
+/// the revisions of the document are deleted with it.
 pub fn delete_doc_store(
     caller: UserId,
     collection: CollectionKey,
@@ -434,11 +1185,27 @@ fn delete_doc_impl(
     key: Key,
     value: DelDoc,
 ) -> Result<Option<Doc>, String> {
-    let current_doc = get_state_doc(context.collection, &key, assert_context.rule)?;
+    let current_doc = get_current_doc(context.collection, &key, assert_context.rule)?;
 
     assert_delete_doc(context, assert_context, &key, &value, &current_doc)?;
 
-    delete_state_doc(context.collection, &key, assert_context.rule)
+    delete_or_trash_doc(context.collection, &key, assert_context.rule)
+}
+
+// The document is moved to the trash if the collection retains the deleted documents.
+fn delete_or_trash_doc(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+) -> Result<Option<Doc>, String> {
+    let current_doc = get_current_doc(collection, key, rule)?;
+
+    match (&current_doc, rule.trash_retention_ns) {
+        (Some(_), Some(retention)) => {
+            trash_state_doc(collection, key, rule, time().saturating_add(retention))
+        }
+        _ => delete_state_doc(collection, key, rule),
+    }
 }
 
 /// Delete multiple documents from a collection's store.
@@ -456,6 +1223,17 @@ fn delete_doc_impl(
 ///
 /// This function allows you to securely delete multiple documents from a Juno collection's store.
 pub fn delete_docs_store(collection: &CollectionKey) -> Result<(), String> {
+    internal_delete_collection_docs_store(collection).map(|_| ())
+}
+
+/// Internal variant of `delete_docs_store`.
+///
+/// Deletes every document of a collection and returns the context of each deleted document.
+///
+/// Useful for notifying the deletion of the documents.
+pub fn internal_delete_collection_docs_store(
+    collection: &CollectionKey,
+) -> Result<Vec<DocContext<Option<Doc>>>, String> {
     let rule = get_state_rule(collection)?;
 
     let keys = match rule.mem() {
@@ -476,12 +1254,20 @@ fn delete_docs_impl(
     keys: &Vec<Key>,
     collection: &CollectionKey,
     rule: &Rule,
-) -> Result<(), String> {
+) -> Result<Vec<DocContext<Option<Doc>>>, String> {
+    let mut results: Vec<DocContext<Option<Doc>>> = Vec::new();
+
     for key in keys {
-        delete_state_doc(collection, key, rule)?;
+        let deleted_doc = delete_state_doc(collection, key, rule)?;
+
+        results.push(DocContext {
+            key: key.clone(),
+            collection: collection.clone(),
+            data: deleted_doc,
+        });
     }
 
-    Ok(())
+    Ok(results)
 }
 
 /// Count the number of documents in a collection's store.
@@ -490,6 +1276,8 @@ fn delete_docs_impl(
 /// based on the memory type (Heap or Stable). It returns the count as a `Result` with `Ok(usize)`
 /// on success, or an error message as `Err(String)` if an issue occurs during counting.
 ///
+/// Documents that are expired but not yet swept are not counted, as they are not listed either.
+///
 /// # Parameters
 /// - `collection`: A reference to the `CollectionKey` representing the collection to count documents in.
 ///
@@ -501,14 +1289,16 @@ fn delete_docs_impl(
 pub fn count_collection_docs_store(collection: &CollectionKey) -> Result<usize, String> {
     let rule = get_state_rule(collection)?;
 
+    let now = time();
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
-            let length = count_docs_heap(collection, &state_ref.heap.db.db)?;
+            let length = count_docs_heap(collection, &state_ref.heap.db.db, now)?;
             Ok(length)
         }),
         Memory::Stable => STATE.with(|state| {
-            let length = count_docs_stable(collection, &state.borrow().stable.db)?;
+            let length = count_docs_stable(collection, &state.borrow().stable.db, now)?;
             Ok(length)
         }),
     }
@@ -586,6 +1376,302 @@ fn delete_filtered_docs_store_impl(
     Ok(results)
 }
 
+// ---------------------------------------------------------
+// Batch
+// ---------------------------------------------------------
+
+/// Apply an ordered batch of set, delete and assert-version operations across collections.
+///
+/// Each operation goes through the same assertions as its single counterpart - permissions, versions,
+/// assertion hooks, user usage and rate limits - and sees the effects of the previous operations.
+///
+/// Every operation is asserted - including the changes, items and rate it accounts for - before any
+/// is applied. If one fails, nothing is applied and the failing operation is returned as a
+/// `DocsBatchError`.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the batch.
+/// - `operations`: The operations to apply, in order.
+///
+/// # Returns
+/// - `Ok(DocsBatchChanges)`: The results of the operations and the context of the documents set and deleted.
+/// - `Err(DocsBatchError)`: The index, collection, key and error of the operation that failed.
+pub fn apply_docs_batch_store(
+    caller: UserId,
+    operations: Vec<DocsBatchOperation>,
+) -> Result<DocsBatchChanges, DocsBatchError> {
+    let controllers: Controllers = get_controllers();
+    let config = get_config();
+
+    assert_docs_batch(caller, &controllers, &config, &operations)?;
+
+    let mut changes = DocsBatchChanges {
+        results: Vec::new(),
+        set_docs: Vec::new(),
+        deleted_docs: Vec::new(),
+    };
+
+    for (index, operation) in operations.iter().enumerate() {
+        let context = StoreContext {
+            caller,
+            controllers: &controllers,
+            collection: operation.collection(),
+        };
+
+        apply_docs_batch_operation(&context, operation, &mut changes)
+            .map_err(|error| DocsBatchError::from_operation(index, operation, error))?;
+    }
+
+    Ok(changes)
+}
+
+// Asserts each operation against the documents, the usages and the rates as the previous
+// operations leave them. Nothing is written.
+fn assert_docs_batch(
+    caller: UserId,
+    controllers: &Controllers,
+    config: &Option<DbConfig>,
+    operations: &[DocsBatchOperation],
+) -> Result<(), DocsBatchError> {
+    let mut pending = DocsBatchPending::default();
+
+    for (index, operation) in operations.iter().enumerate() {
+        let context = StoreContext {
+            caller,
+            controllers,
+            collection: operation.collection(),
+        };
+
+        assert_docs_batch_operation(&context, config, operation, &mut pending)
+            .map_err(|error| DocsBatchError::from_operation(index, operation, error))?;
+    }
+
+    Ok(())
+}
+
+fn assert_docs_batch_operation(
+    context: &StoreContext,
+    config: &Option<DbConfig>,
+    operation: &DocsBatchOperation,
+    pending: &mut DocsBatchPending,
+) -> Result<(), String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let entry = (context.collection.clone(), operation.key().clone());
+
+    let current_doc = match pending.docs.get(&entry) {
+        Some(doc) => doc.clone(),
+        None => get_current_doc(context.collection, operation.key(), &rule)?,
+    };
+
+    let doc = match operation {
+        DocsBatchOperation::Set { key, doc, .. } => {
+            assert_set_doc_constraints(context, &assert_context, config, key, doc, &current_doc)?;
+
+            // The items are accounted to their owner, which remains the same when they are updated
+            let owner = current_doc
+                .as_ref()
+                .map_or(context.caller, |current_doc| current_doc.owner);
+
+            assert_db_pending_items_usage(
+                context.caller,
+                context.controllers,
+                context.collection,
+                &rule,
+                &current_doc,
+                doc,
+                pending.usage(owner, context.collection),
+            )?;
+
+            let prepared_doc = Doc::prepare(
+                context.caller,
+                &current_doc,
+                doc.clone(),
+                rule.default_ttl_ns,
+            );
+
+            pending.usage(owner, context.collection).track(
+                &current_doc.as_ref().map(UserUsageItem::from_doc),
+                &Some(UserUsageItem::from_doc(&prepared_doc)),
+            );
+
+            assert_docs_batch_changes(context, &rule, pending)?;
+
+            Some(prepared_doc)
+        }
+        DocsBatchOperation::Delete { key, doc, .. } => {
+            assert_delete_doc_constraints(context, &assert_context, key, doc, &current_doc)?;
+
+            if let Some(current_doc) = &current_doc {
+                pending
+                    .usage(current_doc.owner, context.collection)
+                    .track(&Some(UserUsageItem::from_doc(current_doc)), &None);
+            }
+
+            assert_docs_batch_changes(context, &rule, pending)?;
+
+            None
+        }
+        DocsBatchOperation::AssertVersion { key, version, .. } => {
+            // A document the caller cannot read is asserted as if it did not exist
+            let readable_doc = current_doc.clone().filter(|current_doc| {
+                assert_get_doc(context, &assert_context, key, current_doc).is_ok()
+            });
+
+            assert_doc_version(&readable_doc, version)?;
+
+            current_doc
+        }
+    };
+
+    pending.docs.insert(entry, doc);
+
+    Ok(())
+}
+
+// Counts the change of the caller and the rate token of the collection, asserted together with
+// those of the previous operations of the batch.
+fn assert_docs_batch_changes(
+    context: &StoreContext,
+    rule: &Rule,
+    pending: &mut DocsBatchPending,
+) -> Result<(), String> {
+    let usage = pending.usage(context.caller, context.collection);
+
+    usage.changes_count = usage.changes_count.saturating_add(1);
+
+    assert_db_pending_changes_usage(
+        context.caller,
+        context.controllers,
+        context.collection,
+        rule.max_changes_per_user,
+        usage,
+    )?;
+
+    let rate = pending.rates.entry(context.collection.clone()).or_default();
+
+    *rate = rate.saturating_add(1);
+
+    assert_rate(context.collection, &rule.rate_config, *rate)?;
+
+    Ok(())
+}
+
+// The operation - including its usage and rate - was asserted by assert_docs_batch, it is only written.
+fn apply_docs_batch_operation(
+    context: &StoreContext,
+    operation: &DocsBatchOperation,
+    changes: &mut DocsBatchChanges,
+) -> Result<(), String> {
+    match operation {
+        DocsBatchOperation::Set {
+            collection,
+            key,
+            doc,
+        } => {
+            let rule = get_state_rule(collection)?;
+
+            let current_doc = get_current_doc(collection, key, &rule)?;
+
+            increment_docs_batch_usage(context, &rule)?;
+
+            let prepared_doc: Doc = Doc::prepare(
+                context.caller,
+                &current_doc,
+                doc.clone(),
+                rule.default_ttl_ns,
+            );
+
+            let (_evicted_doc, after) =
+                insert_state_doc(collection, key, &prepared_doc, &rule, &context.caller)?;
+
+            changes
+                .results
+                .push(DocsBatchOperationResult::Set(after.clone()));
+
+            changes.set_docs.push(DocContext {
+                key: key.clone(),
+                collection: collection.clone(),
+                data: DocUpsert {
+                    before: current_doc,
+                    after,
+                },
+            });
+        }
+        DocsBatchOperation::Delete {
+            collection,
+            key,
+            doc: _,
+        } => {
+            let rule = get_state_rule(collection)?;
+
+            increment_docs_batch_usage(context, &rule)?;
+
+            let data = delete_or_trash_doc(collection, key, &rule)?;
+
+            changes.results.push(DocsBatchOperationResult::Delete);
+
+            changes.deleted_docs.push(DocContext {
+                key: key.clone(),
+                collection: collection.clone(),
+                data,
+            });
+        }
+        DocsBatchOperation::AssertVersion { .. } => {
+            changes
+                .results
+                .push(DocsBatchOperationResult::AssertVersion);
+        }
+    }
+
+    Ok(())
+}
+
+// The changes of the caller and the rate of the collection were asserted with the batch, these are
+// only incremented.
+fn increment_docs_batch_usage(context: &StoreContext, rule: &Rule) -> Result<(), String> {
+    increment_db_usage(context.caller, context.controllers, context.collection)?;
+
+    increment_and_assert_rate(context.collection, &rule.rate_config)?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------
+// Indexes
+// ---------------------------------------------------------
+
+/// Starts - or restarts - the rebuild of the indexes of a collection according to its current rule.
+///
+/// The rebuild is processed in batches by timers, listing with an index of the collection is refused until it is over.
+pub fn rebuild_indexes_store(collection: &CollectionKey) {
+    start_index_rebuild(collection);
+}
+
+// ---------------------------------------------------------
+// Expiries
+// ---------------------------------------------------------
+
+// Expired documents are hidden right away, even though they are only removed from the state
+// when the next expiry sweep is executed.
+fn get_current_doc(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+) -> Result<Option<Doc>, String> {
+    let doc = get_state_doc(collection, key, rule)?;
+
+    let now = time();
+
+    Ok(doc.filter(|doc| !doc.is_expired(now)))
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
@@ -2,15 +2,18 @@ pub mod state {
     use crate::db::types::config::DbConfig;
     use crate::{DelDoc, SetDoc};
     use candid::CandidType;
+    use ic_cdk_timers::TimerId;
     use ic_stable_structures::StableBTreeMap;
     use junobuild_collections::types::core::CollectionKey;
-    use junobuild_collections::types::rules::Rules;
+    use junobuild_collections::types::rules::{IndexPath, Rules};
     use junobuild_shared::rate::types::RateTokenStore;
     use junobuild_shared::types::core::{Blob, Key};
     use junobuild_shared::types::memory::Memory;
     use junobuild_shared::types::state::{Timestamp, UserId, Version};
     use serde::{Deserialize, Serialize};
+    use serde_json::Value;
     use std::collections::{BTreeMap, HashMap};
+    use std::rc::Rc;
 
     pub type Collection = BTreeMap<Key, Doc>;
     pub type DbHeap = HashMap<CollectionKey, Collection>;
@@ -23,16 +26,168 @@ pub mod state {
         pub key: Key,
     }
 
+    pub type DbIndexesStable = StableBTreeMap<IndexKey, (), Memory>;
+
+    /// An entry of a secondary index: the document `key` of the `collection` has the `value` at
+    /// the JSON `path` of its data.
+    ///
+    /// Entries are ordered by collection, path, value and key, so that equality and range lookups
+    /// are performed with a single range scan.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct IndexKey {
+        pub collection: CollectionKey,
+        pub path: IndexPath,
+        pub value: IndexValue,
+        pub key: Key,
+    }
+
+    /// An indexed scalar value. Numbers are stored as order-preserving bits so that the values of
+    /// the same type are sorted naturally.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum IndexValue {
+        Null,
+        Bool(bool),
+        Number(u64),
+        Text(String),
+    }
+
+    pub type DbExpiriesStable = StableBTreeMap<ExpiryKey, (), Memory>;
+
+    /// An entry of the expiry queue: the document `key` of the `collection` expires - or is purged
+    /// from the trash - at `expires_at`.
+    ///
+    /// Entries are ordered by expiry first, so that the expired documents are found with a single
+    /// range scan. Entries of documents that were overwritten or evicted are not removed eagerly but,
+    /// skipped and cleaned up when swept.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct ExpiryKey {
+        pub expires_at: Timestamp,
+        pub collection: CollectionKey,
+        pub key: Key,
+    }
+
+    /// The trash of the collections: the documents that were soft deleted, keyed like `DbStable`.
+    pub type DbTrashStable = StableBTreeMap<StableKey, Doc, Memory>;
+
+    pub type DbRevisionsStable = StableBTreeMap<RevisionKey, DocRevision, Memory>;
+
+    /// The key of a revision: the `version` of the document `key` of the `collection`.
+    ///
+    /// Entries are ordered by collection, key and version, so that the revisions of a document are
+    /// listed - and the oldest pruned - with a single range scan.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct RevisionKey {
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub version: Version,
+    }
+
+    /// A revision of a document, recorded each time the document is set in a collection that keeps
+    /// revisions.
+    ///
+    /// This struct includes the following fields:
+    /// - `owner`: The `UserId` representing the owner of the document.
+    /// - `data`: A `Blob` containing the data of the document in this revision.
+    /// - `description`: An optional `String` providing the description of the document in this revision.
+    /// - `version`: The version of the document in this revision.
+    /// - `updated_at`: A `u64` timestamp indicating when the revision was written.
+    /// - `caller`: The `UserId` of the caller that wrote the revision.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocRevision {
+        pub owner: UserId,
+        pub data: Blob,
+        pub description: Option<String>,
+        pub version: Version,
+        pub updated_at: Timestamp,
+        pub caller: UserId,
+    }
+
+    pub type DbAccessStable = StableBTreeMap<StableKey, DocAccess, Memory>;
+
+    /// The access list of a document: the users granted to read - respectively to write - the
+    /// document, on top of the permissions of its collection. A grant without `expires_at` does not
+    /// expire.
+    ///
+    /// The writers can read and update the document but, as for the permissions, only its owner and
+    /// the controllers can delete it or edit its access list.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocAccess {
+        pub readers: Vec<DocGrant>,
+        pub writers: Vec<DocGrant>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocGrant {
+        pub user: UserId,
+        pub expires_at: Option<Timestamp>,
+    }
+
+    pub type DbSharesStable = StableBTreeMap<ShareKey, (), Memory>;
+
+    /// An entry of the index of the shared documents: the document `key` of the `collection` is
+    /// granted to the `user`.
+    ///
+    /// Entries are ordered by user first, so that the documents shared with a user are listed with
+    /// a single range scan. The grants are asserted against the access list of the document, which
+    /// is the source of truth - e.g. for their expiry.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct ShareKey {
+        pub user: UserId,
+        pub collection: CollectionKey,
+        pub key: Key,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct DbHeapState {
         pub db: DbHeap,
         pub rules: Rules,
         pub config: Option<DbConfig>,
+        #[serde(default)]
+        pub index_rebuilds: IndexRebuilds,
+    }
+
+    pub type IndexRebuilds = HashMap<CollectionKey, IndexRebuild>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum IndexRebuildStatus {
+        Cleaning,
+        Indexing,
+    }
+
+    /// The rebuild of the indexes of a collection after its rule declared other indexed paths.
+    ///
+    /// The entries of the former indexes are deleted in batches, then the documents are indexed in
+    /// batches ordered by key - up to `last_key` - while the collection remains in use. The writes
+    /// are indexed according to the rule in the meantime but listing with an index of the collection
+    /// is refused until the rebuild is over.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct IndexRebuild {
+        pub status: IndexRebuildStatus,
+        pub last_key: Option<Key>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
     }
 
+    /// The JSON schemas of the collections, parsed once on first use and dropped when their rule
+    /// is set or deleted.
+    pub type JsonSchemas = HashMap<CollectionKey, Rc<Value>>;
+
     #[derive(Default, Clone)]
     pub struct DbRuntimeState {
         pub rate_tokens: RateTokenStore,
+        pub expiry_timer: Option<ExpiryTimer>,
+        pub index_rebuild_timer: Option<TimerId>,
+        pub json_schemas: JsonSchemas,
+    }
+
+    /// The timer scheduled to sweep the documents expiring at `expires_at`.
+    #[derive(Clone)]
+    pub struct ExpiryTimer {
+        pub expires_at: Timestamp,
+        pub timer_id: TimerId,
     }
 
     /// Represents a document in a collection's store.
@@ -44,6 +199,8 @@ pub mod state {
     /// - `created_at`: A `u64` timestamp for the document's creation.
     /// - `updated_at`: A `u64` timestamp for the document's last update.
     /// - `version`: A `u64` number for the document's version. The field is optional for backwards compatibility but, will be populated to 1 on the first create or update.
+    /// - `expires_at`: An optional `u64` timestamp after which the document is considered as deleted.
+    /// - `restorable_until`: An optional `u64` timestamp set when the document was moved to the trash - i.e. soft deleted. The document can be restored until then. It is `None` for the documents of the collection and the documents that were permanently deleted.
     ///
     /// This struct is used to store and manage documents within a collection's store.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -54,6 +211,8 @@ pub mod state {
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
         pub version: Option<Version>,
+        pub expires_at: Option<Timestamp>,
+        pub restorable_until: Option<Timestamp>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -101,9 +260,11 @@ pub mod config {
 
 pub mod interface {
     use crate::db::types::config::DbConfigMaxMemorySize;
+    use crate::db::types::state::{Doc, DocGrant};
     use candid::CandidType;
-    use junobuild_shared::types::core::Blob;
-    use junobuild_shared::types::state::Version;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_shared::types::state::{Timestamp, Version};
     use serde::{Deserialize, Serialize};
 
     /// Parameters for setting a document.
@@ -114,6 +275,7 @@ pub mod interface {
     /// - `description`: An optional `String` providing additional description for the document. This field is optional.
     /// - `version`: An optional `u64` version representing the last version of the document to ensure
     ///   update consistency. This field is optional - i.e. first time a document is saved, it can be left empty but following updates require the current version to be passed.
+    /// - `expires_at`: An optional `u64` timestamp after which the document expires. This field is optional.
     ///
     /// `SetDoc` is used to provide parameters for setting or updating a document in the collection's store.
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -121,6 +283,7 @@ pub mod interface {
         pub data: Blob,
         pub description: Option<String>,
         pub version: Option<Version>,
+        pub expires_at: Option<Timestamp>,
     }
 
     /// Parameters for deleting a document.
@@ -136,6 +299,50 @@ pub mod interface {
         pub version: Option<Version>,
     }
 
+    /// A patch of the JSON data of a document, itself JSON encoded.
+    ///
+    /// - `Merge`: an RFC 7396 JSON merge patch.
+    /// - `Json`: an RFC 6902 JSON patch - i.e. an array of operations.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum DocPatch {
+        Merge(Blob),
+        Json(Blob),
+    }
+
+    /// Parameters for patching a document.
+    ///
+    /// Unlike `SetDoc`, the `version` is optional even if the document exists: without it, the patch is
+    /// applied to the current version of the document - e.g. to let concurrent editors update different
+    /// fields. With a `version`, the patch is only applied if it matches the current version.
+    /// The description of the document is preserved.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct PatchDoc {
+        pub patch: DocPatch,
+        pub version: Option<Version>,
+    }
+
+    /// Parameters for restoring a revision of a document.
+    ///
+    /// The data and description of the `revision` are set as a new version of the document. As for
+    /// `PatchDoc`, the `version` is optional: without it, the revision is restored over the current
+    /// version of the document.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct RestoreDocRevision {
+        pub revision: Version,
+        pub version: Option<Version>,
+    }
+
+    /// Parameters for setting the access list of a document.
+    ///
+    /// The `readers` and `writers` replace those of the current access list. As for `SetDoc`, the
+    /// `version` of the current access list - if any - must be provided.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SetDocAccess {
+        pub readers: Vec<DocGrant>,
+        pub writers: Vec<DocGrant>,
+        pub version: Option<Version>,
+    }
+
     /// Parameters for setting the datastore configuration.
     ///
     /// This struct includes the following fields:
@@ -148,10 +355,74 @@ pub mod interface {
         pub max_memory_size: Option<DbConfigMaxMemorySize>,
         pub version: Option<Version>,
     }
+
+    /// An operation of a batch applied with `apply_docs_batch`.
+    ///
+    /// `AssertVersion` is a precondition that does not write anything: with a `version`, the
+    /// document must exist in that version, without, the document must not exist.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum DocsBatchOperation {
+        Set {
+            collection: CollectionKey,
+            key: Key,
+            doc: SetDoc,
+        },
+        Delete {
+            collection: CollectionKey,
+            key: Key,
+            doc: DelDoc,
+        },
+        AssertVersion {
+            collection: CollectionKey,
+            key: Key,
+            version: Option<Version>,
+        },
+    }
+
+    /// The result of an operation of a batch, in the order of the operations.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum DocsBatchOperationResult {
+        Set(Doc),
+        Delete,
+        AssertVersion,
+    }
+
+    /// The error of the operation that prevented a batch from being applied.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocsBatchError {
+        pub index: usize,
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub error: String,
+    }
 }
 
 pub mod store {
+    use crate::db::types::interface::DocsBatchOperationResult;
+    use crate::db::types::state::{Doc, DocContext, DocUpsert};
+    use crate::user::usage::types::state::UserUsagePending;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::state::UserId;
+    use std::collections::HashMap;
+
     pub struct AssertSetDocOptions {
         pub with_assert_rate: bool,
     }
+
+    /// The documents set and deleted by a batch, besides the results of its operations.
+    pub struct DocsBatchChanges {
+        pub results: Vec<DocsBatchOperationResult>,
+        pub set_docs: Vec<DocContext<DocUpsert>>,
+        pub deleted_docs: Vec<DocContext<Option<Doc>>>,
+    }
+
+    /// What the operations of a batch asserted so far leave pending: the documents as these would
+    /// be, the usages of the users and the rate tokens consumed per collection.
+    #[derive(Default)]
+    pub struct DocsBatchPending {
+        pub docs: HashMap<(CollectionKey, Key), Option<Doc>>,
+        pub usages: HashMap<(UserId, CollectionKey), UserUsagePending>,
+        pub rates: HashMap<CollectionKey, usize>,
+    }
 }
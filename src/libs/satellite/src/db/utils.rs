@@ -1,16 +1,25 @@
-use crate::db::types::state::Doc;
+use crate::db::assert::assert_doc_access;
+use crate::db::data::filter_data;
+use crate::db::state::get_shared_keys;
+use crate::db::types::state::{Doc, DocAccess, DocRevision};
+use crate::user::role::assert::has_role;
 use candid::Principal;
+use ic_cdk::api::time;
 use junobuild_collections::assert::stores::assert_permission;
+use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::data::list::{filter_timestamps, matcher_regex};
-use junobuild_shared::types::core::Key;
-use junobuild_shared::types::list::ListParams;
-use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::types::core::{Blob, Key};
+use junobuild_shared::types::list::{ListMatcher, ListParams};
+use junobuild_shared::types::state::{Controllers, Timestamped, UserId};
+use junobuild_shared::utils::principal_not_anonymous;
 use regex::Regex;
+use std::collections::HashSet;
 
 pub fn filter_values<'a>(
     caller: Principal,
     controllers: &'a Controllers,
+    collection: &CollectionKey,
     rule: &'a Permission,
     col: &'a [(&'a Key, &'a Doc)],
     ListParams {
@@ -22,14 +31,36 @@ pub fn filter_values<'a>(
 ) -> Result<Vec<(&'a Key, &'a Doc)>, String> {
     let (regex_key, regex_description) = matcher_regex(matcher)?;
 
+    let now = time();
+
+    // The roles are granted to the caller regardless of the documents, they are resolved once
+    let caller_has_role = match rule {
+        Permission::Roles(roles) => principal_not_anonymous(caller) && has_role(caller, roles),
+        _ => false,
+    };
+
+    let assert_read_permission = |owner: UserId| match rule {
+        Permission::Roles(_) => caller_has_role,
+        _ => assert_permission(rule, owner, caller, controllers, has_role),
+    };
+
+    // Only the access lists of the documents shared with the caller are read
+    let shared_keys: HashSet<Key> = get_shared_keys(&caller, collection).into_iter().collect();
+
+    let assert_shared_access = |key: &Key| {
+        shared_keys.contains(key) && assert_doc_access(caller, collection, key, DocAccess::can_read)
+    };
+
     let result = col
         .iter()
         .filter_map(|(key, doc)| {
-            if filter_key_matcher(&regex_key, key)
+            if !doc.is_expired(now)
+                && filter_key_matcher(&regex_key, key)
                 && filter_description_matcher(&regex_description, &doc.description)
                 && filter_owner(owner, &doc.owner)
                 && filter_timestamps(matcher, *doc)
-                && assert_permission(rule, doc.owner, caller, controllers)
+                && (assert_read_permission(doc.owner) || assert_shared_access(key))
+                && filter_data_matcher(matcher, &doc.data)
             {
                 Some((*key, *doc))
             } else {
@@ -41,6 +72,57 @@ pub fn filter_values<'a>(
     Ok(result)
 }
 
+/// Filters the revisions of a document - keyed by their version - with the key, description, owner,
+/// timestamps and data criteria of the `ListParams`.
+pub fn filter_revisions<'a>(
+    revisions: &'a [(Key, DocRevision)],
+    filters: &'a ListParams,
+) -> Result<Vec<(&'a Key, &'a DocRevision)>, String> {
+    filter_matches(revisions, filters, |revision| {
+        (&revision.description, &revision.owner, &revision.data)
+    })
+}
+
+/// Filters the trashed documents with the key, description, owner, timestamps and data criteria of
+/// the `ListParams`.
+pub fn filter_trashed_docs<'a>(
+    docs: &'a [(Key, Doc)],
+    filters: &'a ListParams,
+) -> Result<Vec<(&'a Key, &'a Doc)>, String> {
+    filter_matches(docs, filters, |doc| {
+        (&doc.description, &doc.owner, &doc.data)
+    })
+}
+
+fn filter_matches<'a, T: Timestamped>(
+    values: &'a [(Key, T)],
+    ListParams {
+        matcher,
+        order: _,
+        paginate: _,
+        owner,
+    }: &'a ListParams,
+    fields: impl Fn(&T) -> (&Option<String>, &UserId, &Blob),
+) -> Result<Vec<(&'a Key, &'a T)>, String> {
+    let (regex_key, regex_description) = matcher_regex(matcher)?;
+
+    let result = values
+        .iter()
+        .filter(|(key, value)| {
+            let (description, value_owner, data) = fields(value);
+
+            filter_key_matcher(&regex_key, key)
+                && filter_description_matcher(&regex_description, description)
+                && filter_owner(owner, value_owner)
+                && filter_timestamps(matcher, value)
+                && filter_data_matcher(matcher, data)
+        })
+        .map(|(key, value)| (key, value))
+        .collect();
+
+    Ok(result)
+}
+
 fn filter_key_matcher(regex: &Option<Regex>, key: &Key) -> bool {
     match regex {
         None => true,
@@ -64,3 +146,11 @@ fn filter_owner(owner: &Option<UserId>, doc_owner: &UserId) -> bool {
         Some(filter_owner) => filter_owner == doc_owner,
     }
 }
+
+// Evaluated last given that the data of the document has to be decoded.
+fn filter_data_matcher(matcher: &Option<ListMatcher>, data: &Blob) -> bool {
+    match matcher.as_ref().and_then(|matcher| matcher.data.as_ref()) {
+        None => true,
+        Some(matcher) => filter_data(matcher, data),
+    }
+}
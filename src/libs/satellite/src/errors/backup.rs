@@ -0,0 +1,10 @@
+// The data to import cannot be decoded with the provided format.
+pub const JUNO_BACKUP_ERROR_INVALID_DATA: &str = "juno.backup.error.invalid_data";
+// The data to import contains a record that does not match the type of the collection.
+pub const JUNO_BACKUP_ERROR_INVALID_RECORD: &str = "juno.backup.error.invalid_record";
+// An imported asset belongs to another collection than the one it is imported into.
+pub const JUNO_BACKUP_ERROR_COLLECTION_MISMATCH: &str = "juno.backup.error.collection_mismatch";
+// The content of an asset is missing or does not match the length or hash of its encoding.
+pub const JUNO_BACKUP_ERROR_INVALID_CONTENT: &str = "juno.backup.error.invalid_content";
+// The content of an asset is imported but its metadata were not - e.g. they were lost with an upgrade between two pages.
+pub const JUNO_BACKUP_ERROR_ASSET_NOT_STAGED: &str = "juno.backup.error.asset_not_staged";
@@ -0,0 +1,2 @@
+// The requested sequence "{}" has already been dropped from the change log. The client should reload its data.
+pub const JUNO_CHANGES_ERROR_RESYNC_REQUIRED: &str = "juno.changes.error.resync_required";
@@ -1,2 +1,34 @@
 pub const JUNO_DATASTORE_ERROR_CANNOT_WRITE: &str = "juno.datastore.error.cannot_write";
 pub const JUNO_DATASTORE_ERROR_CANNOT_READ: &str = "juno.datastore.error.cannot_read";
+pub const JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND: &str = "juno.datastore.error.index_not_found";
+// The index is being rebuilt after the indexed paths of the collection changed.
+pub const JUNO_DATASTORE_ERROR_INDEX_BUILDING: &str = "juno.datastore.error.index_building";
+// More documents than the maximum that can be read with an index match.
+pub const JUNO_DATASTORE_ERROR_INDEX_TOO_MANY_KEYS: &str =
+    "juno.datastore.error.index_too_many_keys";
+// A criteria of the list matcher that cannot be applied - e.g. an index to the revisions.
+pub const JUNO_DATASTORE_ERROR_MATCHER_NOT_SUPPORTED: &str =
+    "juno.datastore.error.matcher_not_supported";
+pub const JUNO_DATASTORE_ERROR_VERSION_MISMATCH: &str = "juno.datastore.error.version_mismatch";
+pub const JUNO_DATASTORE_ERROR_INVALID_PATCH: &str = "juno.datastore.error.invalid_patch";
+pub const JUNO_DATASTORE_ERROR_INVALID_EXPIRES_AT: &str = "juno.datastore.error.invalid_expires_at";
+pub const JUNO_DATASTORE_ERROR_EXPIRES_AT_NOT_SUPPORTED: &str =
+    "juno.datastore.error.expires_at_not_supported";
+pub const JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND: &str = "juno.datastore.error.revision_not_found";
+pub const JUNO_DATASTORE_ERROR_NOT_IN_TRASH: &str = "juno.datastore.error.not_in_trash";
+// A document with the same key was set after the trashed document was deleted.
+pub const JUNO_DATASTORE_ERROR_RESTORE_CONFLICT: &str = "juno.datastore.error.restore_conflict";
+pub const JUNO_DATASTORE_ERROR_BATCH_OPERATION_FAILED: &str =
+    "juno.datastore.error.batch_operation_failed";
+// The data of the document does not match the JSON Schema of the collection.
+pub const JUNO_DATASTORE_ERROR_JSON_SCHEMA_MISMATCH: &str =
+    "juno.datastore.error.json_schema_mismatch";
+// The access list of documents is not supported in system collections.
+pub const JUNO_DATASTORE_ERROR_DOC_ACCESS_NOT_SUPPORTED: &str =
+    "juno.datastore.error.doc_access_not_supported";
+// At most {} grants, to distinct and not anonymous users, that do not expire in the past.
+pub const JUNO_DATASTORE_ERROR_INVALID_DOC_ACCESS: &str = "juno.datastore.error.invalid_doc_access";
+// The ownership of the documents of system collections cannot be transferred.
+pub const JUNO_DATASTORE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED: &str =
+    "juno.datastore.error.ownership_transfer_not_supported";
+pub const JUNO_DATASTORE_ERROR_INVALID_OWNER: &str = "juno.datastore.error.invalid_owner";
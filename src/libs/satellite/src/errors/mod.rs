@@ -1,3 +1,7 @@
 pub mod auth;
+pub mod backup;
+pub mod changes;
 pub mod db;
+pub mod rules;
 pub mod user;
+pub mod ws;
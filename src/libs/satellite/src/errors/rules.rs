@@ -0,0 +1,5 @@
+// The collection "{}" is being migrated to another memory.
+pub const JUNO_RULES_ERROR_MIGRATION_IN_PROGRESS: &str = "juno.rules.error.migration_in_progress";
+// The memory of a system collection cannot be migrated.
+pub const JUNO_RULES_ERROR_MIGRATION_SYSTEM_COLLECTION: &str =
+    "juno.rules.error.migration_system_collection";
@@ -23,6 +23,22 @@ pub const JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED: &str =
     "juno.datastore.error.user.usage.change_limit_reached";
 pub const JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA: &str =
     "juno.datastore.error.user.usage.invalid_data";
+// Maximal number of documents - or assets - owned by a user in a collection reached.
+pub const JUNO_DATASTORE_ERROR_USER_USAGE_ITEMS_LIMIT_REACHED: &str =
+    "juno.datastore.error.user.usage.items_limit_reached";
+// Maximal size of the documents - or assets - owned by a user in a collection reached.
+pub const JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED: &str =
+    "juno.datastore.error.user.usage.bytes_limit_reached";
+
+// The data of the anonymous principal cannot be purged.
+pub const JUNO_DATASTORE_ERROR_USER_PURGE_INVALID_USER: &str =
+    "juno.datastore.error.user.purge.invalid_user";
+
+// User role key must be a textual representation of a principal.
+pub const JUNO_DATASTORE_ERROR_USER_ROLE_KEY_NO_PRINCIPAL: &str =
+    "juno.datastore.error.user.role.key_no_principal";
+pub const JUNO_DATASTORE_ERROR_USER_ROLE_INVALID_DATA: &str =
+    "juno.datastore.error.user.role.invalid_data";
 
 // The webauthn data can only be created not updated because those information are set during the sign-up process.
 pub const JUNO_DATASTORE_ERROR_USER_WEBAUTHN_CANNOT_UPDATE: &str =
@@ -0,0 +1,33 @@
+// Collection "{}" not found in the Datastore or Storage.
+pub const JUNO_WS_ERROR_COLLECTION_NOT_FOUND: &str = "juno.ws.error.collection_not_found";
+// Caller cannot read the collection and therefore cannot subscribe to it.
+pub const JUNO_WS_ERROR_CANNOT_SUBSCRIBE: &str = "juno.ws.error.cannot_subscribe";
+// The message sent by the client is not a valid JSON command.
+pub const JUNO_WS_ERROR_INVALID_MESSAGE: &str = "juno.ws.error.invalid_message";
+// The command sent by the client is not supported.
+pub const JUNO_WS_ERROR_UNKNOWN_COMMAND: &str = "juno.ws.error.unknown_command";
+// A subscribe command was sent without any collection.
+pub const JUNO_WS_ERROR_NO_COLLECTIONS: &str = "juno.ws.error.no_collections";
+// The subscription "{}" to unsubscribe does not exist.
+pub const JUNO_WS_ERROR_SUBSCRIPTION_NOT_FOUND: &str = "juno.ws.error.subscription_not_found";
+// The gateway "{}" is not allowed to relay WebSocket clients.
+pub const JUNO_WS_ERROR_GATEWAY_NOT_ALLOWED: &str = "juno.ws.error.gateway_not_allowed";
+// The maximal number of returned messages must be greater than zero.
+pub const JUNO_WS_ERROR_INVALID_MAX_RETURNED_MESSAGES: &str =
+    "juno.ws.error.invalid_max_returned_messages";
+// The acknowledgement interval must be greater than the keep alive timeout of the clients.
+pub const JUNO_WS_ERROR_INVALID_ACK_INTERVAL: &str = "juno.ws.error.invalid_ack_interval";
+// The caller must be a known user - i.e. have signed in - to open a connection.
+pub const JUNO_WS_ERROR_USER_REQUIRED: &str = "juno.ws.error.user_required";
+// The rate configuration is invalid - e.g. time_per_token_ns is zero.
+pub const JUNO_WS_ERROR_INVALID_RATE_CONFIG: &str = "juno.ws.error.invalid_rate_config";
+// The client sent too many messages or subscriptions. The connection is closed.
+pub const JUNO_WS_ERROR_RATE_LIMITED: &str = "juno.ws.error.rate_limited";
+// A join or leave command was sent without any channel.
+pub const JUNO_WS_ERROR_NO_CHANNELS: &str = "juno.ws.error.no_channels";
+// A channel cannot be named after a collection - subscribe to the collection instead.
+pub const JUNO_WS_ERROR_CHANNEL_IS_COLLECTION: &str = "juno.ws.error.channel_is_collection";
+// The client "{}" is not connected.
+pub const JUNO_WS_ERROR_CLIENT_NOT_CONNECTED: &str = "juno.ws.error.client_not_connected";
+// The payload of a message must be valid JSON.
+pub const JUNO_WS_ERROR_INVALID_PAYLOAD: &str = "juno.ws.error.invalid_payload";
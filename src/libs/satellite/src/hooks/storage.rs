@@ -3,14 +3,15 @@
 use crate::assets::constants::CDN_JUNO_RELEASES_COLLECTION_KEY;
 use crate::types::hooks::{
     AssertDeleteAssetContext, AssertUploadAssetContext, OnDeleteAssetContext,
-    OnDeleteFilteredAssetsContext, OnDeleteManyAssetsContext, OnUploadAssetContext,
+    OnDeleteFilteredAssetsContext, OnDeleteManyAssetsContext, OnTransferAssetOwnershipContext,
+    OnUploadAssetContext,
 };
 #[allow(unused)]
 use ic_cdk_timers::set_timer;
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::types::state::UserId;
-use junobuild_storage::types::store::{Asset, AssetAssertUpload};
+use junobuild_storage::types::store::{Asset, AssetAssertUpload, AssetUpsert};
 #[allow(unused)]
 use std::time::Duration;
 
@@ -19,11 +20,13 @@ extern "Rust" {
     fn juno_on_delete_asset(context: OnDeleteAssetContext);
     fn juno_on_delete_many_assets(context: OnDeleteManyAssetsContext);
     fn juno_on_delete_filtered_assets(context: OnDeleteFilteredAssetsContext);
+    fn juno_on_transfer_asset_ownership(context: OnTransferAssetOwnershipContext);
 
     fn juno_on_upload_asset_collections() -> Option<Vec<String>>;
     fn juno_on_delete_asset_collections() -> Option<Vec<String>>;
     fn juno_on_delete_many_assets_collections() -> Option<Vec<String>>;
     fn juno_on_delete_filtered_assets_collections() -> Option<Vec<String>>;
+    fn juno_on_transfer_asset_ownership_collections() -> Option<Vec<String>>;
 
     fn juno_assert_upload_asset(context: AssertUploadAssetContext) -> Result<(), String>;
     fn juno_assert_delete_asset(context: AssertDeleteAssetContext) -> Result<(), String>;
@@ -134,6 +137,27 @@ pub fn invoke_on_delete_filtered_assets(caller: &UserId, assets: &[Option<Asset>
     }
 }
 
+#[allow(dead_code, unused_variables)]
+pub fn invoke_on_transfer_asset_ownership(caller: &UserId, asset: &AssetUpsert) {
+    #[cfg(feature = "on_transfer_asset_ownership")]
+    {
+        let context: OnTransferAssetOwnershipContext = OnTransferAssetOwnershipContext {
+            caller: *caller,
+            data: asset.clone(),
+        };
+
+        unsafe {
+            let collections = juno_on_transfer_asset_ownership_collections();
+
+            if should_invoke_asset_hook(collections, &context.data.after.key.collection) {
+                set_timer(Duration::ZERO, async {
+                    juno_on_transfer_asset_ownership(context);
+                });
+            }
+        }
+    }
+}
+
 #[allow(unused_variables)]
 pub fn invoke_assert_upload_asset(
     caller: &UserId,
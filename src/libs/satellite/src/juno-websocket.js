@@ -42,7 +42,9 @@
  * @property {string} key - Document/asset key
  * @property {string} caller - Principal that made the change
  * @property {number} timestamp - Unix timestamp (nanoseconds)
- * @property {*} [data] - Optional additional data
+ * @property {{data: number[], description?: string, version?: number, updated_at: number}} [doc] - The document, for subscriptions with `with_doc`
+ * @property {boolean} [doc_omitted] - The document was requested but exceeds the collection's max_size
+ * @property {number} [sequence] - Sequence of the change, used to resume after a reconnection
  */
 
 // ============================================================================
@@ -82,6 +84,9 @@ export class JunoWebSocket {
     /** @private {number|null} */
     this.reconnectTimer = null;
 
+    /** @private {number|null} Sequence of the last received change */
+    this.lastSequence = null;
+
     // Auto-connect if enabled
     if (this.config.autoConnect) {
       this.connect();
@@ -186,6 +191,31 @@ export class JunoWebSocket {
     }
   }
 
+  /**
+   * Join app-level channels to receive the messages broadcast by the serverless functions
+   * with `ws_broadcast`. Messages are dispatched as `junows:message` events.
+   * @param {string[]} channels - Channels to join
+   * @public
+   */
+  join(channels) {
+    this.sendMessage({
+      command: 'join',
+      data: { channels }
+    });
+  }
+
+  /**
+   * Leave app-level channels
+   * @param {string[]} channels - Channels to leave
+   * @public
+   */
+  leave(channels) {
+    this.sendMessage({
+      command: 'leave',
+      data: { channels }
+    });
+  }
+
   /**
    * Get current connection status
    * @returns {boolean}
@@ -223,10 +253,10 @@ export class JunoWebSocket {
       detail: { clientKey: this.clientKey }
     }));
 
-    // Resubscribe to all collections
+    // Resubscribe to all collections and replay the changes missed while disconnected
     const collections = Array.from(this.subscriptions.keys());
     if (collections.length > 0) {
-      this.sendSubscribe(collections);
+      this.sendSubscribe(collections, this.lastSequence);
     }
   }
 
@@ -268,6 +298,37 @@ export class JunoWebSocket {
         // Heartbeat response - no action needed
         break;
 
+      case 'subscribed':
+      case 'unsubscribed':
+      case 'joined':
+      case 'left':
+        // Acknowledgement of a subscription or channel command - no action needed
+        break;
+
+      case 'message':
+        // App-level message sent by the serverless functions
+        window.dispatchEvent(new CustomEvent('junows:message', {
+          detail: message.payload
+        }));
+        break;
+
+      case 'resync_required':
+        // Missed changes are no longer available - the application should reload its data
+        this.lastSequence = null;
+        window.dispatchEvent(new CustomEvent('junows:resync', {
+          detail: { since: message.payload?.since }
+        }));
+        break;
+
+      case 'replay_truncated':
+        // Only part of the missed changes were replayed - the application should page through the
+        // remaining ones with the list_changes query, starting after the last replayed change
+        this.lastSequence = message.payload?.last_sequence ?? this.lastSequence;
+        window.dispatchEvent(new CustomEvent('junows:replay_truncated', {
+          detail: { lastSequence: message.payload?.last_sequence }
+        }));
+        break;
+
       case 'doc_set':
       case 'doc_deleted':
       case 'asset_uploaded':
@@ -293,6 +354,10 @@ export class JunoWebSocket {
    * @private
    */
   notifySubscribers(notification) {
+    if (notification.sequence !== undefined) {
+      this.lastSequence = Math.max(this.lastSequence ?? 0, notification.sequence);
+    }
+
     const callbacks = this.subscriptions.get(notification.collection);
     if (callbacks) {
       callbacks.forEach((callback) => {
@@ -354,12 +419,14 @@ export class JunoWebSocket {
   /**
    * Send subscription message
    * @param {string[]} collections - Collections to subscribe to
+   * @param {number|null} [since] - Replay the changes that followed this sequence
    * @private
    */
-  sendSubscribe(collections) {
+  sendSubscribe(collections, since = null) {
     this.sendMessage({
       command: 'subscribe',
-      collections: collections
+      collections: collections,
+      ...(since !== null && { data: { since } })
     });
   }
 
@@ -3,7 +3,9 @@
 mod api;
 mod assets;
 mod auth;
+mod backup;
 mod certification;
+mod changes;
 mod controllers;
 mod db;
 mod errors;
@@ -19,16 +21,30 @@ mod types;
 mod user;
 mod ws;
 
+use crate::backup::types::interface::{
+    ExportCollectionArgs, ExportCollectionResult, ImportCollectionArgs, ImportCollectionResult,
+};
+use crate::changes::types::interface::ListChangesResults;
+use crate::changes::types::state::ChangeSequence;
 use crate::db::types::config::DbConfig;
 use crate::guards::{
     caller_is_admin_controller, caller_is_controller, caller_is_controller_with_write,
 };
+use crate::rules::types::state::CollectionMigration;
 use crate::types::interface::{
     AuthenticateResultResponse, AuthenticationArgs, Config, DeleteProposalAssets,
     GetDelegationArgs, GetDelegationResultResponse,
 };
 use crate::types::state::CollectionType;
+use crate::user::usage::types::state::UserUsageData;
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::SetWsConfig;
 use ic_cdk_macros::{init, post_upgrade, pre_upgrade, query, update};
+use ic_websocket_cdk::{
+    CanisterWsCloseArguments, CanisterWsCloseResult, CanisterWsGetMessagesArguments,
+    CanisterWsGetMessagesResult, CanisterWsMessageArguments, CanisterWsMessageResult,
+    CanisterWsOpenArguments, CanisterWsOpenResult,
+};
 use junobuild_auth::state::types::config::AuthenticationConfig;
 use junobuild_cdn::proposals::{
     CommitProposal, ListProposalResults, ListProposalsParams, Proposal, ProposalId, ProposalType,
@@ -38,7 +54,7 @@ use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{
     DelRule, ListRulesParams, ListRulesResults, SetRule,
 };
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::ic::response::ManualReply;
 use junobuild_shared::ic::UnwrapOrTrap;
 use junobuild_shared::types::core::DomainName;
@@ -49,7 +65,7 @@ use junobuild_shared::types::interface::{
 };
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::list::ListResults;
-use junobuild_shared::types::state::Controllers;
+use junobuild_shared::types::state::{Controllers, UserId};
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
@@ -60,16 +76,14 @@ use junobuild_storage::types::interface::{
 };
 use junobuild_storage::types::state::{AssetAccessToken, FullPath};
 use memory::lifecycle;
-use ic_websocket_cdk::{
-    CanisterWsCloseArguments, CanisterWsCloseResult, CanisterWsGetMessagesArguments,
-    CanisterWsGetMessagesResult, CanisterWsMessageArguments, CanisterWsMessageResult,
-    CanisterWsOpenArguments, CanisterWsOpenResult,
-};
 
 // ============================================================================================
 // These types are made available for use in Serverless Functions.
 // ============================================================================================
-use crate::db::types::interface::SetDbConfig;
+use crate::db::types::interface::{
+    DocsBatchOperation, DocsBatchOperationResult, PatchDoc, RestoreDocRevision, SetDbConfig,
+};
+use crate::db::types::state::DocRevision;
 use junobuild_auth::state::types::interface::SetAuthenticationConfig;
 pub use sdk::core::*;
 pub use sdk::internal;
@@ -106,6 +120,12 @@ pub fn set_doc(collection: CollectionKey, key: Key, doc: SetDoc) -> Doc {
     api::db::set_doc(collection, key, doc)
 }
 
+#[doc(hidden)]
+#[update]
+pub fn patch_doc(collection: CollectionKey, key: Key, doc: PatchDoc) -> Doc {
+    api::db::patch_doc(collection, key, doc)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn get_doc(collection: CollectionKey, key: Key) -> Option<Doc> {
@@ -124,6 +144,34 @@ pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<D
     api::db::list_docs(collection, filter)
 }
 
+#[doc(hidden)]
+#[query]
+pub fn list_shared_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
+    api::db::list_shared_docs(collection, filter)
+}
+
+#[doc(hidden)]
+#[update]
+pub fn set_doc_access(collection: CollectionKey, key: Key, access: SetDocAccess) -> DocAccess {
+    api::db::set_doc_access(collection, key, access)
+}
+
+#[doc(hidden)]
+#[update]
+pub fn transfer_doc_ownership(
+    collection: CollectionKey,
+    key: Key,
+    value: TransferOwnership,
+) -> Doc {
+    api::db::transfer_doc_ownership(collection, key, value)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn get_doc_access(collection: CollectionKey, key: Key) -> Option<DocAccess> {
+    api::db::get_doc_access(collection, key)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn count_docs(collection: CollectionKey, filter: ListParams) -> usize {
@@ -148,6 +196,42 @@ pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
     api::db::del_many_docs(docs)
 }
 
+#[doc(hidden)]
+#[update]
+pub fn apply_docs_batch(
+    operations: Vec<DocsBatchOperation>,
+) -> Result<Vec<DocsBatchOperationResult>, DocsBatchError> {
+    api::db::apply_docs_batch(operations)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn list_doc_revisions(
+    collection: CollectionKey,
+    key: Key,
+    filter: ListParams,
+) -> ListResults<DocRevision> {
+    api::db::list_doc_revisions(collection, key, filter)
+}
+
+#[doc(hidden)]
+#[update]
+pub fn restore_doc_revision(collection: CollectionKey, key: Key, doc: RestoreDocRevision) -> Doc {
+    api::db::restore_doc_revision(collection, key, doc)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn list_trashed_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
+    api::db::list_trashed_docs(collection, filter)
+}
+
+#[doc(hidden)]
+#[update]
+pub fn restore_doc(collection: CollectionKey, key: Key) -> Doc {
+    api::db::restore_doc(collection, key)
+}
+
 #[doc(hidden)]
 #[update]
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
@@ -216,6 +300,16 @@ pub fn switch_storage_system_memory() {
     api::rules::switch_storage_system_memory()
 }
 
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller")]
+pub fn migrate_collection_memory(
+    collection_type: CollectionType,
+    collection: CollectionKey,
+    memory: Memory,
+) -> CollectionMigration {
+    api::rules::migrate_collection_memory(&collection_type, &collection, &memory)
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
@@ -391,6 +485,22 @@ pub fn get_db_config() -> Option<DbConfig> {
     api::config::get_db_config()
 }
 
+// ---------------------------------------------------------
+// WebSocket config
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller")]
+pub fn set_ws_config(config: SetWsConfig) -> WsConfig {
+    api::config::set_ws_config(config)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_ws_config() -> Option<WsConfig> {
+    api::config::get_ws_config()
+}
+
 // ---------------------------------------------------------
 // Storage config
 // ---------------------------------------------------------
@@ -489,6 +599,16 @@ pub fn set_asset_token(collection: CollectionKey, full_path: FullPath, token: As
     api::storage::set_asset_token(collection, full_path, token);
 }
 
+#[doc(hidden)]
+#[update]
+pub fn transfer_asset_ownership(
+    collection: CollectionKey,
+    full_path: FullPath,
+    value: TransferOwnership,
+) -> AssetNoContent {
+    api::storage::transfer_asset_ownership(collection, full_path, value)
+}
+
 #[doc(hidden)]
 #[query(guard = "caller_is_controller_with_write")]
 pub fn count_collection_assets(collection: CollectionKey) -> usize {
@@ -527,6 +647,59 @@ pub fn memory_size() -> MemorySize {
     junobuild_shared::segments::utils::memory_size()
 }
 
+// ---------------------------------------------------------
+// Changes
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query]
+pub fn list_changes(
+    since: ChangeSequence,
+    collections: Option<Vec<CollectionKey>>,
+    limit: Option<usize>,
+) -> ListChangesResults {
+    api::changes::list_changes(since, collections, limit)
+}
+
+// ---------------------------------------------------------
+// Usage
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query]
+pub fn get_user_usage(
+    collection_type: CollectionType,
+    collection: CollectionKey,
+) -> Option<UserUsageData> {
+    api::usage::get_user_usage(&collection_type, &collection)
+}
+
+// ---------------------------------------------------------
+// Users
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller")]
+pub fn purge_user_data(user_id: UserId) -> UserPurge {
+    api::user::purge_user_data(&user_id)
+}
+
+// ---------------------------------------------------------
+// Backup
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller_with_write")]
+pub fn export_collection(args: ExportCollectionArgs) -> ExportCollectionResult {
+    api::backup::export_collection(args)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_with_write")]
+pub fn import_collection(args: ImportCollectionArgs) -> ImportCollectionResult {
+    api::backup::import_collection(args)
+}
+
 // ---------------------------------------------------------
 // WebSocket
 // ---------------------------------------------------------
@@ -559,20 +732,20 @@ pub fn ws_stats() -> Vec<(String, String, usize)> {
 /// Get pending WebSocket messages for the gateway
 ///
 /// This is called by the WebSocket gateway to retrieve messages
-/// that should be sent to connected clients.
+/// that should be sent to connected clients. Only the configured gateways are allowed.
 #[doc(hidden)]
 #[query]
 pub fn ws_get_messages(args: CanisterWsGetMessagesArguments) -> CanisterWsGetMessagesResult {
-    ic_websocket_cdk::ws_get_messages(args)
+    api::ws::ws_get_messages(args)
 }
 
 /// WebSocket connection open handler
 ///
-/// Called when a new WebSocket client connects.
+/// Called when a new WebSocket client connects through one of the configured gateways.
 #[doc(hidden)]
 #[update]
 pub fn ws_open(args: CanisterWsOpenArguments) -> CanisterWsOpenResult {
-    ic_websocket_cdk::ws_open(args)
+    api::ws::ws_open(args)
 }
 
 /// WebSocket message handler
@@ -612,20 +785,24 @@ pub fn ws_close(args: CanisterWsCloseArguments) -> CanisterWsCloseResult {
 macro_rules! include_satellite {
     () => {
         use junobuild_satellite::{
-            authenticate, commit_asset_upload, commit_proposal, commit_proposal_asset_upload,
-            commit_proposal_many_assets_upload, count_assets, count_collection_assets,
-            count_collection_docs, count_docs, count_proposals, del_asset, del_assets,
-            del_controllers, del_custom_domain, del_doc, del_docs, del_filtered_assets,
+            apply_docs_batch, authenticate, commit_asset_upload, commit_proposal,
+            commit_proposal_asset_upload, commit_proposal_many_assets_upload, count_assets,
+            count_collection_assets, count_collection_docs, count_docs, count_proposals, del_asset,
+            del_assets, del_controllers, del_custom_domain, del_doc, del_docs, del_filtered_assets,
             del_filtered_docs, del_many_assets, del_many_docs, del_rule, delete_proposal_assets,
-            deposit_cycles, get_asset, get_auth_config, get_config, get_db_config, get_delegation,
-            get_doc, get_many_assets, get_many_docs, get_proposal, get_storage_config,
-            http_request, http_request_streaming_callback, init, init_asset_upload, init_proposal,
-            init_proposal_asset_upload, init_proposal_many_assets_upload, list_assets,
-            list_controllers, list_custom_domains, list_docs, list_proposals, list_rules,
-            post_upgrade, pre_upgrade, reject_proposal, set_asset_token, set_auth_config,
-            set_controllers, set_custom_domain, set_db_config, set_doc, set_many_docs, set_rule,
-            set_storage_config, submit_proposal, switch_storage_system_memory, upload_asset_chunk,
-            upload_proposal_asset_chunk,
+            deposit_cycles, export_collection, get_asset, get_auth_config, get_config,
+            get_db_config, get_delegation, get_doc, get_doc_access, get_many_assets, get_many_docs,
+            get_proposal, get_storage_config, get_user_usage, get_ws_config, http_request,
+            http_request_streaming_callback, import_collection, init, init_asset_upload,
+            init_proposal, init_proposal_asset_upload, init_proposal_many_assets_upload,
+            list_assets, list_changes, list_controllers, list_custom_domains, list_doc_revisions,
+            list_docs, list_proposals, list_rules, list_shared_docs, list_trashed_docs,
+            migrate_collection_memory, patch_doc, post_upgrade, pre_upgrade, purge_user_data,
+            reject_proposal, restore_doc, restore_doc_revision, set_asset_token, set_auth_config,
+            set_controllers, set_custom_domain, set_db_config, set_doc, set_doc_access,
+            set_many_docs, set_rule, set_storage_config, set_ws_config, submit_proposal,
+            switch_storage_system_memory, transfer_asset_ownership, transfer_doc_ownership,
+            upload_asset_chunk, upload_proposal_asset_chunk,
         };
 
         ic_cdk::export_candid!();
@@ -94,6 +94,7 @@ fn set_log<T: Serialize>(level: LogLevel, message: String, data: Option<&T>) ->
         description: None,
         data: encode_doc_data(&log)?,
         version: None,
+        expires_at: None,
     };
 
     set_doc_store(id(), COLLECTION_LOG_KEY.to_string(), key, doc)?;
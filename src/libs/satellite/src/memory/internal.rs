@@ -1,7 +1,8 @@
 use crate::memory::manager::{
-    get_memory_assets, get_memory_content_chunks, get_memory_db, get_memory_polyfill,
-    get_memory_proposal_assets, get_memory_proposal_content_chunks, get_memory_proposals,
-    get_memory_upgrades,
+    get_memory_assets, get_memory_changes, get_memory_content_chunks, get_memory_db,
+    get_memory_db_access, get_memory_db_expiries, get_memory_db_indexes, get_memory_db_revisions,
+    get_memory_db_shares, get_memory_db_trash, get_memory_polyfill, get_memory_proposal_assets,
+    get_memory_proposal_content_chunks, get_memory_proposals, get_memory_upgrades,
 };
 use crate::types::state::StableState;
 use ic_stable_structures::StableBTreeMap;
@@ -19,6 +20,13 @@ pub fn init_stable_state() -> StableState {
         proposals_assets: StableBTreeMap::init(get_memory_proposal_assets()),
         proposals_content_chunks: StableBTreeMap::init(get_memory_proposal_content_chunks()),
         proposals: StableBTreeMap::init(get_memory_proposals()),
+        changes: StableBTreeMap::init(get_memory_changes()),
+        db_indexes: StableBTreeMap::init(get_memory_db_indexes()),
+        db_expiries: StableBTreeMap::init(get_memory_db_expiries()),
+        db_revisions: StableBTreeMap::init(get_memory_db_revisions()),
+        db_trash: StableBTreeMap::init(get_memory_db_trash()),
+        db_access: StableBTreeMap::init(get_memory_db_access()),
+        db_shares: StableBTreeMap::init(get_memory_db_shares()),
     }
 }
 
@@ -1,4 +1,6 @@
 use crate::assets::storage::certified_assets::upgrade::defer_init_certified_assets;
+use crate::db::expiry::init_expiry_sweep;
+use crate::db::rebuild::init_index_rebuilds;
 use crate::hooks::lifecycle::{
     invoke_on_init, invoke_on_init_sync, invoke_on_post_upgrade, invoke_on_post_upgrade_sync,
 };
@@ -7,6 +9,7 @@ use crate::memory::state::STATE;
 use crate::memory::utils::init_storage_heap_state;
 use crate::random::init::defer_init_random_seed;
 use crate::types::state::{HeapState, RuntimeState, State};
+use crate::user::usage::recount::init_usage_recounts;
 use crate::ws;
 use ciborium::{from_reader, into_writer};
 use junobuild_shared::memory::upgrade::{read_post_upgrade, write_pre_upgrade};
@@ -55,13 +58,22 @@ pub fn post_upgrade() {
     let memory: Memory = get_memory_for_upgrade();
     let state_bytes = read_post_upgrade(&memory);
 
-    let state = from_reader(&*state_bytes)
+    let mut state: State = from_reader(&*state_bytes)
         .expect("Failed to decode the state of the satellite in post_upgrade hook.");
+
+    // System collections introduced by a newer version - e.g. #user-role - are created with their default rule.
+    state.heap.db.init_system_collections();
+
     STATE.with(|s| *s.borrow_mut() = state);
 
     defer_init_certified_assets();
     defer_init_random_seed();
 
+    // Timers are not preserved across upgrades
+    init_expiry_sweep();
+    init_index_rebuilds();
+    init_usage_recounts();
+
     // Reinitialize WebSocket module after upgrade
     ws::init();
 
@@ -11,6 +11,13 @@ const POLYFILL: MemoryId = MemoryId::new(4);
 const PROPOSAL_ASSETS: MemoryId = MemoryId::new(5);
 const PROPOSAL_CONTENT_CHUNKS: MemoryId = MemoryId::new(6);
 const PROPOSALS: MemoryId = MemoryId::new(7);
+const CHANGES: MemoryId = MemoryId::new(8);
+const DB_INDEXES: MemoryId = MemoryId::new(9);
+const DB_EXPIRIES: MemoryId = MemoryId::new(10);
+const DB_REVISIONS: MemoryId = MemoryId::new(11);
+const DB_TRASH: MemoryId = MemoryId::new(12);
+const DB_ACCESS: MemoryId = MemoryId::new(13);
+const DB_SHARES: MemoryId = MemoryId::new(14);
 
 thread_local! {
     static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> =
@@ -48,3 +55,31 @@ pub fn get_memory_proposal_content_chunks() -> Memory {
 pub fn get_memory_proposals() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(PROPOSALS))
 }
+
+pub fn get_memory_changes() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(CHANGES))
+}
+
+pub fn get_memory_db_indexes() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_INDEXES))
+}
+
+pub fn get_memory_db_expiries() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_EXPIRIES))
+}
+
+pub fn get_memory_db_revisions() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_REVISIONS))
+}
+
+pub fn get_memory_db_trash() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_TRASH))
+}
+
+pub fn get_memory_db_access() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_ACCESS))
+}
+
+pub fn get_memory_db_shares() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_SHARES))
+}
@@ -0,0 +1,8 @@
+// The number of documents copied or deleted per call of a migration of a collection to another memory.
+pub const MIGRATION_DOCS_BATCH: usize = 500;
+
+// The number of assets copied or deleted per call of a migration of a collection to another memory.
+pub const MIGRATION_ASSETS_BATCH: usize = 50;
+
+// The length of the content of the assets after which a call of a migration stops copying. It keeps a call within the instructions limit.
+pub const MIGRATION_ASSETS_MAX_BYTES: usize = 20_000_000;
@@ -0,0 +1,71 @@
+use crate::rules::types::state::{CollectionMigration, CollectionMigrationStatus};
+use ic_cdk::api::time;
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::core::Key;
+
+impl CollectionMigration {
+    pub fn init(memory: &Memory) -> Self {
+        let now = time();
+
+        CollectionMigration {
+            memory: memory.clone(),
+            status: CollectionMigrationStatus::Copying,
+            last_key: None,
+            copied: 0,
+            deleted: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the entry was already copied to the target memory - i.e. whether its writes
+    /// should be mirrored.
+    pub fn is_copied(&self, key: &Key) -> bool {
+        self.status == CollectionMigrationStatus::Copying
+            && self
+                .last_key
+                .as_ref()
+                .is_some_and(|last_key| key <= last_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migration(status: CollectionMigrationStatus, last_key: Option<&str>) -> CollectionMigration {
+        CollectionMigration {
+            memory: Memory::Stable,
+            status,
+            last_key: last_key.map(|key| key.to_string()),
+            copied: 0,
+            deleted: 0,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn copied_up_to_last_key() {
+        let migration = migration(CollectionMigrationStatus::Copying, Some("m"));
+
+        assert!(migration.is_copied(&"a".to_string()));
+        assert!(migration.is_copied(&"m".to_string()));
+        assert!(!migration.is_copied(&"m0".to_string()));
+        assert!(!migration.is_copied(&"z".to_string()));
+    }
+
+    #[test]
+    fn nothing_copied_before_first_batch() {
+        let migration = migration(CollectionMigrationStatus::Copying, None);
+
+        assert!(!migration.is_copied(&"a".to_string()));
+    }
+
+    #[test]
+    fn no_mirroring_once_switched() {
+        let migration = migration(CollectionMigrationStatus::Cleaning, Some("m"));
+
+        assert!(!migration.is_copied(&"a".to_string()));
+    }
+}
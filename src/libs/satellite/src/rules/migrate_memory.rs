@@ -0,0 +1,189 @@
+use crate::assets::storage::internal::{unsafe_copy_assets, unsafe_delete_assets_from_memory};
+use crate::db::internal::{unsafe_copy_docs, unsafe_delete_docs_from_memory};
+use crate::db::store::init_collection_store;
+use crate::errors::rules::{
+    JUNO_RULES_ERROR_MIGRATION_IN_PROGRESS, JUNO_RULES_ERROR_MIGRATION_SYSTEM_COLLECTION,
+};
+use crate::rules::constants::{
+    MIGRATION_ASSETS_BATCH, MIGRATION_ASSETS_MAX_BYTES, MIGRATION_DOCS_BATCH,
+};
+use crate::rules::internal::{unsafe_set_rule, unsafe_set_rule_db};
+use crate::rules::store::{
+    delete_collection_migration, get_collection_migration, get_rule_db, get_rule_storage,
+    insert_collection_migration,
+};
+use crate::rules::types::state::{CollectionMigration, CollectionMigrationStatus};
+use crate::types::state::CollectionType;
+use ic_cdk::api::time;
+use junobuild_collections::assert::collection::is_system_collection;
+use junobuild_collections::msg::{msg_db_collection_not_found, msg_storage_collection_not_found};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Memory, Rule};
+
+/// Processes a batch of the migration of a collection to another memory and returns its progress.
+///
+/// The migration is resumed on each call - first copying the entries, then switching the rule and
+/// finally deleting the entries from the former memory - until its status is `Completed`.
+pub fn migrate_collection_memory(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    memory: &Memory,
+) -> Result<CollectionMigration, String> {
+    if is_system_collection(collection) {
+        return Err(format!(
+            "{JUNO_RULES_ERROR_MIGRATION_SYSTEM_COLLECTION} ({collection})"
+        ));
+    }
+
+    let rule = get_rule(collection_type, collection)?;
+
+    let migration = match get_collection_migration(collection_type, collection) {
+        Some(migration) => {
+            if migration.memory != *memory {
+                return Err(format!(
+                    "{JUNO_RULES_ERROR_MIGRATION_IN_PROGRESS} ({collection})"
+                ));
+            }
+
+            migration
+        }
+        None => {
+            if rule.mem() == *memory {
+                return Ok(CollectionMigration {
+                    status: CollectionMigrationStatus::Completed,
+                    ..CollectionMigration::init(memory)
+                });
+            }
+
+            // Documents are copied into an existing collection of the heap
+            if *collection_type == CollectionType::Db {
+                init_collection_store(collection, memory);
+            }
+
+            CollectionMigration::init(memory)
+        }
+    };
+
+    let migration = match migration.status {
+        CollectionMigrationStatus::Copying => {
+            copy_batch(collection_type, collection, &rule, migration)?
+        }
+        CollectionMigrationStatus::Cleaning | CollectionMigrationStatus::Completed => {
+            clean_batch(collection_type, collection, migration)
+        }
+    };
+
+    match migration.status {
+        CollectionMigrationStatus::Completed => {
+            delete_collection_migration(collection_type, collection)
+        }
+        _ => insert_collection_migration(collection_type, collection, &migration),
+    }
+
+    Ok(migration)
+}
+
+fn get_rule(collection_type: &CollectionType, collection: &CollectionKey) -> Result<Rule, String> {
+    match collection_type {
+        CollectionType::Db => {
+            get_rule_db(collection).ok_or_else(|| msg_db_collection_not_found(collection))
+        }
+        CollectionType::Storage => {
+            get_rule_storage(collection).ok_or_else(|| msg_storage_collection_not_found(collection))
+        }
+    }
+}
+
+fn copy_batch(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    rule: &Rule,
+    migration: CollectionMigration,
+) -> Result<CollectionMigration, String> {
+    // Resume right after the last copied key
+    let start = migration
+        .last_key
+        .as_ref()
+        .map_or_else(String::new, |last_key| format!("{last_key}\0"));
+
+    let (copied, last_key) = match collection_type {
+        CollectionType::Db => unsafe_copy_docs(
+            collection,
+            &start,
+            MIGRATION_DOCS_BATCH,
+            rule,
+            &migration.memory,
+        )?,
+        CollectionType::Storage => unsafe_copy_assets(
+            collection,
+            &start,
+            MIGRATION_ASSETS_BATCH,
+            MIGRATION_ASSETS_MAX_BYTES,
+            rule,
+            &migration.memory,
+        ),
+    };
+
+    if copied > 0 {
+        return Ok(CollectionMigration {
+            last_key: last_key.or(migration.last_key.clone()),
+            copied: migration.copied + copied as u64,
+            updated_at: time(),
+            ..migration
+        });
+    }
+
+    // Everything was copied, the collection now uses the target memory
+    let switched_rule = Rule::switch_rule_memory(rule);
+
+    match collection_type {
+        CollectionType::Db => unsafe_set_rule_db(collection, &switched_rule),
+        CollectionType::Storage => unsafe_set_rule(collection, &switched_rule),
+    }
+
+    Ok(clean_batch(
+        collection_type,
+        collection,
+        CollectionMigration {
+            status: CollectionMigrationStatus::Cleaning,
+            ..migration
+        },
+    ))
+}
+
+fn clean_batch(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    migration: CollectionMigration,
+) -> CollectionMigration {
+    let former_memory = match migration.memory {
+        Memory::Heap => Memory::Stable,
+        Memory::Stable => Memory::Heap,
+    };
+
+    let limit = match collection_type {
+        CollectionType::Db => MIGRATION_DOCS_BATCH,
+        CollectionType::Storage => MIGRATION_ASSETS_BATCH,
+    };
+
+    let deleted = match collection_type {
+        CollectionType::Db => unsafe_delete_docs_from_memory(collection, limit, &former_memory),
+        CollectionType::Storage => {
+            unsafe_delete_assets_from_memory(collection, limit, &former_memory)
+        }
+    };
+
+    // The heap is cleared at once, the stable memory in batches
+    let status = if former_memory == Memory::Heap || deleted < limit {
+        CollectionMigrationStatus::Completed
+    } else {
+        CollectionMigrationStatus::Cleaning
+    };
+
+    CollectionMigration {
+        status,
+        deleted: migration.deleted + deleted as u64,
+        updated_at: time(),
+        ..migration
+    }
+}
@@ -1,3 +1,7 @@
+mod constants;
+mod impls;
 mod internal;
+pub mod migrate_memory;
 pub mod store;
 pub mod switch_memory;
+pub mod types;
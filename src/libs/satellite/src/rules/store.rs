@@ -1,6 +1,12 @@
 use crate::assets::storage::store::assert_assets_collection_empty_store;
-use crate::db::store::{delete_collection_store, init_collection_store};
+use crate::db::runtime::reset_json_schema;
+use crate::db::store::{delete_collection_store, init_collection_store, rebuild_indexes_store};
+use crate::errors::rules::JUNO_RULES_ERROR_MIGRATION_IN_PROGRESS;
 use crate::memory::state::STATE;
+use crate::rules::types::state::{CollectionMigration, CollectionMigrationKey};
+use crate::types::state::CollectionType;
+use crate::user::usage::recount::start_usage_recount;
+use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::store::{del_rule, filter_rules, list_rules, set_rule};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{
@@ -37,6 +43,9 @@ pub fn list_rules_storage(filter: &ListRulesParams) -> ListRulesResults {
 }
 
 pub fn set_rule_db(collection: CollectionKey, rule: SetRule) -> Result<Rule, String> {
+    let current_rule = get_rule_db(&collection);
+    let current_indexes = current_rule.as_ref().and_then(|rule| rule.indexes.clone());
+
     let rule = STATE.with(|state| {
         set_rule(
             collection.clone(),
@@ -46,24 +55,66 @@ pub fn set_rule_db(collection: CollectionKey, rule: SetRule) -> Result<Rule, Str
         )
     })?;
 
+    // The schema is parsed again on the next write
+    reset_json_schema(&collection);
+
     // If the collection does not exist yet we initialize it
     init_collection_store(&collection, &rule.memory.clone().unwrap_or(Memory::Stable));
 
+    // Existing documents are (re)indexed when the indexed paths change
+    if current_indexes != rule.indexes {
+        rebuild_indexes_store(&collection);
+    }
+
+    recount_usage_on_quotas(&CollectionType::Db, &collection, &current_rule, &rule);
+
     Ok(rule)
 }
 
 pub fn set_rule_storage(collection: CollectionKey, rule: SetRule) -> Result<Rule, String> {
-    STATE.with(|state| {
+    let current_rule = get_rule_storage(&collection);
+
+    let rule = STATE.with(|state| {
         set_rule(
-            collection,
+            collection.clone(),
             rule,
             true,
             &mut state.borrow_mut().heap.storage.rules,
         )
-    })
+    })?;
+
+    recount_usage_on_quotas(&CollectionType::Storage, &collection, &current_rule, &rule);
+
+    Ok(rule)
+}
+
+// The items written before their usage was tracked - i.e. with a former version - are accounted
+// once a quota on the items per user is introduced on an existing collection.
+fn recount_usage_on_quotas(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    current_rule: &Option<Rule>,
+    rule: &Rule,
+) {
+    let has_quotas =
+        |rule: &Rule| rule.max_items_per_user.is_some() || rule.max_bytes_per_user.is_some();
+
+    if is_system_collection(collection) {
+        return;
+    }
+
+    if current_rule
+        .as_ref()
+        .is_some_and(|current_rule| !has_quotas(current_rule))
+        && has_quotas(rule)
+    {
+        start_usage_recount(collection_type, collection);
+    }
 }
 
 pub fn del_rule_db(collection: CollectionKey, rule: DelRule) -> Result<(), String> {
+    assert_no_collection_migration(&CollectionType::Db, &collection)?;
+
     // We delete the empty collection first.
     delete_collection_store(&collection)?;
 
@@ -75,12 +126,81 @@ pub fn del_rule_db(collection: CollectionKey, rule: DelRule) -> Result<(), Strin
         )
     })?;
 
+    reset_json_schema(&collection);
+
     Ok(())
 }
 
 pub fn del_rule_storage(collection: CollectionKey, rule: DelRule) -> Result<(), String> {
+    assert_no_collection_migration(&CollectionType::Storage, &collection)?;
+
     // Only unused rule can be removed
     assert_assets_collection_empty_store(&collection)?;
 
     STATE.with(|state| del_rule(collection, rule, &mut state.borrow_mut().heap.storage.rules))
 }
+
+// ---------------------------------------------------------
+// Migrations
+// ---------------------------------------------------------
+
+pub fn get_collection_migration(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+) -> Option<CollectionMigration> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .migrations
+            .get(&migration_key(collection_type, collection))
+            .cloned()
+    })
+}
+
+pub fn insert_collection_migration(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    migration: &CollectionMigration,
+) {
+    STATE.with(|state| {
+        state.borrow_mut().heap.migrations.insert(
+            migration_key(collection_type, collection),
+            migration.clone(),
+        )
+    });
+}
+
+pub fn delete_collection_migration(collection_type: &CollectionType, collection: &CollectionKey) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .migrations
+            .remove(&migration_key(collection_type, collection))
+    });
+}
+
+// A collection cannot be removed while its entries are being copied or deleted.
+fn assert_no_collection_migration(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+) -> Result<(), String> {
+    if get_collection_migration(collection_type, collection).is_some() {
+        return Err(format!(
+            "{JUNO_RULES_ERROR_MIGRATION_IN_PROGRESS} ({collection})"
+        ));
+    }
+
+    Ok(())
+}
+
+fn migration_key(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+) -> CollectionMigrationKey {
+    CollectionMigrationKey {
+        collection_type: collection_type.clone(),
+        collection: collection.clone(),
+    }
+}
@@ -33,7 +33,7 @@ pub fn switch_storage_memory() -> Result<(), String> {
     // For simplicity and performance reasons, we delete without previous assertion.
     // Any potential changes is rolled back if an error occurs later on in the function.
     for well_known_path in &well_known_paths {
-        unsafe_delete_asset(&dapp_collection, well_known_path, &dapp_rule);
+        unsafe_delete_asset(&dapp_collection, well_known_path, &dapp_rule)?;
     }
 
     // We assert the collection is empty otherwise switching memory might lead
@@ -0,0 +1,42 @@
+pub mod state {
+    use crate::types::state::CollectionType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::rules::Memory;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type CollectionMigrations = HashMap<CollectionMigrationKey, CollectionMigration>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+    pub struct CollectionMigrationKey {
+        pub collection_type: CollectionType,
+        pub collection: CollectionKey,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum CollectionMigrationStatus {
+        Copying,
+        Cleaning,
+        Completed,
+    }
+
+    /// The migration of a collection to another `memory`.
+    ///
+    /// The documents - or assets - are copied in batches ordered by key while the collection remains
+    /// in use. The writes on the entries that were already copied - up to `last_key` - are mirrored
+    /// to the target memory. Once everything was copied, the rule is switched to the target memory
+    /// and the entries left in the former memory are deleted.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CollectionMigration {
+        pub memory: Memory,
+        pub status: CollectionMigrationStatus,
+        pub last_key: Option<Key>,
+        pub copied: u64,
+        pub deleted: u64,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
@@ -1,10 +1,20 @@
 pub use crate::db::store::{
-    count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_doc_store, list_docs_store, set_doc_store,
+    apply_docs_batch_store, count_collection_docs_store, count_docs_store, delete_doc_store,
+    delete_docs_store, delete_filtered_docs_store, get_doc_access_store, get_doc_store,
+    list_doc_revisions_store, list_docs_store, list_shared_docs_store, list_trashed_docs_store,
+    patch_doc_store, restore_doc_revision_store, restore_doc_store, set_doc_access_store,
+    set_doc_store, transfer_doc_ownership_store,
+};
+pub use crate::db::types::interface::{
+    DelDoc, DocPatch, DocsBatchError, DocsBatchOperation, DocsBatchOperationResult, PatchDoc,
+    RestoreDocRevision, SetDoc, SetDocAccess,
 };
-pub use crate::db::types::interface::{DelDoc, SetDoc};
 pub use crate::db::types::state::Doc;
+pub use crate::db::types::state::DocAccess;
 pub use crate::db::types::state::DocAssertDelete;
 pub use crate::db::types::state::DocAssertSet;
 pub use crate::db::types::state::DocContext;
+pub use crate::db::types::state::DocGrant;
+pub use crate::db::types::state::DocRevision;
 pub use crate::db::types::state::DocUpsert;
+pub use crate::db::types::store::DocsBatchChanges;
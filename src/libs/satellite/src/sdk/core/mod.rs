@@ -4,7 +4,10 @@ mod hooks;
 mod ic;
 mod logs;
 mod random;
+mod roles;
 mod storage;
+mod users;
+mod ws;
 
 pub use controllers::*;
 pub use db::*;
@@ -12,4 +15,9 @@ pub use hooks::*;
 pub use ic::*;
 pub use logs::*;
 pub use random::*;
+pub use roles::*;
 pub use storage::*;
+pub use users::*;
+pub use ws::*;
+
+pub use crate::types::interface::TransferOwnership;
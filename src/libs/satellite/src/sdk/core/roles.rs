@@ -0,0 +1,2 @@
+pub use crate::user::role::assert::has_role;
+pub use crate::user::role::store::get_user_roles;
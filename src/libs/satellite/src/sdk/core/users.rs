@@ -0,0 +1,3 @@
+pub use crate::user::purge::store::purge_user_data_store;
+pub use crate::user::purge::types::state::{UserPurge, UserPurgeStatus};
+pub use crate::user::purge::types::store::UserPurgeBatch;
@@ -0,0 +1,2 @@
+pub use crate::ws::store::{ws_broadcast, ws_list_clients, ws_send};
+pub use crate::ws::types::interface::WsClient;
@@ -1,7 +1,17 @@
 pub mod state {
     use crate::assets::storage::types::state::{AssetsStable, ContentChunksStable};
-    use crate::db::types::state::{DbHeapState, DbRuntimeState, DbStable};
+    use crate::backup::types::state::BackupRuntimeState;
+    use crate::changes::types::state::ChangesStable;
+    use crate::db::types::state::{
+        DbAccessStable, DbExpiriesStable, DbHeapState, DbIndexesStable, DbRevisionsStable,
+        DbRuntimeState, DbSharesStable, DbStable, DbTrashStable,
+    };
     use crate::memory::internal::init_stable_state;
+    use crate::rules::types::state::CollectionMigrations;
+    use crate::user::purge::types::state::UserPurges;
+    use crate::user::usage::types::state::{UsageRecounts, UsageRuntimeState};
+    use crate::ws::types::config::WsConfig;
+    use crate::ws::types::state::WsRuntimeState;
     use candid::CandidType;
     use junobuild_auth::state::types::state::AuthenticationHeapState;
     use junobuild_cdn::proposals::ProposalsStable;
@@ -32,6 +42,13 @@ pub mod state {
         pub proposals_assets: ProposalAssetsStable,
         pub proposals_content_chunks: ProposalContentChunksStable,
         pub proposals: ProposalsStable,
+        pub changes: ChangesStable,
+        pub db_indexes: DbIndexesStable,
+        pub db_expiries: DbExpiriesStable,
+        pub db_revisions: DbRevisionsStable,
+        pub db_trash: DbTrashStable,
+        pub db_access: DbAccessStable,
+        pub db_shares: DbSharesStable,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -40,12 +57,22 @@ pub mod state {
         pub db: DbHeapState,
         pub storage: StorageHeapState,
         pub authentication: Option<AuthenticationHeapState>,
+        pub ws: Option<WsConfig>,
+        #[serde(default)]
+        pub migrations: CollectionMigrations,
+        #[serde(default)]
+        pub purges: UserPurges,
+        #[serde(default)]
+        pub usage_recounts: UsageRecounts,
     }
 
     #[derive(Default, Clone)]
     pub struct RuntimeState {
         pub rng: Option<StdRng>, // rng = Random Number Generator
         pub db: DbRuntimeState,
+        pub ws: WsRuntimeState,
+        pub backup: BackupRuntimeState,
+        pub usage: UsageRuntimeState,
     }
 
     #[derive(CandidType, Deserialize, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -57,6 +84,7 @@ pub mod state {
 
 pub mod interface {
     use crate::db::types::config::DbConfig;
+    use crate::ws::types::config::WsConfig;
     use crate::Doc;
     use candid::CandidType;
     use junobuild_auth::delegation::types::{
@@ -65,6 +93,7 @@ pub mod interface {
     };
     use junobuild_auth::state::types::config::AuthenticationConfig;
     use junobuild_cdn::proposals::ProposalId;
+    use junobuild_shared::types::state::{UserId, Version};
     use junobuild_storage::types::config::StorageConfig;
     use serde::{Deserialize, Serialize};
 
@@ -73,6 +102,7 @@ pub mod interface {
         pub storage: StorageConfig,
         pub db: Option<DbConfig>,
         pub authentication: Option<AuthenticationConfig>,
+        pub ws: Option<WsConfig>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -80,6 +110,16 @@ pub mod interface {
         pub proposal_ids: Vec<ProposalId>,
     }
 
+    /// Parameters for transferring the ownership of a document or an asset.
+    ///
+    /// The `owner` becomes the owner of the item, which keeps its creation date and history. As for
+    /// `SetDoc`, the `version` of the current item must be provided.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct TransferOwnership {
+        pub owner: UserId,
+        pub version: Option<Version>,
+    }
+
     #[derive(CandidType, Serialize, Deserialize)]
     pub enum AuthenticationArgs {
         OpenId(OpenIdPrepareDelegationArgs),
@@ -142,8 +182,9 @@ pub mod hooks {
     use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext, DocUpsert};
     use crate::Doc;
     use candid::{CandidType, Deserialize};
-    use junobuild_shared::types::state::UserId;
-    use junobuild_storage::types::store::{Asset, AssetAssertUpload};
+    use junobuild_shared::types::core::Blob;
+    use junobuild_shared::types::state::{Timestamp, UserId, Version};
+    use junobuild_storage::types::store::{Asset, AssetAssertUpload, AssetUpsert};
     use serde::{Serialize, Serializer};
 
     /// A generic context struct used in Juno satellite hooks.
@@ -197,6 +238,9 @@ pub mod hooks {
     /// A type alias for the context used in the `on_delete_filtered_assets` satellite hook.
     pub type OnDeleteFilteredAssetsContext = HookContext<Vec<Option<Asset>>>;
 
+    /// A type alias for the context used in the `on_transfer_asset_ownership` satellite hook.
+    pub type OnTransferAssetOwnershipContext = HookContext<AssetUpsert>;
+
     /// A type alias for the context used in the `assert_set_doc` satellite hook.
     pub type AssertSetDocContext = HookContext<DocContext<DocAssertSet>>;
 
@@ -220,6 +264,9 @@ pub mod hooks {
     /// - `key`: The key of the affected document/asset
     /// - `caller`: The principal that made the change
     /// - `timestamp`: When the change occurred (nanoseconds since epoch)
+    /// - `doc`: The new document - or the deleted one - for subscriptions that opted in to receive it
+    /// - `doc_omitted`: Set when the document was requested but exceeds the `max_size` of the collection
+    /// - `sequence`: The sequence of the change in the change log, used to resume after a reconnection
     #[derive(CandidType, Deserialize, Serialize, Clone)]
     pub struct NotificationMessage {
         #[serde(rename = "type")]
@@ -228,51 +275,20 @@ pub mod hooks {
         pub key: String,
         pub caller: String,
         pub timestamp: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub doc: Option<NotificationDoc>,
+        #[serde(default)]
+        pub doc_omitted: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sequence: Option<u64>,
     }
 
-    /// Create a notification message for a document set event
-    impl NotificationMessage {
-        pub fn doc_set(collection: String, key: String, caller: UserId) -> Self {
-            Self {
-                msg_type: "doc_set".to_string(),
-                collection,
-                key,
-                caller: caller.to_string(),
-                timestamp: ic_cdk::api::time(),
-            }
-        }
-
-        /// Create a notification message for a document deleted event
-        pub fn doc_deleted(collection: String, key: String, caller: UserId) -> Self {
-            Self {
-                msg_type: "doc_deleted".to_string(),
-                collection,
-                key,
-                caller: caller.to_string(),
-                timestamp: ic_cdk::api::time(),
-            }
-        }
-
-        /// Create a notification message for an asset uploaded event
-        pub fn asset_uploaded(collection: String, key: String, caller: UserId) -> Self {
-            Self {
-                msg_type: "asset_uploaded".to_string(),
-                collection,
-                key,
-                caller: caller.to_string(),
-                timestamp: ic_cdk::api::time(),
-            }
-        }
-
-        /// Create a notification message for an asset deleted event
-        pub fn asset_deleted(collection: String, key: String, caller: UserId) -> Self {
-            Self {
-                msg_type: "asset_deleted".to_string(),
-                collection,
-                key,
-                caller: caller.to_string(),
-                timestamp: ic_cdk::api::time(),
-            }
-        }
+    /// The document attached to a notification.
+    #[derive(CandidType, Deserialize, Serialize, Clone)]
+    pub struct NotificationDoc {
+        pub data: Blob,
+        pub description: Option<String>,
+        pub version: Option<Version>,
+        pub updated_at: Timestamp,
     }
 }
@@ -112,6 +112,7 @@ impl UserData {
             data,
             description: None,
             version: current_doc.as_ref().and_then(|d| d.version),
+            expires_at: None,
         };
 
         Ok(set_doc)
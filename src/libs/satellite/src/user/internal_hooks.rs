@@ -1,4 +1,5 @@
 use crate::db::types::state::DocContext;
+use crate::user::role::store::delete_user_roles;
 use crate::user::usage::internal_hooks::invoke_delete_user_usage;
 use crate::user::webauthn::store::{delete_user_webauthn_and_index, set_user_webauthn_index};
 use crate::{Doc, DocUpsert};
@@ -40,6 +41,9 @@ fn cleanup_user(doc: &DocContext<Option<Doc>>) -> Result<(), String> {
         // as they are part of the user authentication process.
         delete_user_webauthn_and_index(&user.owner)?;
 
+        // A principal signing in again after being deleted should not be granted its former roles.
+        delete_user_roles(&user.owner)?;
+
         invoke_delete_user_usage(&user.owner);
     }
 
@@ -1,4 +1,6 @@
 pub mod core;
 pub mod internal_hooks;
+pub mod purge;
+pub mod role;
 pub mod usage;
 pub mod webauthn;
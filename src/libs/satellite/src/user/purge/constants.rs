@@ -0,0 +1,11 @@
+// The number of documents scanned per call of a purge of the data of a user.
+pub const PURGE_DOCS_BATCH: usize = 500;
+
+// The number of trashed documents scanned per call of a purge of the data of a user.
+pub const PURGE_TRASHED_DOCS_BATCH: usize = 500;
+
+// The number of documents shared with the user whose access list is updated per call of a purge.
+pub const PURGE_SHARES_BATCH: usize = 100;
+
+// The number of assets scanned per call of a purge of the data of a user.
+pub const PURGE_ASSETS_BATCH: usize = 50;
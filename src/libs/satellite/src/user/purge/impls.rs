@@ -0,0 +1,31 @@
+use crate::user::purge::types::state::{UserPurge, UserPurgeStatus};
+use ic_cdk::api::time;
+
+impl UserPurge {
+    pub fn init() -> Self {
+        let now = time();
+
+        UserPurge {
+            status: UserPurgeStatus::Docs,
+            collection: None,
+            last_key: None,
+            deleted_docs: 0,
+            deleted_assets: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl UserPurgeStatus {
+    /// The step that follows once every collection of the current one was processed.
+    pub fn next(&self) -> Self {
+        match self {
+            UserPurgeStatus::Docs => UserPurgeStatus::Trash,
+            UserPurgeStatus::Trash => UserPurgeStatus::Shares,
+            UserPurgeStatus::Shares => UserPurgeStatus::Assets,
+            UserPurgeStatus::Assets => UserPurgeStatus::User,
+            UserPurgeStatus::User | UserPurgeStatus::Completed => UserPurgeStatus::Completed,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::memory::state::STATE;
+use crate::user::purge::types::state::UserPurge;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_user_purge(user_id: &UserId) -> Option<UserPurge> {
+    STATE.with(|state| state.borrow().heap.purges.get(user_id).cloned())
+}
+
+pub fn insert_user_purge(user_id: &UserId, purge: &UserPurge) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .purges
+            .insert(*user_id, purge.clone())
+    });
+}
+
+pub fn delete_user_purge(user_id: &UserId) {
+    STATE.with(|state| state.borrow_mut().heap.purges.remove(user_id));
+}
@@ -0,0 +1,294 @@
+use crate::assets::storage::internal::unsafe_get_assets_from;
+use crate::assets::storage::store::internal_delete_assets_store;
+use crate::db::internal::{
+    unsafe_delete_doc, unsafe_get_docs_from, unsafe_get_shared_keys_from,
+    unsafe_get_trashed_docs_from, unsafe_purge_trashed_doc, unsafe_revoke_doc_access,
+};
+use crate::db::types::state::{Doc, DocContext};
+use crate::errors::user::JUNO_DATASTORE_ERROR_USER_PURGE_INVALID_USER;
+use crate::rules::store::{get_non_system_rules_db, get_non_system_rules_storage, get_rule_db};
+use crate::user::purge::constants::{
+    PURGE_ASSETS_BATCH, PURGE_DOCS_BATCH, PURGE_SHARES_BATCH, PURGE_TRASHED_DOCS_BATCH,
+};
+use crate::user::purge::state::{delete_user_purge, get_user_purge, insert_user_purge};
+use crate::user::purge::types::state::{UserPurge, UserPurgeStatus};
+use crate::user::purge::types::store::UserPurgeBatch;
+use crate::user::role::store::delete_user_roles;
+use crate::user::usage::store::delete_user_usage;
+use crate::user::webauthn::store::delete_user_webauthn_and_index;
+use ic_cdk::api::time;
+use junobuild_collections::constants::db::COLLECTION_USER_KEY;
+use junobuild_collections::msg::msg_db_collection_not_found;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Rule;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use junobuild_shared::utils::principal_not_anonymous;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::Asset;
+
+/// Purge the data owned by a user.
+///
+/// This function processes a batch of the purge of the documents and assets owned by a user in every
+/// non-system collection - including the documents awaiting in the trash - and returns its progress.
+/// The user is removed from the access lists of the documents shared with it as well. The purge is
+/// resumed on each call until its status is `Completed`, at which point the user itself, with its
+/// usage, passkeys and roles, was deleted.
+///
+/// No assertions are performed and the deleted documents - trashed ones included - and assets are
+/// returned for the caller to invoke the hooks.
+///
+/// # Parameters
+/// - `user_id`: The `UserId` of the user whose data should be purged.
+///
+/// # Returns
+/// - `Ok(UserPurgeBatch)`: The progress of the purge and the documents and assets deleted by the batch.
+/// - `Err(String)`: An error message if the operation fails.
+pub fn purge_user_data_store(user_id: &UserId) -> Result<UserPurgeBatch, String> {
+    if !principal_not_anonymous(*user_id) {
+        return Err(JUNO_DATASTORE_ERROR_USER_PURGE_INVALID_USER.to_string());
+    }
+
+    let purge = get_user_purge(user_id).unwrap_or_else(UserPurge::init);
+
+    let batch = purge_batch(user_id, purge)?;
+
+    match batch.purge.status {
+        UserPurgeStatus::Completed => delete_user_purge(user_id),
+        _ => insert_user_purge(user_id, &batch.purge),
+    }
+
+    Ok(batch)
+}
+
+fn purge_batch(user_id: &UserId, purge: UserPurge) -> Result<UserPurgeBatch, String> {
+    if matches!(
+        purge.status,
+        UserPurgeStatus::User | UserPurgeStatus::Completed
+    ) {
+        return purge_user(user_id, purge);
+    }
+
+    // Resume with the collection in progress or, if it was deleted in the meantime, the next one.
+    let Some((collection, rule)) = next_collection(&purge.status, &purge.collection, true) else {
+        return purge_batch(
+            user_id,
+            UserPurge {
+                status: purge.status.next(),
+                collection: None,
+                last_key: None,
+                ..purge
+            },
+        );
+    };
+
+    let last_key = if purge.collection.as_ref() == Some(&collection) {
+        purge.last_key.clone()
+    } else {
+        None
+    };
+
+    // Resume right after the last scanned key
+    let start = last_key
+        .as_ref()
+        .map_or_else(String::new, |last_key| format!("{last_key}\0"));
+
+    let (scanned_all, scanned_last_key, docs, assets) = match purge.status {
+        UserPurgeStatus::Docs => {
+            let (scanned_all, scanned_last_key, docs) =
+                purge_docs(user_id, &collection, &start, &rule)?;
+            (scanned_all, scanned_last_key, docs, Vec::new())
+        }
+        UserPurgeStatus::Trash => {
+            let (scanned_all, scanned_last_key, docs) =
+                purge_trashed_docs(user_id, &collection, &start);
+            (scanned_all, scanned_last_key, docs, Vec::new())
+        }
+        UserPurgeStatus::Shares => {
+            let (scanned_all, scanned_last_key) = revoke_shares(user_id, &collection, &start);
+            (scanned_all, scanned_last_key, Vec::new(), Vec::new())
+        }
+        _ => {
+            let (scanned_all, scanned_last_key, assets) =
+                purge_assets(user_id, &collection, &start, &rule)?;
+            (scanned_all, scanned_last_key, Vec::new(), assets)
+        }
+    };
+
+    let purge = if scanned_all {
+        match next_collection(&purge.status, &Some(collection), false) {
+            Some((next, _)) => UserPurge {
+                collection: Some(next),
+                last_key: None,
+                ..purge
+            },
+            None => UserPurge {
+                status: purge.status.next(),
+                collection: None,
+                last_key: None,
+                ..purge
+            },
+        }
+    } else {
+        UserPurge {
+            collection: Some(collection),
+            last_key: scanned_last_key.or(last_key),
+            ..purge
+        }
+    };
+
+    Ok(UserPurgeBatch {
+        purge: UserPurge {
+            deleted_docs: purge.deleted_docs + docs.len() as u64,
+            deleted_assets: purge.deleted_assets + assets.len() as u64,
+            updated_at: time(),
+            ..purge
+        },
+        docs,
+        assets,
+    })
+}
+
+/// The first collection of the step - ordered by key - that follows `current`, or is `current`
+/// itself if `inclusive`. Without `current`, the first collection of the step.
+fn next_collection(
+    status: &UserPurgeStatus,
+    current: &Option<CollectionKey>,
+    inclusive: bool,
+) -> Option<(CollectionKey, Rule)> {
+    let mut rules = match status {
+        UserPurgeStatus::Docs | UserPurgeStatus::Trash | UserPurgeStatus::Shares => {
+            get_non_system_rules_db()
+        }
+        UserPurgeStatus::Assets => get_non_system_rules_storage(),
+        UserPurgeStatus::User | UserPurgeStatus::Completed => return None,
+    };
+
+    rules.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    rules.into_iter().find(|(collection, _)| match current {
+        None => true,
+        Some(current) if inclusive => collection >= current,
+        Some(current) => collection > current,
+    })
+}
+
+// Returns whether the end of the collection was reached, the last scanned key and the deleted documents.
+fn purge_docs(
+    user_id: &UserId,
+    collection: &CollectionKey,
+    start: &Key,
+    rule: &Rule,
+) -> Result<(bool, Option<Key>, Vec<DocContext<Option<Doc>>>), String> {
+    let docs = unsafe_get_docs_from(collection, start, PURGE_DOCS_BATCH, rule)?;
+
+    let last_key = docs.last().map(|(key, _)| key.clone());
+
+    let mut deleted_docs: Vec<DocContext<Option<Doc>>> = Vec::new();
+
+    for (key, _) in docs.iter().filter(|(_, doc)| doc.owner == *user_id) {
+        let deleted_doc = unsafe_delete_doc(collection, key, rule)?;
+
+        deleted_docs.push(DocContext {
+            collection: collection.clone(),
+            key: key.clone(),
+            data: deleted_doc,
+        });
+    }
+
+    Ok((docs.len() < PURGE_DOCS_BATCH, last_key, deleted_docs))
+}
+
+// Returns whether the end of the trash of the collection was reached, the last scanned key and the purged documents.
+fn purge_trashed_docs(
+    user_id: &UserId,
+    collection: &CollectionKey,
+    start: &Key,
+) -> (bool, Option<Key>, Vec<DocContext<Option<Doc>>>) {
+    let docs = unsafe_get_trashed_docs_from(collection, start, PURGE_TRASHED_DOCS_BATCH);
+
+    let last_key = docs.last().map(|(key, _)| key.clone());
+
+    let purged_docs: Vec<DocContext<Option<Doc>>> = docs
+        .iter()
+        .filter(|(_, doc)| doc.owner == *user_id)
+        .filter_map(|(key, _)| {
+            unsafe_purge_trashed_doc(collection, key).map(|purged_doc| DocContext {
+                collection: collection.clone(),
+                key: key.clone(),
+                data: Some(purged_doc),
+            })
+        })
+        .collect();
+
+    (docs.len() < PURGE_TRASHED_DOCS_BATCH, last_key, purged_docs)
+}
+
+// Returns whether every document of the collection shared with the user was processed and the last key.
+fn revoke_shares(user_id: &UserId, collection: &CollectionKey, start: &Key) -> (bool, Option<Key>) {
+    let keys = unsafe_get_shared_keys_from(user_id, collection, start, PURGE_SHARES_BATCH);
+
+    for key in &keys {
+        unsafe_revoke_doc_access(collection, key, *user_id);
+    }
+
+    (keys.len() < PURGE_SHARES_BATCH, keys.last().cloned())
+}
+
+// Returns whether the end of the collection was reached, the last scanned full path and the deleted assets.
+fn purge_assets(
+    user_id: &UserId,
+    collection: &CollectionKey,
+    start: &FullPath,
+    rule: &Rule,
+) -> Result<(bool, Option<FullPath>, Vec<Option<Asset>>), String> {
+    let assets = unsafe_get_assets_from(collection, start, PURGE_ASSETS_BATCH, rule);
+
+    let last_key = assets.last().map(|asset| asset.key.full_path.clone());
+
+    let full_paths: Vec<FullPath> = assets
+        .iter()
+        .filter(|asset| asset.key.owner == *user_id)
+        .map(|asset| asset.key.full_path.clone())
+        .collect();
+
+    let deleted_assets = internal_delete_assets_store(collection, &full_paths, rule)?;
+
+    Ok((assets.len() < PURGE_ASSETS_BATCH, last_key, deleted_assets))
+}
+
+// The entries of the user are deleted last, given that deleting the documents and assets updates its usage.
+fn purge_user(user_id: &UserId, purge: UserPurge) -> Result<UserPurgeBatch, String> {
+    let user_collection = COLLECTION_USER_KEY.to_string();
+
+    let rule = get_rule_db(&user_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_collection))?;
+
+    let key = user_id.to_text();
+
+    let deleted_user = unsafe_delete_doc(&user_collection, &key, &rule)?;
+
+    delete_user_webauthn_and_index(user_id)?;
+    delete_user_roles(user_id)?;
+    delete_user_usage(user_id)?;
+
+    let docs = match deleted_user {
+        None => Vec::new(),
+        Some(deleted_user) => vec![DocContext {
+            collection: user_collection,
+            key,
+            data: Some(deleted_user),
+        }],
+    };
+
+    Ok(UserPurgeBatch {
+        purge: UserPurge {
+            status: UserPurgeStatus::Completed,
+            deleted_docs: purge.deleted_docs + docs.len() as u64,
+            updated_at: time(),
+            ..purge
+        },
+        docs,
+        assets: Vec::new(),
+    })
+}
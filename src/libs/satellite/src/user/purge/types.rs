@@ -0,0 +1,55 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type UserPurges = HashMap<UserId, UserPurge>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum UserPurgeStatus {
+        Docs,
+        Trash,
+        Shares,
+        Assets,
+        User,
+        Completed,
+    }
+
+    /// The purge of the data owned by a user.
+    ///
+    /// The documents, the trashed documents, the documents shared with the user and then the assets
+    /// of the non-system collections are scanned in batches ordered by collection and key -
+    /// `collection` and `last_key` being the position of the scan. Once every collection was
+    /// processed, the entries of the user in `#user`, `#user-usage`, `#user-webauthn` and
+    /// `#user-role` are deleted.
+    ///
+    /// The revisions of the documents owned by other users keep the user as their `caller`, given
+    /// that they are the history of those documents - which can be restored - and not data owned by
+    /// the user.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserPurge {
+        pub status: UserPurgeStatus,
+        pub collection: Option<CollectionKey>,
+        pub last_key: Option<Key>,
+        pub deleted_docs: u64,
+        pub deleted_assets: u64,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
+
+pub mod store {
+    use crate::db::types::state::{Doc, DocContext};
+    use crate::user::purge::types::state::UserPurge;
+    use junobuild_storage::types::store::Asset;
+
+    /// The progress of a purge and the documents and assets deleted by the last batch.
+    pub struct UserPurgeBatch {
+        pub purge: UserPurge,
+        pub docs: Vec<DocContext<Option<Doc>>>,
+        pub assets: Vec<Option<Asset>>,
+    }
+}
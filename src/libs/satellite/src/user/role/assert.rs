@@ -0,0 +1,49 @@
+use crate::errors::user::{
+    JUNO_DATASTORE_ERROR_USER_ROLE_INVALID_DATA, JUNO_DATASTORE_ERROR_USER_ROLE_KEY_NO_PRINCIPAL,
+};
+use crate::user::role::store::get_user_roles;
+use crate::user::role::types::state::UserRoleData;
+use crate::SetDoc;
+use candid::Principal;
+use junobuild_collections::assert::roles::assert_roles;
+use junobuild_collections::constants::db::COLLECTION_USER_ROLE_KEY;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::RoleName;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use junobuild_utils::decode_doc_data;
+
+/// Whether the user was granted at least one of the roles. Roles that cannot be read are not granted.
+pub fn has_role(user_id: UserId, roles: &[RoleName]) -> bool {
+    get_user_roles(&user_id)
+        .map(|user_roles| user_roles.iter().any(|role| roles.contains(role)))
+        .unwrap_or(false)
+}
+
+pub fn assert_user_role_collection_key(
+    collection: &CollectionKey,
+    key: &Key,
+) -> Result<(), String> {
+    if collection != COLLECTION_USER_ROLE_KEY {
+        return Ok(());
+    }
+
+    Principal::from_text(key)
+        .map_err(|_| JUNO_DATASTORE_ERROR_USER_ROLE_KEY_NO_PRINCIPAL.to_string())?;
+
+    Ok(())
+}
+
+pub fn assert_user_role_collection_data(
+    collection: &CollectionKey,
+    doc: &SetDoc,
+) -> Result<(), String> {
+    if collection != COLLECTION_USER_ROLE_KEY {
+        return Ok(());
+    }
+
+    let data = decode_doc_data::<UserRoleData>(&doc.data)
+        .map_err(|err| format!("{JUNO_DATASTORE_ERROR_USER_ROLE_INVALID_DATA}: {err}"))?;
+
+    assert_roles(&data.roles)
+}
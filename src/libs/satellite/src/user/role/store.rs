@@ -0,0 +1,36 @@
+use crate::db::internal::{unsafe_delete_doc, unsafe_get_doc};
+use crate::rules::store::get_rule_db;
+use crate::user::role::types::state::UserRoleData;
+use junobuild_collections::constants::db::COLLECTION_USER_ROLE_KEY;
+use junobuild_collections::msg::msg_db_collection_not_found;
+use junobuild_collections::types::rules::RoleName;
+use junobuild_shared::types::state::UserId;
+use junobuild_utils::decode_doc_data;
+
+pub fn get_user_roles(user_id: &UserId) -> Result<Vec<RoleName>, String> {
+    let user_role_collection = COLLECTION_USER_ROLE_KEY.to_string();
+
+    let rule = get_rule_db(&user_role_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_role_collection))?;
+
+    let doc = unsafe_get_doc(&user_role_collection, &user_id.to_text(), &rule)?;
+
+    match doc {
+        None => Ok(Vec::new()),
+        Some(doc) => {
+            let data = decode_doc_data::<UserRoleData>(&doc.data)?;
+            Ok(data.roles)
+        }
+    }
+}
+
+pub fn delete_user_roles(user_id: &UserId) -> Result<(), String> {
+    let user_role_collection = COLLECTION_USER_ROLE_KEY.to_string();
+
+    let rule = get_rule_db(&user_role_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_role_collection))?;
+
+    unsafe_delete_doc(&user_role_collection, &user_id.to_text(), &rule)?;
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod state {
+    use junobuild_collections::types::rules::RoleName;
+    use serde::{Deserialize, Serialize};
+
+    // The key for the collection is the textual representation of the user principal.
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub struct UserRoleData {
+        pub roles: Vec<RoleName>,
+    }
+}
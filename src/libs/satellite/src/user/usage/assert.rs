@@ -1,16 +1,22 @@
+use crate::db::types::state::Doc;
 use crate::errors::user::{
+    JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED,
     JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED,
     JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA,
+    JUNO_DATASTORE_ERROR_USER_USAGE_ITEMS_LIMIT_REACHED,
 };
 use crate::types::state::CollectionType;
-use crate::user::usage::store::increment_usage;
-use crate::user::usage::types::state::UserUsageData;
+use crate::user::usage::impls::doc_bytes;
+use crate::user::usage::store::{get_usage, increment_usage};
+use crate::user::usage::types::state::{UserUsageData, UserUsageItem, UserUsagePending};
 use crate::SetDoc;
 use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::constants::db::COLLECTION_USER_USAGE_KEY;
 use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Rule;
 use junobuild_shared::segments::controllers::controller_can_write;
 use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_storage::types::store::{Asset, AssetEncoding, EncodingType};
 use junobuild_utils::decode_doc_data;
 // ---------------------------------------------------------
 // Increment user usage - i.e. when a user edit, create or delete
@@ -46,6 +52,48 @@ pub fn increment_and_assert_storage_usage(
     )
 }
 
+/// Increments the changes of the caller without asserting their limit - i.e. once asserted with
+/// `assert_db_pending_changes_usage`.
+pub fn increment_db_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+) -> Result<(), String> {
+    increment_and_assert_usage(caller, controllers, collection, &CollectionType::Db, None)
+}
+
+/// Asserts that the pending changes of the caller - including the one being asserted - would not
+/// exceed the limit once incremented, without incrementing them.
+pub fn assert_db_pending_changes_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    max_changes_per_user: Option<u32>,
+    pending: &UserUsagePending,
+) -> Result<(), String> {
+    let max_changes_per_user = match max_changes_per_user {
+        Some(max_changes_per_user) => max_changes_per_user,
+        None => return Ok(()),
+    };
+
+    if is_system_collection(collection) {
+        return Ok(());
+    }
+
+    if controller_can_write(caller, controllers) {
+        return Ok(());
+    }
+
+    let changes_count = get_usage(collection, &CollectionType::Db, &caller)?
+        .map_or(0, |user_usage| user_usage.changes_count);
+
+    if changes_count.saturating_add(pending.changes_count) > max_changes_per_user {
+        return Err(JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED.to_string());
+    }
+
+    Ok(())
+}
+
 fn increment_and_assert_usage(
     caller: UserId,
     controllers: &Controllers,
@@ -74,6 +122,169 @@ fn increment_and_assert_usage(
     Ok(())
 }
 
+// ---------------------------------------------------------
+// Assert the items owned by a user - i.e. before a document or an asset is written
+// ---------------------------------------------------------
+
+pub fn assert_db_items_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    rule: &Rule,
+    current_doc: &Option<Doc>,
+    value: &SetDoc,
+) -> Result<(), String> {
+    assert_db_pending_items_usage(
+        caller,
+        controllers,
+        collection,
+        rule,
+        current_doc,
+        value,
+        &UserUsagePending::default(),
+    )
+}
+
+/// Asserts the quotas given the pending items of the owner of the document - e.g. created or
+/// deleted by the previous operations of a batch.
+pub fn assert_db_pending_items_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    rule: &Rule,
+    current_doc: &Option<Doc>,
+    value: &SetDoc,
+    pending: &UserUsagePending,
+) -> Result<(), String> {
+    assert_items_usage(
+        caller,
+        controllers,
+        collection,
+        &CollectionType::Db,
+        rule,
+        &current_doc.as_ref().map(UserUsageItem::from_doc),
+        doc_bytes(&value.data, &value.description),
+        pending,
+    )
+}
+
+/// The encoding is added to - or replaces the one of the same type of - the current asset.
+#[allow(clippy::too_many_arguments)]
+pub fn assert_storage_items_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    rule: &Rule,
+    current: &Option<Asset>,
+    encoding_type: &EncodingType,
+    encoding: &AssetEncoding,
+) -> Result<(), String> {
+    let current_usage = current.as_ref().map(UserUsageItem::from_asset);
+
+    let replaced_length = current
+        .as_ref()
+        .and_then(|current| current.encodings.get(encoding_type))
+        .map_or(0, |current_encoding| current_encoding.total_length);
+
+    let total_length = current_usage
+        .as_ref()
+        .map_or(0, |usage| usage.bytes as u128)
+        .saturating_sub(replaced_length)
+        + encoding.total_length;
+
+    assert_items_usage(
+        caller,
+        controllers,
+        collection,
+        &CollectionType::Storage,
+        rule,
+        &current_usage,
+        u64::try_from(total_length).unwrap_or(u64::MAX),
+        &UserUsagePending::default(),
+    )
+}
+
+/// When its ownership is transferred, an item is accounted to its new owner as if they created it.
+pub fn assert_transfer_items_usage(
+    owner: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    collection_type: &CollectionType,
+    rule: &Rule,
+    item: &UserUsageItem,
+) -> Result<(), String> {
+    assert_items_usage(
+        owner,
+        controllers,
+        collection,
+        collection_type,
+        rule,
+        &None,
+        item.bytes,
+        &UserUsagePending::default(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn assert_items_usage(
+    caller: UserId,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    collection_type: &CollectionType,
+    rule: &Rule,
+    current: &Option<UserUsageItem>,
+    bytes: u64,
+    pending: &UserUsagePending,
+) -> Result<(), String> {
+    if rule.max_items_per_user.is_none() && rule.max_bytes_per_user.is_none() {
+        return Ok(());
+    }
+
+    // Items of the system collections are not tracked.
+    if is_system_collection(collection) {
+        return Ok(());
+    }
+
+    // The quotas only apply to users
+    if controller_can_write(caller, controllers) {
+        return Ok(());
+    }
+
+    // The items are accounted to their owner, which remains the same when they are updated.
+    let owner = current.as_ref().map_or(caller, |current| current.owner);
+
+    let user_usage = get_usage(collection, collection_type, &owner)?;
+
+    let (items_count, items_bytes) = user_usage.map_or((0, 0), |user_usage| {
+        (user_usage.items_count, user_usage.items_bytes)
+    });
+
+    let items_count = items_count.saturating_add_signed(pending.items_count);
+    let items_bytes = items_bytes.saturating_add_signed(pending.items_bytes);
+
+    if let Some(max_items_per_user) = rule.max_items_per_user {
+        if current.is_none() && items_count >= max_items_per_user {
+            return Err(JUNO_DATASTORE_ERROR_USER_USAGE_ITEMS_LIMIT_REACHED.to_string());
+        }
+    }
+
+    if let Some(max_bytes_per_user) = rule.max_bytes_per_user {
+        let current_bytes = current.as_ref().map_or(0, |current| current.bytes);
+
+        // An item can always be shrunk, even if the quota was lowered in the meantime.
+        if bytes > current_bytes
+            && items_bytes
+                .saturating_sub(current_bytes)
+                .saturating_add(bytes)
+                > max_bytes_per_user
+        {
+            return Err(JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED.to_string());
+        }
+    }
+
+    Ok(())
+}
+
 // ---------------------------------------------------------
 // Assert struct - useful when an admit set imperatively a user usage
 // ---------------------------------------------------------
@@ -0,0 +1,5 @@
+// The number of usages reset - or documents counted - per batch of the recount of the usage of a collection.
+pub const USAGE_RECOUNT_DOCS_BATCH: usize = 500;
+
+// The number of assets counted per batch of the recount of the usage of a collection.
+pub const USAGE_RECOUNT_ASSETS_BATCH: usize = 50;
@@ -1,7 +1,13 @@
+use crate::db::types::state::Doc;
 use crate::types::state::CollectionType;
-use crate::user::usage::types::state::{UserUsageData, UserUsageKey};
+use crate::user::usage::types::state::{
+    UsageRecount, UsageRecountStatus, UserUsageData, UserUsageItem, UserUsageKey, UserUsagePending,
+};
+use ic_cdk::api::time;
 use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::{Blob, Key};
 use junobuild_shared::types::state::UserId;
+use junobuild_storage::types::store::Asset;
 
 impl UserUsageData {
     pub fn increment(current_user_usage: &Option<UserUsageData>) -> Self {
@@ -14,8 +20,73 @@ impl UserUsageData {
 
         UserUsageData {
             changes_count: items_count,
+            ..Self::current(current_user_usage)
         }
     }
+
+    /// Applies the difference in number and size of the items owned by the user.
+    pub fn track(current_user_usage: &Option<UserUsageData>, items: i32, bytes: i64) -> Self {
+        let current = Self::current(current_user_usage);
+
+        UserUsageData {
+            items_count: current.items_count.saturating_add_signed(items),
+            items_bytes: current.items_bytes.saturating_add_signed(bytes),
+            ..current
+        }
+    }
+
+    fn current(current_user_usage: &Option<UserUsageData>) -> Self {
+        current_user_usage.clone().unwrap_or(UserUsageData {
+            changes_count: 0,
+            items_count: 0,
+            items_bytes: 0,
+        })
+    }
+}
+
+impl UserUsageItem {
+    pub fn from_doc(doc: &Doc) -> Self {
+        UserUsageItem {
+            owner: doc.owner,
+            bytes: doc_bytes(&doc.data, &doc.description),
+        }
+    }
+
+    pub fn from_asset(asset: &Asset) -> Self {
+        let bytes: u128 = asset
+            .encodings
+            .values()
+            .map(|encoding| encoding.total_length)
+            .sum();
+
+        UserUsageItem {
+            owner: asset.key.owner,
+            bytes: u64::try_from(bytes).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl UserUsagePending {
+    /// Accounts the replacement of an item of the user - `None` if it is created or deleted.
+    pub fn track(&mut self, before: &Option<UserUsageItem>, after: &Option<UserUsageItem>) {
+        let bytes =
+            |item: &Option<UserUsageItem>| item.as_ref().map_or(0, |item| item.bytes as i64);
+
+        self.items_count = self
+            .items_count
+            .saturating_add(i32::from(after.is_some()) - i32::from(before.is_some()));
+        self.items_bytes = self
+            .items_bytes
+            .saturating_add(bytes(after) - bytes(before));
+    }
+}
+
+/// The size of a document accounted in the usage: its data and its description.
+pub fn doc_bytes(data: &Blob, description: &Option<String>) -> u64 {
+    (data.len()
+        + description
+            .as_ref()
+            .map_or(0, |description| description.len())) as u64
 }
 
 impl UserUsageKey {
@@ -39,4 +110,77 @@ impl UserUsageKey {
             self.collection_key
         )
     }
+
+    /// The end of the keys of the usages of all the users in a collection.
+    pub fn collection_suffix(
+        collection_key: &CollectionKey,
+        collection_type: &CollectionType,
+    ) -> String {
+        format!("#{collection_type}#{collection_key}")
+    }
+}
+
+impl UsageRecount {
+    pub fn init() -> Self {
+        let now = time();
+
+        UsageRecount {
+            status: UsageRecountStatus::Resetting,
+            last_key: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the entry was already counted - i.e. whether its writes should be tracked.
+    pub fn is_counted(&self, key: &Key) -> bool {
+        self.status == UsageRecountStatus::Counting
+            && self
+                .last_key
+                .as_ref()
+                .is_some_and(|last_key| key <= last_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_from_no_usage() {
+        let usage = UserUsageData::track(&None, 1, 100);
+
+        assert_eq!(usage.changes_count, 0);
+        assert_eq!(usage.items_count, 1);
+        assert_eq!(usage.items_bytes, 100);
+    }
+
+    #[test]
+    fn track_keeps_changes_count() {
+        let current = Some(UserUsageData {
+            changes_count: 5,
+            items_count: 2,
+            items_bytes: 300,
+        });
+
+        let usage = UserUsageData::track(&current, -1, -100);
+
+        assert_eq!(usage.changes_count, 5);
+        assert_eq!(usage.items_count, 1);
+        assert_eq!(usage.items_bytes, 200);
+
+        let usage = UserUsageData::increment(&Some(usage));
+
+        assert_eq!(usage.changes_count, 6);
+        assert_eq!(usage.items_count, 1);
+        assert_eq!(usage.items_bytes, 200);
+    }
+
+    #[test]
+    fn track_does_not_underflow() {
+        let usage = UserUsageData::track(&None, -1, -100);
+
+        assert_eq!(usage.items_count, 0);
+        assert_eq!(usage.items_bytes, 0);
+    }
 }
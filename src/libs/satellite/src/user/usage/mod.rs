@@ -1,5 +1,10 @@
 pub mod assert;
+mod constants;
 mod impls;
 pub mod internal_hooks;
-mod store;
-mod types;
+pub mod recount;
+mod runtime;
+mod state;
+pub mod store;
+pub mod track;
+pub mod types;
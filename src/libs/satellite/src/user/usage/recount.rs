@@ -0,0 +1,188 @@
+use crate::assets::storage::internal::unsafe_get_assets_from;
+use crate::db::internal::unsafe_get_docs_from;
+use crate::rules::store::{get_rule_db, get_rule_storage};
+use crate::types::state::CollectionType;
+use crate::user::usage::constants::{USAGE_RECOUNT_ASSETS_BATCH, USAGE_RECOUNT_DOCS_BATCH};
+use crate::user::usage::runtime::{reset_usage_recount, schedule_usage_recount};
+use crate::user::usage::state::{
+    delete_usage_recount, get_usage_recount, get_usage_recounts, insert_usage_recount,
+};
+use crate::user::usage::store::{reset_items_usage, track_usage};
+use crate::user::usage::types::state::{
+    UsageRecount, UsageRecountKey, UsageRecountStatus, UserUsageItem,
+};
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Rule;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use std::collections::HashMap;
+
+/// Starts - or restarts from scratch - the recount of the items owned by the users in a
+/// collection, e.g. after its rule introduced a quota on the items per user.
+pub fn start_usage_recount(collection_type: &CollectionType, collection: &CollectionKey) {
+    insert_usage_recount(collection_type, collection, &UsageRecount::init());
+
+    schedule_usage_recount();
+}
+
+/// Schedules the recounts that are not over - e.g. after an upgrade, given that timers are not
+/// preserved.
+pub fn init_usage_recounts() {
+    if !get_usage_recounts().is_empty() {
+        schedule_usage_recount();
+    }
+}
+
+/// Whether the writes on an entry should be tracked in the usage, i.e. unless a recount of its
+/// collection is going to count it.
+pub fn is_usage_tracked(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    key: &Key,
+) -> bool {
+    get_usage_recount(collection_type, collection).is_none_or(|recount| recount.is_counted(key))
+}
+
+/// Processes a batch of the recount of the usage of each collection and schedules the next one
+/// until every recount is over.
+pub fn recount_usage_batch() {
+    reset_usage_recount();
+
+    for UsageRecountKey {
+        collection_type,
+        collection,
+    } in get_usage_recounts()
+    {
+        let recount = get_usage_recount(&collection_type, &collection);
+
+        let rule = match collection_type {
+            CollectionType::Db => get_rule_db(&collection),
+            CollectionType::Storage => get_rule_storage(&collection),
+        };
+
+        // The recount is dropped as well if the collection was removed in the meantime
+        let next = match (recount, rule) {
+            (Some(recount), Some(rule)) => {
+                match recount_batch(&collection_type, &collection, &rule, recount) {
+                    Ok(next) => next,
+                    Err(err) => {
+                        // The items of the batch may have been partially counted, therefore the
+                        // recount restarts from scratch
+                        ic_cdk::println!(
+                            "❌ Failed to recount the usage of {}: {}",
+                            collection,
+                            err
+                        );
+                        Some(UsageRecount::init())
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        match next {
+            Some(recount) => insert_usage_recount(&collection_type, &collection, &recount),
+            None => delete_usage_recount(&collection_type, &collection),
+        }
+    }
+
+    init_usage_recounts();
+}
+
+// Returns the progress of the recount, None once it is over or an error if the recount should
+// restart.
+fn recount_batch(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    rule: &Rule,
+    recount: UsageRecount,
+) -> Result<Option<UsageRecount>, String> {
+    // Resume right after the last reset usage - or counted entry
+    let start = recount
+        .last_key
+        .as_ref()
+        .map_or_else(String::new, |last_key| format!("{last_key}\0"));
+
+    match recount.status {
+        UsageRecountStatus::Resetting => {
+            let last_key = reset_items_usage(
+                collection,
+                collection_type,
+                &start,
+                USAGE_RECOUNT_DOCS_BATCH,
+            )?;
+
+            // Every usage was reset, the entries are counted from the next batch on
+            let status = if last_key.is_none() {
+                UsageRecountStatus::Counting
+            } else {
+                UsageRecountStatus::Resetting
+            };
+
+            Ok(Some(UsageRecount {
+                status,
+                last_key,
+                updated_at: time(),
+                ..recount
+            }))
+        }
+        UsageRecountStatus::Counting => {
+            let (items, limit): (Vec<(Key, UserUsageItem)>, usize) = match collection_type {
+                CollectionType::Db => (
+                    unsafe_get_docs_from(collection, &start, USAGE_RECOUNT_DOCS_BATCH, rule)?
+                        .iter()
+                        .map(|(key, doc)| (key.clone(), UserUsageItem::from_doc(doc)))
+                        .collect(),
+                    USAGE_RECOUNT_DOCS_BATCH,
+                ),
+                CollectionType::Storage => (
+                    unsafe_get_assets_from(collection, &start, USAGE_RECOUNT_ASSETS_BATCH, rule)
+                        .iter()
+                        .map(|asset| {
+                            (
+                                asset.key.full_path.clone(),
+                                UserUsageItem::from_asset(asset),
+                            )
+                        })
+                        .collect(),
+                    USAGE_RECOUNT_ASSETS_BATCH,
+                ),
+            };
+
+            count_items(collection_type, collection, &items)?;
+
+            if items.len() < limit {
+                return Ok(None);
+            }
+
+            Ok(Some(UsageRecount {
+                last_key: items.last().map(|(key, _)| key.clone()),
+                updated_at: time(),
+                ..recount
+            }))
+        }
+    }
+}
+
+// Adds the items to the usage of their owners, once per owner.
+fn count_items(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    items: &[(Key, UserUsageItem)],
+) -> Result<(), String> {
+    let mut owners: HashMap<UserId, (i32, i64)> = HashMap::new();
+
+    for (_, item) in items {
+        let (count, bytes) = owners.entry(item.owner).or_default();
+
+        *count = count.saturating_add(1);
+        *bytes = bytes.saturating_add(i64::try_from(item.bytes).unwrap_or(i64::MAX));
+    }
+
+    for (owner, (count, bytes)) in owners {
+        track_usage(collection, collection_type, &owner, count, bytes)?;
+    }
+
+    Ok(())
+}
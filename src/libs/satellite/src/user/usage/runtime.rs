@@ -0,0 +1,24 @@
+use crate::memory::state::STATE;
+use crate::user::usage::recount::recount_usage_batch;
+use ic_cdk_timers::set_timer;
+use std::time::Duration;
+
+/// Schedules the next batch of the recounts of the usage, unless it is already scheduled.
+pub fn schedule_usage_recount() {
+    let scheduled = STATE.with(|state| state.borrow().runtime.usage.recount_timer.is_some());
+
+    if scheduled {
+        return;
+    }
+
+    let timer_id = set_timer(Duration::ZERO, async {
+        recount_usage_batch();
+    });
+
+    STATE.with(|state| state.borrow_mut().runtime.usage.recount_timer = Some(timer_id));
+}
+
+/// Forgets the scheduled batch once it has been executed.
+pub fn reset_usage_recount() {
+    STATE.with(|state| state.borrow_mut().runtime.usage.recount_timer = None);
+}
@@ -0,0 +1,57 @@
+use crate::memory::state::STATE;
+use crate::types::state::CollectionType;
+use crate::user::usage::types::state::{UsageRecount, UsageRecountKey};
+use junobuild_collections::types::core::CollectionKey;
+
+// ---------------------------------------------------------
+// Recounts
+// ---------------------------------------------------------
+
+pub fn get_usage_recount(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+) -> Option<UsageRecount> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .usage_recounts
+            .get(&recount_key(collection_type, collection))
+            .cloned()
+    })
+}
+
+pub fn get_usage_recounts() -> Vec<UsageRecountKey> {
+    STATE.with(|state| state.borrow().heap.usage_recounts.keys().cloned().collect())
+}
+
+pub fn insert_usage_recount(
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    recount: &UsageRecount,
+) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .usage_recounts
+            .insert(recount_key(collection_type, collection), recount.clone())
+    });
+}
+
+pub fn delete_usage_recount(collection_type: &CollectionType, collection: &CollectionKey) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .usage_recounts
+            .remove(&recount_key(collection_type, collection))
+    });
+}
+
+fn recount_key(collection_type: &CollectionType, collection: &CollectionKey) -> UsageRecountKey {
+    UsageRecountKey {
+        collection_type: collection_type.clone(),
+        collection: collection.clone(),
+    }
+}
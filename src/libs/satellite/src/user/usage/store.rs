@@ -1,4 +1,7 @@
-use crate::db::internal::{unsafe_delete_doc, unsafe_get_doc, unsafe_set_doc};
+use crate::db::internal::{
+    unsafe_delete_doc, unsafe_get_doc, unsafe_get_docs_from, unsafe_set_doc,
+};
+use crate::db::types::state::Doc;
 use crate::rules::store::{get_non_system_rules_db, get_non_system_rules_storage, get_rule_db};
 use crate::types::state::CollectionType;
 use crate::user::usage::types::state::{UserUsageData, UserUsageKey};
@@ -8,6 +11,7 @@ use junobuild_collections::msg::msg_db_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Rule;
 use junobuild_shared::ic::api::id;
+use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::UserId;
 use junobuild_utils::{decode_doc_data, encode_doc_data};
 
@@ -15,6 +19,51 @@ pub fn increment_usage(
     collection_key: &CollectionKey,
     collection_type: &CollectionType,
     user_id: &UserId,
+) -> Result<UserUsageData, String> {
+    update_usage(
+        collection_key,
+        collection_type,
+        user_id,
+        UserUsageData::increment,
+    )
+}
+
+pub fn track_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+    items: i32,
+    bytes: i64,
+) -> Result<UserUsageData, String> {
+    update_usage(collection_key, collection_type, user_id, |current_usage| {
+        UserUsageData::track(current_usage, items, bytes)
+    })
+}
+
+pub fn get_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+) -> Result<Option<UserUsageData>, String> {
+    let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
+
+    let user_usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
+
+    let rule = get_rule_db(&user_usage_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_usage_collection))?;
+
+    let doc = unsafe_get_doc(&user_usage_collection, &user_usage_key, &rule)?;
+
+    doc.as_ref()
+        .map(|doc| decode_doc_data(&doc.data))
+        .transpose()
+}
+
+fn update_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+    update: impl FnOnce(&Option<UserUsageData>) -> UserUsageData,
 ) -> Result<UserUsageData, String> {
     let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
 
@@ -30,23 +79,91 @@ pub fn increment_usage(
         .map(|doc| decode_doc_data(&doc.data))
         .transpose()?;
 
-    let update_usage = UserUsageData::increment(&current_usage);
+    let update_usage = update(&current_usage);
 
+    set_usage(
+        &user_usage_collection,
+        &user_usage_key,
+        &doc,
+        &update_usage,
+        &rule,
+    )?;
+
+    Ok(update_usage)
+}
+
+/// Resets the number and size of the items owned by the users in a collection - keeping their
+/// changes - for a batch of the usages ordered by key, starting at - and including - `start`.
+///
+/// Returns the key of the last usage that was read if the batch was full, i.e. if the reset should
+/// continue after it.
+pub fn reset_items_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    start: &Key,
+    limit: usize,
+) -> Result<Option<Key>, String> {
+    let user_usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
+
+    let rule = get_rule_db(&user_usage_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_usage_collection))?;
+
+    let docs = unsafe_get_docs_from(&user_usage_collection, start, limit, &rule)?;
+
+    let suffix = UserUsageKey::collection_suffix(collection_key, collection_type);
+
+    for (user_usage_key, doc) in &docs {
+        if !user_usage_key.ends_with(&suffix) {
+            continue;
+        }
+
+        let current_usage: UserUsageData = decode_doc_data(&doc.data)?;
+
+        let reset_usage = UserUsageData {
+            items_count: 0,
+            items_bytes: 0,
+            ..current_usage
+        };
+
+        set_usage(
+            &user_usage_collection,
+            user_usage_key,
+            &Some(doc.clone()),
+            &reset_usage,
+            &rule,
+        )?;
+    }
+
+    if docs.len() < limit {
+        return Ok(None);
+    }
+
+    Ok(docs.last().map(|(key, _)| key.clone()))
+}
+
+fn set_usage(
+    user_usage_collection: &CollectionKey,
+    user_usage_key: &Key,
+    current_doc: &Option<Doc>,
+    usage: &UserUsageData,
+    rule: &Rule,
+) -> Result<(), String> {
     let update_doc = SetDoc {
-        data: encode_doc_data(&update_usage)?,
-        description: doc.as_ref().and_then(|d| d.description.clone()),
-        version: doc.as_ref().and_then(|d| d.version),
+        data: encode_doc_data(usage)?,
+        description: current_doc.as_ref().and_then(|d| d.description.clone()),
+        version: current_doc.as_ref().and_then(|d| d.version),
+        expires_at: None,
     };
 
     unsafe_set_doc(
         id(),
-        &user_usage_collection.to_string(),
-        &user_usage_key,
+        user_usage_collection,
+        user_usage_key,
         update_doc,
-        &rule,
+        rule,
     )?;
 
-    Ok(update_usage)
+    Ok(())
 }
 
 pub fn delete_user_usage(user_id: &UserId) -> Result<(), String> {
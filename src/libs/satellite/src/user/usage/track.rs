@@ -0,0 +1,96 @@
+use crate::types::state::CollectionType;
+use crate::user::usage::recount::is_usage_tracked;
+use crate::user::usage::store::track_usage;
+use crate::user::usage::types::state::UserUsageItem;
+use junobuild_collections::assert::collection::is_system_collection;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_storage::types::state::FullPath;
+
+// ---------------------------------------------------------
+// Track the items owned by the users - i.e. when a document or an asset is written or removed
+// ---------------------------------------------------------
+
+/// Accounts the replacement of a document - `None` if it is created or deleted - in the usage of
+/// its owner(s).
+pub fn track_db_usage(
+    collection: &CollectionKey,
+    key: &Key,
+    before: &Option<UserUsageItem>,
+    after: &Option<UserUsageItem>,
+) -> Result<(), String> {
+    track_usage_impl(collection, &CollectionType::Db, key, before, after)
+}
+
+/// Accounts the replacement of an asset - `None` if it is created or deleted - in the usage of
+/// its owner(s).
+pub fn track_storage_usage(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    before: &Option<UserUsageItem>,
+    after: &Option<UserUsageItem>,
+) -> Result<(), String> {
+    track_usage_impl(
+        collection,
+        &CollectionType::Storage,
+        full_path,
+        before,
+        after,
+    )
+}
+
+fn track_usage_impl(
+    collection: &CollectionKey,
+    collection_type: &CollectionType,
+    key: &Key,
+    before: &Option<UserUsageItem>,
+    after: &Option<UserUsageItem>,
+) -> Result<(), String> {
+    // Same as for the changes, we do not collect usage on system collections.
+    // Unlike the changes, the items of the controllers are tracked as well, so that the usage remains accurate if the controllers are modified.
+    if is_system_collection(collection) {
+        return Ok(());
+    }
+
+    // The entries that a recount of the collection did not count yet are accounted by the recount
+    if !is_usage_tracked(collection_type, collection, key) {
+        return Ok(());
+    }
+
+    match (before, after) {
+        (Some(before), Some(after)) if before.owner == after.owner => {
+            if before.bytes != after.bytes {
+                track_usage(
+                    collection,
+                    collection_type,
+                    &after.owner,
+                    0,
+                    after.bytes as i64 - before.bytes as i64,
+                )?;
+            }
+        }
+        _ => {
+            if let Some(before) = before {
+                track_usage(
+                    collection,
+                    collection_type,
+                    &before.owner,
+                    -1,
+                    -(before.bytes as i64),
+                )?;
+            }
+
+            if let Some(after) = after {
+                track_usage(
+                    collection,
+                    collection_type,
+                    &after.owner,
+                    1,
+                    after.bytes as i64,
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,8 +1,12 @@
 pub mod state {
     use crate::types::state::CollectionType;
+    use candid::CandidType;
+    use ic_cdk_timers::TimerId;
     use junobuild_collections::types::core::CollectionKey;
-    use junobuild_shared::types::state::UserId;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::state::{Timestamp, UserId};
     use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
     /// A unique key for identifying user usage within a collection.
     /// The key will be parsed to `user-id#db|storage#collection`.
@@ -13,10 +17,64 @@ pub mod state {
         pub collection_type: CollectionType,
     }
 
-    /// Tracks the usage (create, set and delete) of a user in a collection.
-    #[derive(Serialize, Deserialize)]
+    /// Tracks the usage of a user in a collection: the changes (create, set and delete) made by the
+    /// user, and the number and size of the documents - or assets - owned by the user.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     #[serde(deny_unknown_fields)]
     pub struct UserUsageData {
         pub changes_count: u32,
+        #[serde(default)]
+        pub items_count: u32,
+        #[serde(default)]
+        pub items_bytes: u64,
+    }
+
+    /// The owner of a document - or an asset - and its size, as accounted in the usage.
+    pub struct UserUsageItem {
+        pub owner: UserId,
+        pub bytes: u64,
+    }
+
+    /// The changes and the items of a user that are asserted but not yet applied - e.g. by the
+    /// previous operations of a batch.
+    #[derive(Default, Clone)]
+    pub struct UserUsagePending {
+        pub changes_count: u32,
+        pub items_count: i32,
+        pub items_bytes: i64,
+    }
+
+    pub type UsageRecounts = HashMap<UsageRecountKey, UsageRecount>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+    pub struct UsageRecountKey {
+        pub collection_type: CollectionType,
+        pub collection: CollectionKey,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum UsageRecountStatus {
+        Resetting,
+        Counting,
+    }
+
+    /// The recount of the items owned by the users in a collection, e.g. after its rule introduced
+    /// a quota on the items per user.
+    ///
+    /// The items of the users in the collection are reset in batches, then the documents - or
+    /// assets - are counted in batches ordered by key - up to `last_key` - while the collection
+    /// remains in use. In the meantime, only the writes on the entries that were already counted
+    /// are tracked, and the quotas are asserted against a partial usage.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UsageRecount {
+        pub status: UsageRecountStatus,
+        pub last_key: Option<Key>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct UsageRuntimeState {
+        pub recount_timer: Option<TimerId>,
     }
 }
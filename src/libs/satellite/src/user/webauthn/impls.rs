@@ -10,6 +10,7 @@ impl UserWebAuthnIndex {
             data: vec![],
             description: Some(credential_id.clone()),
             version: current_doc.as_ref().and_then(|d| d.version),
+            expires_at: None,
         }
     }
 }
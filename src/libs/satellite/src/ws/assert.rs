@@ -0,0 +1,478 @@
+use crate::changes::assert::assert_read_entity;
+use crate::errors::ws::{
+    JUNO_WS_ERROR_CANNOT_SUBSCRIBE, JUNO_WS_ERROR_CHANNEL_IS_COLLECTION,
+    JUNO_WS_ERROR_COLLECTION_NOT_FOUND, JUNO_WS_ERROR_GATEWAY_NOT_ALLOWED,
+    JUNO_WS_ERROR_INVALID_ACK_INTERVAL, JUNO_WS_ERROR_INVALID_MAX_RETURNED_MESSAGES,
+    JUNO_WS_ERROR_INVALID_RATE_CONFIG, JUNO_WS_ERROR_USER_REQUIRED,
+};
+use crate::rules::store::{get_rule_db, get_rule_storage};
+use crate::types::state::CollectionType;
+use crate::user::role::assert::has_role;
+use crate::ws::constants::CLIENT_KEEP_ALIVE_TIMEOUT_MS;
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::SetWsConfig;
+use crate::ws::types::state::WsChannel;
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Permission, Rule};
+use junobuild_shared::assert::assert_version;
+use junobuild_shared::rate::types::RateConfig;
+use junobuild_shared::segments::controllers::controller_can_write;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::utils::principal_not_anonymous;
+
+/// Asserts that a client may subscribe to a collection.
+///
+/// A subscription is refused if the collection exists neither in the Datastore nor in the Storage,
+/// or if none of the matching rules would ever grant the caller read access — e.g. an anonymous
+/// caller subscribing to a `Private` collection or a non-controller subscribing to a `Controllers` collection.
+pub fn assert_subscribe(
+    caller: Principal,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+) -> Result<(), String> {
+    let rules: Vec<Rule> = [get_rule_db(collection), get_rule_storage(collection)]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if rules.is_empty() {
+        return Err(format!(
+            "{JUNO_WS_ERROR_COLLECTION_NOT_FOUND} ({collection})"
+        ));
+    }
+
+    let can_subscribe = rules
+        .iter()
+        .any(|rule| assert_subscribe_permission(&rule.read, caller, controllers));
+
+    if !can_subscribe {
+        return Err(format!("{JUNO_WS_ERROR_CANNOT_SUBSCRIBE} ({collection})"));
+    }
+
+    Ok(())
+}
+
+/// Asserts that a subscriber can read the entity that triggered a notification.
+///
+/// Applies the same read permission as `get_doc_store` respectively `get_asset_store` — owner-only
+/// for `Private`, owner and controllers for `Managed`, controllers only for `Controllers`, and the
+/// listed principals respectively the users granted one of the roles for `Principals` and `Roles`.
+/// The readers and writers of the access list of a document are notified as well.
+pub fn assert_broadcast(
+    subscriber: Principal,
+    controllers: &Controllers,
+    collection_type: &CollectionType,
+    collection: &CollectionKey,
+    key: &Key,
+    owner: UserId,
+) -> bool {
+    assert_read_entity(
+        subscriber,
+        controllers,
+        collection_type,
+        collection,
+        key,
+        owner,
+    )
+}
+
+/// Asserts that a client can receive an app-level message broadcast to a channel by the
+/// serverless functions.
+///
+/// A channel that is not a collection is open to any client that joined it. A message broadcast
+/// to a collection is not related to a document or asset, therefore it has no owner and is only
+/// delivered to the subscribers granted read access to the whole collection - anyone for `Public`,
+/// the controllers for `Managed` and `Controllers`, and the listed principals respectively the
+/// users granted one of the roles for `Principals` and `Roles`. The subscribers of a `Private`
+/// collection never receive it.
+pub fn assert_broadcast_channel(
+    client: Principal,
+    controllers: &Controllers,
+    channel: &WsChannel,
+) -> bool {
+    let rules: Vec<Rule> = [get_rule_db(channel), get_rule_storage(channel)]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if rules.is_empty() {
+        return true;
+    }
+
+    rules
+        .iter()
+        .any(|rule| assert_collection_permission(&rule.read, client, controllers))
+}
+
+// Whether the caller is granted read access to all the documents or assets, regardless of their owner.
+fn assert_collection_permission(
+    permission: &Permission,
+    caller: Principal,
+    controllers: &Controllers,
+) -> bool {
+    match permission {
+        Permission::Public => true,
+        Permission::Private => false,
+        Permission::Managed | Permission::Controllers => controller_can_write(caller, controllers),
+        Permission::Principals(principals) => principals.contains(&caller),
+        Permission::Roles(roles) => principal_not_anonymous(caller) && has_role(caller, roles),
+    }
+}
+
+// Whether the caller can, at least for some documents or assets, be granted read access.
+fn assert_subscribe_permission(
+    permission: &Permission,
+    caller: Principal,
+    controllers: &Controllers,
+) -> bool {
+    match permission {
+        Permission::Public => true,
+        Permission::Private => principal_not_anonymous(caller),
+        Permission::Managed => {
+            principal_not_anonymous(caller) || controller_can_write(caller, controllers)
+        }
+        Permission::Controllers => controller_can_write(caller, controllers),
+        Permission::Principals(principals) => principals.contains(&caller),
+        Permission::Roles(roles) => principal_not_anonymous(caller) && has_role(caller, roles),
+    }
+}
+
+/// Asserts that a client may join a channel.
+///
+/// A channel cannot be named after a collection, otherwise joining it would deliver the messages
+/// broadcast to the subscribers of that collection without asserting its read permission.
+pub fn assert_join(channel: &WsChannel) -> Result<(), String> {
+    if get_rule_db(channel).is_some() || get_rule_storage(channel).is_some() {
+        return Err(format!("{JUNO_WS_ERROR_CHANNEL_IS_COLLECTION} ({channel})"));
+    }
+
+    Ok(())
+}
+
+/// Asserts that a gateway is allowed to relay clients. Any gateway is allowed if none is configured.
+pub fn assert_gateway(gateway: &Principal, config: &Option<WsConfig>) -> Result<(), String> {
+    let Some(config) = config else {
+        return Ok(());
+    };
+
+    if config.gateway_principals.is_empty() || config.gateway_principals.contains(gateway) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{JUNO_WS_ERROR_GATEWAY_NOT_ALLOWED} ({})",
+        gateway.to_text()
+    ))
+}
+
+/// Asserts that a client may open a connection.
+///
+/// When the configuration requires a user, callers without a `#user` document - including
+/// anonymous callers - are rejected. Banned users are asserted beforehand with
+/// `assert_user_is_not_banned`, like any other call.
+pub fn assert_open(
+    config: &Option<WsConfig>,
+    is_known_user: impl FnOnce() -> bool,
+) -> Result<(), String> {
+    let require_user = config
+        .as_ref()
+        .and_then(|config| config.require_user)
+        .unwrap_or(false);
+
+    if require_user && !is_known_user() {
+        return Err(JUNO_WS_ERROR_USER_REQUIRED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_set_config(
+    proposed_config: &SetWsConfig,
+    current_config: &Option<WsConfig>,
+) -> Result<(), String> {
+    if let Some(cfg) = current_config {
+        assert_version(proposed_config.version, cfg.version)?;
+    }
+
+    if proposed_config.max_number_of_returned_messages == Some(0) {
+        return Err(JUNO_WS_ERROR_INVALID_MAX_RETURNED_MESSAGES.to_string());
+    }
+
+    if let Some(send_ack_interval_ms) = proposed_config.send_ack_interval_ms {
+        if send_ack_interval_ms <= CLIENT_KEEP_ALIVE_TIMEOUT_MS {
+            return Err(format!(
+                "{JUNO_WS_ERROR_INVALID_ACK_INTERVAL} ({send_ack_interval_ms} <= {CLIENT_KEEP_ALIVE_TIMEOUT_MS})"
+            ));
+        }
+    }
+
+    assert_rate_config(&proposed_config.message_rate_config)?;
+    assert_rate_config(&proposed_config.subscription_rate_config)?;
+
+    Ok(())
+}
+
+fn assert_rate_config(config: &Option<RateConfig>) -> Result<(), String> {
+    if let Some(config) = config {
+        if config.time_per_token_ns == 0 {
+            return Err(JUNO_WS_ERROR_INVALID_RATE_CONFIG.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::state::STATE;
+
+    fn insert_rule(collection: &str, read: Permission) {
+        let rule = Rule {
+            read,
+            write: Permission::Managed,
+            mutable_permissions: Some(true),
+            memory: None,
+            max_size: None,
+            max_capacity: None,
+            max_changes_per_user: None,
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+            rate_config: None,
+            indexes: None,
+            default_ttl_ns: None,
+            max_revisions: None,
+            trash_retention_ns: None,
+            max_items_per_user: None,
+            max_bytes_per_user: None,
+            json_schema: None,
+        };
+
+        STATE.with(|state| {
+            state
+                .borrow_mut()
+                .heap
+                .db
+                .rules
+                .insert(collection.to_string(), rule)
+        });
+    }
+
+    fn user(id: u8) -> Principal {
+        Principal::from_slice(&[id; 29])
+    }
+
+    #[test]
+    fn rejects_subscription_to_unknown_collection() {
+        let result = assert_subscribe(user(1), &Controllers::new(), &"unknown".to_string());
+
+        assert!(result
+            .unwrap_err()
+            .starts_with(JUNO_WS_ERROR_COLLECTION_NOT_FOUND));
+    }
+
+    #[test]
+    fn anonymous_can_only_subscribe_to_public() {
+        insert_rule("public", Permission::Public);
+        insert_rule("private", Permission::Private);
+        insert_rule("managed", Permission::Managed);
+        insert_rule("controllers", Permission::Controllers);
+        insert_rule("roles", Permission::Roles(vec!["admin".to_string()]));
+
+        let anonymous = Principal::anonymous();
+        let controllers = Controllers::new();
+
+        assert!(assert_subscribe(anonymous, &controllers, &"public".to_string()).is_ok());
+
+        for collection in ["private", "managed", "controllers", "roles"] {
+            let result = assert_subscribe(anonymous, &controllers, &collection.to_string());
+
+            assert!(result
+                .unwrap_err()
+                .starts_with(JUNO_WS_ERROR_CANNOT_SUBSCRIBE));
+        }
+    }
+
+    #[test]
+    fn user_can_subscribe_to_owned_documents() {
+        insert_rule("private", Permission::Private);
+        insert_rule("managed", Permission::Managed);
+
+        let controllers = Controllers::new();
+
+        assert!(assert_subscribe(user(1), &controllers, &"private".to_string()).is_ok());
+        assert!(assert_subscribe(user(1), &controllers, &"managed".to_string()).is_ok());
+    }
+
+    #[test]
+    fn listed_principals_only_can_subscribe() {
+        insert_rule("principals", Permission::Principals(vec![user(1)]));
+
+        let controllers = Controllers::new();
+
+        assert!(assert_subscribe(user(1), &controllers, &"principals".to_string()).is_ok());
+        assert!(assert_subscribe(user(2), &controllers, &"principals".to_string()).is_err());
+    }
+
+    #[test]
+    fn broadcasts_private_changes_to_owner_only() {
+        insert_rule("private", Permission::Private);
+
+        let controllers = Controllers::new();
+        let collection = "private".to_string();
+
+        assert!(assert_broadcast(
+            user(1),
+            &controllers,
+            &CollectionType::Db,
+            &collection,
+            &"key".to_string(),
+            user(1)
+        ));
+        assert!(!assert_broadcast(
+            user(2),
+            &controllers,
+            &CollectionType::Db,
+            &collection,
+            &"key".to_string(),
+            user(1)
+        ));
+        assert!(!assert_broadcast(
+            Principal::anonymous(),
+            &controllers,
+            &CollectionType::Db,
+            &collection,
+            &"key".to_string(),
+            user(1)
+        ));
+    }
+
+    #[test]
+    fn broadcasts_public_changes_to_everyone() {
+        insert_rule("public", Permission::Public);
+
+        let controllers = Controllers::new();
+        let collection = "public".to_string();
+
+        assert!(assert_broadcast(
+            user(2),
+            &controllers,
+            &CollectionType::Db,
+            &collection,
+            &"key".to_string(),
+            user(1)
+        ));
+        assert!(assert_broadcast(
+            Principal::anonymous(),
+            &controllers,
+            &CollectionType::Db,
+            &collection,
+            &"key".to_string(),
+            user(1)
+        ));
+    }
+
+    #[test]
+    fn no_broadcast_without_rule() {
+        insert_rule("public", Permission::Public);
+
+        // The rule of the collection exists in the Datastore but not in the Storage
+        assert!(!assert_broadcast(
+            user(1),
+            &Controllers::new(),
+            &CollectionType::Storage,
+            &"public".to_string(),
+            &"key".to_string(),
+            user(1)
+        ));
+    }
+
+    #[test]
+    fn opens_without_config() {
+        assert!(assert_open(&None, || false).is_ok());
+    }
+
+    #[test]
+    fn requires_known_user() {
+        let config = Some(WsConfig {
+            require_user: Some(true),
+            ..Default::default()
+        });
+
+        assert!(assert_open(&config, || true).is_ok());
+        assert_eq!(
+            assert_open(&config, || false).unwrap_err(),
+            JUNO_WS_ERROR_USER_REQUIRED
+        );
+    }
+
+    #[test]
+    fn allows_configured_gateways_only() {
+        let config = Some(WsConfig {
+            gateway_principals: vec![user(1)],
+            ..Default::default()
+        });
+
+        assert!(assert_gateway(&user(1), &config).is_ok());
+        assert!(assert_gateway(&user(2), &config)
+            .unwrap_err()
+            .starts_with(JUNO_WS_ERROR_GATEWAY_NOT_ALLOWED));
+        assert!(assert_gateway(&user(2), &None).is_ok());
+        assert!(assert_gateway(&user(2), &Some(WsConfig::default())).is_ok());
+    }
+
+    #[test]
+    fn broadcasts_to_channels_that_are_not_collections() {
+        let channel = "chat".to_string();
+
+        assert!(assert_broadcast_channel(
+            Principal::anonymous(),
+            &Controllers::new(),
+            &channel
+        ));
+        assert!(assert_broadcast_channel(
+            user(1),
+            &Controllers::new(),
+            &channel
+        ));
+    }
+
+    #[test]
+    fn broadcasts_to_collection_subscribers_granted_read_access() {
+        insert_rule("public", Permission::Public);
+        insert_rule("private", Permission::Private);
+        insert_rule("principals", Permission::Principals(vec![user(1)]));
+        insert_rule("managed", Permission::Managed);
+
+        let controllers = Controllers::new();
+
+        assert!(assert_broadcast_channel(
+            user(1),
+            &controllers,
+            &"public".to_string()
+        ));
+        assert!(!assert_broadcast_channel(
+            user(1),
+            &controllers,
+            &"private".to_string()
+        ));
+        assert!(assert_broadcast_channel(
+            user(1),
+            &controllers,
+            &"principals".to_string()
+        ));
+        assert!(!assert_broadcast_channel(
+            user(2),
+            &controllers,
+            &"principals".to_string()
+        ));
+        assert!(!assert_broadcast_channel(
+            Principal::anonymous(),
+            &controllers,
+            &"managed".to_string()
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::errors::ws::JUNO_WS_ERROR_NO_CHANNELS;
+use crate::ws::assert::assert_join;
+use crate::ws::runtime::increment_and_assert_subscription_rate;
+use crate::ws::state::{delete_channels, get_config, insert_channels};
+use crate::ws::types::interface::WsChannelsData;
+use crate::ws::types::state::WsChannel;
+use candid::Principal;
+
+/// Joins the channels. Like subscriptions, each channel consumes a token of the subscription
+/// rate of the client.
+pub fn join(
+    client: Principal,
+    WsChannelsData { channels }: &WsChannelsData,
+) -> Result<Vec<WsChannel>, String> {
+    if channels.is_empty() {
+        return Err(JUNO_WS_ERROR_NO_CHANNELS.to_string());
+    }
+
+    for channel in channels {
+        assert_join(channel)?;
+    }
+
+    let rate_config = get_config().and_then(|config| config.subscription_rate_config);
+
+    increment_and_assert_subscription_rate(&client, &rate_config, channels.len())?;
+
+    insert_channels(&client, channels);
+
+    Ok(channels.clone())
+}
+
+/// Leaves the channels and returns those the client had effectively joined.
+pub fn leave(
+    client: Principal,
+    WsChannelsData { channels }: &WsChannelsData,
+) -> Result<Vec<WsChannel>, String> {
+    if channels.is_empty() {
+        return Err(JUNO_WS_ERROR_NO_CHANNELS.to_string());
+    }
+
+    Ok(delete_channels(&client, channels))
+}
@@ -0,0 +1,7 @@
+pub const DEFAULT_MAX_NUMBER_OF_RETURNED_MESSAGES: usize = 100;
+// 5 minutes
+pub const DEFAULT_SEND_ACK_INTERVAL_MS: u64 = 300_000;
+// The keep alive timeout of the clients in ic-websocket-cdk. The acknowledgement interval must be greater.
+pub const CLIENT_KEEP_ALIVE_TIMEOUT_MS: u64 = 60_000;
+// The maximal number of changes replayed to a client that resubscribes. The remaining changes are paged with list_changes.
+pub const MAX_REPLAYED_CHANGES: usize = 100;
@@ -0,0 +1,251 @@
+use crate::changes::types::state::{Change, ChangeSequence};
+use crate::db::types::state::Doc;
+use crate::types::hooks::{NotificationDoc, NotificationMessage};
+use crate::types::state::CollectionType;
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::{SetWsConfig, WsClient, WsSubscriptionAck};
+use crate::ws::types::notify::NotificationSource;
+use crate::ws::types::state::{ClientSession, Subscription};
+use candid::Principal;
+use ic_cdk::api::time;
+use junobuild_shared::data::version::next_version;
+use junobuild_shared::types::state::{Timestamp, Timestamped, Version, Versioned};
+use junobuild_storage::types::store::Asset;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+impl<'a> NotificationSource<'a> {
+    pub fn from_doc(doc: &'a Doc) -> Self {
+        NotificationSource {
+            collection_type: CollectionType::Db,
+            owner: doc.owner,
+            description: doc.description.clone(),
+            created_at: doc.created_at,
+            updated_at: doc.updated_at,
+            doc: Some(doc),
+        }
+    }
+
+    pub fn from_asset(asset: &Asset) -> Self {
+        NotificationSource {
+            collection_type: CollectionType::Storage,
+            owner: asset.key.owner,
+            description: asset.key.description.clone(),
+            created_at: asset.created_at,
+            updated_at: asset.updated_at,
+            doc: None,
+        }
+    }
+
+    pub fn from_change(change: &Change) -> Self {
+        NotificationSource {
+            collection_type: change.kind.collection_type(),
+            owner: change.owner,
+            description: change.description.clone(),
+            created_at: change.created_at,
+            updated_at: change.updated_at,
+            doc: None,
+        }
+    }
+}
+
+impl NotificationMessage {
+    pub fn from_change(sequence: ChangeSequence, change: &Change) -> Self {
+        NotificationMessage {
+            msg_type: change.kind.msg_type().to_string(),
+            collection: change.collection.clone(),
+            key: change.key.clone(),
+            caller: change.caller.to_string(),
+            timestamp: change.timestamp,
+            doc: None,
+            doc_omitted: false,
+            sequence: Some(sequence),
+        }
+    }
+
+    /// Attaches the document to the notification unless it exceeds the `max_size` of the
+    /// collection, in which case the notification is flagged so that the client knows it should
+    /// fetch the document.
+    pub fn with_doc(&self, doc: Option<&Doc>, max_size: Option<u128>) -> Self {
+        let Some(doc) = doc else {
+            return self.clone();
+        };
+
+        let doc_omitted = max_size.is_some_and(|max_size| doc.data.len() as u128 > max_size);
+
+        NotificationMessage {
+            doc: if doc_omitted { None } else { Some(doc.into()) },
+            doc_omitted,
+            ..self.clone()
+        }
+    }
+}
+
+impl Timestamped for NotificationSource<'_> {
+    fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    fn cmp_updated_at(&self, other: &Self) -> Ordering {
+        self.updated_at.cmp(&other.updated_at)
+    }
+
+    fn cmp_created_at(&self, other: &Self) -> Ordering {
+        self.created_at.cmp(&other.created_at)
+    }
+}
+
+impl From<&Doc> for NotificationDoc {
+    fn from(doc: &Doc) -> Self {
+        NotificationDoc {
+            data: doc.data.clone(),
+            description: doc.description.clone(),
+            version: doc.version,
+            updated_at: doc.updated_at,
+        }
+    }
+}
+
+impl ClientSession {
+    pub fn init() -> Self {
+        ClientSession {
+            connected_at: time(),
+            subscriptions: Vec::new(),
+            channels: HashSet::new(),
+        }
+    }
+}
+
+impl WsClient {
+    pub fn from_session(client: &Principal, session: &ClientSession) -> Self {
+        WsClient {
+            client: *client,
+            connected_at: session.connected_at,
+            subscriptions: session
+                .subscriptions
+                .iter()
+                .map(|subscription| subscription.collection.clone())
+                .collect(),
+            channels: session.channels.iter().cloned().collect(),
+        }
+    }
+}
+
+impl From<&Subscription> for WsSubscriptionAck {
+    fn from(subscription: &Subscription) -> Self {
+        WsSubscriptionAck {
+            id: subscription.id,
+            collection: subscription.collection.clone(),
+        }
+    }
+}
+
+impl WsConfig {
+    pub fn prepare(current_config: &Option<WsConfig>, user_config: &SetWsConfig) -> Self {
+        let now = time();
+
+        let created_at: Timestamp = match current_config {
+            None => now,
+            Some(current_config) => current_config.created_at.unwrap_or(now),
+        };
+
+        let version = next_version(current_config);
+
+        let updated_at: Timestamp = now;
+
+        WsConfig {
+            gateway_principals: user_config.gateway_principals.clone(),
+            max_number_of_returned_messages: user_config.max_number_of_returned_messages,
+            send_ack_interval_ms: user_config.send_ack_interval_ms,
+            require_user: user_config.require_user,
+            message_rate_config: user_config.message_rate_config.clone(),
+            subscription_rate_config: user_config.subscription_rate_config.clone(),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            version: Some(version),
+        }
+    }
+}
+
+impl Versioned for WsConfig {
+    fn version(&self) -> Option<Version> {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification() -> NotificationMessage {
+        NotificationMessage {
+            msg_type: "doc_set".to_string(),
+            collection: "posts".to_string(),
+            key: "a".to_string(),
+            caller: Principal::anonymous().to_text(),
+            timestamp: 1,
+            doc: None,
+            doc_omitted: false,
+            sequence: Some(1),
+        }
+    }
+
+    fn doc(data: &[u8]) -> Doc {
+        Doc {
+            owner: Principal::anonymous(),
+            data: data.to_vec(),
+            description: Some("hello".to_string()),
+            created_at: 1,
+            updated_at: 2,
+            version: Some(3),
+            expires_at: None,
+            restorable_until: None,
+        }
+    }
+
+    #[test]
+    fn attaches_doc() {
+        let doc = doc(b"data");
+
+        let notification = notification().with_doc(Some(&doc), None);
+
+        let attached = notification.doc.unwrap();
+        assert_eq!(attached.data, b"data".to_vec());
+        assert_eq!(attached.description, Some("hello".to_string()));
+        assert_eq!(attached.version, Some(3));
+        assert_eq!(attached.updated_at, 2);
+        assert!(!notification.doc_omitted);
+    }
+
+    #[test]
+    fn attaches_doc_within_max_size() {
+        let doc = doc(b"data");
+
+        let notification = notification().with_doc(Some(&doc), Some(4));
+
+        assert!(notification.doc.is_some());
+        assert!(!notification.doc_omitted);
+    }
+
+    #[test]
+    fn omits_doc_exceeding_max_size() {
+        let doc = doc(b"data");
+
+        let notification = notification().with_doc(Some(&doc), Some(3));
+
+        assert!(notification.doc.is_none());
+        assert!(notification.doc_omitted);
+    }
+
+    #[test]
+    fn nothing_to_attach_without_doc() {
+        let notification = notification().with_doc(None, Some(0));
+
+        assert!(notification.doc.is_none());
+        assert!(!notification.doc_omitted);
+    }
+}
@@ -3,8 +3,12 @@
 //! This module provides utilities for parsing and creating WebSocket messages
 //! exchanged between clients and the Juno satellite.
 
+use crate::changes::types::state::ChangeSequence;
+use crate::types::hooks::NotificationMessage;
+use crate::ws::types::interface::WsSubscriptionAck;
+use crate::ws::types::state::{SubscriptionId, WsChannel};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::string::String;
 
 /// Represents a WebSocket message from client to server
 #[derive(Debug, Clone, Deserialize)]
@@ -14,7 +18,7 @@ pub struct WsClientMessage {
     /// Optional list of collections for subscription commands
     #[serde(default)]
     pub collections: Option<Vec<String>>,
-    /// Optional additional data
+    /// Optional additional data - e.g. the scope of a subscription
     #[serde(default)]
     pub data: Option<serde_json::Value>,
 }
@@ -40,11 +44,24 @@ pub struct WsServerMessage {
 /// # Returns
 /// Parsed message or error
 pub fn parse(data: &[u8]) -> Result<WsClientMessage, String> {
-    let json_str = std::str::from_utf8(data)
-        .map_err(|e| format!("Invalid UTF-8: {}", e))?;
+    let json_str = std::str::from_utf8(data).map_err(|e| format!("Invalid UTF-8: {}", e))?;
 
-    serde_json::from_str(json_str)
-        .map_err(|e| format!("Invalid JSON: {}", e))
+    serde_json::from_str(json_str).map_err(|e| format!("Invalid JSON: {}", e))
+}
+
+/// Parse the optional data of a client message into the structure expected by its command
+///
+/// # Arguments
+/// * `message` - The parsed client message
+///
+/// # Returns
+/// The data, its default value if none was provided, or an error
+pub fn parse_data<T: DeserializeOwned + Default>(message: &WsClientMessage) -> Result<T, String> {
+    match &message.data {
+        None => Ok(T::default()),
+        Some(data) => serde_json::from_value(data.clone())
+            .map_err(|e| format!("Invalid data for command {}: {}", message.command, e)),
+    }
 }
 
 /// Create a pong response message
@@ -52,14 +69,7 @@ pub fn parse(data: &[u8]) -> Result<WsClientMessage, String> {
 /// # Returns
 /// Serialized pong message bytes
 pub fn create_pong() -> Result<Vec<u8>, String> {
-    let msg = WsServerMessage {
-        msg_type: "pong".to_string(),
-        timestamp: ic_cdk::api::time(),
-        payload: None,
-    };
-
-    serde_json::to_vec(&msg)
-        .map_err(|e| format!("Failed to serialize pong: {}", e))
+    create_message("pong", None)
 }
 
 /// Create an error message
@@ -70,14 +80,7 @@ pub fn create_pong() -> Result<Vec<u8>, String> {
 /// # Returns
 /// Serialized error message bytes
 pub fn create_error(error: &str) -> Result<Vec<u8>, String> {
-    let msg = WsServerMessage {
-        msg_type: "error".to_string(),
-        timestamp: ic_cdk::api::time(),
-        payload: Some(serde_json::json!({ "error": error })),
-    };
-
-    serde_json::to_vec(&msg)
-        .map_err(|e| format!("Failed to serialize error: {}", e))
+    create_message("error", Some(serde_json::json!({ "error": error })))
 }
 
 /// Create a welcome message sent on connection
@@ -88,16 +91,178 @@ pub fn create_error(error: &str) -> Result<Vec<u8>, String> {
 /// # Returns
 /// Serialized welcome message bytes
 pub fn create_welcome(client_key: &str) -> Result<Vec<u8>, String> {
-    let msg = WsServerMessage {
-        msg_type: "welcome".to_string(),
-        timestamp: ic_cdk::api::time(),
-        payload: Some(serde_json::json!({
+    create_message(
+        "welcome",
+        Some(serde_json::json!({
             "client_key": client_key,
             "server": "Juno Satellite WebSocket",
             "version": "1.0.0"
         })),
+    )
+}
+
+/// Create the acknowledgement of a subscribe command
+///
+/// # Arguments
+/// * `subscriptions` - The ids and collections of the registered subscriptions
+///
+/// # Returns
+/// Serialized subscribed message bytes
+pub fn create_subscribed(subscriptions: &[WsSubscriptionAck]) -> Result<Vec<u8>, String> {
+    create_message(
+        "subscribed",
+        Some(serde_json::json!({ "subscriptions": subscriptions })),
+    )
+}
+
+/// Create the acknowledgement of an unsubscribe command
+///
+/// # Arguments
+/// * `ids` - The ids of the removed subscriptions
+///
+/// # Returns
+/// Serialized unsubscribed message bytes
+pub fn create_unsubscribed(ids: &[SubscriptionId]) -> Result<Vec<u8>, String> {
+    create_message("unsubscribed", Some(serde_json::json!({ "ids": ids })))
+}
+
+/// Create the acknowledgement of a join command
+///
+/// # Arguments
+/// * `channels` - The channels the client joined
+///
+/// # Returns
+/// Serialized joined message bytes
+pub fn create_joined(channels: &[WsChannel]) -> Result<Vec<u8>, String> {
+    create_message("joined", Some(serde_json::json!({ "channels": channels })))
+}
+
+/// Create the acknowledgement of a leave command
+///
+/// # Arguments
+/// * `channels` - The channels the client left
+///
+/// # Returns
+/// Serialized left message bytes
+pub fn create_left(channels: &[WsChannel]) -> Result<Vec<u8>, String> {
+    create_message("left", Some(serde_json::json!({ "channels": channels })))
+}
+
+/// Create an app-level message sent by the serverless functions
+///
+/// # Arguments
+/// * `channel` - The channel or collection the message was broadcast to, if any
+/// * `data` - The JSON payload of the message
+///
+/// # Returns
+/// Serialized message bytes
+pub fn create_app_message(
+    channel: Option<&str>,
+    data: serde_json::Value,
+) -> Result<Vec<u8>, String> {
+    create_message(
+        "message",
+        Some(serde_json::json!({ "channel": channel, "data": data })),
+    )
+}
+
+/// Create the message informing a client that the changes it missed cannot be replayed
+///
+/// # Arguments
+/// * `since` - The sequence the client asked to resume from
+/// * `error` - The reason why the changes cannot be replayed
+///
+/// # Returns
+/// Serialized resync required message bytes
+pub fn create_resync_required(since: ChangeSequence, error: &str) -> Result<Vec<u8>, String> {
+    create_message(
+        "resync_required",
+        Some(serde_json::json!({ "since": since, "error": error })),
+    )
+}
+
+/// Create the message informing a client that only part of the changes it missed were replayed
+///
+/// # Arguments
+/// * `last_sequence` - The sequence of the last replayed change, from which the client should
+///   continue with `list_changes`
+///
+/// # Returns
+/// Serialized replay truncated message bytes
+pub fn create_replay_truncated(last_sequence: ChangeSequence) -> Result<Vec<u8>, String> {
+    create_message(
+        "replay_truncated",
+        Some(serde_json::json!({ "last_sequence": last_sequence })),
+    )
+}
+
+/// Serialize a notification
+///
+/// # Arguments
+/// * `notification` - The notification of a change
+///
+/// # Returns
+/// Serialized notification bytes
+pub fn create_notification(notification: &NotificationMessage) -> Result<Vec<u8>, String> {
+    serde_json::to_vec(notification).map_err(|e| format!("Failed to serialize notification: {}", e))
+}
+
+fn create_message(msg_type: &str, payload: Option<serde_json::Value>) -> Result<Vec<u8>, String> {
+    let msg = WsServerMessage {
+        msg_type: msg_type.to_string(),
+        timestamp: ic_cdk::api::time(),
+        payload,
     };
 
-    serde_json::to_vec(&msg)
-        .map_err(|e| format!("Failed to serialize welcome: {}", e))
+    serde_json::to_vec(&msg).map_err(|e| format!("Failed to serialize {}: {}", msg_type, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::types::interface::{WsSubscribeData, WsUnsubscribeData};
+
+    #[test]
+    fn parses_command_with_collections_and_data() {
+        let message = parse(
+            br#"{"command":"subscribe","collections":["posts"],"data":{"key":"a","with_doc":true}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(message.command, "subscribe");
+        assert_eq!(message.collections, Some(vec!["posts".to_string()]));
+
+        let data: WsSubscribeData = parse_data(&message).unwrap();
+
+        assert_eq!(data.key, Some("a".to_string()));
+        assert_eq!(data.with_doc, Some(true));
+        assert!(data.owner.is_none());
+    }
+
+    #[test]
+    fn parses_command_without_data() {
+        let message = parse(br#"{"command":"unsubscribe","collections":["posts"]}"#).unwrap();
+
+        let data: WsUnsubscribeData = parse_data(&message).unwrap();
+
+        assert!(data.id.is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        assert!(parse(&[0xff, 0xfe]).is_err());
+        assert!(parse(b"subscribe:posts").is_err());
+        assert!(parse(br#"{"collections":["posts"]}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let message = parse(br#"{"command":"unsubscribe","data":{"id":"abc"}}"#).unwrap();
+
+        let result: Result<WsUnsubscribeData, String> = parse_data(&message);
+
+        assert!(result
+            .unwrap_err()
+            .starts_with("Invalid data for command unsubscribe"));
+    }
 }
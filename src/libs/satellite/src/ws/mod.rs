@@ -4,19 +4,52 @@
 //!
 //! This module provides real-time push notifications for document and asset changes.
 
+mod assert;
+mod channels;
+mod constants;
+mod impls;
+mod message;
+pub mod notify;
+mod replay;
+mod runtime;
+mod state;
+pub mod store;
+mod subscriptions;
+pub mod types;
+
+use crate::controllers::store::get_controllers;
+use crate::errors::ws::{
+    JUNO_WS_ERROR_INVALID_MESSAGE, JUNO_WS_ERROR_RATE_LIMITED, JUNO_WS_ERROR_UNKNOWN_COMMAND,
+};
+use crate::rules::store::get_rule_db;
 use crate::types::hooks::NotificationMessage;
+use crate::ws::assert::assert_broadcast;
+use crate::ws::channels::{join, leave};
+use crate::ws::constants::{DEFAULT_MAX_NUMBER_OF_RETURNED_MESSAGES, DEFAULT_SEND_ACK_INTERVAL_MS};
+use crate::ws::message::{
+    create_error, create_joined, create_left, create_notification, create_pong, create_subscribed,
+    create_unsubscribed, create_welcome, parse, parse_data, WsClientMessage,
+};
+use crate::ws::replay::replay;
+use crate::ws::runtime::{increment_and_assert_message_rate, prune_rate_tokens};
+use crate::ws::state::{
+    count_clients, delete_client, filter_clients, get_config, insert_client, list_clients,
+};
+use crate::ws::subscriptions::{filter_subscription, subscribe, unsubscribe};
+use crate::ws::types::interface::{
+    WsChannelsData, WsSubscribeData, WsSubscriptionAck, WsUnsubscribeData,
+};
+use crate::ws::types::notify::NotificationSource;
+use crate::ws::types::state::ClientSession;
 use candid::Principal;
 use ic_websocket_cdk::{
-    OnCloseCallbackArgs, OnMessageCallbackArgs, OnOpenCallbackArgs, ClientPrincipal, WsHandlers,
-    WsInitParams,
+    OnCloseCallbackArgs, OnMessageCallbackArgs, OnOpenCallbackArgs, WsHandlers, WsInitParams,
 };
+use junobuild_shared::types::state::Controllers;
 use std::cell::RefCell;
-use std::collections::HashMap;
 
-/// Connected clients: principal -> subscriptions
 thread_local! {
-    static SUBSCRIBERS: RefCell<HashMap<Principal, Vec<String>>> = RefCell::new(HashMap::new());
-    static CDK_INITIALIZED: RefCell<bool> = RefCell::new(false);
+    static CDK_INITIALIZED: RefCell<bool> = const { RefCell::new(false) };
 }
 
 /// Callback handlers for WebSocket events
@@ -27,71 +60,161 @@ mod handlers {
     pub fn on_open(args: OnOpenCallbackArgs) {
         let client_principal = args.client_principal;
 
-        SUBSCRIBERS.with(|subs| {
-            subs.borrow_mut()
-                .entry(client_principal)
-                .or_insert_with(Vec::new);
-        });
+        // The connection was already asserted in ws_open
+        insert_client(client_principal, ClientSession::init());
+
+        reply(
+            client_principal,
+            create_welcome(&client_principal.to_text()),
+        );
 
         println!("✅ WebSocket opened: {}", client_principal.to_text());
     }
 
     pub fn on_message(args: OnMessageCallbackArgs) {
         let client_principal = args.client_principal;
-        let data = args.message;
-
-        // Simple message parsing for subscribe/unsubscribe
-        if let Ok(text) = String::from_utf8(data) {
-            println!("📨 WebSocket message from {}: {}", client_principal.to_text(), text);
-
-            if text.starts_with("subscribe:") {
-                let collection = text.strip_prefix("subscribe:").unwrap_or("");
-                subscribe(client_principal, collection.to_string());
-            } else if text.starts_with("unsubscribe:") {
-                let collection = text.strip_prefix("unsubscribe:").unwrap_or("");
-                unsubscribe(client_principal, collection.to_string());
+
+        let rate_config = get_config().and_then(|config| config.message_rate_config);
+
+        let result = increment_and_assert_message_rate(&client_principal, &rate_config)
+            .and_then(|_| execute(client_principal, &args.message));
+
+        // A client that exceeds its rate is informed with an error frame before being disconnected.
+        if let Err(err) = &result {
+            if err.starts_with(JUNO_WS_ERROR_RATE_LIMITED) {
+                println!(
+                    "⛔ WebSocket {} rate limited: {}",
+                    client_principal.to_text(),
+                    err
+                );
+
+                reply(client_principal, create_error(err));
+                disconnect(client_principal);
+                return;
             }
         }
+
+        let result = result.or_else(|err| {
+            println!(
+                "⛔ WebSocket message from {} refused: {}",
+                client_principal.to_text(),
+                err
+            );
+
+            create_error(&err).map(|error| vec![error])
+        });
+
+        match result {
+            Ok(messages) => {
+                for message in messages {
+                    reply(client_principal, Ok(message));
+                }
+            }
+            Err(err) => reply(client_principal, Err(err)),
+        }
     }
 
     pub fn on_close(args: OnCloseCallbackArgs) {
         let client_principal = args.client_principal;
 
-        SUBSCRIBERS.with(|subs| {
-            subs.borrow_mut().remove(&client_principal);
-        });
+        delete_client(&client_principal);
+        prune_rate_tokens(&get_config());
 
         println!("🔌 WebSocket closed: {}", client_principal.to_text());
     }
 
-    fn subscribe(client_principal: Principal, collection: String) {
-        SUBSCRIBERS.with(|subs| {
-            if let Some(subs_list) = subs.borrow_mut().get_mut(&client_principal) {
-                if !subs_list.contains(&collection) {
-                    println!("📝 {} subscribed to {}", client_principal.to_text(), collection);
-                    subs_list.push(collection);
+    // Executes the command of the client and returns the messages to reply, in order.
+    fn execute(client_principal: Principal, data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+        let message: WsClientMessage =
+            parse(data).map_err(|err| format!("{JUNO_WS_ERROR_INVALID_MESSAGE} ({err})"))?;
+
+        let collections = message.collections.clone().unwrap_or_default();
+
+        match message.command.as_str() {
+            "subscribe" => {
+                let data: WsSubscribeData = parse_data(&message)?;
+                let subscriptions = subscribe(client_principal, &collections, &data)?;
+
+                println!(
+                    "📝 {} subscribed to {}",
+                    client_principal.to_text(),
+                    collections.join(", ")
+                );
+
+                let acks: Vec<WsSubscriptionAck> =
+                    subscriptions.iter().map(WsSubscriptionAck::from).collect();
+
+                let mut messages = vec![create_subscribed(&acks)?];
+
+                // Changes missed since the provided sequence are replayed before any live notification.
+                if let Some(since) = data.since {
+                    messages.extend(replay(client_principal, since, &subscriptions)?);
                 }
+
+                Ok(messages)
             }
-        });
-    }
+            "unsubscribe" => {
+                let data: WsUnsubscribeData = parse_data(&message)?;
+                let ids = unsubscribe(client_principal, &collections, &data)?;
+
+                println!(
+                    "🚫 {} unsubscribed {} subscription(s)",
+                    client_principal.to_text(),
+                    ids.len()
+                );
+
+                Ok(vec![create_unsubscribed(&ids)?])
+            }
+            "join" => {
+                let data: WsChannelsData = parse_data(&message)?;
+                let channels = join(client_principal, &data)?;
+
+                Ok(vec![create_joined(&channels)?])
+            }
+            "leave" => {
+                let data: WsChannelsData = parse_data(&message)?;
+                let channels = leave(client_principal, &data)?;
 
-    fn unsubscribe(client_principal: Principal, collection: String) {
-        SUBSCRIBERS.with(|subs| {
-            if let Some(subs_list) = subs.borrow_mut().get_mut(&client_principal) {
-                subs_list.retain(|c| c != &collection);
-                println!("🚫 {} unsubscribed from {}", client_principal.to_text(), collection);
+                Ok(vec![create_left(&channels)?])
             }
+            "ping" => Ok(vec![create_pong()?]),
+            command => Err(format!("{JUNO_WS_ERROR_UNKNOWN_COMMAND} ({command})")),
+        }
+    }
+
+    fn disconnect(client_principal: Principal) {
+        if let Err(err) = ic_websocket_cdk::close(client_principal) {
+            println!(
+                "❌ Failed to close WebSocket {}: {}",
+                client_principal.to_text(),
+                err
+            );
+        }
+    }
+
+    fn reply(client_principal: Principal, message: Result<Vec<u8>, String>) {
+        let result = message.and_then(|data| {
+            ic_websocket_cdk::send(client_principal, data).map_err(|e| format!("{:?}", e))
         });
+
+        if let Err(err) = result {
+            println!(
+                "❌ Failed to reply to {}: {}",
+                client_principal.to_text(),
+                err
+            );
+        }
     }
 }
 
-/// Initialize the WebSocket subsystem with ic-websocket-cdk
+/// Initialize the WebSocket subsystem with ic-websocket-cdk, using the configuration stored
+/// in the heap state - or the defaults if none was set.
 pub fn init() {
-    CDK_INITIALIZED.with_borrow(|initialized| {
-        if *initialized {
-            return;
-        }
-    });
+    if CDK_INITIALIZED.with_borrow(|initialized| *initialized) {
+        return;
+    }
+
+    let config = get_config().unwrap_or_default();
 
     // Create WebSocket handlers
     let ws_handlers = WsHandlers {
@@ -102,8 +225,16 @@ pub fn init() {
 
     // Initialize with parameters
     let params = WsInitParams::new(ws_handlers)
-        .with_max_number_of_returned_messages(100)
-        .with_send_ack_interval_ms(300_000); // 5 minutes
+        .with_max_number_of_returned_messages(
+            config
+                .max_number_of_returned_messages
+                .unwrap_or(DEFAULT_MAX_NUMBER_OF_RETURNED_MESSAGES),
+        )
+        .with_send_ack_interval_ms(
+            config
+                .send_ack_interval_ms
+                .unwrap_or(DEFAULT_SEND_ACK_INTERVAL_MS),
+        );
 
     ic_websocket_cdk::init(params);
 
@@ -113,64 +244,97 @@ pub fn init() {
 }
 
 /// Broadcast a notification to all subscribed clients
-pub fn broadcast(notification: NotificationMessage) {
-    let collection = notification.collection.clone();
+///
+/// Only the subscribers with at least one subscription matching the change, and that are allowed
+/// to read the document or asset - according to the read permission of the collection and its
+/// owner - receive the notification. Subscribers that opted in with `with_doc` receive the
+/// notification with the document attached.
+pub fn broadcast(source: &NotificationSource, notification: NotificationMessage) {
+    let collection = &notification.collection;
+    let key = &notification.key;
 
-    SUBSCRIBERS.with(|subs| {
-        let subs = subs.borrow();
-        if subs.is_empty() {
-            return;
-        }
+    let controllers: Controllers = get_controllers();
 
-        // Find clients subscribed to this collection
-        let target_principals: Vec<_> = subs
-            .iter()
-            .filter(|(_, collections)| collections.is_empty() || collections.contains(&collection))
-            .map(|(principal, _)| *principal)
+    // Find clients subscribed to this change and allowed to read the entity
+    let targets: Vec<(Principal, bool)> =
+        filter_clients(|subscription| filter_subscription(subscription, collection, key, source))
+            .into_iter()
+            .filter(|(principal, _)| {
+                assert_broadcast(
+                    *principal,
+                    &controllers,
+                    &source.collection_type,
+                    collection,
+                    key,
+                    source.owner,
+                )
+            })
+            .map(|(principal, subscriptions)| {
+                let with_doc = subscriptions
+                    .iter()
+                    .any(|subscription| subscription.with_doc);
+                (principal, with_doc)
+            })
             .collect();
 
-        if target_principals.is_empty() {
-            ic_cdk::println!("📭 No subscribers for collection: {}", collection);
-            return;
-        }
+    if targets.is_empty() {
+        ic_cdk::println!("📭 No subscribers for collection: {}", collection);
+        return;
+    }
 
-        ic_cdk::println!(
-            "📢 Broadcasting to {} clients (collection: {})",
-            target_principals.len(),
-            collection
-        );
+    ic_cdk::println!(
+        "📢 Broadcasting to {} clients (collection: {})",
+        targets.len(),
+        collection
+    );
+
+    // Serialize the notification as JSON - like any other message of the protocol - with and,
+    // only if needed, without the document.
+    let data = create_notification(&notification);
+
+    let data_with_doc = if targets.iter().any(|(_, with_doc)| *with_doc) {
+        let max_size = get_rule_db(&notification.collection).and_then(|rule| rule.max_size);
 
-        // Serialize and send notification using Candid encoding
-        if let Ok(data) = candid::encode_one(&notification) {
-            for principal in target_principals {
-                match ic_websocket_cdk::send(principal, data.clone()) {
-                    Ok(_) => ic_cdk::println!("📤 Sent to {}", principal.to_text()),
-                    Err(e) => ic_cdk::println!("❌ Failed to send to {}: {:?}", principal.to_text(), e),
+        Some(create_notification(
+            &notification.with_doc(source.doc, max_size),
+        ))
+    } else {
+        None
+    };
+
+    for (principal, with_doc) in targets {
+        let message = match (with_doc, &data_with_doc) {
+            (true, Some(data_with_doc)) => data_with_doc,
+            _ => &data,
+        };
+
+        match message {
+            Ok(message) => match ic_websocket_cdk::send(principal, message.clone()) {
+                Ok(_) => ic_cdk::println!("📤 Sent to {}", principal.to_text()),
+                Err(e) => {
+                    ic_cdk::println!("❌ Failed to send to {}: {:?}", principal.to_text(), e)
                 }
-            }
-        } else {
-            ic_cdk::println!("❌ Failed to serialize notification");
+            },
+            Err(e) => ic_cdk::println!("❌ {}", e),
         }
-    });
+    }
 }
 
 /// Get the number of connected clients
 pub fn connected_client_count() -> usize {
-    SUBSCRIBERS.with(|subs| subs.borrow().len())
+    count_clients()
 }
 
 /// Get information about all connected clients
 pub fn connected_clients_info() -> Vec<(String, String, usize)> {
-    SUBSCRIBERS.with(|subs| {
-        subs.borrow()
-            .iter()
-            .map(|(principal, collections)| {
-                (
-                    principal.to_text(),
-                    principal.to_text(),
-                    collections.len(),
-                )
-            })
-            .collect()
-    })
+    list_clients()
+        .into_iter()
+        .map(|client| {
+            (
+                client.client.to_text(),
+                client.client.to_text(),
+                client.subscriptions.len(),
+            )
+        })
+        .collect()
 }
@@ -0,0 +1,109 @@
+use crate::changes::store::record_change;
+use crate::changes::types::state::{Change, ChangeKind};
+use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::types::hooks::NotificationMessage;
+use crate::ws::broadcast;
+use crate::ws::types::notify::NotificationSource;
+use junobuild_collections::assert::collection::is_system_collection;
+use junobuild_shared::types::state::UserId;
+use junobuild_storage::types::store::Asset;
+
+// ---------------------------------------------------------
+// Datastore
+// ---------------------------------------------------------
+
+pub fn notify_set_doc(caller: &UserId, doc: &DocContext<DocUpsert>) {
+    if is_system_collection(&doc.collection) {
+        return;
+    }
+
+    let change = Change::from_doc(
+        ChangeKind::DocSet,
+        &doc.collection,
+        &doc.key,
+        &doc.data.after,
+        caller,
+    );
+
+    notify(&change, &NotificationSource::from_doc(&doc.data.after));
+}
+
+pub fn notify_set_many_docs(caller: &UserId, docs: &[DocContext<DocUpsert>]) {
+    for doc in docs {
+        notify_set_doc(caller, doc);
+    }
+}
+
+pub fn notify_delete_doc(caller: &UserId, doc: &DocContext<Option<Doc>>) {
+    if is_system_collection(&doc.collection) {
+        return;
+    }
+
+    // Nothing was deleted, therefore there is nothing to notify
+    let Some(deleted_doc) = &doc.data else {
+        return;
+    };
+
+    let change = Change::from_doc(
+        ChangeKind::DocDeleted,
+        &doc.collection,
+        &doc.key,
+        deleted_doc,
+        caller,
+    );
+
+    notify(&change, &NotificationSource::from_doc(deleted_doc));
+}
+
+pub fn notify_delete_many_docs(caller: &UserId, docs: &[DocContext<Option<Doc>>]) {
+    for doc in docs {
+        notify_delete_doc(caller, doc);
+    }
+}
+
+// ---------------------------------------------------------
+// Storage
+// ---------------------------------------------------------
+
+pub fn notify_upload_asset(caller: &UserId, asset: &Asset) {
+    // We skip system collections - e.g. #dapp - given that a deployment of the frontend would otherwise lead to a broadcast per file.
+    if is_system_collection(&asset.key.collection) {
+        return;
+    }
+
+    let change = Change::from_asset(ChangeKind::AssetUploaded, asset, caller);
+
+    notify(&change, &NotificationSource::from_asset(asset));
+}
+
+pub fn notify_delete_asset(caller: &UserId, asset: &Option<Asset>) {
+    let Some(asset) = asset else {
+        return;
+    };
+
+    if is_system_collection(&asset.key.collection) {
+        return;
+    }
+
+    let change = Change::from_asset(ChangeKind::AssetDeleted, asset, caller);
+
+    notify(&change, &NotificationSource::from_asset(asset));
+}
+
+pub fn notify_delete_many_assets(caller: &UserId, assets: &[Option<Asset>]) {
+    for asset in assets {
+        notify_delete_asset(caller, asset);
+    }
+}
+
+// ---------------------------------------------------------
+// Change log and broadcast
+// ---------------------------------------------------------
+
+// Every change is recorded - whether or not clients are currently connected - so that
+// clients can catch up after a reconnection.
+fn notify(change: &Change, source: &NotificationSource) {
+    let sequence = record_change(change);
+
+    broadcast(source, NotificationMessage::from_change(sequence, change));
+}
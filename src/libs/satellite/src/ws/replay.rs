@@ -0,0 +1,83 @@
+use crate::changes::store::list_changes_since;
+use crate::changes::types::state::{ChangeKind, ChangeSequence};
+use crate::controllers::store::get_controllers;
+use crate::types::hooks::NotificationMessage;
+use crate::ws::assert::assert_broadcast;
+use crate::ws::constants::MAX_REPLAYED_CHANGES;
+use crate::ws::message::{create_notification, create_replay_truncated, create_resync_required};
+use crate::ws::subscriptions::filter_subscription;
+use crate::ws::types::notify::NotificationSource;
+use crate::ws::types::state::Subscription;
+use candid::Principal;
+use junobuild_shared::types::state::Controllers;
+
+/// Prepares the notifications of the changes recorded after `since` that match the subscriptions
+/// and that the client is allowed to read.
+///
+/// At most `MAX_REPLAYED_CHANGES` changes are replayed. If more were recorded, a "replay truncated"
+/// message carrying the sequence of the last replayed change follows the notifications, so that the
+/// client can page through the remaining changes with `list_changes`.
+///
+/// If the change log has already dropped some of these changes, a single "resync required"
+/// message is returned instead.
+pub fn replay(
+    client: Principal,
+    since: ChangeSequence,
+    subscriptions: &[Subscription],
+) -> Result<Vec<Vec<u8>>, String> {
+    // One more change than replayed is read to find out if the replay is truncated
+    let mut changes = match list_changes_since(since, MAX_REPLAYED_CHANGES + 1) {
+        Ok(changes) => changes,
+        Err(err) => return Ok(vec![create_resync_required(since, &err)?]),
+    };
+
+    let truncated = changes.len() > MAX_REPLAYED_CHANGES;
+    changes.truncate(MAX_REPLAYED_CHANGES);
+
+    let controllers: Controllers = get_controllers();
+
+    let mut messages = changes
+        .iter()
+        .filter_map(|(sequence, change)| {
+            let source = NotificationSource::from_change(change);
+
+            let matches: Vec<&Subscription> = subscriptions
+                .iter()
+                .filter(|subscription| {
+                    filter_subscription(subscription, &change.collection, &change.key, &source)
+                })
+                .collect();
+
+            if matches.is_empty()
+                || !assert_broadcast(
+                    client,
+                    &controllers,
+                    &source.collection_type,
+                    &change.collection,
+                    &change.key,
+                    change.owner,
+                )
+            {
+                return None;
+            }
+
+            // The log does not keep the documents. Clients that requested them are informed
+            // that they should be fetched.
+            let doc_omitted = change.kind == ChangeKind::DocSet
+                && matches.iter().any(|subscription| subscription.with_doc);
+
+            Some(create_notification(&NotificationMessage {
+                doc_omitted,
+                ..NotificationMessage::from_change(*sequence, change)
+            }))
+        })
+        .collect::<Result<Vec<Vec<u8>>, String>>()?;
+
+    if truncated {
+        if let Some((last_sequence, _)) = changes.last() {
+            messages.push(create_replay_truncated(*last_sequence)?);
+        }
+    }
+
+    Ok(messages)
+}
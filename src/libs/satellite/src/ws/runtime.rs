@@ -0,0 +1,65 @@
+use crate::errors::ws::JUNO_WS_ERROR_RATE_LIMITED;
+use crate::memory::state::STATE;
+use crate::ws::types::config::WsConfig;
+use candid::Principal;
+use junobuild_shared::rate::types::RateConfig;
+use junobuild_shared::rate::utils::{
+    increment_and_assert_rate_store, increment_and_assert_rate_store_many, prune_rate_store,
+};
+
+// ---------------------------------------------------------
+// Rates
+// ---------------------------------------------------------
+
+pub fn increment_and_assert_message_rate(
+    client: &Principal,
+    config: &Option<RateConfig>,
+) -> Result<(), String> {
+    STATE
+        .with(|state| {
+            increment_and_assert_rate_store(
+                &client.to_text(),
+                config,
+                &mut state.borrow_mut().runtime.ws.message_rate_tokens,
+            )
+        })
+        .map_err(|err| format!("{JUNO_WS_ERROR_RATE_LIMITED} (messages: {err})"))
+}
+
+/// Consumes `count` tokens of the subscription rate of the client - all or none of them.
+pub fn increment_and_assert_subscription_rate(
+    client: &Principal,
+    config: &Option<RateConfig>,
+    count: usize,
+) -> Result<(), String> {
+    STATE
+        .with(|state| {
+            increment_and_assert_rate_store_many(
+                &client.to_text(),
+                config,
+                count,
+                &mut state.borrow_mut().runtime.ws.subscription_rate_tokens,
+            )
+        })
+        .map_err(|err| format!("{JUNO_WS_ERROR_RATE_LIMITED} (subscriptions: {err})"))
+}
+
+/// Removes the rate tokens that were refilled since the clients last consumed them, for example
+/// once a connection was closed. The tokens of the clients that recently reached their rate are
+/// kept, so that reconnecting does not refill their buckets.
+pub fn prune_rate_tokens(config: &Option<WsConfig>) {
+    let (message_rate_config, subscription_rate_config) =
+        config.as_ref().map_or((None, None), |config| {
+            (
+                config.message_rate_config.clone(),
+                config.subscription_rate_config.clone(),
+            )
+        });
+
+    STATE.with(|state| {
+        let ws = &mut state.borrow_mut().runtime.ws;
+
+        prune_rate_store(&message_rate_config, &mut ws.message_rate_tokens);
+        prune_rate_store(&subscription_rate_config, &mut ws.subscription_rate_tokens);
+    })
+}
@@ -0,0 +1,182 @@
+use crate::memory::state::STATE;
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::WsClient;
+use crate::ws::types::state::{ClientSession, Clients, Subscription, SubscriptionId, WsChannel};
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use std::cell::RefCell;
+
+thread_local! {
+    static CLIENTS: RefCell<Clients> = RefCell::new(Clients::new());
+    static NEXT_SUBSCRIPTION_ID: RefCell<SubscriptionId> = const { RefCell::new(0) };
+}
+
+// ---------------------------------------------------------
+// Clients
+// ---------------------------------------------------------
+
+pub fn insert_client(client: Principal, session: ClientSession) {
+    CLIENTS.with_borrow_mut(|clients| {
+        clients.insert(client, session);
+    });
+}
+
+pub fn delete_client(client: &Principal) {
+    CLIENTS.with_borrow_mut(|clients| {
+        clients.remove(client);
+    });
+}
+
+pub fn count_clients() -> usize {
+    CLIENTS.with_borrow(|clients| clients.len())
+}
+
+pub fn is_client_connected(client: &Principal) -> bool {
+    CLIENTS.with_borrow(|clients| clients.contains_key(client))
+}
+
+pub fn list_clients() -> Vec<WsClient> {
+    CLIENTS.with_borrow(|clients| {
+        clients
+            .iter()
+            .map(|(client, session)| WsClient::from_session(client, session))
+            .collect()
+    })
+}
+
+// ---------------------------------------------------------
+// Channels
+// ---------------------------------------------------------
+
+pub fn insert_channels(client: &Principal, channels: &[WsChannel]) {
+    CLIENTS.with_borrow_mut(|clients| {
+        if let Some(session) = clients.get_mut(client) {
+            session.channels.extend(channels.iter().cloned());
+        }
+    });
+}
+
+pub fn delete_channels(client: &Principal, channels: &[WsChannel]) -> Vec<WsChannel> {
+    CLIENTS.with_borrow_mut(|clients| {
+        let Some(session) = clients.get_mut(client) else {
+            return Vec::new();
+        };
+
+        channels
+            .iter()
+            .filter(|channel| session.channels.remove(*channel))
+            .cloned()
+            .collect()
+    })
+}
+
+/// Returns the clients that either joined the channel or subscribed to a collection of the same name.
+pub fn filter_channel_clients(channel: &str) -> Vec<Principal> {
+    CLIENTS.with_borrow(|clients| {
+        clients
+            .iter()
+            .filter(|(_, session)| {
+                session.channels.contains(channel)
+                    || session
+                        .subscriptions
+                        .iter()
+                        .any(|subscription| subscription.collection == channel)
+            })
+            .map(|(client, _)| *client)
+            .collect()
+    })
+}
+
+// ---------------------------------------------------------
+// Subscriptions
+// ---------------------------------------------------------
+
+pub fn next_subscription_id() -> SubscriptionId {
+    NEXT_SUBSCRIPTION_ID.with_borrow_mut(|id| {
+        *id += 1;
+        *id
+    })
+}
+
+pub fn insert_subscriptions(client: &Principal, subscriptions: Vec<Subscription>) {
+    CLIENTS.with_borrow_mut(|clients| {
+        if let Some(session) = clients.get_mut(client) {
+            session.subscriptions.extend(subscriptions);
+        }
+    });
+}
+
+pub fn delete_subscription(client: &Principal, id: SubscriptionId) -> Option<SubscriptionId> {
+    CLIENTS.with_borrow_mut(|clients| {
+        let session = clients.get_mut(client)?;
+
+        let index = session
+            .subscriptions
+            .iter()
+            .position(|subscription| subscription.id == id)?;
+
+        Some(session.subscriptions.remove(index).id)
+    })
+}
+
+pub fn delete_collections_subscriptions(
+    client: &Principal,
+    collections: &[CollectionKey],
+) -> Vec<SubscriptionId> {
+    CLIENTS.with_borrow_mut(|clients| {
+        let Some(session) = clients.get_mut(client) else {
+            return Vec::new();
+        };
+
+        let (deleted, kept): (Vec<Subscription>, Vec<Subscription>) = session
+            .subscriptions
+            .drain(..)
+            .partition(|subscription| collections.contains(&subscription.collection));
+
+        session.subscriptions = kept;
+
+        deleted
+            .into_iter()
+            .map(|subscription| subscription.id)
+            .collect()
+    })
+}
+
+/// Returns the clients that have at least one subscription matching the filter, together with
+/// these matching subscriptions.
+pub fn filter_clients<F>(filter: F) -> Vec<(Principal, Vec<Subscription>)>
+where
+    F: Fn(&Subscription) -> bool,
+{
+    CLIENTS.with_borrow(|clients| {
+        clients
+            .iter()
+            .filter_map(|(client, session)| {
+                let matches: Vec<Subscription> = session
+                    .subscriptions
+                    .iter()
+                    .filter(|subscription| filter(subscription))
+                    .cloned()
+                    .collect();
+
+                if matches.is_empty() {
+                    None
+                } else {
+                    Some((*client, matches))
+                }
+            })
+            .collect()
+    })
+}
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<WsConfig> {
+    STATE.with(|state| state.borrow().heap.ws.clone())
+}
+
+pub fn insert_config(config: &WsConfig) {
+    STATE.with(|state| state.borrow_mut().heap.ws = Some(config.clone()))
+}
@@ -0,0 +1,141 @@
+use crate::controllers::store::get_controllers;
+use crate::errors::ws::{JUNO_WS_ERROR_CLIENT_NOT_CONNECTED, JUNO_WS_ERROR_INVALID_PAYLOAD};
+use crate::user::core::assert::{assert_user_is_not_banned, is_known_user};
+use crate::ws::assert::{assert_broadcast_channel, assert_gateway, assert_open, assert_set_config};
+use crate::ws::message::create_app_message;
+use crate::ws::state::{
+    filter_channel_clients, get_config, insert_config, is_client_connected, list_clients,
+};
+use crate::ws::types::config::WsConfig;
+use crate::ws::types::interface::{SetWsConfig, WsClient};
+use candid::Principal;
+use junobuild_shared::segments::controllers::controller_can_write;
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::types::state::Controllers;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn set_config_store(proposed_config: &SetWsConfig) -> Result<WsConfig, String> {
+    let current_config = get_config();
+
+    assert_set_config(proposed_config, &current_config)?;
+
+    let config = WsConfig::prepare(&current_config, proposed_config);
+
+    insert_config(&config);
+
+    Ok(config)
+}
+
+pub fn get_config_store() -> Option<WsConfig> {
+    get_config()
+}
+
+// ---------------------------------------------------------
+// Gateway
+// ---------------------------------------------------------
+
+pub fn assert_gateway_store(gateway: &Principal) -> Result<(), String> {
+    let config = get_config();
+
+    assert_gateway(gateway, &config)
+}
+
+// ---------------------------------------------------------
+// Session
+// ---------------------------------------------------------
+
+pub fn assert_open_store(client: Principal) -> Result<(), String> {
+    let controllers = get_controllers();
+
+    // Controllers are not necessarily users and cannot be banned
+    if controller_can_write(client, &controllers) {
+        return Ok(());
+    }
+
+    assert_user_is_not_banned(client, &controllers)?;
+
+    let config = get_config();
+
+    assert_open(&config, || is_known_user(client))
+}
+
+// ---------------------------------------------------------
+// Messages
+// ---------------------------------------------------------
+
+/// Sends an app-level message to a connected client.
+///
+/// # Arguments
+/// - `client`: The principal of the client.
+/// - `payload`: The JSON payload of the message - e.g. encoded with `encode_doc_data`.
+///
+/// # Returns
+/// - `Ok(())`: If the message was queued for the client.
+/// - `Err(String)`: If the client is not connected or the payload is not valid JSON.
+pub fn ws_send(client: Principal, payload: Blob) -> Result<(), String> {
+    if !is_client_connected(&client) {
+        return Err(format!(
+            "{JUNO_WS_ERROR_CLIENT_NOT_CONNECTED} ({})",
+            client.to_text()
+        ));
+    }
+
+    let message = create_app_message(None, parse_payload(&payload)?)?;
+
+    ic_websocket_cdk::send(client, message)
+}
+
+/// Broadcasts an app-level message to the clients that joined a channel or subscribed to a
+/// collection of the same name.
+///
+/// When broadcast to a collection, the message is only delivered to the subscribers granted
+/// read access to the whole collection - see `assert_broadcast_channel`.
+///
+/// # Arguments
+/// - `channel`: The name of the channel or the collection.
+/// - `payload`: The JSON payload of the message - e.g. encoded with `encode_doc_data`.
+///
+/// # Returns
+/// - `Ok(usize)`: The number of clients the message was queued for - failures are logged.
+/// - `Err(String)`: If the payload is not valid JSON.
+pub fn ws_broadcast(channel: &str, payload: Blob) -> Result<usize, String> {
+    let message = create_app_message(Some(channel), parse_payload(&payload)?)?;
+
+    let controllers: Controllers = get_controllers();
+
+    let channel = channel.to_string();
+
+    let clients: Vec<Principal> = filter_channel_clients(&channel)
+        .into_iter()
+        .filter(|client| assert_broadcast_channel(*client, &controllers, &channel))
+        .collect();
+
+    let mut sent: usize = 0;
+
+    for client in clients {
+        match ic_websocket_cdk::send(client, message.clone()) {
+            Ok(_) => sent += 1,
+            Err(err) => ic_cdk::println!(
+                "❌ Failed to broadcast to {} on {}: {:?}",
+                client.to_text(),
+                channel,
+                err
+            ),
+        }
+    }
+
+    Ok(sent)
+}
+
+/// Lists the connected clients with their subscriptions and channels.
+pub fn ws_list_clients() -> Vec<WsClient> {
+    list_clients()
+}
+
+fn parse_payload(payload: &Blob) -> Result<serde_json::Value, String> {
+    serde_json::from_slice(payload)
+        .map_err(|err| format!("{JUNO_WS_ERROR_INVALID_PAYLOAD} ({err})"))
+}
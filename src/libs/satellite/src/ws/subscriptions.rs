@@ -0,0 +1,340 @@
+use crate::controllers::store::get_controllers;
+use crate::errors::ws::{JUNO_WS_ERROR_NO_COLLECTIONS, JUNO_WS_ERROR_SUBSCRIPTION_NOT_FOUND};
+use crate::ws::assert::assert_subscribe;
+use crate::ws::runtime::increment_and_assert_subscription_rate;
+use crate::ws::state::{
+    delete_collections_subscriptions, delete_subscription, get_config, insert_subscriptions,
+    next_subscription_id,
+};
+use crate::ws::types::interface::{WsSubscribeData, WsUnsubscribeData};
+use crate::ws::types::notify::NotificationSource;
+use crate::ws::types::state::{Subscription, SubscriptionId};
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::data::list::{filter_timestamps, matcher_regex};
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Controllers;
+
+/// Registers a subscription for each of the collections.
+///
+/// The subscriptions are only registered if the client is allowed to subscribe to all the
+/// collections and the matcher - if any - is valid. Each subscription consumes a token of the
+/// subscription rate of the client.
+pub fn subscribe(
+    client: Principal,
+    collections: &[CollectionKey],
+    WsSubscribeData {
+        key,
+        matcher,
+        owner,
+        with_doc,
+        since: _,
+    }: &WsSubscribeData,
+) -> Result<Vec<Subscription>, String> {
+    if collections.is_empty() {
+        return Err(JUNO_WS_ERROR_NO_COLLECTIONS.to_string());
+    }
+
+    let controllers: Controllers = get_controllers();
+
+    for collection in collections {
+        assert_subscribe(client, &controllers, collection)?;
+    }
+
+    let (regex_key, regex_description) = matcher_regex(matcher)?;
+
+    let rate_config = get_config().and_then(|config| config.subscription_rate_config);
+
+    increment_and_assert_subscription_rate(&client, &rate_config, collections.len())?;
+
+    let subscriptions: Vec<Subscription> = collections
+        .iter()
+        .map(|collection| Subscription {
+            id: next_subscription_id(),
+            collection: collection.clone(),
+            key: key.clone(),
+            matcher: matcher.clone(),
+            regex_key: regex_key.clone(),
+            regex_description: regex_description.clone(),
+            owner: *owner,
+            with_doc: with_doc.unwrap_or(false),
+        })
+        .collect();
+
+    insert_subscriptions(&client, subscriptions.clone());
+
+    Ok(subscriptions)
+}
+
+/// Removes either the subscription with the given id or all the subscriptions of the client
+/// to the collections.
+pub fn unsubscribe(
+    client: Principal,
+    collections: &[CollectionKey],
+    WsUnsubscribeData { id }: &WsUnsubscribeData,
+) -> Result<Vec<SubscriptionId>, String> {
+    match id {
+        Some(id) => delete_subscription(&client, *id)
+            .map(|id| vec![id])
+            .ok_or_else(|| format!("{JUNO_WS_ERROR_SUBSCRIPTION_NOT_FOUND} ({id})")),
+        None => Ok(delete_collections_subscriptions(&client, collections)),
+    }
+}
+
+/// Whether the change of the document or asset identified by collection and key, matches
+/// the scope of the subscription.
+pub fn filter_subscription(
+    subscription: &Subscription,
+    collection: &CollectionKey,
+    key: &Key,
+    source: &NotificationSource,
+) -> bool {
+    subscription.collection == *collection
+        && filter_key(&subscription.key, key)
+        && filter_matcher(subscription, key, source)
+        && filter_owner(subscription, source)
+}
+
+fn filter_key(subscription_key: &Option<Key>, key: &Key) -> bool {
+    match subscription_key {
+        None => true,
+        Some(subscription_key) => subscription_key == key,
+    }
+}
+
+fn filter_matcher(subscription: &Subscription, key: &Key, source: &NotificationSource) -> bool {
+    let key_matches = match &subscription.regex_key {
+        None => true,
+        Some(re) => re.is_match(key),
+    };
+
+    let description_matches = match &subscription.regex_description {
+        None => true,
+        Some(re) => match &source.description {
+            None => false,
+            Some(description) => re.is_match(description),
+        },
+    };
+
+    key_matches && description_matches && filter_timestamps(&subscription.matcher, source)
+}
+
+fn filter_owner(subscription: &Subscription, source: &NotificationSource) -> bool {
+    match subscription.owner {
+        None => true,
+        Some(owner) => owner == source.owner,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::state::CollectionType;
+    use junobuild_shared::types::list::{ListMatcher, TimestampMatcher};
+
+    fn subscription(
+        key: Option<&str>,
+        matcher: Option<ListMatcher>,
+        owner: Option<Principal>,
+    ) -> Subscription {
+        let (regex_key, regex_description) = matcher_regex(&matcher).unwrap();
+
+        Subscription {
+            id: 1,
+            collection: "posts".to_string(),
+            key: key.map(|key| key.to_string()),
+            matcher,
+            regex_key,
+            regex_description,
+            owner,
+            with_doc: false,
+        }
+    }
+
+    fn source(
+        owner: Principal,
+        description: Option<&str>,
+        updated_at: u64,
+    ) -> NotificationSource<'static> {
+        NotificationSource {
+            collection_type: CollectionType::Db,
+            owner,
+            description: description.map(|description| description.to_string()),
+            created_at: 0,
+            updated_at,
+            doc: None,
+        }
+    }
+
+    fn user(id: u8) -> Principal {
+        Principal::from_slice(&[id; 29])
+    }
+
+    fn filter(
+        subscription: &Subscription,
+        collection: &str,
+        key: &str,
+        source: &NotificationSource,
+    ) -> bool {
+        filter_subscription(
+            subscription,
+            &collection.to_string(),
+            &key.to_string(),
+            source,
+        )
+    }
+
+    // ------------------------
+    // Collection and key
+    // ------------------------
+
+    #[test]
+    fn matches_whole_collection() {
+        let subscription = subscription(None, None, None);
+        let source = source(user(1), None, 0);
+
+        assert!(filter(&subscription, "posts", "a", &source));
+        assert!(filter(&subscription, "posts", "b", &source));
+        assert!(!filter(&subscription, "comments", "a", &source));
+    }
+
+    #[test]
+    fn matches_single_key() {
+        let subscription = subscription(Some("a"), None, None);
+        let source = source(user(1), None, 0);
+
+        assert!(filter(&subscription, "posts", "a", &source));
+        assert!(!filter(&subscription, "posts", "ab", &source));
+    }
+
+    // ------------------------
+    // Matcher
+    // ------------------------
+
+    #[test]
+    fn matches_key_regex() {
+        let matcher = ListMatcher {
+            key: Some("^draft-".to_string()),
+            ..Default::default()
+        };
+
+        let subscription = subscription(None, Some(matcher), None);
+        let source = source(user(1), None, 0);
+
+        assert!(filter(&subscription, "posts", "draft-1", &source));
+        assert!(!filter(&subscription, "posts", "published-1", &source));
+    }
+
+    #[test]
+    fn matches_description_regex() {
+        let matcher = ListMatcher {
+            description: Some("urgent".to_string()),
+            ..Default::default()
+        };
+
+        let subscription = subscription(None, Some(matcher), None);
+
+        assert!(filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), Some("very urgent"), 0)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), Some("later"), 0)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), None, 0)
+        ));
+    }
+
+    #[test]
+    fn matches_timestamps() {
+        let matcher = ListMatcher {
+            updated_at: Some(TimestampMatcher::GreaterThan(10)),
+            ..Default::default()
+        };
+
+        let subscription = subscription(None, Some(matcher), None);
+
+        assert!(filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), None, 11)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), None, 10)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_regex() {
+        let matcher = Some(ListMatcher {
+            key: Some("(".to_string()),
+            ..Default::default()
+        });
+
+        assert!(matcher_regex(&matcher).is_err());
+    }
+
+    // ------------------------
+    // Owner
+    // ------------------------
+
+    #[test]
+    fn matches_owner() {
+        let subscription = subscription(None, None, Some(user(1)));
+
+        assert!(filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(1), None, 0)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "a",
+            &source(user(2), None, 0)
+        ));
+    }
+
+    #[test]
+    fn combines_scopes() {
+        let matcher = ListMatcher {
+            key: Some("^draft-".to_string()),
+            ..Default::default()
+        };
+
+        let subscription = subscription(Some("draft-1"), Some(matcher), Some(user(1)));
+
+        assert!(filter(
+            &subscription,
+            "posts",
+            "draft-1",
+            &source(user(1), None, 0)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "draft-2",
+            &source(user(1), None, 0)
+        ));
+        assert!(!filter(
+            &subscription,
+            "posts",
+            "draft-1",
+            &source(user(2), None, 0)
+        ));
+    }
+}
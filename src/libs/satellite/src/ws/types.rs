@@ -0,0 +1,170 @@
+pub mod state {
+    use candid::Principal;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::rate::types::RateTokenStore;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::list::ListMatcher;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use regex::Regex;
+    use std::collections::{HashMap, HashSet};
+
+    pub type SubscriptionId = u64;
+
+    /// An app-level channel clients can join to receive the messages broadcast by the serverless functions.
+    pub type WsChannel = String;
+
+    pub type Clients = HashMap<Principal, ClientSession>;
+
+    /// The rate tokens of the clients, keyed by principal. They are kept across connections so that
+    /// reconnecting does not refill the buckets, until they were refilled anyway - the tokens are
+    /// pruned whenever a connection is closed.
+    #[derive(Default, Clone)]
+    pub struct WsRuntimeState {
+        pub message_rate_tokens: RateTokenStore,
+        pub subscription_rate_tokens: RateTokenStore,
+    }
+
+    /// The session of a connected client.
+    ///
+    /// The session is identified by the principal of the client, which is asserted - not banned
+    /// and, if required, a known user - once when the connection is opened.
+    pub struct ClientSession {
+        pub connected_at: Timestamp,
+        pub subscriptions: Vec<Subscription>,
+        pub channels: HashSet<WsChannel>,
+    }
+
+    /// A subscription of a connected client to the changes of a collection.
+    ///
+    /// Without any scope, the subscription covers the whole collection. Otherwise, all the
+    /// provided scopes - exact key, matcher and owner - must match for a change to be delivered.
+    /// The regexes of the matcher are compiled once when the subscription is registered.
+    ///
+    /// With `with_doc`, the notifications of the Datastore carry the document that was set or deleted.
+    #[derive(Clone)]
+    pub struct Subscription {
+        pub id: SubscriptionId,
+        pub collection: CollectionKey,
+        pub key: Option<Key>,
+        pub matcher: Option<ListMatcher>,
+        pub regex_key: Option<Regex>,
+        pub regex_description: Option<Regex>,
+        pub owner: Option<UserId>,
+        pub with_doc: bool,
+    }
+}
+
+pub mod interface {
+    use crate::changes::types::state::ChangeSequence;
+    use crate::ws::types::state::{SubscriptionId, WsChannel};
+    use candid::{CandidType, Principal};
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::rate::types::RateConfig;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::list::ListMatcher;
+    use junobuild_shared::types::state::{Timestamp, UserId, Version};
+    use serde::{Deserialize, Serialize};
+
+    /// Parameters for setting the WebSocket configuration.
+    ///
+    /// The `version` must match the one of the current configuration, if any, to apply the update.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct SetWsConfig {
+        pub gateway_principals: Vec<Principal>,
+        pub max_number_of_returned_messages: Option<usize>,
+        pub send_ack_interval_ms: Option<u64>,
+        pub require_user: Option<bool>,
+        pub message_rate_config: Option<RateConfig>,
+        pub subscription_rate_config: Option<RateConfig>,
+        pub version: Option<Version>,
+    }
+
+    /// The scope of a `subscribe` command, provided as its `data`.
+    ///
+    /// With `since`, the changes recorded after that sequence - e.g. while the client was
+    /// disconnected - are replayed before any live notification.
+    #[derive(Default, Deserialize, Clone)]
+    pub struct WsSubscribeData {
+        pub key: Option<Key>,
+        pub matcher: Option<ListMatcher>,
+        pub owner: Option<UserId>,
+        pub with_doc: Option<bool>,
+        pub since: Option<ChangeSequence>,
+    }
+
+    /// The `data` of an `unsubscribe` command. When an `id` is provided, only that subscription
+    /// is removed, otherwise all the subscriptions to the `collections` of the command.
+    #[derive(Default, Deserialize, Clone)]
+    pub struct WsUnsubscribeData {
+        pub id: Option<SubscriptionId>,
+    }
+
+    #[derive(Serialize, Clone)]
+    pub struct WsSubscriptionAck {
+        pub id: SubscriptionId,
+        pub collection: CollectionKey,
+    }
+
+    /// The `data` of a `join` or `leave` command.
+    #[derive(Default, Deserialize)]
+    pub struct WsChannelsData {
+        pub channels: Vec<WsChannel>,
+    }
+
+    /// A connected client, as listed by `ws_list_clients`.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct WsClient {
+        pub client: Principal,
+        pub connected_at: Timestamp,
+        pub subscriptions: Vec<CollectionKey>,
+        pub channels: Vec<WsChannel>,
+    }
+}
+
+pub mod notify {
+    use crate::db::types::state::Doc;
+    use crate::types::state::CollectionType;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+
+    /// The document or asset that triggered a notification, used to select the subscriptions
+    /// and subscribers that should receive it. For the Datastore, it also references the document
+    /// that can be attached to the notification.
+    pub struct NotificationSource<'a> {
+        pub collection_type: CollectionType,
+        pub owner: UserId,
+        pub description: Option<String>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub doc: Option<&'a Doc>,
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize, Principal};
+    use junobuild_shared::rate::types::RateConfig;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use serde::Serialize;
+
+    /// The configuration of the WebSocket subsystem.
+    ///
+    /// - `gateway_principals`: The gateways allowed to relay clients. When empty, any gateway is accepted.
+    /// - `max_number_of_returned_messages`: The maximal number of messages a gateway receives per poll.
+    /// - `send_ack_interval_ms`: The interval at which the satellite acknowledges the messages of the clients.
+    /// - `require_user`: Whether only the callers with a user - i.e. signed in - can open a connection.
+    /// - `message_rate_config`: The rate at which each client can send messages.
+    /// - `subscription_rate_config`: The rate at which each client can subscribe to collections.
+    ///
+    /// The parameters of the CDK are applied when the satellite is initialized or upgraded.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct WsConfig {
+        pub gateway_principals: Vec<Principal>,
+        pub max_number_of_returned_messages: Option<usize>,
+        pub send_ack_interval_ms: Option<u64>,
+        pub require_user: Option<bool>,
+        pub message_rate_config: Option<RateConfig>,
+        pub subscription_rate_config: Option<RateConfig>,
+        pub version: Option<Version>,
+        pub created_at: Option<Timestamp>,
+        pub updated_at: Option<Timestamp>,
+    }
+}
@@ -7,21 +7,75 @@ pub fn increment_and_assert_rate_store(
     key: &String,
     config: &Option<RateConfig>,
     rate_tokens: &mut RateTokenStore,
+) -> Result<(), String> {
+    increment_and_assert_rate_store_many(key, config, 1, rate_tokens)
+}
+
+/// Deducts `count` tokens at once: either all of them are available and consumed, or none is.
+pub fn increment_and_assert_rate_store_many(
+    key: &String,
+    config: &Option<RateConfig>,
+    count: usize,
+    rate_tokens: &mut RateTokenStore,
 ) -> Result<(), String> {
     let config = match config {
         Some(config) => config,
         None => return Ok(()),
     };
 
-    if let Some(tokens) = rate_tokens.get_mut(key) {
-        increment_and_assert_rate(config, tokens)?;
-    } else {
-        rate_tokens.insert(key.clone(), RateTokens::default());
+    increment_and_assert_rate_store_at(key, config, count, rate_tokens, time())
+}
+
+/// Asserts that `count` tokens could be deducted at once, without deducting them.
+pub fn assert_rate_store_many(
+    key: &String,
+    config: &Option<RateConfig>,
+    count: usize,
+    rate_tokens: &RateTokenStore,
+) -> Result<(), String> {
+    let config = match config {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    deduct_rate_tokens_at(key, config, count, rate_tokens, time()).map(|_| ())
+}
+
+fn increment_and_assert_rate_store_at(
+    key: &String,
+    config: &RateConfig,
+    count: usize,
+    rate_tokens: &mut RateTokenStore,
+    now: Timestamp,
+) -> Result<(), String> {
+    if let Some(tokens) = deduct_rate_tokens_at(key, config, count, rate_tokens, now)? {
+        rate_tokens.insert(key.clone(), tokens);
     }
 
     Ok(())
 }
 
+// The tokens are deducted from a copy, so that the store is left untouched if the rate is reached
+// along the way.
+fn deduct_rate_tokens_at(
+    key: &String,
+    config: &RateConfig,
+    count: usize,
+    rate_tokens: &RateTokenStore,
+    now: Timestamp,
+) -> Result<Option<RateTokens>, String> {
+    let mut tokens = rate_tokens.get(key).cloned();
+
+    for _ in 0..count {
+        match tokens.as_mut() {
+            Some(tokens) => increment_and_assert_rate_at(config, tokens, now)?,
+            None => tokens = Some(RateTokens::default()),
+        }
+    }
+
+    Ok(tokens)
+}
+
 pub fn increment_and_assert_rate(
     config: &RateConfig,
     tokens: &mut RateTokens,
@@ -56,6 +110,35 @@ fn increment_and_assert_rate_at(
     }
 }
 
+/// Removes the entries of the store whose tokens were refilled to the maximum - i.e. entries that
+/// are equivalent to no entry at all. The entries of the keys that recently consumed their tokens
+/// are kept.
+pub fn prune_rate_store(config: &Option<RateConfig>, rate_tokens: &mut RateTokenStore) {
+    prune_rate_store_at(config, rate_tokens, time())
+}
+
+fn prune_rate_store_at(
+    config: &Option<RateConfig>,
+    rate_tokens: &mut RateTokenStore,
+    now: Timestamp,
+) {
+    let Some(config) = config else {
+        // Without configuration the tokens are not asserted
+        rate_tokens.clear();
+        return;
+    };
+
+    rate_tokens.retain(|_, tokens| !is_refilled(config, tokens, now));
+}
+
+fn is_refilled(config: &RateConfig, tokens: &RateTokens, now: Timestamp) -> bool {
+    let missing_tokens = config.max_tokens.saturating_sub(tokens.tokens);
+
+    now.saturating_sub(tokens.updated_at)
+        .checked_div(config.time_per_token_ns)
+        .is_none_or(|new_tokens| new_tokens >= missing_tokens)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +283,127 @@ mod tests {
         assert_eq!(tokens.tokens, 5);
         assert_eq!(tokens.updated_at, 1000);
     }
+
+    // ---------- Store ----------
+
+    #[test]
+    fn consumes_all_the_tokens_of_the_store_at_once() {
+        let config = cfg(1000, 5);
+
+        let mut store = RateTokenStore::new();
+        store.insert(
+            "key".to_string(),
+            RateTokens {
+                tokens: 3,
+                updated_at: 10_000,
+            },
+        );
+
+        let result =
+            increment_and_assert_rate_store_at(&"key".to_string(), &config, 3, &mut store, 10_500);
+
+        assert!(result.is_ok());
+        assert_eq!(store.get("key").unwrap().tokens, 0);
+    }
+
+    #[test]
+    fn consumes_no_token_of_the_store_when_not_enough_are_available() {
+        let config = cfg(1000, 5);
+
+        let mut store = RateTokenStore::new();
+        store.insert(
+            "key".to_string(),
+            RateTokens {
+                tokens: 2,
+                updated_at: 10_000,
+            },
+        );
+
+        let result =
+            increment_and_assert_rate_store_at(&"key".to_string(), &config, 3, &mut store, 10_500);
+
+        assert!(result.is_err());
+        assert_eq!(store.get("key").unwrap().tokens, 2);
+        assert_eq!(store.get("key").unwrap().updated_at, 10_000);
+    }
+
+    #[test]
+    fn does_not_insert_a_new_key_when_not_enough_tokens_are_available() {
+        // The first call of a key is not counted, the next ones consume the refilled tokens
+        let config = cfg(1000, 1);
+
+        let mut store = RateTokenStore::new();
+
+        let result =
+            increment_and_assert_rate_store_at(&"key".to_string(), &config, 3, &mut store, 10_000);
+
+        assert!(result.is_err());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn asserts_the_tokens_of_the_store_without_consuming_them() {
+        let config = cfg(1000, 5);
+
+        let mut store = RateTokenStore::new();
+        store.insert(
+            "key".to_string(),
+            RateTokens {
+                tokens: 3,
+                updated_at: 10_000,
+            },
+        );
+
+        assert!(deduct_rate_tokens_at(&"key".to_string(), &config, 3, &store, 10_500).is_ok());
+        assert!(deduct_rate_tokens_at(&"key".to_string(), &config, 4, &store, 10_500).is_err());
+
+        assert_eq!(store.get("key").unwrap().tokens, 3);
+        assert_eq!(store.get("key").unwrap().updated_at, 10_000);
+    }
+
+    // ---------- Prune ----------
+
+    #[test]
+    fn prunes_refilled_tokens_only() {
+        let config = Some(cfg(100, 5));
+
+        let mut store = RateTokenStore::new();
+        store.insert(
+            "full".to_string(),
+            RateTokens {
+                tokens: 5,
+                updated_at: 1000,
+            },
+        );
+        store.insert(
+            "refilled".to_string(),
+            RateTokens {
+                tokens: 0,
+                updated_at: 500,
+            },
+        );
+        store.insert(
+            "exhausted".to_string(),
+            RateTokens {
+                tokens: 0,
+                updated_at: 900,
+            },
+        );
+
+        // "refilled" gained 5 tokens since 500, "exhausted" only 1 since 900
+        prune_rate_store_at(&config, &mut store, 1000);
+
+        assert_eq!(store.len(), 1);
+        assert!(store.contains_key("exhausted"));
+    }
+
+    #[test]
+    fn prunes_everything_without_config() {
+        let mut store = RateTokenStore::new();
+        store.insert("key".to_string(), RateTokens::default());
+
+        prune_rate_store_at(&None, &mut store, 1000);
+
+        assert!(store.is_empty());
+    }
 }
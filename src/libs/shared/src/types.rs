@@ -300,12 +300,70 @@ pub mod list {
         Between(Timestamp, Timestamp),
     }
 
+    /// A scalar value of the JSON data of a document.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum DataValue {
+        Null,
+        Bool(bool),
+        Number(f64),
+        Text(String),
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum IndexMatcher {
+        Equal(DataValue),
+        GreaterThan(DataValue),
+        LessThan(DataValue),
+        Between(DataValue, DataValue),
+    }
+
+    /// Matches the documents through one of the indexes declared in the rule of the collection.
+    ///
+    /// The `path` must be one of the indexed JSON paths - e.g. `status` or `author.name`.
+    /// Range lookups only match values of the same type as the bound(s).
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct ListIndexMatcher {
+        pub path: String,
+        pub value: IndexMatcher,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum DataPredicate {
+        Equal(DataValue),
+        NotEqual(DataValue),
+        GreaterThan(DataValue),
+        LessThan(DataValue),
+        In(Vec<DataValue>),
+        Exists(bool),
+        StartsWith(String),
+    }
+
+    /// A predicate on the value found at a JSON path of the data of a document - e.g. `status` or `author.name`.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct DataFieldMatcher {
+        pub path: String,
+        pub predicate: DataPredicate,
+    }
+
+    /// Matches the documents according to their decoded JSON data.
+    ///
+    /// Comparisons only match values of the same type - e.g. a number is never greater than a text.
+    /// `NotEqual` matches documents whose value is missing. Data that is not valid JSON is never matched.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum DataMatcher {
+        Field(DataFieldMatcher),
+        And(Vec<DataMatcher>),
+        Or(Vec<DataMatcher>),
+    }
+
     #[derive(Default, CandidType, Deserialize, Clone)]
     pub struct ListMatcher {
         pub key: Option<Key>,
         pub description: Option<String>,
         pub created_at: Option<TimestampMatcher>,
         pub updated_at: Option<TimestampMatcher>,
+        pub index: Option<ListIndexMatcher>,
+        pub data: Option<DataMatcher>,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
@@ -19,3 +19,10 @@ pub const JUNO_STORAGE_ERROR_CHUNK_TO_COMMIT_NOT_FOUND: &str =
 // Asset exceed max allowed size
 pub const JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE: &str =
     "juno.storage.error.asset_max_allowed_size";
+// The ownership of the assets of system collections cannot be transferred.
+pub const JUNO_STORAGE_ERROR_OWNERSHIP_TRANSFER_NOT_SUPPORTED: &str =
+    "juno.storage.error.ownership_transfer_not_supported";
+pub const JUNO_STORAGE_ERROR_INVALID_OWNER: &str = "juno.storage.error.invalid_owner";
+// Assets can be matched neither with an index nor with data predicates.
+pub const JUNO_STORAGE_ERROR_MATCHER_NOT_SUPPORTED: &str =
+    "juno.storage.error.matcher_not_supported";
@@ -18,7 +18,7 @@ use junobuild_shared::memory::serializers::{
 };
 use junobuild_shared::types::core::{Blob, Hash, Hashable};
 use junobuild_shared::types::state::Timestamped;
-use junobuild_shared::types::state::{Timestamp, Version, Versioned};
+use junobuild_shared::types::state::{Timestamp, UserId, Version, Versioned};
 use sha2::{Digest, Sha256};
 use std::borrow::Cow;
 use std::cmp::Ordering;
@@ -53,6 +53,13 @@ impl StorageHeapState {
                             updated_at: now,
                             version: rule.version,
                             rate_config: rule.rate_config,
+                            indexes: rule.indexes,
+                            default_ttl_ns: rule.default_ttl_ns,
+                            max_revisions: rule.max_revisions,
+                            trash_retention_ns: rule.trash_retention_ns,
+                            max_items_per_user: rule.max_items_per_user,
+                            max_bytes_per_user: rule.max_bytes_per_user,
+                            json_schema: rule.json_schema,
                         },
                     )
                 })
@@ -239,6 +246,23 @@ impl Asset {
             ..current_asset.clone()
         }
     }
+
+    /// The asset, including its content, is handed over to its new owner as is.
+    pub fn transfer(current_asset: &Asset, owner: UserId) -> Self {
+        let now = time();
+
+        let version = next_version(&Some(current_asset));
+
+        Self {
+            key: AssetKey {
+                owner,
+                ..current_asset.key.clone()
+            },
+            updated_at: now,
+            version: Some(version),
+            ..current_asset.clone()
+        }
+    }
 }
 
 impl Versioned for Asset {
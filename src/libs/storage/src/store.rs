@@ -360,6 +360,19 @@ fn commit_chunks(
         }
     }
 
+    if let Err(err) = assertions.assert_storage_items_usage(
+        &caller,
+        controllers,
+        &batch.key.collection,
+        rule,
+        current,
+        &encoding_type,
+        &encoding,
+    ) {
+        clear_runtime_batch(&batch_id, &chunk_ids);
+        return Err(err);
+    }
+
     storage_upload.insert_asset_encoding(
         &batch.reference_id,
         &batch.key.full_path,
@@ -68,6 +68,18 @@ pub trait StorageAssertionsStrategy {
         collection: &CollectionKey,
         max_changes_per_user: Option<u32>,
     ) -> Result<(), String>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn assert_storage_items_usage(
+        &self,
+        caller: &Principal,
+        controllers: &Controllers,
+        collection: &CollectionKey,
+        rule: &Rule,
+        current: &Option<Asset>,
+        encoding_type: &EncodingType,
+        encoding: &AssetEncoding,
+    ) -> Result<(), String>;
 }
 
 pub trait StorageStateStrategy {
@@ -103,7 +115,7 @@ pub trait StorageStateStrategy {
         full_path: &FullPath,
         asset: &Asset,
         rule: &Rule,
-    );
+    ) -> Result<(), String>;
 
     fn insert_asset_encoding(
         &self,
@@ -119,7 +131,7 @@ pub trait StorageStateStrategy {
         collection: &CollectionKey,
         full_path: &FullPath,
         rule: &Rule,
-    ) -> Option<Asset>;
+    ) -> Result<Option<Asset>, String>;
 
     fn init_certified_assets(&self);
 }
@@ -144,6 +144,12 @@ pub mod store {
         pub batch: Batch,
         pub commit_batch: CommitBatch,
     }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct AssetUpsert {
+        pub before: Option<Asset>,
+        pub after: Asset,
+    }
 }
 
 pub mod interface {
@@ -85,7 +85,7 @@ fn update_asset(
         &rule,
     );
 
-    storage_state.insert_asset(&collection, full_path, &asset, &rule);
+    storage_state.insert_asset(&collection, full_path, &asset, &rule)?;
 
     let config = storage_state.get_config();
 
@@ -104,7 +104,7 @@ fn delete_asset(
     // #app collection rule
     let rule = storage_state.get_rule(&collection)?;
 
-    let asset = storage_state.delete_asset(&collection, full_path, &rule);
+    let asset = storage_state.delete_asset(&collection, full_path, &rule)?;
 
     if let Some(asset) = asset {
         delete_certified_asset(&asset, certificate);
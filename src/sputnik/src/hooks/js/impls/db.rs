@@ -17,6 +17,8 @@ impl<'js> JsDoc<'js> {
             created_at: doc.created_at,
             updated_at: doc.updated_at,
             version: doc.version,
+            expires_at: doc.expires_at,
+            restorable_until: doc.restorable_until,
         })
     }
 }
@@ -27,6 +29,7 @@ impl<'js> JsSetDoc<'js> {
             data: JsRawData::from_bytes(ctx, &doc.data)?,
             description: doc.description,
             version: doc.version,
+            expires_at: doc.expires_at,
         })
     }
 }
@@ -45,6 +48,7 @@ impl<'js> JsSetDoc<'js> {
             data: self.data.to_vec()?,
             description: self.description.clone(),
             version: self.version,
+            expires_at: self.expires_at,
         })
     }
 }
@@ -93,6 +97,11 @@ impl<'js> IntoJs<'js> for JsDoc<'js> {
         obj.set("updated_at", into_bigint_js(ctx, self.updated_at))?;
 
         obj.set("version", into_optional_bigint_js(ctx, self.version)?)?;
+        obj.set("expires_at", into_optional_bigint_js(ctx, self.expires_at)?)?;
+        obj.set(
+            "restorable_until",
+            into_optional_bigint_js(ctx, self.restorable_until)?,
+        )?;
 
         Ok(obj.into_value())
     }
@@ -105,6 +114,7 @@ impl<'js> IntoJs<'js> for JsSetDoc<'js> {
         obj.set("description", self.description)?;
 
         obj.set("version", into_optional_bigint_js(ctx, self.version)?)?;
+        obj.set("expires_at", into_optional_bigint_js(ctx, self.expires_at)?)?;
 
         Ok(obj.into_value())
     }
@@ -135,10 +145,14 @@ impl<'js> FromJs<'js> for JsSetDoc<'js> {
         let version: Option<u64> =
             from_optional_bigint_js(obj.get::<_, Option<BigInt>>("version")?)?;
 
+        let expires_at: Option<u64> =
+            from_optional_bigint_js(obj.get::<_, Option<BigInt>>("expires_at")?)?;
+
         Ok(JsSetDoc {
             data,
             description,
             version,
+            expires_at,
         })
     }
 }
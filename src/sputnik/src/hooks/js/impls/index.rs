@@ -0,0 +1,98 @@
+use crate::hooks::js::types::list::{JsDataValue, JsIndexMatcher, JsListIndexMatcher};
+use junobuild_shared::types::list::{DataValue, IndexMatcher, ListIndexMatcher};
+use rquickjs::{Ctx, Error as JsError, FromJs, Object, Result as JsResult, Value};
+
+impl JsDataValue {
+    pub fn to_value(&self) -> DataValue {
+        match self {
+            JsDataValue::Null => DataValue::Null,
+            JsDataValue::Bool(value) => DataValue::Bool(*value),
+            JsDataValue::Number(value) => DataValue::Number(*value),
+            JsDataValue::Text(value) => DataValue::Text(value.clone()),
+        }
+    }
+}
+
+impl JsListIndexMatcher {
+    pub fn to_matcher(&self) -> ListIndexMatcher {
+        ListIndexMatcher {
+            path: self.path.clone(),
+            value: match &self.value {
+                JsIndexMatcher::Equal(value) => IndexMatcher::Equal(value.to_value()),
+                JsIndexMatcher::GreaterThan(value) => IndexMatcher::GreaterThan(value.to_value()),
+                JsIndexMatcher::LessThan(value) => IndexMatcher::LessThan(value.to_value()),
+                JsIndexMatcher::Between(start, end) => {
+                    IndexMatcher::Between(start.to_value(), end.to_value())
+                }
+            },
+        }
+    }
+}
+
+// ---------------------------------------------------------
+// FromJs
+// ---------------------------------------------------------
+
+impl<'js> FromJs<'js> for JsDataValue {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        if value.is_null() || value.is_undefined() {
+            return Ok(Self::Null);
+        }
+
+        if let Some(value) = value.as_bool() {
+            return Ok(Self::Bool(value));
+        }
+
+        if let Some(value) = value.as_number() {
+            return Ok(Self::Number(value));
+        }
+
+        if let Some(value) = value.as_string() {
+            return Ok(Self::Text(value.to_string()?));
+        }
+
+        Err(JsError::new_from_js("Value", "JsDataValue"))
+    }
+}
+
+impl<'js> FromJs<'js> for JsIndexMatcher {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if obj.contains_key("equal")? {
+            let value = JsDataValue::from_js(ctx, obj.get("equal")?)?;
+            return Ok(Self::Equal(value));
+        }
+
+        if obj.contains_key("greater_than")? {
+            let value = JsDataValue::from_js(ctx, obj.get("greater_than")?)?;
+            return Ok(Self::GreaterThan(value));
+        }
+
+        if obj.contains_key("less_than")? {
+            let value = JsDataValue::from_js(ctx, obj.get("less_than")?)?;
+            return Ok(Self::LessThan(value));
+        }
+
+        if let Ok(vec) = obj.get::<_, Vec<Value>>("between") {
+            if let [from, to] = vec.as_slice() {
+                let from = JsDataValue::from_js(ctx, from.clone())?;
+                let to = JsDataValue::from_js(ctx, to.clone())?;
+                return Ok(Self::Between(from, to));
+            }
+        }
+
+        Err(JsError::new_from_js("JsIndexMatcher", "Invalid matcher"))
+    }
+}
+
+impl<'js> FromJs<'js> for JsListIndexMatcher {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        Ok(Self {
+            path: obj.get("path")?,
+            value: JsIndexMatcher::from_js(ctx, obj.get("value")?)?,
+        })
+    }
+}
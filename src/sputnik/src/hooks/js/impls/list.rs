@@ -2,6 +2,7 @@ use crate::hooks::js::types::db::JsDoc;
 use crate::hooks::js::types::hooks::JsKey;
 use crate::hooks::js::types::interface::JsAssetNoContent;
 use crate::hooks::js::types::list::{
+    JsDataFieldMatcher, JsDataMatcher, JsDataPredicate, JsDataValue, JsListIndexMatcher,
     JsListMatcher, JsListOrder, JsListOrderField, JsListPaginate, JsListParams, JsListResults,
     JsTimestampMatcher,
 };
@@ -9,7 +10,8 @@ use crate::js::types::primitives::JsUsize;
 use crate::js::utils::primitives::from_bigint_js;
 use junobuild_satellite::Doc;
 use junobuild_shared::types::list::{
-    ListMatcher, ListOrder, ListOrderField, ListPaginate, ListParams, ListResults, TimestampMatcher,
+    DataFieldMatcher, DataMatcher, DataPredicate, ListMatcher, ListOrder, ListOrderField,
+    ListPaginate, ListParams, ListResults, TimestampMatcher,
 };
 use junobuild_storage::types::interface::AssetNoContent;
 use rquickjs::{
@@ -46,6 +48,8 @@ impl JsListMatcher {
                 .as_ref()
                 .map(|m| m.to_matcher())
                 .transpose()?,
+            index: self.index.as_ref().map(|m| m.to_matcher()),
+            data: self.data.as_ref().map(|m| m.to_matcher()),
         })
     }
 }
@@ -89,6 +93,39 @@ impl JsTimestampMatcher {
     }
 }
 
+impl JsDataMatcher {
+    pub fn to_matcher(&self) -> DataMatcher {
+        match self {
+            JsDataMatcher::Field(field) => DataMatcher::Field(field.to_matcher()),
+            JsDataMatcher::And(matchers) => {
+                DataMatcher::And(matchers.iter().map(|m| m.to_matcher()).collect())
+            }
+            JsDataMatcher::Or(matchers) => {
+                DataMatcher::Or(matchers.iter().map(|m| m.to_matcher()).collect())
+            }
+        }
+    }
+}
+
+impl JsDataFieldMatcher {
+    pub fn to_matcher(&self) -> DataFieldMatcher {
+        DataFieldMatcher {
+            path: self.path.clone(),
+            predicate: match &self.predicate {
+                JsDataPredicate::Equal(value) => DataPredicate::Equal(value.to_value()),
+                JsDataPredicate::NotEqual(value) => DataPredicate::NotEqual(value.to_value()),
+                JsDataPredicate::GreaterThan(value) => DataPredicate::GreaterThan(value.to_value()),
+                JsDataPredicate::LessThan(value) => DataPredicate::LessThan(value.to_value()),
+                JsDataPredicate::In(values) => {
+                    DataPredicate::In(values.iter().map(|v| v.to_value()).collect())
+                }
+                JsDataPredicate::Exists(exists) => DataPredicate::Exists(*exists),
+                JsDataPredicate::StartsWith(prefix) => DataPredicate::StartsWith(prefix.clone()),
+            },
+        }
+    }
+}
+
 impl<'js> JsListResults<JsDoc<'js>> {
     pub fn from_doc_results(ctx: &Ctx<'js>, results: &ListResults<Doc>) -> JsResult<Self> {
         Ok(Self {
@@ -254,6 +291,81 @@ impl<'js> FromJs<'js> for JsTimestampMatcher {
     }
 }
 
+impl<'js> FromJs<'js> for JsDataPredicate {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if obj.contains_key("equal")? {
+            let value = JsDataValue::from_js(ctx, obj.get("equal")?)?;
+            return Ok(Self::Equal(value));
+        }
+
+        if obj.contains_key("not_equal")? {
+            let value = JsDataValue::from_js(ctx, obj.get("not_equal")?)?;
+            return Ok(Self::NotEqual(value));
+        }
+
+        if obj.contains_key("greater_than")? {
+            let value = JsDataValue::from_js(ctx, obj.get("greater_than")?)?;
+            return Ok(Self::GreaterThan(value));
+        }
+
+        if obj.contains_key("less_than")? {
+            let value = JsDataValue::from_js(ctx, obj.get("less_than")?)?;
+            return Ok(Self::LessThan(value));
+        }
+
+        if let Ok(values) = obj.get::<_, Vec<Value>>("in") {
+            let values = values
+                .into_iter()
+                .map(|value| JsDataValue::from_js(ctx, value))
+                .collect::<JsResult<Vec<JsDataValue>>>()?;
+            return Ok(Self::In(values));
+        }
+
+        if let Ok(exists) = obj.get::<_, bool>("exists") {
+            return Ok(Self::Exists(exists));
+        }
+
+        if let Ok(prefix) = obj.get::<_, String>("starts_with") {
+            return Ok(Self::StartsWith(prefix));
+        }
+
+        Err(JsError::new_from_js("JsDataPredicate", "Invalid predicate"))
+    }
+}
+
+impl<'js> FromJs<'js> for JsDataMatcher {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if let Ok(field) = obj.get::<_, Object>("field") {
+            return Ok(Self::Field(JsDataFieldMatcher {
+                path: field.get("path")?,
+                predicate: JsDataPredicate::from_js(ctx, field.get("predicate")?)?,
+            }));
+        }
+
+        if let Ok(matchers) = obj.get::<_, Vec<Value>>("and") {
+            let matchers = matchers
+                .into_iter()
+                .map(|value| JsDataMatcher::from_js(ctx, value))
+                .collect::<JsResult<Vec<JsDataMatcher>>>()?;
+            return Ok(Self::And(matchers));
+        }
+
+        if let Ok(matchers) = obj.get::<_, Vec<Value>>("or") {
+            let matchers = matchers
+                .into_iter()
+                .map(|value| JsDataMatcher::from_js(ctx, value))
+                .collect::<JsResult<Vec<JsDataMatcher>>>()?;
+            return Ok(Self::Or(matchers));
+        }
+
+        Err(JsError::new_from_js("JsDataMatcher", "Invalid matcher"))
+    }
+}
+
 impl<'js> FromJs<'js> for JsListMatcher {
     fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
         let obj = Object::from_value(value)?;
@@ -268,11 +380,23 @@ impl<'js> FromJs<'js> for JsListMatcher {
             .map(|value| JsTimestampMatcher::from_js(ctx, value))
             .transpose()?;
 
+        let index = obj
+            .get::<_, Option<Value>>("index")?
+            .map(|value| JsListIndexMatcher::from_js(ctx, value))
+            .transpose()?;
+
+        let data = obj
+            .get::<_, Option<Value>>("data")?
+            .map(|value| JsDataMatcher::from_js(ctx, value))
+            .transpose()?;
+
         Ok(Self {
             key: obj.get("key").ok(),
             description: obj.get("description").ok(),
             created_at,
             updated_at,
+            index,
+            data,
         })
     }
 }
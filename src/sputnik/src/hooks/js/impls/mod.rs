@@ -1,6 +1,8 @@
 mod db;
 mod hooks;
+mod index;
 mod list;
 mod rules;
 mod shared;
 mod storage;
+mod ws;
@@ -0,0 +1,34 @@
+use crate::hooks::js::types::ws::JsWsClient;
+use crate::js::types::candid::JsRawPrincipal;
+use crate::js::utils::primitives::into_bigint_js;
+use junobuild_satellite::WsClient;
+use rquickjs::{Ctx, IntoJs, Object, Result as JsResult, Value};
+
+impl<'js> JsWsClient<'js> {
+    pub fn from_client(ctx: &Ctx<'js>, client: WsClient) -> JsResult<Self> {
+        Ok(Self {
+            client: JsRawPrincipal::from_principal(ctx, &client.client)?,
+            connected_at: client.connected_at,
+            subscriptions: client.subscriptions,
+            channels: client.channels,
+        })
+    }
+}
+
+// ---------------------------------------------------------
+// IntoJs
+// ---------------------------------------------------------
+
+impl<'js> IntoJs<'js> for JsWsClient<'js> {
+    fn into_js(self, ctx: &Ctx<'js>) -> JsResult<Value<'js>> {
+        let obj = Object::new(ctx.clone())?;
+        obj.set("client", self.client)?;
+
+        obj.set("connected_at", into_bigint_js(ctx, self.connected_at))?;
+
+        obj.set("subscriptions", self.subscriptions)?;
+        obj.set("channels", self.channels)?;
+
+        Ok(obj.into_value())
+    }
+}
@@ -0,0 +1,16 @@
+mod ws_broadcast;
+mod ws_list_clients;
+mod ws_send;
+
+use crate::hooks::js::sdk::ws::ws_broadcast::init_ws_broadcast;
+use crate::hooks::js::sdk::ws::ws_list_clients::init_ws_list_clients;
+use crate::hooks::js::sdk::ws::ws_send::init_ws_send;
+use rquickjs::{Ctx, Error as JsError};
+
+pub fn init_ws_sdk(ctx: &Ctx) -> Result<(), JsError> {
+    init_ws_send(ctx)?;
+    init_ws_broadcast(ctx)?;
+    init_ws_list_clients(ctx)?;
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::hooks::js::types::hooks::JsRawData;
+use crate::js::types::primitives::JsUsize;
+use junobuild_satellite::ws_broadcast as ws_broadcast_sdk;
+use rquickjs::{Ctx, Error as JsError, Exception, Result as JsResult};
+
+pub fn init_ws_broadcast(ctx: &Ctx) -> Result<(), JsError> {
+    let global = ctx.globals();
+
+    global.set("__juno_satellite_ws_broadcast", js_ws_broadcast)?;
+
+    Ok(())
+}
+
+#[rquickjs::function]
+fn ws_broadcast<'js>(ctx: Ctx<'js>, channel: String, payload: JsRawData<'js>) -> JsResult<JsUsize> {
+    let sent = ws_broadcast_sdk(&channel, payload.to_vec()?)
+        .map_err(|e| Exception::throw_message(&ctx, &e))?;
+    Ok(JsUsize(sent))
+}
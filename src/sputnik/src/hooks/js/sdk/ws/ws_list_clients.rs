@@ -0,0 +1,19 @@
+use crate::hooks::js::types::ws::JsWsClient;
+use junobuild_satellite::ws_list_clients as ws_list_clients_sdk;
+use rquickjs::{Ctx, Error as JsError, Result as JsResult};
+
+pub fn init_ws_list_clients(ctx: &Ctx) -> Result<(), JsError> {
+    let global = ctx.globals();
+
+    global.set("__juno_satellite_ws_list_clients", js_ws_list_clients)?;
+
+    Ok(())
+}
+
+#[rquickjs::function]
+fn ws_list_clients<'js>(ctx: Ctx<'js>) -> JsResult<Vec<JsWsClient<'js>>> {
+    ws_list_clients_sdk()
+        .into_iter()
+        .map(|client| JsWsClient::from_client(&ctx, client))
+        .collect()
+}
@@ -0,0 +1,18 @@
+use crate::hooks::js::types::hooks::JsRawData;
+use crate::hooks::js::types::shared::JsUserId;
+use junobuild_satellite::ws_send as ws_send_sdk;
+use rquickjs::{Ctx, Error as JsError, Exception, Result as JsResult};
+
+pub fn init_ws_send(ctx: &Ctx) -> Result<(), JsError> {
+    let global = ctx.globals();
+
+    global.set("__juno_satellite_ws_send", js_ws_send)?;
+
+    Ok(())
+}
+
+#[rquickjs::function]
+fn ws_send<'js>(ctx: Ctx<'js>, client: JsUserId<'js>, payload: JsRawData<'js>) -> JsResult<()> {
+    ws_send_sdk(client.to_principal()?, payload.to_vec()?)
+        .map_err(|e| Exception::throw_message(&ctx, &e))
+}
@@ -107,6 +107,8 @@ pub mod db {
         pub created_at: JsTimestamp,
         pub updated_at: JsTimestamp,
         pub version: Option<JsVersion>,
+        pub expires_at: Option<JsTimestamp>,
+        pub restorable_until: Option<JsTimestamp>,
     }
 }
 
@@ -186,6 +188,7 @@ pub mod interface {
         pub data: JsRawData<'js>,
         pub description: Option<String>,
         pub version: Option<JsVersion>,
+        pub expires_at: Option<JsTimestamp>,
     }
 
     #[derive(Clone)]
@@ -256,12 +259,60 @@ pub mod list {
         Between(JsTimestamp, JsTimestamp),
     }
 
+    #[derive(Clone)]
+    pub enum JsDataValue {
+        Null,
+        Bool(bool),
+        Number(f64),
+        Text(String),
+    }
+
+    #[derive(Clone)]
+    pub enum JsIndexMatcher {
+        Equal(JsDataValue),
+        GreaterThan(JsDataValue),
+        LessThan(JsDataValue),
+        Between(JsDataValue, JsDataValue),
+    }
+
+    #[derive(Clone)]
+    pub struct JsListIndexMatcher {
+        pub path: String,
+        pub value: JsIndexMatcher,
+    }
+
+    #[derive(Clone)]
+    pub enum JsDataPredicate {
+        Equal(JsDataValue),
+        NotEqual(JsDataValue),
+        GreaterThan(JsDataValue),
+        LessThan(JsDataValue),
+        In(Vec<JsDataValue>),
+        Exists(bool),
+        StartsWith(String),
+    }
+
+    #[derive(Clone)]
+    pub struct JsDataFieldMatcher {
+        pub path: String,
+        pub predicate: JsDataPredicate,
+    }
+
+    #[derive(Clone)]
+    pub enum JsDataMatcher {
+        Field(JsDataFieldMatcher),
+        And(Vec<JsDataMatcher>),
+        Or(Vec<JsDataMatcher>),
+    }
+
     #[derive(Clone)]
     pub struct JsListMatcher {
         pub key: Option<JsKey>,
         pub description: Option<String>,
         pub created_at: Option<JsTimestampMatcher>,
         pub updated_at: Option<JsTimestampMatcher>,
+        pub index: Option<JsListIndexMatcher>,
+        pub data: Option<JsDataMatcher>,
     }
 
     #[derive(Clone)]
@@ -281,3 +332,15 @@ pub mod list {
         pub matches_pages: Option<JsUsize>,
     }
 }
+
+pub mod ws {
+    use crate::hooks::js::types::shared::{JsCollectionKey, JsTimestamp, JsUserId};
+
+    #[derive(Clone)]
+    pub struct JsWsClient<'js> {
+        pub client: JsUserId<'js>,
+        pub connected_at: JsTimestamp,
+        pub subscriptions: Vec<JsCollectionKey>,
+        pub channels: Vec<String>,
+    }
+}
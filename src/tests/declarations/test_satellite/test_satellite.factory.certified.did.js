@@ -7,12 +7,38 @@
 // Additionally, you should also exclude this file from your linter and/or formatter to prevent it from being checked or modified.
 
 export const idlFactory = ({ IDL }) => {
+	const DataMatcher = IDL.Rec();
 	const Memory = IDL.Variant({ Heap: IDL.Null, Stable: IDL.Null });
 	const InitStorageArgs = IDL.Record({ system_memory: IDL.Opt(Memory) });
 	const InitSatelliteArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal),
 		storage: IDL.Opt(InitStorageArgs)
 	});
+	const SetDoc = IDL.Record({
+		data: IDL.Vec(IDL.Nat8),
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Opt(IDL.Nat64),
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const DelDoc = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
+	const DocsBatchOperation = IDL.Variant({
+		Set: IDL.Record({
+			doc: SetDoc,
+			key: IDL.Text,
+			collection: IDL.Text
+		}),
+		AssertVersion: IDL.Record({
+			key: IDL.Text,
+			collection: IDL.Text,
+			version: IDL.Opt(IDL.Nat64)
+		}),
+		Delete: IDL.Record({
+			doc: DelDoc,
+			key: IDL.Text,
+			collection: IDL.Text
+		})
+	});
+	const Result = IDL.Variant({ Ok: IDL.Int32, Err: IDL.Text });
 	const OpenIdPrepareDelegationArgs = IDL.Record({
 		jwt: IDL.Text,
 		session_key: IDL.Vec(IDL.Nat8),
@@ -23,11 +49,13 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const Doc = IDL.Record({
 		updated_at: IDL.Nat64,
+		restorable_until: IDL.Opt(IDL.Nat64),
 		owner: IDL.Principal,
 		data: IDL.Vec(IDL.Nat8),
 		description: IDL.Opt(IDL.Text),
 		created_at: IDL.Nat64,
-		version: IDL.Opt(IDL.Nat64)
+		version: IDL.Opt(IDL.Nat64),
+		expires_at: IDL.Opt(IDL.Nat64)
 	});
 	const PreparedDelegation = IDL.Record({
 		user_key: IDL.Vec(IDL.Nat8),
@@ -96,11 +124,49 @@ export const idlFactory = ({ IDL }) => {
 		GreaterThan: IDL.Nat64,
 		LessThan: IDL.Nat64
 	});
+	const DataValue = IDL.Variant({
+		Bool: IDL.Bool,
+		Null: IDL.Null,
+		Text: IDL.Text,
+		Number: IDL.Float64
+	});
+	const DataPredicate = IDL.Variant({
+		In: IDL.Vec(DataValue),
+		Equal: DataValue,
+		GreaterThan: DataValue,
+		LessThan: DataValue,
+		NotEqual: DataValue,
+		StartsWith: IDL.Text,
+		Exists: IDL.Bool
+	});
+	const DataFieldMatcher = IDL.Record({
+		path: IDL.Text,
+		predicate: DataPredicate
+	});
+	DataMatcher.fill(
+		IDL.Variant({
+			Or: IDL.Vec(DataMatcher),
+			And: IDL.Vec(DataMatcher),
+			Field: DataFieldMatcher
+		})
+	);
+	const IndexMatcher = IDL.Variant({
+		Equal: DataValue,
+		Between: IDL.Tuple(DataValue, DataValue),
+		GreaterThan: DataValue,
+		LessThan: DataValue
+	});
+	const ListIndexMatcher = IDL.Record({
+		value: IndexMatcher,
+		path: IDL.Text
+	});
 	const ListMatcher = IDL.Record({
 		key: IDL.Opt(IDL.Text),
 		updated_at: IDL.Opt(TimestampMatcher),
+		data: IDL.Opt(DataMatcher),
 		description: IDL.Opt(IDL.Text),
-		created_at: IDL.Opt(TimestampMatcher)
+		created_at: IDL.Opt(TimestampMatcher),
+		index: IDL.Opt(ListIndexMatcher)
 	});
 	const ListPaginate = IDL.Record({
 		start_after: IDL.Opt(IDL.Text),
@@ -127,7 +193,6 @@ export const idlFactory = ({ IDL }) => {
 		scope: ControllerScope,
 		expires_at: IDL.Opt(IDL.Nat64)
 	});
-	const DelDoc = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const CollectionType = IDL.Variant({ Db: IDL.Null, Storage: IDL.Null });
 	const DelRule = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const DeleteProposalAssets = IDL.Record({
@@ -137,6 +202,29 @@ export const idlFactory = ({ IDL }) => {
 		cycles: IDL.Nat,
 		destination_id: IDL.Principal
 	});
+	const ExportContentCursor = IDL.Record({
+		chunk_index: IDL.Nat64,
+		offset: IDL.Nat64,
+		encoding_type: IDL.Text
+	});
+	const ExportCursor = IDL.Record({
+		key: IDL.Text,
+		content: IDL.Opt(ExportContentCursor)
+	});
+	const ExportFormat = IDL.Variant({
+		Cbor: IDL.Null,
+		JsonLines: IDL.Null
+	});
+	const ExportCollectionArgs = IDL.Record({
+		collection: IDL.Text,
+		cursor: IDL.Opt(ExportCursor),
+		collection_type: CollectionType,
+		format: ExportFormat
+	});
+	const ExportCollectionResult = IDL.Record({
+		cursor: IDL.Opt(ExportCursor),
+		data: IDL.Vec(IDL.Nat8)
+	});
 	const AssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
@@ -253,6 +341,17 @@ export const idlFactory = ({ IDL }) => {
 		Ok: SignedDelegation,
 		Err: GetDelegationError
 	});
+	const DocGrant = IDL.Record({
+		user: IDL.Principal,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const DocAccess = IDL.Record({
+		readers: IDL.Vec(DocGrant),
+		updated_at: IDL.Nat64,
+		created_at: IDL.Nat64,
+		version: IDL.Opt(IDL.Nat64),
+		writers: IDL.Vec(DocGrant)
+	});
 	const ProposalStatus = IDL.Variant({
 		Initialized: IDL.Null,
 		Failed: IDL.Null,
@@ -286,7 +385,9 @@ export const idlFactory = ({ IDL }) => {
 	const Permission = IDL.Variant({
 		Controllers: IDL.Null,
 		Private: IDL.Null,
+		Roles: IDL.Vec(IDL.Text),
 		Public: IDL.Null,
+		Principals: IDL.Vec(IDL.Principal),
 		Managed: IDL.Null
 	});
 	const RateConfig = IDL.Record({
@@ -296,16 +397,39 @@ export const idlFactory = ({ IDL }) => {
 	const Rule = IDL.Record({
 		max_capacity: IDL.Opt(IDL.Nat32),
 		memory: IDL.Opt(Memory),
+		max_items_per_user: IDL.Opt(IDL.Nat32),
 		updated_at: IDL.Nat64,
 		max_size: IDL.Opt(IDL.Nat),
+		trash_retention_ns: IDL.Opt(IDL.Nat64),
+		default_ttl_ns: IDL.Opt(IDL.Nat64),
 		read: Permission,
 		created_at: IDL.Nat64,
+		json_schema: IDL.Opt(IDL.Text),
 		version: IDL.Opt(IDL.Nat64),
 		mutable_permissions: IDL.Opt(IDL.Bool),
+		max_revisions: IDL.Opt(IDL.Nat32),
 		rate_config: IDL.Opt(RateConfig),
 		write: Permission,
+		indexes: IDL.Opt(IDL.Vec(IDL.Text)),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64),
 		max_changes_per_user: IDL.Opt(IDL.Nat32)
 	});
+	const UserUsageData = IDL.Record({
+		items_count: IDL.Nat32,
+		changes_count: IDL.Nat32,
+		items_bytes: IDL.Nat64
+	});
+	const WsConfig = IDL.Record({
+		message_rate_config: IDL.Opt(RateConfig),
+		updated_at: IDL.Opt(IDL.Nat64),
+		max_number_of_returned_messages: IDL.Opt(IDL.Nat64),
+		created_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		gateway_principals: IDL.Vec(IDL.Principal),
+		send_ack_interval_ms: IDL.Opt(IDL.Nat64),
+		require_user: IDL.Opt(IDL.Bool),
+		subscription_rate_config: IDL.Opt(RateConfig)
+	});
 	const HttpRequest = IDL.Record({
 		url: IDL.Text,
 		method: IDL.Text,
@@ -338,6 +462,22 @@ export const idlFactory = ({ IDL }) => {
 		token: IDL.Opt(StreamingCallbackToken),
 		body: IDL.Vec(IDL.Nat8)
 	});
+	const ImportCollectionArgs = IDL.Record({
+		collection: IDL.Text,
+		data: IDL.Vec(IDL.Nat8),
+		collection_type: CollectionType,
+		dry_run: IDL.Bool,
+		format: ExportFormat
+	});
+	const ImportConflict = IDL.Record({
+		key: IDL.Text,
+		imported_version: IDL.Opt(IDL.Nat64),
+		current_version: IDL.Opt(IDL.Nat64)
+	});
+	const ImportCollectionResult = IDL.Record({
+		imported: IDL.Nat64,
+		conflicts: IDL.Vec(ImportConflict)
+	});
 	const InitAssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
@@ -354,12 +494,47 @@ export const idlFactory = ({ IDL }) => {
 		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
 		items_length: IDL.Nat64
 	});
+	const NotificationDoc = IDL.Record({
+		updated_at: IDL.Nat64,
+		data: IDL.Vec(IDL.Nat8),
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Opt(IDL.Nat64)
+	});
+	const NotificationMessage = IDL.Record({
+		doc: IDL.Opt(NotificationDoc),
+		key: IDL.Text,
+		collection: IDL.Text,
+		type: IDL.Text,
+		timestamp: IDL.Nat64,
+		caller: IDL.Text,
+		doc_omitted: IDL.Bool,
+		sequence: IDL.Opt(IDL.Nat64)
+	});
+	const ListChangesResults = IDL.Record({
+		last_sequence: IDL.Nat64,
+		items: IDL.Vec(NotificationMessage)
+	});
 	const CustomDomain = IDL.Record({
 		updated_at: IDL.Nat64,
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64),
 		bn_id: IDL.Opt(IDL.Text)
 	});
+	const DocRevision = IDL.Record({
+		updated_at: IDL.Nat64,
+		owner: IDL.Principal,
+		data: IDL.Vec(IDL.Nat8),
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Nat64,
+		caller: IDL.Principal
+	});
+	const ListResults_2 = IDL.Record({
+		matches_pages: IDL.Opt(IDL.Nat64),
+		matches_length: IDL.Nat64,
+		items_page: IDL.Opt(IDL.Nat64),
+		items: IDL.Vec(IDL.Tuple(IDL.Text, DocRevision)),
+		items_length: IDL.Nat64
+	});
 	const ListResults_1 = IDL.Record({
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
@@ -390,6 +565,49 @@ export const idlFactory = ({ IDL }) => {
 		items_length: IDL.Nat64
 	});
 	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const CollectionMigrationStatus = IDL.Variant({
+		Cleaning: IDL.Null,
+		Copying: IDL.Null,
+		Completed: IDL.Null
+	});
+	const CollectionMigration = IDL.Record({
+		memory: Memory,
+		status: CollectionMigrationStatus,
+		updated_at: IDL.Nat64,
+		deleted: IDL.Nat64,
+		created_at: IDL.Nat64,
+		copied: IDL.Nat64,
+		last_key: IDL.Opt(IDL.Text)
+	});
+	const DocPatch = IDL.Variant({
+		Json: IDL.Vec(IDL.Nat8),
+		Merge: IDL.Vec(IDL.Nat8)
+	});
+	const PatchDoc = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		patch: DocPatch
+	});
+	const UserPurgeStatus = IDL.Variant({
+		Docs: IDL.Null,
+		User: IDL.Null,
+		Shares: IDL.Null,
+		Assets: IDL.Null,
+		Trash: IDL.Null,
+		Completed: IDL.Null
+	});
+	const UserPurge = IDL.Record({
+		status: UserPurgeStatus,
+		updated_at: IDL.Nat64,
+		deleted_docs: IDL.Nat64,
+		collection: IDL.Opt(IDL.Text),
+		created_at: IDL.Nat64,
+		deleted_assets: IDL.Nat64,
+		last_key: IDL.Opt(IDL.Text)
+	});
+	const RestoreDocRevision = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		revision: IDL.Nat64
+	});
 	const SetAuthenticationConfig = IDL.Record({
 		openid: IDL.Opt(AuthenticationConfigOpenId),
 		version: IDL.Opt(IDL.Nat64),
@@ -409,20 +627,27 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize)
 	});
-	const SetDoc = IDL.Record({
-		data: IDL.Vec(IDL.Nat8),
-		description: IDL.Opt(IDL.Text),
-		version: IDL.Opt(IDL.Nat64)
+	const SetDocAccess = IDL.Record({
+		readers: IDL.Vec(DocGrant),
+		version: IDL.Opt(IDL.Nat64),
+		writers: IDL.Vec(DocGrant)
 	});
 	const SetRule = IDL.Record({
 		max_capacity: IDL.Opt(IDL.Nat32),
 		memory: IDL.Opt(Memory),
+		max_items_per_user: IDL.Opt(IDL.Nat32),
 		max_size: IDL.Opt(IDL.Nat),
+		trash_retention_ns: IDL.Opt(IDL.Nat64),
+		default_ttl_ns: IDL.Opt(IDL.Nat64),
 		read: Permission,
+		json_schema: IDL.Opt(IDL.Text),
 		version: IDL.Opt(IDL.Nat64),
 		mutable_permissions: IDL.Opt(IDL.Bool),
+		max_revisions: IDL.Opt(IDL.Nat32),
 		rate_config: IDL.Opt(RateConfig),
 		write: Permission,
+		indexes: IDL.Opt(IDL.Vec(IDL.Text)),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64),
 		max_changes_per_user: IDL.Opt(IDL.Nat32)
 	});
 	const SetStorageConfig = IDL.Record({
@@ -434,15 +659,62 @@ export const idlFactory = ({ IDL }) => {
 		raw_access: IDL.Opt(StorageConfigRawAccess),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
+	const SetWsConfig = IDL.Record({
+		message_rate_config: IDL.Opt(RateConfig),
+		max_number_of_returned_messages: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		gateway_principals: IDL.Vec(IDL.Principal),
+		send_ack_interval_ms: IDL.Opt(IDL.Nat64),
+		require_user: IDL.Opt(IDL.Bool),
+		subscription_rate_config: IDL.Opt(RateConfig)
+	});
+	const TransferOwnership = IDL.Record({
+		owner: IDL.Principal,
+		version: IDL.Opt(IDL.Nat64)
+	});
 	const UploadChunk = IDL.Record({
 		content: IDL.Vec(IDL.Nat8),
 		batch_id: IDL.Nat,
 		order_id: IDL.Opt(IDL.Nat)
 	});
 	const UploadChunkResult = IDL.Record({ chunk_id: IDL.Nat });
-	const Result = IDL.Variant({ Ok: IDL.Int32, Err: IDL.Text });
+	const ClientKey = IDL.Record({
+		client_principal: IDL.Principal,
+		client_nonce: IDL.Nat64
+	});
+	const CanisterWsCloseArguments = IDL.Record({ client_key: ClientKey });
+	const Result_1 = IDL.Variant({ Ok: IDL.Null, Err: IDL.Text });
+	const CanisterWsGetMessagesArguments = IDL.Record({ nonce: IDL.Nat64 });
+	const CanisterOutputMessage = IDL.Record({
+		key: IDL.Text,
+		content: IDL.Vec(IDL.Nat8),
+		client_key: ClientKey
+	});
+	const CanisterOutputCertifiedMessages = IDL.Record({
+		messages: IDL.Vec(CanisterOutputMessage),
+		cert: IDL.Vec(IDL.Nat8),
+		tree: IDL.Vec(IDL.Nat8),
+		is_end_of_queue: IDL.Bool
+	});
+	const Result_2 = IDL.Variant({
+		Ok: CanisterOutputCertifiedMessages,
+		Err: IDL.Text
+	});
+	const WebsocketMessage = IDL.Record({
+		sequence_num: IDL.Nat64,
+		content: IDL.Vec(IDL.Nat8),
+		client_key: ClientKey,
+		timestamp: IDL.Nat64,
+		is_service_message: IDL.Bool
+	});
+	const CanisterWsMessageArguments = IDL.Record({ msg: WebsocketMessage });
+	const CanisterWsOpenArguments = IDL.Record({
+		gateway_principal: IDL.Principal,
+		client_nonce: IDL.Nat64
+	});
 
 	return IDL.Service({
+		apply_docs_batch: IDL.Func([IDL.Vec(DocsBatchOperation)], [Result], []),
 		authenticate: IDL.Func([AuthenticationArgs], [AuthenticateResultResponse], []),
 		commit_asset_upload: IDL.Func([CommitBatch], [], []),
 		commit_proposal: IDL.Func([CommitProposal], [IDL.Null], []),
@@ -470,12 +742,14 @@ export const idlFactory = ({ IDL }) => {
 		del_rule: IDL.Func([CollectionType, IDL.Text, DelRule], [], []),
 		delete_proposal_assets: IDL.Func([DeleteProposalAssets], [], []),
 		deposit_cycles: IDL.Func([DepositCyclesArgs], [], []),
+		export_collection: IDL.Func([ExportCollectionArgs], [ExportCollectionResult], []),
 		get_asset: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(AssetNoContent)], []),
 		get_auth_config: IDL.Func([], [IDL.Opt(AuthenticationConfig)], []),
 		get_config: IDL.Func([], [Config], []),
 		get_db_config: IDL.Func([], [IDL.Opt(DbConfig)], []),
 		get_delegation: IDL.Func([GetDelegationArgs], [GetDelegationResultResponse], []),
 		get_doc: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(Doc)], []),
+		get_doc_access: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(DocAccess)], []),
 		get_many_assets: IDL.Func(
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))],
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Opt(AssetNoContent)))],
@@ -489,12 +763,15 @@ export const idlFactory = ({ IDL }) => {
 		get_proposal: IDL.Func([IDL.Nat], [IDL.Opt(Proposal)], []),
 		get_rule: IDL.Func([CollectionType, IDL.Text], [IDL.Opt(Rule)], []),
 		get_storage_config: IDL.Func([], [StorageConfig], []),
+		get_user_usage: IDL.Func([CollectionType, IDL.Text], [IDL.Opt(UserUsageData)], []),
+		get_ws_config: IDL.Func([], [IDL.Opt(WsConfig)], []),
 		http_request: IDL.Func([HttpRequest], [HttpResponse], []),
 		http_request_streaming_callback: IDL.Func(
 			[StreamingCallbackToken],
 			[StreamingCallbackHttpResponse],
 			[]
 		),
+		import_collection: IDL.Func([ImportCollectionArgs], [ImportCollectionResult], []),
 		init_asset_upload: IDL.Func([InitAssetKey], [InitUploadResult], []),
 		init_proposal: IDL.Func([ProposalType], [IDL.Nat, Proposal], []),
 		init_proposal_asset_upload: IDL.Func([InitAssetKey, IDL.Nat], [InitUploadResult], []),
@@ -504,13 +781,30 @@ export const idlFactory = ({ IDL }) => {
 			[]
 		),
 		list_assets: IDL.Func([IDL.Text, ListParams], [ListResults], []),
+		list_changes: IDL.Func(
+			[IDL.Nat64, IDL.Opt(IDL.Vec(IDL.Text)), IDL.Opt(IDL.Nat64)],
+			[ListChangesResults],
+			[]
+		),
 		list_controllers: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Controller))], []),
 		list_custom_domains: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, CustomDomain))], []),
+		list_doc_revisions: IDL.Func([IDL.Text, IDL.Text, ListParams], [ListResults_2], []),
 		list_docs: IDL.Func([IDL.Text, ListParams], [ListResults_1], []),
 		list_proposals: IDL.Func([ListProposalsParams], [ListProposalResults], []),
 		list_rules: IDL.Func([CollectionType, ListRulesParams], [ListRulesResults], []),
+		list_shared_docs: IDL.Func([IDL.Text, ListParams], [ListResults_1], []),
+		list_trashed_docs: IDL.Func([IDL.Text, ListParams], [ListResults_1], []),
 		memory_size: IDL.Func([], [MemorySize], []),
+		migrate_collection_memory: IDL.Func(
+			[CollectionType, IDL.Text, Memory],
+			[CollectionMigration],
+			[]
+		),
+		patch_doc: IDL.Func([IDL.Text, IDL.Text, PatchDoc], [Doc], []),
+		purge_user_data: IDL.Func([IDL.Principal], [UserPurge], []),
 		reject_proposal: IDL.Func([CommitProposal], [IDL.Null], []),
+		restore_doc: IDL.Func([IDL.Text, IDL.Text], [Doc], []),
+		restore_doc_revision: IDL.Func([IDL.Text, IDL.Text, RestoreDocRevision], [Doc], []),
 		set_asset_token: IDL.Func([IDL.Text, IDL.Text, IDL.Opt(IDL.Text)], [], []),
 		set_auth_config: IDL.Func([SetAuthenticationConfig], [AuthenticationConfig], []),
 		set_controllers: IDL.Func(
@@ -521,6 +815,7 @@ export const idlFactory = ({ IDL }) => {
 		set_custom_domain: IDL.Func([IDL.Text, IDL.Opt(IDL.Text)], [], []),
 		set_db_config: IDL.Func([SetDbConfig], [DbConfig], []),
 		set_doc: IDL.Func([IDL.Text, IDL.Text, SetDoc], [Doc], []),
+		set_doc_access: IDL.Func([IDL.Text, IDL.Text, SetDocAccess], [DocAccess], []),
 		set_many_docs: IDL.Func(
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text, SetDoc))],
 			[IDL.Vec(IDL.Tuple(IDL.Text, Doc))],
@@ -528,10 +823,23 @@ export const idlFactory = ({ IDL }) => {
 		),
 		set_rule: IDL.Func([CollectionType, IDL.Text, SetRule], [Rule], []),
 		set_storage_config: IDL.Func([SetStorageConfig], [StorageConfig], []),
+		set_ws_config: IDL.Func([SetWsConfig], [WsConfig], []),
 		submit_proposal: IDL.Func([IDL.Nat], [IDL.Nat, Proposal], []),
 		switch_storage_system_memory: IDL.Func([], [], []),
+		transfer_asset_ownership: IDL.Func(
+			[IDL.Text, IDL.Text, TransferOwnership],
+			[AssetNoContent],
+			[]
+		),
+		transfer_doc_ownership: IDL.Func([IDL.Text, IDL.Text, TransferOwnership], [Doc], []),
 		upload_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], []),
 		upload_proposal_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], []),
+		ws_close: IDL.Func([CanisterWsCloseArguments], [Result_1], []),
+		ws_get_gateway_url: IDL.Func([], [IDL.Text], []),
+		ws_get_messages: IDL.Func([CanisterWsGetMessagesArguments], [Result_2], []),
+		ws_message: IDL.Func([CanisterWsMessageArguments], [Result_1], []),
+		ws_open: IDL.Func([CanisterWsOpenArguments], [Result_1], []),
+		ws_stats: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text, IDL.Nat64))], []),
 		get_random: IDL.Func([], [Result], []),
 		whoami: IDL.Func([], [IDL.Principal], [])
 	});
@@ -56,6 +56,7 @@ fn on_set_doc(context: OnSetDocContext) -> Result<(), String> {
                 data: encode_doc_data(&update_data)?,
                 description: None,
                 version: context.data.data.after.version,
+                expires_at: None,
             };
 
             let _ = set_doc_store(id(), "test_utils".to_string(), context.data.key, doc)?;
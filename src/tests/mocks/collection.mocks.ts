@@ -10,5 +10,12 @@ export const mockSetRule: SatelliteDid.SetRule = {
 	write: { Managed: null },
 	version: toNullable(),
 	rate_config: toNullable(),
-	max_changes_per_user: toNullable()
+	max_changes_per_user: toNullable(),
+	max_items_per_user: toNullable(),
+	max_bytes_per_user: toNullable(),
+	json_schema: toNullable(),
+	trash_retention_ns: toNullable(),
+	max_revisions: toNullable(),
+	default_ttl_ns: toNullable(),
+	indexes: toNullable()
 };
@@ -57,7 +57,8 @@ describe('Satellite > Utils', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		return key;
@@ -223,7 +223,14 @@ describe.each(MEMORIES)('Satellite > Cdn > $title', ({ memory }) => {
 				write: { Managed: null },
 				version: toNullable(),
 				rate_config: toNullable(),
-				max_changes_per_user: toNullable()
+				max_changes_per_user: toNullable(),
+				max_items_per_user: toNullable(),
+				max_bytes_per_user: toNullable(),
+				json_schema: toNullable(),
+				trash_retention_ns: toNullable(),
+				max_revisions: toNullable(),
+				default_ttl_ns: toNullable(),
+				indexes: toNullable()
 			};
 
 			await set_rule({ Storage: null }, INVALID_COLLECTION, setRule);
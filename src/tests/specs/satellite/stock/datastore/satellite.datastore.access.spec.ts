@@ -0,0 +1,295 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import type { Identity } from '@icp-sdk/core/agent';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { Principal } from '@icp-sdk/core/principal';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockSetRule } from '../../../../mocks/collection.mocks';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { createUser } from '../../../../utils/satellite-doc-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Access', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const owner = Ed25519KeyIdentity.generate();
+	const reader = Ed25519KeyIdentity.generate();
+	const writer = Ed25519KeyIdentity.generate();
+	const stranger = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_access';
+
+	const key = nanoid();
+
+	const grant = (user: Identity): SatelliteDid.DocGrant => ({
+		user: user.getPrincipal(),
+		expires_at: toNullable()
+	});
+
+	const setDoc = ({
+		identity,
+		version
+	}: {
+		identity: Identity;
+		version?: bigint;
+	}): Promise<SatelliteDid.Doc> => {
+		actor.setIdentity(identity);
+
+		const { set_doc } = actor;
+
+		return set_doc(TEST_COLLECTION, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(version),
+			expires_at: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, {
+			...mockSetRule,
+			read: { Private: null },
+			write: { Private: null }
+		});
+
+		await createUser({ actor, user: owner });
+
+		await setDoc({ identity: owner });
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('set', () => {
+		it('should not set the access list if the caller is not the owner', async () => {
+			actor.setIdentity(reader);
+
+			const { set_doc_access } = actor;
+
+			await expect(
+				set_doc_access(TEST_COLLECTION, key, {
+					readers: [grant(reader)],
+					writers: [],
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.cannot_write');
+		});
+
+		it('should throw with invalid grants', async () => {
+			actor.setIdentity(owner);
+
+			const { set_doc_access } = actor;
+
+			await expect(
+				set_doc_access(TEST_COLLECTION, key, {
+					readers: [grant(reader), grant(reader)],
+					writers: [],
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.invalid_doc_access');
+
+			await expect(
+				set_doc_access(TEST_COLLECTION, key, {
+					readers: [{ user: Principal.anonymous(), expires_at: toNullable() }],
+					writers: [],
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.invalid_doc_access');
+		});
+
+		it('should not share the documents of a system collection', async () => {
+			actor.setIdentity(owner);
+
+			const { set_doc_access } = actor;
+
+			await expect(
+				set_doc_access('#user', owner.getPrincipal().toText(), {
+					readers: [grant(reader)],
+					writers: [],
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.doc_access_not_supported');
+		});
+
+		it('should set the access list of a document', async () => {
+			actor.setIdentity(owner);
+
+			const { set_doc_access } = actor;
+
+			const access = await set_doc_access(TEST_COLLECTION, key, {
+				readers: [grant(reader)],
+				writers: [grant(writer)],
+				version: toNullable()
+			});
+
+			expect(access.readers.map(({ user }) => user.toText())).toEqual([
+				reader.getPrincipal().toText()
+			]);
+			expect(access.writers.map(({ user }) => user.toText())).toEqual([
+				writer.getPrincipal().toText()
+			]);
+			expect(fromNullable(access.version)).toEqual(1n);
+		});
+
+		it('should read the access list as the owner only', async () => {
+			const { get_doc_access } = actor;
+
+			actor.setIdentity(owner);
+			expect(fromNullable(await get_doc_access(TEST_COLLECTION, key))).not.toBeUndefined();
+
+			actor.setIdentity(reader);
+			expect(fromNullable(await get_doc_access(TEST_COLLECTION, key))).toBeUndefined();
+		});
+	});
+
+	describe('grants', () => {
+		it('should read the document as a reader', async () => {
+			actor.setIdentity(reader);
+
+			const { get_doc } = actor;
+
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+		});
+
+		it('should not update the document as a reader', async () => {
+			actor.setIdentity(reader);
+
+			const { get_doc } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+			assertNonNullish(doc);
+
+			await expect(
+				setDoc({ identity: reader, version: fromNullable(doc.version) })
+			).rejects.toThrowError('juno.datastore.error.cannot_write');
+		});
+
+		it('should update the document as a writer', async () => {
+			actor.setIdentity(writer);
+
+			const { get_doc } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+			assertNonNullish(doc);
+
+			const updated = await setDoc({ identity: writer, version: fromNullable(doc.version) });
+
+			expect(updated.owner.toText()).toEqual(owner.getPrincipal().toText());
+		});
+
+		it('should not read the document as a stranger', async () => {
+			actor.setIdentity(stranger);
+
+			const { get_doc } = actor;
+
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).toBeUndefined();
+		});
+	});
+
+	describe('list', () => {
+		it('should list the documents shared with the caller', async () => {
+			const { list_shared_docs } = actor;
+
+			actor.setIdentity(reader);
+
+			const { items } = await list_shared_docs(TEST_COLLECTION, mockListParams);
+
+			expect(items.map(([docKey]) => docKey)).toEqual([key]);
+
+			actor.setIdentity(stranger);
+
+			const { items: strangerItems } = await list_shared_docs(TEST_COLLECTION, mockListParams);
+
+			expect(strangerItems).toHaveLength(0);
+		});
+
+		it('should list the shared documents with the other documents', async () => {
+			const { list_docs } = actor;
+
+			actor.setIdentity(reader);
+
+			const { items } = await list_docs(TEST_COLLECTION, mockListParams);
+
+			expect(items.map(([docKey]) => docKey)).toEqual([key]);
+
+			actor.setIdentity(stranger);
+
+			const { items: strangerItems } = await list_docs(TEST_COLLECTION, mockListParams);
+
+			expect(strangerItems).toHaveLength(0);
+		});
+
+		it('should list the changes of the shared documents', async () => {
+			const { list_changes } = actor;
+
+			actor.setIdentity(reader);
+
+			const { items } = await list_changes(0n, toNullable([TEST_COLLECTION]), toNullable());
+
+			expect(items.length).toBeGreaterThan(0);
+			expect(items.every((item) => item.key === key)).toBeTruthy();
+
+			actor.setIdentity(stranger);
+
+			const { items: strangerItems } = await list_changes(
+				0n,
+				toNullable([TEST_COLLECTION]),
+				toNullable()
+			);
+
+			expect(strangerItems).toHaveLength(0);
+		});
+	});
+
+	describe('banned', () => {
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			const { set_doc } = actor;
+
+			await set_doc('#user', owner.getPrincipal().toText(), {
+				data: await toArray({
+					provider: 'internet_identity',
+					banned: 'indefinite'
+				}),
+				description: toNullable(),
+				version: toNullable(1n),
+				expires_at: toNullable()
+			});
+		});
+
+		it('should not read the access list if the owner is banned', async () => {
+			actor.setIdentity(owner);
+
+			const { get_doc_access } = actor;
+
+			await expect(get_doc_access(TEST_COLLECTION, key)).rejects.toThrowError(
+				'juno.datastore.error.user.not_allowed'
+			);
+		});
+	});
+});
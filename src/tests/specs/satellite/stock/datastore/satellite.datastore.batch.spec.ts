@@ -0,0 +1,257 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import {
+	JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_FOUND,
+	JUNO_DATASTORE_ERROR_CANNOT_WRITE,
+	JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED
+} from '@junobuild/errors';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Batch', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_batch';
+	const OTHER_COLLECTION = 'test_batch_other';
+	const LIMITED_COLLECTION = 'test_batch_limited';
+
+	const MAX_CHANGES_PER_USER = 2;
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const set = (
+		key: string,
+		version?: bigint,
+		collection = TEST_COLLECTION
+	): SatelliteDid.DocsBatchOperation => ({
+		Set: {
+			collection,
+			key,
+			doc: {
+				data: mockData,
+				description: toNullable(),
+				version: toNullable(version),
+				expires_at: toNullable()
+			}
+		}
+	});
+
+	const del = (key: string, version?: bigint): SatelliteDid.DocsBatchOperation => ({
+		Delete: {
+			collection: TEST_COLLECTION,
+			key,
+			doc: { version: toNullable(version) }
+		}
+	});
+
+	const assertVersion = (key: string, version?: bigint): SatelliteDid.DocsBatchOperation => ({
+		AssertVersion: {
+			collection: TEST_COLLECTION,
+			key,
+			version: toNullable(version)
+		}
+	});
+
+	const getDoc = async (key: string, collection = TEST_COLLECTION) => {
+		const { get_doc } = actor;
+		return fromNullable(await get_doc(collection, key));
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Db: null }, OTHER_COLLECTION, setRule);
+		await set_rule({ Db: null }, LIMITED_COLLECTION, {
+			...setRule,
+			max_changes_per_user: toNullable(MAX_CHANGES_PER_USER)
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('apply', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should apply operations across collections', async () => {
+			const { apply_docs_batch } = actor;
+
+			const key = nanoid();
+			const otherKey = nanoid();
+
+			const result = await apply_docs_batch([
+				set(key),
+				set(otherKey, undefined, OTHER_COLLECTION),
+				assertVersion(nanoid())
+			]);
+
+			assertNonNullish('Ok' in result ? result.Ok : undefined);
+
+			const results = 'Ok' in result ? result.Ok : [];
+
+			expect(results).toHaveLength(3);
+			expect('Set' in results[0]).toBeTruthy();
+			expect('Set' in results[1]).toBeTruthy();
+			expect('AssertVersion' in results[2]).toBeTruthy();
+
+			expect(await getDoc(key)).not.toBeUndefined();
+			expect(await getDoc(otherKey, OTHER_COLLECTION)).not.toBeUndefined();
+		});
+
+		it('should see the effects of the previous operations', async () => {
+			const { apply_docs_batch } = actor;
+
+			const key = nanoid();
+
+			const result = await apply_docs_batch([
+				set(key),
+				assertVersion(key, 1n),
+				set(key, 1n),
+				assertVersion(key, 2n),
+				del(key, 2n),
+				assertVersion(key)
+			]);
+
+			expect('Ok' in result).toBeTruthy();
+
+			expect(await getDoc(key)).toBeUndefined();
+		});
+	});
+
+	describe('atomicity', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should apply nothing if a version does not match', async () => {
+			const { apply_docs_batch } = actor;
+
+			const key = nanoid();
+			const otherKey = nanoid();
+
+			const result = await apply_docs_batch([set(key), assertVersion(otherKey, 3n)]);
+
+			expect('Err' in result).toBeTruthy();
+
+			const error = 'Err' in result ? result.Err : undefined;
+
+			expect(error?.index).toEqual(1n);
+			expect(error?.collection).toEqual(TEST_COLLECTION);
+			expect(error?.key).toEqual(otherKey);
+			expect(error?.error).toContain('juno.datastore.error.version_mismatch');
+
+			expect(await getDoc(key)).toBeUndefined();
+		});
+
+		it('should apply nothing if a collection does not exist', async () => {
+			const { apply_docs_batch } = actor;
+
+			const key = nanoid();
+
+			const result = await apply_docs_batch([set(key), set(nanoid(), undefined, 'unknown')]);
+
+			const error = 'Err' in result ? result.Err : undefined;
+
+			expect(error?.index).toEqual(1n);
+			expect(error?.error).toContain(JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_FOUND);
+
+			expect(await getDoc(key)).toBeUndefined();
+		});
+
+		it('should apply nothing if an operation is not allowed', async () => {
+			const { apply_docs_batch, set_doc } = actor;
+
+			const key = nanoid();
+
+			await set_doc(TEST_COLLECTION, key, {
+				data: mockData,
+				description: toNullable(),
+				version: toNullable(),
+				expires_at: toNullable()
+			});
+
+			const user = Ed25519KeyIdentity.generate();
+			actor.setIdentity(user);
+
+			const userKey = nanoid();
+
+			const result = await apply_docs_batch([set(userKey), del(key, 1n)]);
+
+			const error = 'Err' in result ? result.Err : undefined;
+
+			expect(error?.index).toEqual(1n);
+			expect(error?.error).toContain(JUNO_DATASTORE_ERROR_CANNOT_WRITE);
+
+			actor.setIdentity(controller);
+
+			expect(await getDoc(userKey)).toBeUndefined();
+			expect(await getDoc(key)).not.toBeUndefined();
+		});
+
+		it('should apply nothing if the batch exceeds the changes per user', async () => {
+			const { apply_docs_batch } = actor;
+
+			const user = Ed25519KeyIdentity.generate();
+			actor.setIdentity(user);
+
+			const keys = Array.from({ length: MAX_CHANGES_PER_USER + 1 }, () => nanoid());
+
+			const result = await apply_docs_batch(
+				keys.map((key) => set(key, undefined, LIMITED_COLLECTION))
+			);
+
+			const error = 'Err' in result ? result.Err : undefined;
+
+			expect(error?.index).toEqual(BigInt(MAX_CHANGES_PER_USER));
+			expect(error?.collection).toEqual(LIMITED_COLLECTION);
+			expect(error?.key).toEqual(keys[MAX_CHANGES_PER_USER]);
+			expect(error?.error).toContain(JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED);
+
+			actor.setIdentity(controller);
+
+			for (const key of keys) {
+				expect(await getDoc(key, LIMITED_COLLECTION)).toBeUndefined();
+			}
+		});
+	});
+});
@@ -0,0 +1,314 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { createUser } from '../../../../utils/satellite-doc-tests.utils';
+import { uploadAsset } from '../../../../utils/satellite-storage-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Changes', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_changes';
+	const TEST_STORAGE_COLLECTION = 'test_changes_assets';
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Storage: null }, TEST_STORAGE_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const listChangesSince = async (since: bigint): Promise<SatelliteDid.ListChangesResults> => {
+		const { list_changes } = actor;
+		return await list_changes(since, toNullable(), toNullable());
+	};
+
+	const lastSequence = async (): Promise<bigint> => {
+		const { last_sequence } = await listChangesSince(0n);
+		return last_sequence;
+	};
+
+	const setDoc = (key: string, version?: bigint): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(TEST_COLLECTION, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(version),
+			expires_at: toNullable()
+		});
+	};
+
+	describe('write paths', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should record set_doc', async () => {
+			const since = await lastSequence();
+
+			const key = nanoid();
+			await setDoc(key);
+
+			const { items, last_sequence } = await listChangesSince(since);
+
+			expect(items).toHaveLength(1);
+			expect(items[0].type).toEqual('doc_set');
+			expect(items[0].collection).toEqual(TEST_COLLECTION);
+			expect(items[0].key).toEqual(key);
+			expect(items[0].caller).toEqual(controller.getPrincipal().toText());
+			expect(last_sequence).toEqual(since + 1n);
+		});
+
+		it('should record set_many_docs', async () => {
+			const since = await lastSequence();
+
+			const keys = [nanoid(), nanoid(), nanoid()];
+
+			const { set_many_docs } = actor;
+			await set_many_docs(
+				keys.map((key) => [
+					TEST_COLLECTION,
+					key,
+					{
+						data: mockData,
+						description: toNullable(),
+						version: toNullable(),
+						expires_at: toNullable()
+					}
+				])
+			);
+
+			const { items } = await listChangesSince(since);
+
+			expect(items.map(({ type }) => type)).toEqual(['doc_set', 'doc_set', 'doc_set']);
+			expect(items.map(({ key }) => key).sort()).toEqual([...keys].sort());
+		});
+
+		it('should record del_doc', async () => {
+			const key = nanoid();
+			const doc = await setDoc(key);
+
+			const since = await lastSequence();
+
+			const { del_doc } = actor;
+			await del_doc(TEST_COLLECTION, key, { version: doc.version });
+
+			const { items } = await listChangesSince(since);
+
+			expect(items).toHaveLength(1);
+			expect(items[0].type).toEqual('doc_deleted');
+			expect(items[0].key).toEqual(key);
+		});
+
+		it('should record del_many_docs', async () => {
+			const keys = [nanoid(), nanoid()];
+			const docs = await Promise.all(keys.map((key) => setDoc(key)));
+
+			const since = await lastSequence();
+
+			const { del_many_docs } = actor;
+			await del_many_docs(
+				keys.map((key, i) => [TEST_COLLECTION, key, { version: docs[i].version }])
+			);
+
+			const { items } = await listChangesSince(since);
+
+			expect(items.map(({ type }) => type)).toEqual(['doc_deleted', 'doc_deleted']);
+		});
+
+		it('should record del_filtered_docs', async () => {
+			const prefix = nanoid();
+			const keys = [`${prefix}-1`, `${prefix}-2`];
+
+			for (const key of keys) {
+				await setDoc(key);
+			}
+
+			const since = await lastSequence();
+
+			const { del_filtered_docs } = actor;
+			await del_filtered_docs(TEST_COLLECTION, {
+				matcher: toNullable({
+					key: toNullable(`^${prefix}`),
+					description: toNullable(),
+					created_at: toNullable(),
+					updated_at: toNullable(),
+					index: toNullable(),
+					data: toNullable()
+				}),
+				paginate: toNullable(),
+				order: toNullable(),
+				owner: toNullable()
+			});
+
+			const { items } = await listChangesSince(since);
+
+			expect(items.map(({ type }) => type)).toEqual(['doc_deleted', 'doc_deleted']);
+			expect(items.map(({ key }) => key).sort()).toEqual(keys);
+		});
+
+		it('should not record deleting a document that does not exist', async () => {
+			const since = await lastSequence();
+
+			const { del_doc } = actor;
+			await del_doc(TEST_COLLECTION, nanoid(), { version: toNullable() });
+
+			const { items } = await listChangesSince(since);
+
+			expect(items).toHaveLength(0);
+		});
+
+		it('should record asset upload and delete', async () => {
+			const full_path = `/${TEST_STORAGE_COLLECTION}/${nanoid()}.txt`;
+
+			const since = await lastSequence();
+
+			await uploadAsset({
+				full_path,
+				name: 'hello.txt',
+				collection: TEST_STORAGE_COLLECTION,
+				actor
+			});
+
+			const { del_asset } = actor;
+			await del_asset(TEST_STORAGE_COLLECTION, full_path);
+
+			const { items } = await listChangesSince(since);
+
+			expect(items.map(({ type }) => type)).toEqual(['asset_uploaded', 'asset_deleted']);
+			expect(items.every(({ key }) => key === full_path)).toBeTruthy();
+			expect(items.every(({ collection }) => collection === TEST_STORAGE_COLLECTION)).toBeTruthy();
+		});
+
+		it('should not record system collections', async () => {
+			const since = await lastSequence();
+
+			await createUser({ actor });
+
+			actor.setIdentity(controller);
+
+			const { items } = await listChangesSince(since);
+
+			expect(items).toHaveLength(0);
+		});
+	});
+
+	describe('list', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should filter by collections', async () => {
+			const since = await lastSequence();
+
+			await setDoc(nanoid());
+
+			const { list_changes } = actor;
+
+			const { items: matching } = await list_changes(since, toNullable([TEST_COLLECTION]), []);
+			expect(matching).toHaveLength(1);
+
+			const { items: other, last_sequence } = await list_changes(
+				since,
+				toNullable([TEST_STORAGE_COLLECTION]),
+				[]
+			);
+			expect(other).toHaveLength(0);
+			expect(last_sequence).toEqual(since + 1n);
+		});
+
+		it('should page with limit and last_sequence', async () => {
+			const since = await lastSequence();
+
+			for (const _ of Array.from({ length: 3 })) {
+				await setDoc(nanoid());
+			}
+
+			const { list_changes } = actor;
+
+			const first = await list_changes(since, [], toNullable(2n));
+			expect(first.items).toHaveLength(2);
+			expect(first.last_sequence).toEqual(since + 2n);
+
+			const second = await list_changes(first.last_sequence, [], toNullable(2n));
+			expect(second.items).toHaveLength(1);
+			expect(second.last_sequence).toEqual(since + 3n);
+		});
+
+		it('should not list the changes of a private collection to others', async () => {
+			const PRIVATE_COLLECTION = 'test_changes_private';
+
+			const { set_rule } = actor;
+			await set_rule({ Db: null }, PRIVATE_COLLECTION, {
+				...setRule,
+				read: { Private: null },
+				write: { Private: null }
+			});
+
+			const owner = Ed25519KeyIdentity.generate();
+			actor.setIdentity(owner);
+
+			const since = await lastSequence();
+
+			const { set_doc, list_changes } = actor;
+			await set_doc(PRIVATE_COLLECTION, nanoid(), {
+				data: mockData,
+				description: toNullable(),
+				version: toNullable(),
+				expires_at: toNullable()
+			});
+
+			const { items: ownerItems } = await list_changes(since, [], []);
+			expect(ownerItems).toHaveLength(1);
+
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			const { items: otherItems, last_sequence } = await list_changes(since, [], []);
+			expect(otherItems).toHaveLength(0);
+			expect(last_sequence).toEqual(since + 1n);
+		});
+	});
+});
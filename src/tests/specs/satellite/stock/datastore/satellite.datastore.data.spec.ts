@@ -53,7 +53,14 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				version: toNullable(),
 				max_capacity: toNullable(),
 				rate_config: toNullable(),
-				max_changes_per_user: toNullable()
+				max_changes_per_user: toNullable(),
+				max_items_per_user: toNullable(),
+				max_bytes_per_user: toNullable(),
+				json_schema: toNullable(),
+				trash_retention_ns: toNullable(),
+				max_revisions: toNullable(),
+				default_ttl_ns: toNullable(),
+				indexes: toNullable()
 			};
 
 			const collection = `test_data_${'Heap' in memory ? 'heap' : 'stable'}`;
@@ -89,7 +96,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				await set_doc(collection, key, {
 					data: serializedData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const result = await get_doc(collection, key);
@@ -139,7 +147,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				await set_doc(collection, key, {
 					data: serializedData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const result = await get_doc(collection, key);
@@ -0,0 +1,164 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { tick } from '../../../../utils/pic-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Expiry', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_expiry';
+	const TTL_COLLECTION = 'test_expiry_ttl';
+
+	const TTL_NS = 10_000_000_000n;
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const now = async (): Promise<bigint> => BigInt(await pic.getTime()) * 1_000_000n;
+
+	const setDoc = (
+		key: string,
+		{ expiresAt, version }: { expiresAt?: bigint; version?: bigint } = {},
+		collection = TEST_COLLECTION
+	): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(collection, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(version),
+			expires_at: toNullable(expiresAt)
+		});
+	};
+
+	const getDoc = async (key: string): Promise<SatelliteDid.Doc | undefined> => {
+		const { get_doc } = actor;
+		return fromNullable(await get_doc(TEST_COLLECTION, key));
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Db: null }, TTL_COLLECTION, {
+			...setRule,
+			default_ttl_ns: toNullable(TTL_NS)
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should throw if expires_at is in the past', async () => {
+		await expect(setDoc(nanoid(), { expiresAt: await now() })).rejects.toThrowError(
+			'juno.datastore.error.invalid_expires_at'
+		);
+	});
+
+	it('should apply the default time-to-live of the collection', async () => {
+		const doc = await setDoc(nanoid(), {}, TTL_COLLECTION);
+
+		expect(fromNullable(doc.expires_at)).toEqual(doc.updated_at + TTL_NS);
+	});
+
+	describe('expired', () => {
+		let key: string;
+
+		beforeAll(async () => {
+			key = nanoid();
+
+			await setDoc(key, { expiresAt: (await now()) + TTL_NS });
+
+			// Expired but not yet swept - the timer did not run
+			await pic.advanceTime(Number(TTL_NS / 1_000_000n) + 1_000);
+		});
+
+		it('should hide the document', async () => {
+			expect(await getDoc(key)).toBeUndefined();
+		});
+
+		it('should not list the document', async () => {
+			const { list_docs } = actor;
+
+			const { items, items_length } = await list_docs(TEST_COLLECTION, mockListParams);
+
+			expect(items.find(([k]) => k === key)).toBeUndefined();
+			expect(items_length).toEqual(0n);
+		});
+
+		it('should not count the document', async () => {
+			const { count_collection_docs, count_docs } = actor;
+
+			expect(await count_collection_docs(TEST_COLLECTION)).toEqual(0n);
+			expect(await count_docs(TEST_COLLECTION, mockListParams)).toEqual(0n);
+		});
+
+		it('should sweep the document', async () => {
+			const { list_changes } = actor;
+
+			const { last_sequence: since } = await list_changes(0n, [], []);
+
+			await tick(pic);
+
+			const { items } = await list_changes(since, [], []);
+
+			expect(items).toHaveLength(1);
+			expect(items[0].type).toEqual('doc_deleted');
+			expect(items[0].key).toEqual(key);
+		});
+	});
+
+	it('should not sweep a document updated without expiry', async () => {
+		const key = nanoid();
+
+		const doc = await setDoc(key, { expiresAt: (await now()) + TTL_NS });
+
+		await setDoc(key, { version: fromNullable(doc.version) });
+
+		await pic.advanceTime(Number(TTL_NS / 1_000_000n) + 1_000);
+		await tick(pic);
+
+		const current = await getDoc(key);
+
+		expect(current).not.toBeUndefined();
+		expect(fromNullable(current?.expires_at ?? [])).toBeUndefined();
+	});
+});
@@ -0,0 +1,188 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { tick } from '../../../../utils/pic-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
+	'Satellite > Datastore > Index',
+	({ memory }) => {
+		let pic: PocketIc;
+		let actor: Actor<SatelliteActor>;
+
+		const controller = Ed25519KeyIdentity.generate();
+
+		const collection = `test_index_${'Heap' in memory ? 'heap' : 'stable'}`;
+
+		const setRule: SatelliteDid.SetRule = {
+			memory: toNullable(memory),
+			max_size: toNullable(),
+			read: { Managed: null },
+			mutable_permissions: toNullable(),
+			write: { Managed: null },
+			version: toNullable(),
+			max_capacity: toNullable(),
+			rate_config: toNullable(),
+			max_changes_per_user: toNullable(),
+			max_items_per_user: toNullable(),
+			max_bytes_per_user: toNullable(),
+			json_schema: toNullable(),
+			trash_retention_ns: toNullable(),
+			max_revisions: toNullable(),
+			default_ttl_ns: toNullable(),
+			indexes: toNullable()
+		};
+
+		const listParams = (
+			value: SatelliteDid.IndexMatcher,
+			path = 'status'
+		): SatelliteDid.ListParams => ({
+			...mockListParams,
+			matcher: toNullable({
+				key: toNullable(),
+				description: toNullable(),
+				created_at: toNullable(),
+				updated_at: toNullable(),
+				index: toNullable({ path, value }),
+				data: toNullable()
+			})
+		});
+
+		const setDoc = async (status: string, rank: number): Promise<string> => {
+			const { set_doc } = actor;
+
+			const key = nanoid();
+
+			await set_doc(collection, key, {
+				data: await toArray({ status, rank }),
+				description: toNullable(),
+				version: toNullable(),
+				expires_at: toNullable()
+			});
+
+			return key;
+		};
+
+		beforeAll(async () => {
+			pic = await PocketIc.create(inject('PIC_URL'));
+
+			const { actor: c } = await pic.setupCanister<SatelliteActor>({
+				idlFactory: idlFactorySatellite,
+				wasm: SATELLITE_WASM_PATH,
+				arg: controllersInitArgs(controller),
+				sender: controller.getPrincipal()
+			});
+
+			actor = c;
+
+			actor.setIdentity(controller);
+
+			const { set_rule } = actor;
+
+			await set_rule({ Db: null }, collection, setRule);
+		});
+
+		afterAll(async () => {
+			await pic?.tearDown();
+		});
+
+		it('should throw if the path is not indexed', async () => {
+			const { list_docs } = actor;
+
+			await expect(
+				list_docs(collection, listParams({ Equal: { Text: 'done' } }))
+			).rejects.toThrowError('juno.datastore.error.index_not_found');
+		});
+
+		describe('rebuild', () => {
+			let doneKeys: string[];
+
+			beforeAll(async () => {
+				doneKeys = [await setDoc('done', 1), await setDoc('done', 2)];
+				await setDoc('todo', 3);
+
+				const { set_rule, get_rule } = actor;
+
+				const rule = await get_rule({ Db: null }, collection);
+
+				await set_rule({ Db: null }, collection, {
+					...setRule,
+					indexes: toNullable(['status', 'rank']),
+					version: rule[0]?.version ?? toNullable()
+				});
+
+				// The existing documents are indexed by timers
+				await tick(pic);
+			});
+
+			it('should list the existing documents with the index', async () => {
+				const { list_docs } = actor;
+
+				const { items } = await list_docs(collection, listParams({ Equal: { Text: 'done' } }));
+
+				expect(items.map(([key]) => key).sort()).toEqual([...doneKeys].sort());
+			});
+
+			it('should index the new documents', async () => {
+				const rankedKey = await setDoc('done', 4);
+
+				const { list_docs } = actor;
+
+				const { items } = await list_docs(
+					collection,
+					listParams({ GreaterThan: { Number: 3 } }, 'rank')
+				);
+
+				expect(items.map(([key]) => key)).toEqual([rankedKey]);
+			});
+
+			it('should drop the entries of the paths that are not indexed anymore', async () => {
+				const { set_rule, get_rule, list_docs } = actor;
+
+				const rule = await get_rule({ Db: null }, collection);
+
+				await set_rule({ Db: null }, collection, {
+					...setRule,
+					indexes: toNullable(['rank']),
+					version: rule[0]?.version ?? toNullable()
+				});
+
+				await tick(pic);
+
+				await expect(
+					list_docs(collection, listParams({ Equal: { Text: 'done' } }))
+				).rejects.toThrowError('juno.datastore.error.index_not_found');
+
+				const { items } = await list_docs(
+					collection,
+					listParams({ Between: [{ Number: 1 }, { Number: 2 }] }, 'rank')
+				);
+
+				expect(items.map(([key]) => key).sort()).toEqual([...doneKeys].sort());
+			});
+		});
+
+		describe('assets', () => {
+			const storageCollection = `test_index_assets_${'Heap' in memory ? 'heap' : 'stable'}`;
+
+			beforeAll(async () => {
+				const { set_rule } = actor;
+
+				await set_rule({ Storage: null }, storageCollection, setRule);
+			});
+
+			it('should not list assets with an index', async () => {
+				const { list_assets } = actor;
+
+				await expect(
+					list_assets(storageCollection, listParams({ Equal: { Text: 'done' } }))
+				).rejects.toThrowError('juno.storage.error.matcher_not_supported');
+			});
+		});
+	}
+);
@@ -0,0 +1,134 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { JUNO_ERROR_VERSION_OUTDATED_OR_FUTURE } from '@junobuild/errors';
+import { fromArray, toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Patch', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_patch';
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const setDoc = async (key: string): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return await set_doc(TEST_COLLECTION, key, {
+			data: await toArray({ status: 'todo', rank: 1 }),
+			description: toNullable('desc'),
+			version: toNullable(),
+			expires_at: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should apply a merge patch', async () => {
+		const key = nanoid();
+		await setDoc(key);
+
+		const { patch_doc } = actor;
+
+		const doc = await patch_doc(TEST_COLLECTION, key, {
+			patch: { Merge: await toArray({ status: 'done' }) },
+			version: toNullable()
+		});
+
+		expect(await fromArray(doc.data)).toEqual({ status: 'done', rank: 1 });
+		expect(fromNullable(doc.description)).toEqual('desc');
+		expect(fromNullable(doc.version)).toEqual(2n);
+	});
+
+	it('should apply a JSON patch', async () => {
+		const key = nanoid();
+		await setDoc(key);
+
+		const { patch_doc } = actor;
+
+		const doc = await patch_doc(TEST_COLLECTION, key, {
+			patch: {
+				Json: await toArray([
+					{ op: 'test', path: '/status', value: 'todo' },
+					{ op: 'replace', path: '/rank', value: 2 },
+					{ op: 'remove', path: '/status' }
+				])
+			},
+			version: toNullable(1n)
+		});
+
+		expect(await fromArray(doc.data)).toEqual({ rank: 2 });
+	});
+
+	it('should throw if the version does not match', async () => {
+		const key = nanoid();
+		await setDoc(key);
+
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, key, {
+				patch: { Merge: await toArray({ status: 'done' }) },
+				version: toNullable(3n)
+			})
+		).rejects.toThrowError(JUNO_ERROR_VERSION_OUTDATED_OR_FUTURE);
+	});
+
+	it('should throw if a JSON patch test fails', async () => {
+		const key = nanoid();
+		await setDoc(key);
+
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, key, {
+				patch: { Json: await toArray([{ op: 'test', path: '/status', value: 'done' }]) },
+				version: toNullable()
+			})
+		).rejects.toThrowError('juno.datastore.error.invalid_patch');
+	});
+});
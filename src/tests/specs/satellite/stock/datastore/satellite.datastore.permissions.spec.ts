@@ -0,0 +1,182 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { toNullable } from '@dfinity/utils';
+import type { Identity } from '@icp-sdk/core/agent';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockSetRule } from '../../../../mocks/collection.mocks';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Permissions', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const PRINCIPALS_COLLECTION = 'test_principals';
+	const ROLES_COLLECTION = 'test_roles';
+
+	const ROLE = 'editor';
+
+	const setDoc = ({
+		collection,
+		identity
+	}: {
+		collection: string;
+		identity: Identity;
+	}): Promise<SatelliteDid.Doc> => {
+		actor.setIdentity(identity);
+
+		const { set_doc } = actor;
+
+		return set_doc(collection, nanoid(), {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(),
+			expires_at: toNullable()
+		});
+	};
+
+	const listDocs = ({
+		collection,
+		identity
+	}: {
+		collection: string;
+		identity: Identity;
+	}) => {
+		actor.setIdentity(identity);
+
+		const { list_docs } = actor;
+
+		return list_docs(collection, mockListParams);
+	};
+
+	const grantRole = async (user: Identity) => {
+		actor.setIdentity(controller);
+
+		const { set_doc } = actor;
+
+		await set_doc('#user-role', user.getPrincipal().toText(), {
+			data: await toArray({ roles: [ROLE] }),
+			description: toNullable(),
+			version: toNullable(),
+			expires_at: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('principals', () => {
+		const allowed = Ed25519KeyIdentity.generate();
+		const other = Ed25519KeyIdentity.generate();
+
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			const permission: SatelliteDid.Permission = { Principals: [allowed.getPrincipal()] };
+
+			const { set_rule } = actor;
+			await set_rule({ Db: null }, PRINCIPALS_COLLECTION, {
+				...mockSetRule,
+				read: permission,
+				write: permission
+			});
+		});
+
+		it('should set and list documents with a listed principal', async () => {
+			await setDoc({ collection: PRINCIPALS_COLLECTION, identity: allowed });
+
+			const { items } = await listDocs({ collection: PRINCIPALS_COLLECTION, identity: allowed });
+
+			expect(items).toHaveLength(1);
+		});
+
+		it('should not set or list documents with another principal', async () => {
+			await expect(
+				setDoc({ collection: PRINCIPALS_COLLECTION, identity: other })
+			).rejects.toThrowError('juno.datastore.error.cannot_write');
+
+			const { items } = await listDocs({ collection: PRINCIPALS_COLLECTION, identity: other });
+
+			expect(items).toHaveLength(0);
+		});
+	});
+
+	describe('roles', () => {
+		const editor = Ed25519KeyIdentity.generate();
+		const otherEditor = Ed25519KeyIdentity.generate();
+		const user = Ed25519KeyIdentity.generate();
+
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			const permission: SatelliteDid.Permission = { Roles: [ROLE] };
+
+			const { set_rule } = actor;
+			await set_rule({ Db: null }, ROLES_COLLECTION, {
+				...mockSetRule,
+				read: permission,
+				write: permission
+			});
+		});
+
+		it('should not set documents without the role', async () => {
+			await expect(setDoc({ collection: ROLES_COLLECTION, identity: editor })).rejects.toThrowError(
+				'juno.datastore.error.cannot_write'
+			);
+		});
+
+		it('should set documents once the role is granted', async () => {
+			await grantRole(editor);
+			await grantRole(otherEditor);
+
+			await setDoc({ collection: ROLES_COLLECTION, identity: editor });
+			await setDoc({ collection: ROLES_COLLECTION, identity: otherEditor });
+		});
+
+		it('should list the documents of every owner with the role', async () => {
+			const { items } = await listDocs({ collection: ROLES_COLLECTION, identity: editor });
+
+			expect(items).toHaveLength(2);
+		});
+
+		it('should not list documents without the role', async () => {
+			const { items } = await listDocs({ collection: ROLES_COLLECTION, identity: user });
+
+			expect(items).toHaveLength(0);
+		});
+
+		it('should throw if the roles of a permission are invalid', async () => {
+			actor.setIdentity(controller);
+
+			const { set_rule } = actor;
+
+			await expect(
+				set_rule({ Db: null }, `${ROLES_COLLECTION}_invalid`, {
+					...mockSetRule,
+					read: { Roles: [] },
+					write: { Roles: [] }
+				})
+			).rejects.toThrowError('juno.collections.error.invalid_roles');
+		});
+	});
+});
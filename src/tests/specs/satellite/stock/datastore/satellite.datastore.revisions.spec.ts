@@ -0,0 +1,246 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { fromArray, toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Revisions', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_revisions';
+	const WRITE_ONLY_COLLECTION = 'test_revisions_write_only';
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(3),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const setDoc = async ({
+		key,
+		rank,
+		version,
+		collection = TEST_COLLECTION
+	}: {
+		key: string;
+		rank: number;
+		version?: bigint;
+		collection?: string;
+	}): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return await set_doc(collection, key, {
+			data: await toArray({ rank }),
+			description: toNullable(),
+			version: toNullable(version),
+			expires_at: toNullable()
+		});
+	};
+
+	const setDocs = async (key: string, count: number): Promise<SatelliteDid.Doc> => {
+		let doc = await setDoc({ key, rank: 1 });
+
+		for (let rank = 2; rank <= count; rank++) {
+			doc = await setDoc({ key, rank, version: fromNullable(doc.version) });
+		}
+
+		return doc;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Db: null }, WRITE_ONLY_COLLECTION, {
+			...setRule,
+			read: { Private: null },
+			write: { Public: null }
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('list', () => {
+		const key = nanoid();
+
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			await setDocs(key, 5);
+		});
+
+		it('should keep the last revisions', async () => {
+			const { list_doc_revisions } = actor;
+
+			const { items, matches_length } = await list_doc_revisions(
+				TEST_COLLECTION,
+				key,
+				mockListParams
+			);
+
+			expect(matches_length).toEqual(3n);
+			expect(items.map(([k]) => k)).toEqual(['3', '4', '5']);
+			expect(items.map(([_, { version }]) => version)).toEqual([3n, 4n, 5n]);
+		});
+
+		it('should paginate the revisions', async () => {
+			const { list_doc_revisions } = actor;
+
+			const { items, items_length, matches_length } = await list_doc_revisions(
+				TEST_COLLECTION,
+				key,
+				{
+					...mockListParams,
+					order: toNullable({ desc: true, field: { UpdatedAt: null } }),
+					paginate: toNullable({ start_after: toNullable('5'), limit: toNullable(1n) })
+				}
+			);
+
+			expect(items_length).toEqual(1n);
+			expect(matches_length).toEqual(3n);
+			expect(items.map(([k]) => k)).toEqual(['4']);
+		});
+
+		it('should throw with an index matcher', async () => {
+			const { list_doc_revisions } = actor;
+
+			await expect(
+				list_doc_revisions(TEST_COLLECTION, key, {
+					...mockListParams,
+					matcher: toNullable({
+						key: toNullable(),
+						description: toNullable(),
+						created_at: toNullable(),
+						updated_at: toNullable(),
+						index: toNullable({ path: 'rank', value: { Equal: { Number: 1 } } }),
+						data: toNullable()
+					})
+				})
+			).rejects.toThrowError('juno.datastore.error.matcher_not_supported');
+		});
+
+		it('should not list the revisions to a user that cannot read the document', async () => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			const { list_doc_revisions } = actor;
+
+			const { items } = await list_doc_revisions(TEST_COLLECTION, key, mockListParams);
+
+			expect(items).toHaveLength(0);
+
+			actor.setIdentity(controller);
+		});
+
+		it('should delete the revisions with the document', async () => {
+			const otherKey = nanoid();
+
+			const doc = await setDocs(otherKey, 2);
+
+			const { del_doc, list_doc_revisions } = actor;
+
+			await del_doc(TEST_COLLECTION, otherKey, { version: doc.version });
+
+			await setDoc({ key: otherKey, rank: 1 });
+
+			const { items } = await list_doc_revisions(TEST_COLLECTION, otherKey, mockListParams);
+
+			expect(items.map(([k]) => k)).toEqual(['1']);
+		});
+	});
+
+	describe('restore', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should restore a revision as a new version', async () => {
+			const key = nanoid();
+
+			await setDocs(key, 3);
+
+			const { restore_doc_revision } = actor;
+
+			const doc = await restore_doc_revision(TEST_COLLECTION, key, {
+				revision: 2n,
+				version: toNullable(3n)
+			});
+
+			expect(fromNullable(doc.version)).toEqual(4n);
+			expect(await fromArray(doc.data)).toEqual({ rank: 2 });
+		});
+
+		it('should throw if the revision does not exist', async () => {
+			const key = nanoid();
+
+			await setDocs(key, 1);
+
+			const { restore_doc_revision } = actor;
+
+			await expect(
+				restore_doc_revision(TEST_COLLECTION, key, {
+					revision: 7n,
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.revision_not_found');
+		});
+
+		it('should throw if the caller cannot read the document', async () => {
+			const key = nanoid();
+
+			const doc = await setDoc({ key, rank: 1, collection: WRITE_ONLY_COLLECTION });
+			await setDoc({
+				key,
+				rank: 2,
+				version: fromNullable(doc.version),
+				collection: WRITE_ONLY_COLLECTION
+			});
+
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			const { restore_doc_revision } = actor;
+
+			await expect(
+				restore_doc_revision(WRITE_ONLY_COLLECTION, key, {
+					revision: 1n,
+					version: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.cannot_read');
+
+			actor.setIdentity(controller);
+		});
+	});
+});
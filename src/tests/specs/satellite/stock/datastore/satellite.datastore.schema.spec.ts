@@ -0,0 +1,154 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > JSON Schema', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_schema';
+
+	const schema = (required: string[]): string =>
+		JSON.stringify({
+			type: 'object',
+			properties: {
+				title: { type: 'string', minLength: 1 },
+				rating: { type: 'integer', minimum: 0, maximum: 5 }
+			},
+			required
+		});
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(schema(['title'])),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const setDoc = async (data: unknown): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(TEST_COLLECTION, nanoid(), {
+			data: await toArray(data),
+			description: toNullable(),
+			version: toNullable(),
+			expires_at: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should set a document that matches the schema', async () => {
+		const doc = await setDoc({ title: 'Hello', rating: 4 });
+
+		expect(doc).not.toBeUndefined();
+	});
+
+	it('should throw if a document does not match the schema', async () => {
+		await expect(setDoc({ title: 'Hello', rating: 6 })).rejects.toThrowError(
+			'juno.datastore.error.json_schema_mismatch'
+		);
+
+		await expect(setDoc({ rating: 4 })).rejects.toThrowError(
+			'juno.datastore.error.json_schema_mismatch'
+		);
+	});
+
+	it('should throw if the schema of a rule is invalid', async () => {
+		const { set_rule } = actor;
+
+		await expect(
+			set_rule({ Db: null }, `${TEST_COLLECTION}_invalid`, {
+				...setRule,
+				json_schema: toNullable('{ "type": ')
+			})
+		).rejects.toThrowError('juno.collections.error.invalid_json_schema');
+	});
+
+	it('should validate with the schema of the rule once it is set again', async () => {
+		const { get_rule, set_rule } = actor;
+
+		const rule = fromNullable(await get_rule({ Db: null }, TEST_COLLECTION));
+
+		assertNonNullish(rule);
+
+		await set_rule({ Db: null }, TEST_COLLECTION, {
+			...setRule,
+			version: rule.version,
+			json_schema: toNullable(schema(['title', 'rating']))
+		});
+
+		await expect(setDoc({ title: 'Hello' })).rejects.toThrowError(
+			'juno.datastore.error.json_schema_mismatch'
+		);
+
+		const doc = await setDoc({ title: 'Hello', rating: 1 });
+
+		expect(doc).not.toBeUndefined();
+	});
+
+	it('should not validate once the schema is removed from the rule', async () => {
+		const { get_rule, set_rule } = actor;
+
+		const rule = fromNullable(await get_rule({ Db: null }, TEST_COLLECTION));
+
+		assertNonNullish(rule);
+
+		await set_rule({ Db: null }, TEST_COLLECTION, {
+			...setRule,
+			version: rule.version,
+			json_schema: toNullable()
+		});
+
+		const doc = await setDoc({ rating: 6 });
+
+		expect(doc).not.toBeUndefined();
+	});
+
+	it('should throw if a schema is set on a storage collection', async () => {
+		const { set_rule } = actor;
+
+		await expect(set_rule({ Storage: null }, TEST_COLLECTION, setRule)).rejects.toThrowError(
+			'juno.collections.error.json_schema_not_supported'
+		);
+	});
+});
@@ -49,7 +49,14 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				write: { Managed: null },
 				version: toNullable(),
 				rate_config: toNullable(),
-				max_changes_per_user: toNullable()
+				max_changes_per_user: toNullable(),
+				max_items_per_user: toNullable(),
+				max_bytes_per_user: toNullable(),
+				json_schema: toNullable(),
+				trash_retention_ns: toNullable(),
+				max_revisions: toNullable(),
+				default_ttl_ns: toNullable(),
+				indexes: toNullable()
 			};
 
 			const { set_rule } = actor;
@@ -300,7 +307,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: items[4][1].created_at
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -325,7 +334,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							LessThan: items[4][1].created_at
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -347,7 +358,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							Between: [items[4][1].created_at, items[8][1].created_at]
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -394,7 +407,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							GreaterThan: items[4][1].created_at
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -419,7 +434,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							LessThan: items[4][1].created_at
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -441,7 +458,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							Between: [items[4][1].created_at, items[8][1].created_at]
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -491,7 +510,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: 0n
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -521,7 +542,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 					await set_doc(TEST_COLLECTION, key, {
 						data: mockData,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 					await pic.advanceTime(50);
 				}
@@ -533,7 +555,9 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: 100n
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						index: toNullable(),
+						data: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -652,7 +676,14 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				write: { Managed: null },
 				version: toNullable(),
 				rate_config: toNullable(),
-				max_changes_per_user: toNullable()
+				max_changes_per_user: toNullable(),
+				max_items_per_user: toNullable(),
+				max_bytes_per_user: toNullable(),
+				json_schema: toNullable(),
+				trash_retention_ns: toNullable(),
+				max_revisions: toNullable(),
+				default_ttl_ns: toNullable(),
+				indexes: toNullable()
 			};
 
 			beforeAll(() => {
@@ -677,7 +708,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						{
 							data: mockData,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						}
 					])
 				);
@@ -746,7 +778,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						{
 							data: mockData,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						}
 					])
 				);
@@ -793,7 +826,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 					await set_doc(collectionUnknown, nanoid(), {
 						data: mockData,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 
 					expect(true).toBeFalsy();
@@ -815,7 +849,14 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				version: toNullable(),
 				max_capacity: toNullable(),
 				rate_config: toNullable(),
-				max_changes_per_user: toNullable()
+				max_changes_per_user: toNullable(),
+				max_items_per_user: toNullable(),
+				max_bytes_per_user: toNullable(),
+				json_schema: toNullable(),
+				trash_retention_ns: toNullable(),
+				max_revisions: toNullable(),
+				default_ttl_ns: toNullable(),
+				indexes: toNullable()
 			};
 
 			beforeAll(() => {
@@ -871,7 +912,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 								{
 									data: mockData,
 									description: toNullable(),
-									version: toNullable()
+									version: toNullable(),
+									expires_at: toNullable()
 								}
 							])
 						)
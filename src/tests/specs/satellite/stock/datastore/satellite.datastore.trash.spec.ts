@@ -0,0 +1,228 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > Datastore > Trash', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_trash';
+	const RATE_COLLECTION = 'test_trash_rate';
+
+	const RETENTION_NS = 60_000_000_000n;
+	const TTL_NS = 10_000_000_000n;
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(RETENTION_NS),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const now = async (): Promise<bigint> => BigInt(await pic.getTime()) * 1_000_000n;
+
+	const setDoc = (
+		key: string,
+		{ expiresAt, collection = TEST_COLLECTION }: { expiresAt?: bigint; collection?: string } = {}
+	): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(collection, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(),
+			expires_at: toNullable(expiresAt)
+		});
+	};
+
+	const trashDoc = async (
+		key: string,
+		options: { expiresAt?: bigint; collection?: string } = {}
+	) => {
+		const doc = await setDoc(key, options);
+
+		const { del_doc } = actor;
+		await del_doc(options.collection ?? TEST_COLLECTION, key, { version: doc.version });
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		// One token for the set, one for the delete, none left for the restore
+		await set_rule({ Db: null }, RATE_COLLECTION, {
+			...setRule,
+			rate_config: toNullable({ max_tokens: 2n, time_per_token_ns: 3_600_000_000_000n })
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('list', () => {
+		const prefix = nanoid();
+
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			for (const i of [1, 2, 3]) {
+				await trashDoc(`${prefix}-${i}`);
+			}
+		});
+
+		it('should list the trashed documents', async () => {
+			const { list_trashed_docs } = actor;
+
+			const { items, matches_length } = await list_trashed_docs(TEST_COLLECTION, {
+				...mockListParams,
+				matcher: toNullable({
+					key: toNullable(`^${prefix}`),
+					description: toNullable(),
+					created_at: toNullable(),
+					updated_at: toNullable(),
+					index: toNullable(),
+					data: toNullable()
+				}),
+				order: toNullable({ desc: false, field: { Keys: null } }),
+				paginate: toNullable({ start_after: toNullable(`${prefix}-1`), limit: toNullable(1n) })
+			});
+
+			expect(matches_length).toEqual(3n);
+			expect(items.map(([key]) => key)).toEqual([`${prefix}-2`]);
+			expect(fromNullable(items[0][1].restorable_until)).not.toBeUndefined();
+		});
+
+		it('should throw with an index matcher', async () => {
+			const { list_trashed_docs } = actor;
+
+			await expect(
+				list_trashed_docs(TEST_COLLECTION, {
+					...mockListParams,
+					matcher: toNullable({
+						key: toNullable(),
+						description: toNullable(),
+						created_at: toNullable(),
+						updated_at: toNullable(),
+						index: toNullable({ path: 'rank', value: { Equal: { Number: 1 } } }),
+						data: toNullable()
+					})
+				})
+			).rejects.toThrowError('juno.datastore.error.matcher_not_supported');
+		});
+
+		it('should not list the trashed documents of others', async () => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			const { list_trashed_docs } = actor;
+
+			const { items } = await list_trashed_docs(TEST_COLLECTION, mockListParams);
+
+			expect(items).toHaveLength(0);
+
+			actor.setIdentity(controller);
+		});
+	});
+
+	describe('restore', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should restore a trashed document', async () => {
+			const key = nanoid();
+			await trashDoc(key);
+
+			const { restore_doc, get_doc } = actor;
+
+			const doc = await restore_doc(TEST_COLLECTION, key);
+
+			expect(fromNullable(doc.restorable_until)).toBeUndefined();
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+		});
+
+		it('should throw if a document was set with the same key', async () => {
+			const key = nanoid();
+			await trashDoc(key);
+
+			await setDoc(key);
+
+			const { restore_doc } = actor;
+
+			await expect(restore_doc(TEST_COLLECTION, key)).rejects.toThrowError(
+				'juno.datastore.error.restore_conflict'
+			);
+		});
+
+		it('should keep the expiry of a restored document', async () => {
+			const key = nanoid();
+			const expiresAt = (await now()) + TTL_NS;
+
+			await trashDoc(key, { expiresAt });
+
+			const { restore_doc } = actor;
+
+			const doc = await restore_doc(TEST_COLLECTION, key);
+
+			expect(fromNullable(doc.expires_at)).toEqual(expiresAt);
+		});
+
+		it('should not restore a document that expired in the trash', async () => {
+			const key = nanoid();
+			await trashDoc(key, { expiresAt: (await now()) + TTL_NS });
+
+			await pic.advanceTime(Number(TTL_NS / 1_000_000n) + 1_000);
+
+			const { restore_doc, get_doc } = actor;
+
+			await expect(restore_doc(TEST_COLLECTION, key)).rejects.toThrowError(
+				'juno.datastore.error.not_in_trash'
+			);
+
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).toBeUndefined();
+		});
+
+		it('should assert the rate of the collection', async () => {
+			const key = nanoid();
+			await trashDoc(key, { collection: RATE_COLLECTION });
+
+			const { restore_doc } = actor;
+
+			await expect(restore_doc(RATE_COLLECTION, key)).rejects.toThrowError(
+				'Rate limit reached, try again later.'
+			);
+		});
+	});
+});
@@ -29,7 +29,8 @@ describe('Satellite > Allowed Callers', () => {
 				provider: 'internet_identity'
 			}),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 	};
 
@@ -150,7 +151,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -184,7 +186,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -218,7 +221,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				};
 
@@ -250,7 +254,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					};
 
 					await set_many_docs([
@@ -708,7 +713,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -768,7 +774,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -827,7 +834,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				};
 
@@ -887,7 +895,8 @@ describe('Satellite > Allowed Callers', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					};
 
 					await set_many_docs([
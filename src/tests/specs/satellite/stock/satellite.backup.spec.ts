@@ -0,0 +1,236 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../mocks/doc.mocks';
+import { uploadAsset } from '../../../utils/satellite-storage-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../utils/setup-tests.utils';
+
+describe('Satellite > Backup', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const SOURCE_COLLECTION = 'test_backup_source';
+	const TARGET_COLLECTION = 'test_backup_target';
+	const ASSETS_COLLECTION = 'test_backup_assets';
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const exportCollection = async ({
+		collection,
+		collectionType
+	}: {
+		collection: string;
+		collectionType: SatelliteDid.CollectionType;
+	}): Promise<Uint8Array> => {
+		const { export_collection } = actor;
+
+		const { data, cursor } = await export_collection({
+			collection_type: collectionType,
+			collection,
+			format: { JsonLines: null },
+			cursor: toNullable()
+		});
+
+		expect(fromNullable(cursor)).toBeUndefined();
+
+		return data instanceof Uint8Array ? data : new Uint8Array(data);
+	};
+
+	const importCollection = ({
+		collection,
+		collectionType,
+		data,
+		dryRun = false
+	}: {
+		collection: string;
+		collectionType: SatelliteDid.CollectionType;
+		data: Uint8Array;
+		dryRun?: boolean;
+	}): Promise<SatelliteDid.ImportCollectionResult> => {
+		const { import_collection } = actor;
+
+		return import_collection({
+			collection_type: collectionType,
+			collection,
+			format: { JsonLines: null },
+			data,
+			dry_run: dryRun
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, SOURCE_COLLECTION, setRule);
+		await set_rule({ Db: null }, TARGET_COLLECTION, setRule);
+		await set_rule({ Storage: null }, ASSETS_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('documents', () => {
+		const keys = [nanoid(), nanoid(), nanoid()];
+
+		let data: Uint8Array;
+
+		beforeAll(async () => {
+			const { set_doc } = actor;
+
+			for (const key of keys) {
+				await set_doc(SOURCE_COLLECTION, key, {
+					data: mockData,
+					description: toNullable(),
+					version: toNullable(),
+					expires_at: toNullable()
+				});
+			}
+
+			data = await exportCollection({
+				collection: SOURCE_COLLECTION,
+				collectionType: { Db: null }
+			});
+		});
+
+		it('should not write the documents with a dry run', async () => {
+			const { imported, conflicts } = await importCollection({
+				collection: TARGET_COLLECTION,
+				collectionType: { Db: null },
+				data,
+				dryRun: true
+			});
+
+			expect(imported).toEqual(3n);
+			expect(conflicts).toHaveLength(0);
+
+			const { count_collection_docs } = actor;
+			expect(await count_collection_docs(TARGET_COLLECTION)).toEqual(0n);
+		});
+
+		it('should import the documents as they were exported', async () => {
+			const { imported } = await importCollection({
+				collection: TARGET_COLLECTION,
+				collectionType: { Db: null },
+				data
+			});
+
+			expect(imported).toEqual(3n);
+
+			const { get_doc } = actor;
+
+			for (const key of keys) {
+				const source = fromNullable(await get_doc(SOURCE_COLLECTION, key));
+				const target = fromNullable(await get_doc(TARGET_COLLECTION, key));
+
+				expect(target).toEqual(source);
+			}
+		});
+
+		it('should report the existing documents as conflicts', async () => {
+			const { imported, conflicts } = await importCollection({
+				collection: TARGET_COLLECTION,
+				collectionType: { Db: null },
+				data
+			});
+
+			expect(imported).toEqual(0n);
+			expect(conflicts.map(({ key }) => key).sort()).toEqual([...keys].sort());
+		});
+	});
+
+	describe('assets', () => {
+		const full_path = `/${ASSETS_COLLECTION}/${nanoid()}.txt`;
+
+		let data: Uint8Array;
+
+		beforeAll(async () => {
+			await uploadAsset({
+				full_path,
+				name: 'backup.txt',
+				collection: ASSETS_COLLECTION,
+				actor
+			});
+
+			data = await exportCollection({
+				collection: ASSETS_COLLECTION,
+				collectionType: { Storage: null }
+			});
+
+			const { del_asset } = actor;
+			await del_asset(ASSETS_COLLECTION, full_path);
+		});
+
+		it('should throw if the content of an asset is imported without its metadata', async () => {
+			// The first record contains the metadata of the asset, the next ones its content
+			const [_, ...contentRecords] = new TextDecoder().decode(data).split('\n');
+
+			await expect(
+				importCollection({
+					collection: ASSETS_COLLECTION,
+					collectionType: { Storage: null },
+					data: new TextEncoder().encode(contentRecords.join('\n'))
+				})
+			).rejects.toThrowError('juno.backup.error.asset_not_staged');
+		});
+
+		it('should import the asset once its content was imported', async () => {
+			const { imported, conflicts } = await importCollection({
+				collection: ASSETS_COLLECTION,
+				collectionType: { Storage: null },
+				data
+			});
+
+			expect(imported).toEqual(1n);
+			expect(conflicts).toHaveLength(0);
+
+			const { get_asset } = actor;
+			expect(fromNullable(await get_asset(ASSETS_COLLECTION, full_path))).not.toBeUndefined();
+		});
+
+		it('should skip the content of an asset reported as conflict', async () => {
+			const { imported, conflicts } = await importCollection({
+				collection: ASSETS_COLLECTION,
+				collectionType: { Storage: null },
+				data
+			});
+
+			expect(imported).toEqual(0n);
+			expect(conflicts.map(({ key }) => key)).toEqual([full_path]);
+		});
+	});
+});
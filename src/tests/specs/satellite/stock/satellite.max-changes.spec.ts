@@ -57,7 +57,14 @@ describe('Satellite > Max changes', () => {
 			write: { Public: null },
 			version: nonNullish(rule) ? rule.version : toNullable(),
 			rate_config: toNullable(),
-			max_changes_per_user: toNullable(maxChanges)
+			max_changes_per_user: toNullable(maxChanges),
+			max_items_per_user: toNullable(),
+			max_bytes_per_user: toNullable(),
+			json_schema: toNullable(),
+			trash_retention_ns: toNullable(),
+			max_revisions: toNullable(),
+			default_ttl_ns: toNullable(),
+			indexes: toNullable()
 		});
 	};
 
@@ -0,0 +1,114 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { JUNO_AUTH_ERROR_NOT_ADMIN_CONTROLLER } from '@junobuild/errors';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../mocks/doc.mocks';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../utils/setup-tests.utils';
+
+describe('Satellite > Migrate collection memory', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_migrate';
+
+	const keys = Array.from({ length: 5 }, () => nanoid());
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		for (const key of keys) {
+			await set_doc(TEST_COLLECTION, key, {
+				data: mockData,
+				description: toNullable(),
+				version: toNullable(),
+				expires_at: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should throw if the caller is not an admin controller', async () => {
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { migrate_collection_memory } = actor;
+
+		await expect(
+			migrate_collection_memory({ Db: null }, TEST_COLLECTION, { Stable: null })
+		).rejects.toThrowError(JUNO_AUTH_ERROR_NOT_ADMIN_CONTROLLER);
+
+		actor.setIdentity(controller);
+	});
+
+	it('should migrate the documents to stable memory', async () => {
+		const { migrate_collection_memory, get_rule, get_doc } = actor;
+
+		let migration: SatelliteDid.CollectionMigration;
+
+		do {
+			migration = await migrate_collection_memory({ Db: null }, TEST_COLLECTION, {
+				Stable: null
+			});
+		} while (!('Completed' in migration.status));
+
+		expect(migration.copied).toEqual(BigInt(keys.length));
+
+		const rule = fromNullable(await get_rule({ Db: null }, TEST_COLLECTION));
+		expect(fromNullable(rule?.memory ?? [])).toEqual({ Stable: null });
+
+		for (const key of keys) {
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+		}
+	});
+
+	it('should complete right away if the collection is already in the memory', async () => {
+		const { migrate_collection_memory } = actor;
+
+		const { status, copied } = await migrate_collection_memory({ Db: null }, TEST_COLLECTION, {
+			Stable: null
+		});
+
+		expect(status).toEqual({ Completed: null });
+		expect(copied).toEqual(0n);
+	});
+});
@@ -41,7 +41,8 @@ describe('Satellite > Rate', () => {
 		const doc = await set_doc(collection, user.getPrincipal().toText(), {
 			data: await toArray({}),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		return { doc, user };
@@ -168,6 +169,13 @@ describe('Satellite > Rate', () => {
 			max_size: toNullable(),
 			max_capacity: toNullable(),
 			max_changes_per_user: toNullable(),
+			max_items_per_user: toNullable(),
+			max_bytes_per_user: toNullable(),
+			json_schema: toNullable(),
+			trash_retention_ns: toNullable(),
+			max_revisions: toNullable(),
+			default_ttl_ns: toNullable(),
+			indexes: toNullable(),
 			read: { Public: null },
 			mutable_permissions: toNullable(true),
 			write: { Public: null },
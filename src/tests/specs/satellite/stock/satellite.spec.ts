@@ -47,6 +47,13 @@ describe('Satellite', () => {
 		max_size: toNullable(),
 		max_capacity: toNullable(),
 		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable(),
 		read: { Managed: null },
 		mutable_permissions: toNullable(),
 		write: { Managed: null },
@@ -59,6 +66,13 @@ describe('Satellite', () => {
 		max_size: toNullable(123n),
 		max_capacity: toNullable(456),
 		max_changes_per_user: toNullable(999),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable(),
 		read: { Private: null },
 		mutable_permissions: toNullable(false),
 		write: { Private: null },
@@ -0,0 +1,262 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import type { Identity } from '@icp-sdk/core/agent';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { Principal } from '@icp-sdk/core/principal';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockSetRule } from '../../../mocks/collection.mocks';
+import { mockData } from '../../../mocks/doc.mocks';
+import { mockListParams } from '../../../mocks/list.mocks';
+import { createUser } from '../../../utils/satellite-doc-tests.utils';
+import { uploadAsset } from '../../../utils/satellite-storage-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../utils/setup-tests.utils';
+
+describe('Satellite > Transfer ownership', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const owner = Ed25519KeyIdentity.generate();
+	const newOwner = Ed25519KeyIdentity.generate();
+	const reader = Ed25519KeyIdentity.generate();
+	const stranger = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_transfer';
+
+	const grant = (user: Identity): SatelliteDid.DocGrant => ({
+		user: user.getPrincipal(),
+		expires_at: toNullable()
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, mockSetRule);
+		await set_rule({ Storage: null }, TEST_COLLECTION, mockSetRule);
+
+		await createUser({ actor, user: owner });
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('doc', () => {
+		const key = nanoid();
+
+		let doc: SatelliteDid.Doc;
+
+		beforeAll(async () => {
+			actor.setIdentity(owner);
+
+			const { set_doc, set_doc_access } = actor;
+
+			doc = await set_doc(TEST_COLLECTION, key, {
+				data: mockData,
+				description: toNullable(),
+				version: toNullable(),
+				expires_at: toNullable()
+			});
+
+			await set_doc_access(TEST_COLLECTION, key, {
+				readers: [grant(reader), grant(newOwner)],
+				writers: [grant(newOwner)],
+				version: toNullable()
+			});
+		});
+
+		it('should not transfer a document if the caller is not the owner', async () => {
+			actor.setIdentity(stranger);
+
+			const { transfer_doc_ownership } = actor;
+
+			await expect(
+				transfer_doc_ownership(TEST_COLLECTION, key, {
+					owner: stranger.getPrincipal(),
+					version: doc.version
+				})
+			).rejects.toThrowError('juno.datastore.error.cannot_write');
+		});
+
+		it('should not transfer a document to the anonymous principal', async () => {
+			actor.setIdentity(owner);
+
+			const { transfer_doc_ownership } = actor;
+
+			await expect(
+				transfer_doc_ownership(TEST_COLLECTION, key, {
+					owner: Principal.anonymous(),
+					version: doc.version
+				})
+			).rejects.toThrowError('juno.datastore.error.invalid_owner');
+		});
+
+		it('should not transfer a document of a system collection', async () => {
+			actor.setIdentity(owner);
+
+			const { transfer_doc_ownership } = actor;
+
+			await expect(
+				transfer_doc_ownership('#user', owner.getPrincipal().toText(), {
+					owner: newOwner.getPrincipal(),
+					version: toNullable(1n)
+				})
+			).rejects.toThrowError('juno.datastore.error.ownership_transfer_not_supported');
+		});
+
+		it('should transfer a document to a new owner', async () => {
+			actor.setIdentity(owner);
+
+			const { transfer_doc_ownership } = actor;
+
+			const transferred = await transfer_doc_ownership(TEST_COLLECTION, key, {
+				owner: newOwner.getPrincipal(),
+				version: doc.version
+			});
+
+			expect(transferred.owner.toText()).toEqual(newOwner.getPrincipal().toText());
+			expect(transferred.data).toEqual(doc.data);
+			expect(transferred.created_at).toEqual(doc.created_at);
+			expect(fromNullable(transferred.version)).toEqual((fromNullable(doc.version) ?? 0n) + 1n);
+		});
+
+		it('should remove the new owner from the access list', async () => {
+			actor.setIdentity(newOwner);
+
+			const { get_doc_access } = actor;
+
+			const access = fromNullable(await get_doc_access(TEST_COLLECTION, key));
+
+			assertNonNullish(access);
+
+			expect(access.readers.map(({ user }) => user.toText())).toEqual([
+				reader.getPrincipal().toText()
+			]);
+			expect(access.writers).toHaveLength(0);
+			expect(fromNullable(access.version)).toEqual(2n);
+		});
+
+		it('should not list the document as shared with the new owner', async () => {
+			actor.setIdentity(newOwner);
+
+			const { list_shared_docs } = actor;
+
+			const { items } = await list_shared_docs(TEST_COLLECTION, mockListParams);
+
+			expect(items).toHaveLength(0);
+		});
+
+		it('should not let the previous owner update the document', async () => {
+			actor.setIdentity(owner);
+
+			const { get_doc, set_doc } = actor;
+
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).toBeUndefined();
+
+			await expect(
+				set_doc(TEST_COLLECTION, key, {
+					data: mockData,
+					description: toNullable(),
+					version: toNullable((fromNullable(doc.version) ?? 0n) + 1n),
+					expires_at: toNullable()
+				})
+			).rejects.toThrowError('juno.datastore.error.cannot_write');
+		});
+	});
+
+	describe('asset', () => {
+		const name = `hello-${nanoid()}.html`;
+		const full_path = `/${TEST_COLLECTION}/${name}`;
+
+		let asset: SatelliteDid.AssetNoContent;
+
+		beforeAll(async () => {
+			actor.setIdentity(owner);
+
+			await uploadAsset({
+				full_path,
+				name,
+				collection: TEST_COLLECTION,
+				actor
+			});
+
+			const { get_asset } = actor;
+
+			const result = fromNullable(await get_asset(TEST_COLLECTION, full_path));
+
+			assertNonNullish(result);
+
+			asset = result;
+		});
+
+		it('should not transfer an asset if the caller is not the owner', async () => {
+			actor.setIdentity(stranger);
+
+			const { transfer_asset_ownership } = actor;
+
+			await expect(
+				transfer_asset_ownership(TEST_COLLECTION, full_path, {
+					owner: stranger.getPrincipal(),
+					version: asset.version
+				})
+			).rejects.toThrowError('juno.storage.error.asset_not_found');
+		});
+
+		it('should not transfer an asset to the anonymous principal', async () => {
+			actor.setIdentity(owner);
+
+			const { transfer_asset_ownership } = actor;
+
+			await expect(
+				transfer_asset_ownership(TEST_COLLECTION, full_path, {
+					owner: Principal.anonymous(),
+					version: asset.version
+				})
+			).rejects.toThrowError('juno.storage.error.invalid_owner');
+		});
+
+		it('should transfer an asset to a new owner', async () => {
+			actor.setIdentity(owner);
+
+			const { transfer_asset_ownership } = actor;
+
+			const transferred = await transfer_asset_ownership(TEST_COLLECTION, full_path, {
+				owner: newOwner.getPrincipal(),
+				version: asset.version
+			});
+
+			expect(transferred.key.owner.toText()).toEqual(newOwner.getPrincipal().toText());
+			expect(transferred.key.full_path).toEqual(full_path);
+			expect(transferred.created_at).toEqual(asset.created_at);
+		});
+
+		it('should let the new owner read the asset', async () => {
+			const { get_asset } = actor;
+
+			actor.setIdentity(newOwner);
+
+			const transferred = fromNullable(await get_asset(TEST_COLLECTION, full_path));
+
+			expect(transferred?.key.owner.toText()).toEqual(newOwner.getPrincipal().toText());
+
+			actor.setIdentity(owner);
+
+			expect(fromNullable(await get_asset(TEST_COLLECTION, full_path))).toBeUndefined();
+		});
+	});
+});
@@ -0,0 +1,207 @@
+import type { SatelliteActor, SatelliteDid } from '$declarations';
+import type { Actor, PocketIc } from '@dfinity/pic';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { AnonymousIdentity } from '@icp-sdk/core/agent';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import type { Principal } from '@icp-sdk/core/principal';
+import {
+	JUNO_AUTH_ERROR_NOT_ADMIN_CONTROLLER,
+	JUNO_ERROR_NO_VERSION_PROVIDED,
+	JUNO_ERROR_VERSION_OUTDATED_OR_FUTURE
+} from '@junobuild/errors';
+import { setupSatelliteStock } from '../../../utils/satellite-tests.utils';
+import { upgradeSatellite } from '../../../utils/satellite-upgrade-tests.utils';
+
+describe('Satellite > WebSocket', () => {
+	let pic: PocketIc;
+	let canisterId: Principal;
+	let actor: Actor<SatelliteActor>;
+	let controller: Ed25519KeyIdentity;
+
+	const gateway = Ed25519KeyIdentity.generate();
+
+	const setConfig: SatelliteDid.SetWsConfig = {
+		gateway_principals: [gateway.getPrincipal()],
+		max_number_of_returned_messages: toNullable(50n),
+		send_ack_interval_ms: toNullable(300_000n),
+		require_user: toNullable(),
+		message_rate_config: toNullable({ max_tokens: 10n, time_per_token_ns: 1_000_000n }),
+		subscription_rate_config: toNullable(),
+		version: toNullable()
+	};
+
+	beforeAll(async () => {
+		const { actor: a, canisterId: c, pic: p, controller: cO } = await setupSatelliteStock();
+
+		pic = p;
+		canisterId = c;
+		actor = a;
+		controller = cO;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should have no config by default', async () => {
+			const { get_ws_config } = actor;
+
+			const config = await get_ws_config();
+
+			expect(fromNullable(config)).toBeUndefined();
+		});
+
+		it('should set config', async () => {
+			const { set_ws_config, get_ws_config } = actor;
+
+			const config = await set_ws_config(setConfig);
+
+			expect(config.gateway_principals.map((p) => p.toText())).toEqual([
+				gateway.getPrincipal().toText()
+			]);
+			expect(config.max_number_of_returned_messages).toEqual(toNullable(50n));
+			expect(config.send_ack_interval_ms).toEqual(toNullable(300_000n));
+			expect(config.message_rate_config).toEqual(setConfig.message_rate_config);
+			expect(config.version).toEqual(toNullable(1n));
+			expect(fromNullable(config.created_at)).toBeGreaterThan(0n);
+			expect(fromNullable(config.updated_at)).toBeGreaterThan(0n);
+
+			const stored = fromNullable(await get_ws_config());
+
+			expect(stored?.version).toEqual(toNullable(1n));
+			expect(stored?.gateway_principals).toHaveLength(1);
+		});
+
+		it('should throw if version is missing', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(set_ws_config(setConfig)).rejects.toThrowError(JUNO_ERROR_NO_VERSION_PROVIDED);
+		});
+
+		it('should throw if version is outdated', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(
+				set_ws_config({
+					...setConfig,
+					version: toNullable(0n)
+				})
+			).rejects.toThrowError(JUNO_ERROR_VERSION_OUTDATED_OR_FUTURE);
+		});
+
+		it('should throw if max number of returned messages is zero', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(
+				set_ws_config({
+					...setConfig,
+					max_number_of_returned_messages: toNullable(0n),
+					version: toNullable(1n)
+				})
+			).rejects.toThrowError('juno.ws.error.invalid_max_returned_messages');
+		});
+
+		it('should throw if ack interval does not exceed the keep alive timeout', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(
+				set_ws_config({
+					...setConfig,
+					send_ack_interval_ms: toNullable(60_000n),
+					version: toNullable(1n)
+				})
+			).rejects.toThrowError('juno.ws.error.invalid_ack_interval');
+		});
+
+		it('should throw if rate config is invalid', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(
+				set_ws_config({
+					...setConfig,
+					subscription_rate_config: toNullable({ max_tokens: 10n, time_per_token_ns: 0n }),
+					version: toNullable(1n)
+				})
+			).rejects.toThrowError('juno.ws.error.invalid_rate_config');
+		});
+
+		it('should update config', async () => {
+			const { set_ws_config } = actor;
+
+			const config = await set_ws_config({
+				...setConfig,
+				max_number_of_returned_messages: toNullable(20n),
+				version: toNullable(1n)
+			});
+
+			expect(config.max_number_of_returned_messages).toEqual(toNullable(20n));
+			expect(config.version).toEqual(toNullable(2n));
+		});
+
+		it('should keep config after upgrade', async () => {
+			await upgradeSatellite({ pic, canisterId, controller });
+
+			const { get_ws_config } = actor;
+
+			const config = fromNullable(await get_ws_config());
+
+			expect(config?.max_number_of_returned_messages).toEqual(toNullable(20n));
+			expect(config?.version).toEqual(toNullable(2n));
+		});
+	});
+
+	describe('gateway', () => {
+		const user = Ed25519KeyIdentity.generate();
+
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should reject a client relayed by a gateway that is not allowed', async () => {
+			const { ws_open } = actor;
+
+			const result = await ws_open({
+				client_nonce: 1n,
+				gateway_principal: Ed25519KeyIdentity.generate().getPrincipal()
+			});
+
+			expect('Err' in result && result.Err).toContain('juno.ws.error.gateway_not_allowed');
+		});
+
+		it('should not deliver messages to a gateway that is not allowed', async () => {
+			const { ws_get_messages } = actor;
+
+			const result = await ws_get_messages({ nonce: 0n });
+
+			expect('Err' in result && result.Err).toContain('juno.ws.error.gateway_not_allowed');
+		});
+	});
+
+	describe.each([
+		{ title: 'anonymous', identity: () => new AnonymousIdentity() },
+		{ title: 'user', identity: () => Ed25519KeyIdentity.generate() }
+	])('$title', ({ identity }) => {
+		beforeAll(() => {
+			actor.setIdentity(identity());
+		});
+
+		it('should throw on set config', async () => {
+			const { set_ws_config } = actor;
+
+			await expect(set_ws_config(setConfig)).rejects.toThrowError(
+				JUNO_AUTH_ERROR_NOT_ADMIN_CONTROLLER
+			);
+		});
+
+		it('should throw on get config', async () => {
+			const { get_ws_config } = actor;
+
+			await expect(get_ws_config()).rejects.toThrowError(JUNO_AUTH_ERROR_NOT_ADMIN_CONTROLLER);
+		});
+	});
+});
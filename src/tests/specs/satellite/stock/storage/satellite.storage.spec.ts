@@ -355,7 +355,14 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 							write: { Managed: null },
 							version: toNullable(),
 							rate_config: toNullable(),
-							max_changes_per_user: toNullable()
+							max_changes_per_user: toNullable(),
+							max_items_per_user: toNullable(),
+							max_bytes_per_user: toNullable(),
+							json_schema: toNullable(),
+							trash_retention_ns: toNullable(),
+							max_revisions: toNullable(),
+							default_ttl_ns: toNullable(),
+							indexes: toNullable()
 						};
 
 						await set_rule({ Storage: null }, collection, setRule);
@@ -749,7 +756,14 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 							write: { Managed: null },
 							version: toNullable(),
 							rate_config: toNullable(),
-							max_changes_per_user: toNullable()
+							max_changes_per_user: toNullable(),
+							max_items_per_user: toNullable(),
+							max_bytes_per_user: toNullable(),
+							json_schema: toNullable(),
+							trash_retention_ns: toNullable(),
+							max_revisions: toNullable(),
+							default_ttl_ns: toNullable(),
+							indexes: toNullable()
 						};
 
 						await set_rule({ Storage: null }, collection, setRule);
@@ -873,7 +887,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									created_at: toNullable({
 										GreaterThan: items[4][1].created_at
 									}),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -898,7 +914,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									created_at: toNullable({
 										LessThan: items[4][1].created_at
 									}),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -923,7 +941,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									created_at: toNullable({
 										Between: [items[4][1].created_at, items[8][1].created_at]
 									}),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -970,7 +990,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									updated_at: toNullable({
 										GreaterThan: items[4][1].created_at
 									}),
-									created_at: toNullable()
+									created_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -995,7 +1017,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									updated_at: toNullable({
 										LessThan: items[4][1].created_at
 									}),
-									created_at: toNullable()
+									created_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -1020,7 +1044,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									updated_at: toNullable({
 										Between: [items[4][1].created_at, items[8][1].created_at]
 									}),
-									created_at: toNullable()
+									created_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
@@ -1053,7 +1079,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/asset2\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable({
 									desc: true,
@@ -1068,7 +1096,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/asset\\d+\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable({
 									desc: true,
@@ -1102,7 +1132,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/asset1\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable({
 									desc: true,
@@ -1119,7 +1151,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/asset\\d+\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable({
 									desc: true,
@@ -1140,7 +1174,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/user2_asset\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable({
 									desc: true,
@@ -1171,7 +1207,9 @@ describe.each([{ title: 'Heap (default)', memory: null }, ...MEMORIES])(
 									key: toNullable('/user2_asset\\.svg$'),
 									description: toNullable(),
 									created_at: toNullable(),
-									updated_at: toNullable()
+									updated_at: toNullable(),
+									index: toNullable(),
+									data: toNullable()
 								}),
 								order: toNullable(),
 								owner: toNullable(),
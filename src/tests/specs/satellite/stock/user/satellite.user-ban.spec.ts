@@ -31,7 +31,8 @@ describe('Satellite > User Ban', () => {
 				provider: 'internet_identity'
 			}),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 	};
 
@@ -46,7 +47,8 @@ describe('Satellite > User Ban', () => {
 				banned: 'indefinite'
 			}),
 			description: toNullable(),
-			version
+			version,
+			expires_at: toNullable()
 		});
 	};
 
@@ -61,7 +63,8 @@ describe('Satellite > User Ban', () => {
 				banned: undefined
 			}),
 			description: toNullable(),
-			version
+			version,
+			expires_at: toNullable()
 		});
 	};
 
@@ -105,7 +108,8 @@ describe('Satellite > User Ban', () => {
 					set_doc('#user', user.getPrincipal().toText(), {
 						data,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(
 					new RegExp(
@@ -197,7 +201,8 @@ describe('Satellite > User Ban', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -242,7 +247,8 @@ describe('Satellite > User Ban', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				});
 
@@ -285,7 +291,8 @@ describe('Satellite > User Ban', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					});
 				};
 
@@ -326,7 +333,8 @@ describe('Satellite > User Ban', () => {
 							hello: 'world'
 						}),
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					};
 
 					await set_many_docs([
@@ -0,0 +1,245 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockSetRule } from '../../../../mocks/collection.mocks';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { mockListParams } from '../../../../mocks/list.mocks';
+import { createUser } from '../../../../utils/satellite-doc-tests.utils';
+import { uploadAsset } from '../../../../utils/satellite-storage-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > User > Purge', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+	const other = Ed25519KeyIdentity.generate();
+	const reader = Ed25519KeyIdentity.generate();
+
+	const FIRST_COLLECTION = 'test_purge_a';
+	const SECOND_COLLECTION = 'test_purge_b';
+	const ASSETS_COLLECTION = 'test_purge_assets';
+
+	// One more than the number of assets scanned per call to resume within the collection
+	const ASSETS_COUNT = 51;
+
+	const RETENTION_NS = 60_000_000_000n;
+
+	const trashedKey = nanoid();
+	const sharedKey = nanoid();
+
+	const setDoc = ({
+		collection,
+		key = nanoid()
+	}: {
+		collection: string;
+		key?: string;
+	}): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(collection, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(),
+			expires_at: toNullable()
+		});
+	};
+
+	const purge = (): Promise<SatelliteDid.UserPurge> => {
+		actor.setIdentity(controller);
+
+		const { purge_user_data } = actor;
+
+		return purge_user_data(user.getPrincipal());
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, FIRST_COLLECTION, {
+			...mockSetRule,
+			trash_retention_ns: toNullable(RETENTION_NS)
+		});
+		await set_rule({ Db: null }, SECOND_COLLECTION, mockSetRule);
+		await set_rule({ Storage: null }, ASSETS_COLLECTION, mockSetRule);
+
+		await createUser({ actor, user });
+		await createUser({ actor, user: other });
+
+		// The documents of the user - one of those being trashed
+		actor.setIdentity(user);
+
+		await setDoc({ collection: FIRST_COLLECTION });
+		await setDoc({ collection: SECOND_COLLECTION });
+
+		const trashedDoc = await setDoc({ collection: FIRST_COLLECTION, key: trashedKey });
+
+		const { del_doc } = actor;
+		await del_doc(FIRST_COLLECTION, trashedKey, { version: trashedDoc.version });
+
+		for (let i = 0; i < ASSETS_COUNT; i++) {
+			const name = `hello-${i}-${nanoid()}.html`;
+
+			await uploadAsset({
+				full_path: `/${ASSETS_COLLECTION}/${name}`,
+				name,
+				collection: ASSETS_COLLECTION,
+				actor
+			});
+		}
+
+		// A document of another user shared with the user
+		actor.setIdentity(other);
+
+		await setDoc({ collection: SECOND_COLLECTION, key: sharedKey });
+
+		const { set_doc_access } = actor;
+		await set_doc_access(SECOND_COLLECTION, sharedKey, {
+			readers: [
+				{ user: user.getPrincipal(), expires_at: toNullable() },
+				{ user: reader.getPrincipal(), expires_at: toNullable() }
+			],
+			writers: [{ user: user.getPrincipal(), expires_at: toNullable() }],
+			version: toNullable()
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not purge the data of a user if the caller is not a controller', async () => {
+		actor.setIdentity(user);
+
+		const { purge_user_data } = actor;
+
+		await expect(purge_user_data(user.getPrincipal())).rejects.toThrowError();
+	});
+
+	describe('batches', () => {
+		const statuses: SatelliteDid.UserPurge[] = [];
+
+		it('should resume with the next collection', async () => {
+			const progress = await purge();
+
+			statuses.push(progress);
+
+			expect('Docs' in progress.status).toBeTruthy();
+			expect(fromNullable(progress.collection)).toEqual(SECOND_COLLECTION);
+			expect(fromNullable(progress.last_key)).toBeUndefined();
+			expect(progress.deleted_docs).toEqual(1n);
+		});
+
+		it('should purge the data of the user until completed', async () => {
+			let progress: SatelliteDid.UserPurge;
+
+			do {
+				progress = await purge();
+
+				statuses.push(progress);
+			} while (!('Completed' in progress.status));
+
+			const steps = statuses.map(({ status }) => Object.keys(status)[0]);
+
+			expect([...new Set(steps)]).toEqual([
+				'Docs',
+				'Trash',
+				'Shares',
+				'Assets',
+				'User',
+				'Completed'
+			]);
+
+			// Own documents, the trashed document and the entry in #user
+			expect(progress.deleted_docs).toEqual(4n);
+			expect(progress.deleted_assets).toEqual(BigInt(ASSETS_COUNT));
+		});
+
+		it('should resume the assets with the cursor of the collection', () => {
+			const assets = statuses.filter(
+				({ status, last_key }) => 'Assets' in status && fromNullable(last_key) !== undefined
+			);
+
+			expect(assets).toHaveLength(1);
+			expect(fromNullable(assets[0].collection)).toEqual(ASSETS_COLLECTION);
+			expect(assets[0].deleted_assets).toEqual(50n);
+		});
+	});
+
+	describe('purged', () => {
+		it('should have purged the documents of the user', async () => {
+			actor.setIdentity(controller);
+
+			const { list_docs, get_doc } = actor;
+
+			const { items: first } = await list_docs(FIRST_COLLECTION, mockListParams);
+			expect(first).toHaveLength(0);
+
+			const { items: second } = await list_docs(SECOND_COLLECTION, mockListParams);
+			expect(second.map(([key]) => key)).toEqual([sharedKey]);
+
+			expect(fromNullable(await get_doc('#user', user.getPrincipal().toText()))).toBeUndefined();
+		});
+
+		it('should have purged the trashed documents of the user', async () => {
+			actor.setIdentity(controller);
+
+			const { list_trashed_docs } = actor;
+
+			const { items } = await list_trashed_docs(FIRST_COLLECTION, mockListParams);
+
+			expect(items.find(([key]) => key === trashedKey)).toBeUndefined();
+		});
+
+		it('should have purged the assets of the user', async () => {
+			actor.setIdentity(controller);
+
+			const { list_assets } = actor;
+
+			const { items } = await list_assets(ASSETS_COLLECTION, mockListParams);
+
+			expect(items).toHaveLength(0);
+		});
+
+		it('should have removed the user from the access lists of the shared documents', async () => {
+			actor.setIdentity(other);
+
+			const { get_doc_access } = actor;
+
+			const access = fromNullable(await get_doc_access(SECOND_COLLECTION, sharedKey));
+
+			assertNonNullish(access);
+
+			expect(access.readers.map(({ user: grantee }) => grantee.toText())).toEqual([
+				reader.getPrincipal().toText()
+			]);
+			expect(access.writers).toHaveLength(0);
+		});
+
+		it('should not list the documents shared with the user anymore', async () => {
+			actor.setIdentity(user);
+
+			const { list_shared_docs } = actor;
+
+			const { items } = await list_shared_docs(SECOND_COLLECTION, mockListParams);
+
+			expect(items).toHaveLength(0);
+		});
+	});
+});
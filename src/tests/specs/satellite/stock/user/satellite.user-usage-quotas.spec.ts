@@ -0,0 +1,182 @@
+import { type SatelliteActor, type SatelliteDid, idlFactorySatellite } from '$declarations';
+import { type Actor, PocketIc } from '@dfinity/pic';
+import { assertNonNullish, fromNullable, toNullable } from '@dfinity/utils';
+import { Ed25519KeyIdentity } from '@icp-sdk/core/identity';
+import { nanoid } from 'nanoid';
+import { inject } from 'vitest';
+import { mockData } from '../../../../mocks/doc.mocks';
+import { tick } from '../../../../utils/pic-tests.utils';
+import { controllersInitArgs, SATELLITE_WASM_PATH } from '../../../../utils/setup-tests.utils';
+
+describe('Satellite > User Usage > Quotas', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const QUOTA_COLLECTION = 'test_quota';
+	const RECOUNT_COLLECTION = 'test_recount';
+
+	const MAX_ITEMS = 2;
+
+	const setRule: SatelliteDid.SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
+	};
+
+	const setDoc = ({
+		collection,
+		key,
+		version
+	}: {
+		collection: string;
+		key: string;
+		version?: bigint;
+	}): Promise<SatelliteDid.Doc> => {
+		const { set_doc } = actor;
+
+		return set_doc(collection, key, {
+			data: mockData,
+			description: toNullable(),
+			version: toNullable(version),
+			expires_at: toNullable()
+		});
+	};
+
+	const getUsage = async (collection: string): Promise<SatelliteDid.UserUsageData | undefined> => {
+		const { get_user_usage } = actor;
+
+		return fromNullable(await get_user_usage({ Db: null }, collection));
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorySatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, QUOTA_COLLECTION, {
+			...setRule,
+			max_items_per_user: toNullable(MAX_ITEMS)
+		});
+		await set_rule({ Db: null }, RECOUNT_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('quota', () => {
+		const keys = Array.from({ length: MAX_ITEMS }, () => nanoid());
+
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should count the documents of the caller', async () => {
+			for (const key of keys) {
+				await setDoc({ collection: QUOTA_COLLECTION, key });
+			}
+
+			const usage = await getUsage(QUOTA_COLLECTION);
+
+			expect(usage?.items_count).toEqual(MAX_ITEMS);
+			expect(usage?.items_bytes).toBeGreaterThan(0n);
+		});
+
+		it('should throw if the maximal number of documents is reached', async () => {
+			await expect(setDoc({ collection: QUOTA_COLLECTION, key: nanoid() })).rejects.toThrowError(
+				'juno.datastore.error.user.usage.items_limit_reached'
+			);
+		});
+
+		it('should still update an existing document', async () => {
+			const { get_doc } = actor;
+
+			const [key] = keys;
+			const doc = fromNullable(await get_doc(QUOTA_COLLECTION, key));
+
+			assertNonNullish(doc);
+
+			await setDoc({ collection: QUOTA_COLLECTION, key, version: fromNullable(doc.version) });
+
+			const usage = await getUsage(QUOTA_COLLECTION);
+			expect(usage?.items_count).toEqual(MAX_ITEMS);
+		});
+
+		it('should not expose the usage of another user', async () => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			expect(await getUsage(QUOTA_COLLECTION)).toBeUndefined();
+
+			actor.setIdentity(user);
+		});
+	});
+
+	describe('recount', () => {
+		const keys = Array.from({ length: 3 }, () => nanoid());
+
+		beforeAll(async () => {
+			actor.setIdentity(user);
+
+			for (const key of keys) {
+				await setDoc({ collection: RECOUNT_COLLECTION, key });
+			}
+		});
+
+		it('should recount the documents once a quota is introduced', async () => {
+			actor.setIdentity(controller);
+
+			const { get_rule, set_rule } = actor;
+
+			const rule = fromNullable(await get_rule({ Db: null }, RECOUNT_COLLECTION));
+
+			assertNonNullish(rule);
+
+			await set_rule({ Db: null }, RECOUNT_COLLECTION, {
+				...setRule,
+				version: rule.version,
+				max_items_per_user: toNullable(keys.length)
+			});
+
+			await tick(pic);
+
+			actor.setIdentity(user);
+
+			const usage = await getUsage(RECOUNT_COLLECTION);
+			expect(usage?.items_count).toEqual(keys.length);
+		});
+
+		it('should enforce the quota with the recounted usage', async () => {
+			actor.setIdentity(user);
+
+			await expect(setDoc({ collection: RECOUNT_COLLECTION, key: nanoid() })).rejects.toThrowError(
+				'juno.datastore.error.user.usage.items_limit_reached'
+			);
+		});
+	});
+});
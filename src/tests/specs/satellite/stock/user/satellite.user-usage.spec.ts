@@ -34,7 +34,14 @@ describe('Satellite > User Usage', () => {
 		write: { Managed: null },
 		version: toNullable(),
 		rate_config: toNullable(),
-		max_changes_per_user: toNullable()
+		max_changes_per_user: toNullable(),
+		max_items_per_user: toNullable(),
+		max_bytes_per_user: toNullable(),
+		json_schema: toNullable(),
+		trash_retention_ns: toNullable(),
+		max_revisions: toNullable(),
+		default_ttl_ns: toNullable(),
+		indexes: toNullable()
 	};
 
 	const NO_FILTER_PARAMS: SatelliteDid.ListParams = {
@@ -86,7 +93,8 @@ describe('Satellite > User Usage', () => {
 				provider: 'internet_identity'
 			}),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 	};
 
@@ -181,7 +189,8 @@ describe('Satellite > User Usage', () => {
 					{
 						data: mockData,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					}
 				]);
 
@@ -334,7 +343,8 @@ describe('Satellite > User Usage', () => {
 						changes_count: 345
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				};
 
 				await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
@@ -356,7 +366,8 @@ describe('Satellite > User Usage', () => {
 				await set_doc('#log', key, {
 					data: mockData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const doc = await get_doc('#log', key);
@@ -385,7 +396,8 @@ describe('Satellite > User Usage', () => {
 				await set_doc('#user', key, {
 					data,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const doc = await get_doc('#user', key);
@@ -409,7 +421,8 @@ describe('Satellite > User Usage', () => {
 				await set_doc('#log', key, {
 					data: mockData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const doc = await get_doc('#log', key);
@@ -444,7 +457,8 @@ describe('Satellite > User Usage', () => {
 							changes_count: 345
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					const usage = await set_doc('#user-usage', key, doc);
@@ -476,7 +490,8 @@ describe('Satellite > User Usage', () => {
 							changes_count: 'invalid'
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
@@ -500,7 +515,8 @@ describe('Satellite > User Usage', () => {
 							unknown: 'field'
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
@@ -693,7 +709,8 @@ describe('Satellite > User Usage', () => {
 						changes_count: 345
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				};
 
 				await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
@@ -752,7 +769,8 @@ describe('Satellite > User Usage', () => {
 							changes_count: 456
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					const usage = await set_doc('#user-usage', key, doc);
@@ -784,7 +802,8 @@ describe('Satellite > User Usage', () => {
 							changes_count: 'invalid'
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
@@ -808,7 +827,8 @@ describe('Satellite > User Usage', () => {
 							unknown: 'field'
 						}),
 						description: toNullable(),
-						version: fromNullable(currentDoc)?.version ?? []
+						version: fromNullable(currentDoc)?.version ?? [],
+						expires_at: toNullable()
 					};
 
 					await expect(set_doc('#user-usage', key, doc)).rejects.toThrowError(
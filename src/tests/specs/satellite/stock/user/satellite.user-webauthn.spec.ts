@@ -41,7 +41,8 @@ describe('Satellite > User Webauthn', () => {
 				aaguid
 			}),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 	};
 
@@ -107,7 +108,8 @@ describe('Satellite > User Webauthn', () => {
 					set_doc('#user-webauthn', credentialId, {
 						data,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(
 					new RegExp(
@@ -126,7 +128,8 @@ describe('Satellite > User Webauthn', () => {
 					set_doc('#user-webauthn', credentialId, {
 						data,
 						description: toNullable(),
-						version: toNullable()
+						version: toNullable(),
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(
 					new RegExp(
@@ -147,7 +150,8 @@ describe('Satellite > User Webauthn', () => {
 							publicKey: userPublicKey
 						}),
 						description: toNullable(),
-						version: toNullable(1n)
+						version: toNullable(1n),
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_WEBAUTHN_CANNOT_UPDATE);
 			});
@@ -236,7 +240,8 @@ describe('Satellite > User Webauthn', () => {
 					provider: 'internet_identity'
 				}),
 				description: toNullable(),
-				version: toNullable()
+				version: toNullable(),
+				expires_at: toNullable()
 			});
 		};
 
@@ -62,7 +62,8 @@ describe('Satellite > User', () => {
 					providerData
 				}),
 				description: toNullable(),
-				version: toNullable()
+				version: toNullable(),
+				expires_at: toNullable()
 			});
 
 			const { items: users } = await list_docs('#user', {
@@ -111,7 +112,8 @@ describe('Satellite > User', () => {
 						provider: undefined
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				expect(doc).not.toBeUndefined();
@@ -123,7 +125,8 @@ describe('Satellite > User', () => {
 				const doc = await set_doc('#user', user.getPrincipal().toText(), {
 					data: await toArray({}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				expect(doc).not.toBeUndefined();
@@ -139,7 +142,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const before = await get_doc('#user', user.getPrincipal().toText());
@@ -163,7 +167,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const before = await get_doc('#user', user.getPrincipal().toText());
@@ -174,7 +179,8 @@ describe('Satellite > User', () => {
 							provider: 'nfid'
 						}),
 						description: toNullable(),
-						version: fromNullable(before)?.version ?? []
+						version: fromNullable(before)?.version ?? [],
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_CANNOT_UPDATE);
 			});
@@ -185,7 +191,8 @@ describe('Satellite > User', () => {
 				const before = await set_doc('#user', user.getPrincipal().toText(), {
 					data: await toArray({}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				actor.setIdentity(controller);
@@ -197,7 +204,8 @@ describe('Satellite > User', () => {
 						banned: 'indefinite'
 					}),
 					description: toNullable(),
-					version: before.version
+					version: before.version,
+					expires_at: toNullable()
 				});
 
 				actor.setIdentity(user);
@@ -208,7 +216,8 @@ describe('Satellite > User', () => {
 							provider: 'nfid'
 						}),
 						description: toNullable(),
-						version: bannedUser.version ?? []
+						version: bannedUser.version ?? [],
+						expires_at: toNullable()
 					})
 				).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_NOT_ALLOWED);
 			});
@@ -257,7 +266,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(
 						new RegExp(JUNO_DATASTORE_ERROR_USER_PROVIDER_WEBAUTHN_INVALID_DATA, 'i')
@@ -276,7 +286,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(
 						new RegExp(
@@ -317,7 +328,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(new RegExp(JUNO_DATASTORE_ERROR_USER_AAGUID_INVALID_LENGTH, 'i'));
 				});
@@ -334,7 +346,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(new RegExp(JUNO_DATASTORE_ERROR_USER_AAGUID_INVALID_LENGTH, 'i'));
 				});
@@ -353,7 +366,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_PROVIDER_INVALID_DATA);
 				});
@@ -370,7 +384,8 @@ describe('Satellite > User', () => {
 						set_doc('#user', user.getPrincipal().toText(), {
 							data,
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_PROVIDER_INVALID_DATA);
 				});
@@ -394,7 +409,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				})
 			).rejects.toThrowError(JUNO_DATASTORE_ERROR_CANNOT_WRITE);
 		});
@@ -410,7 +426,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				})
 			).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_CALLER_KEY);
 		});
@@ -427,7 +444,8 @@ describe('Satellite > User', () => {
 					provider: 'internet_identity'
 				}),
 				description: toNullable(),
-				version: toNullable()
+				version: toNullable(),
+				expires_at: toNullable()
 			});
 
 			const before = await get_doc('#user', user.getPrincipal().toText());
@@ -475,7 +493,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const before = await get_doc('#user', user.getPrincipal().toText());
@@ -490,7 +509,8 @@ describe('Satellite > User', () => {
 							provider: 'nfid'
 						}),
 						description: toNullable(),
-						version: fromNullable(before)?.version ?? []
+						version: fromNullable(before)?.version ?? [],
+						expires_at: toNullable()
 					})
 				).resolves.not.toThrowError();
 			});
@@ -509,7 +529,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const before = await get_doc('#user', user.getPrincipal().toText());
@@ -541,7 +562,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				const before = await get_doc('#user', user.getPrincipal().toText());
@@ -555,7 +577,8 @@ describe('Satellite > User', () => {
 						provider: 'nfid'
 					}),
 					description: toNullable(),
-					version: fromNullable(before)?.version ?? []
+					version: fromNullable(before)?.version ?? [],
+					expires_at: toNullable()
 				});
 
 				const after = await get_doc_after('#user', user.getPrincipal().toText());
@@ -578,7 +601,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				actor.setIdentity(controller);
@@ -604,7 +628,8 @@ describe('Satellite > User', () => {
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				});
 
 				actor.setIdentity(controller);
@@ -617,7 +642,8 @@ describe('Satellite > User', () => {
 						banned: 'indefinite'
 					}),
 					description: toNullable(),
-					version: before.version
+					version: before.version,
+					expires_at: toNullable()
 				});
 
 				await expect(
@@ -645,7 +671,8 @@ describe('Satellite > User', () => {
 								provider: 'internet_identity'
 							}),
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_CALLER_KEY);
 				});
@@ -659,7 +686,8 @@ describe('Satellite > User', () => {
 								provider: 'internet_identity'
 							}),
 							description: toNullable(),
-							version: toNullable()
+							version: toNullable(),
+							expires_at: toNullable()
 						})
 					).rejects.toThrowError(JUNO_DATASTORE_ERROR_USER_KEY_NO_PRINCIPAL);
 				});
@@ -685,7 +713,8 @@ describe('Satellite > User', () => {
 				set_doc('#user', user.getPrincipal().toText(), {
 					data,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				})
 			).rejects.toThrowError(
 				new RegExp(
@@ -707,7 +736,8 @@ describe('Satellite > User', () => {
 				set_doc('#user', user.getPrincipal().toText(), {
 					data,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				})
 			).rejects.toThrowError(
 				new RegExp(
@@ -729,7 +759,8 @@ describe('Satellite > User', () => {
 				set_doc('#user', user.getPrincipal().toText(), {
 					data,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				})
 			).rejects.toThrowError(
 				new RegExp(
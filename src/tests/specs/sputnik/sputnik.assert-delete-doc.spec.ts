@@ -88,7 +88,8 @@ describe('Sputnik > assert_delete_doc', () => {
 		await set_doc(TEST_ASSERTED_COLLECTION, key, {
 			data,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		const doc = fromNullable(await get_doc(TEST_ASSERTED_COLLECTION, key));
@@ -119,7 +120,8 @@ describe('Sputnik > assert_delete_doc', () => {
 		await set_doc(TEST_ASSERTED_COLLECTION, key, {
 			data,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		const doc = fromNullable(await get_doc(TEST_ASSERTED_COLLECTION, key));
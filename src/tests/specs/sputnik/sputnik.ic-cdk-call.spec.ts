@@ -42,13 +42,15 @@ describe('Sputnik > ic-cdk > call', () => {
 		await set_doc(MOCK_COLLECTION, nanoid(), {
 			data: await toArray(mockSputnikObj),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await set_doc(MOCK_COLLECTION, nanoid(), {
 			data: await toArray(mockSputnikObj),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 	};
 
@@ -60,7 +62,8 @@ describe('Sputnik > ic-cdk > call', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: Uint8Array.from([]),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
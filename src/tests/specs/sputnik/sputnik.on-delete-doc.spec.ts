@@ -49,7 +49,8 @@ describe('Sputnik > on_delete_doc', () => {
 		const doc = await set_doc(collection, key, {
 			data: mockData,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await del_doc(collection, key, {
@@ -72,7 +72,9 @@ describe('Sputnik > on_delete_filtered_assets', () => {
 					key: [full_path],
 					description: [],
 					created_at: [],
-					updated_at: []
+					updated_at: [],
+					index: [],
+					data: []
 				}
 			]
 		});
@@ -50,7 +50,8 @@ describe('Sputnik > on_delete_filtered_docs', () => {
 		await set_doc(collection, key, {
 			data: mockData,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await del_filtered_docs(collection, {
@@ -60,7 +61,9 @@ describe('Sputnik > on_delete_filtered_docs', () => {
 					key: [key],
 					description: [],
 					created_at: [],
-					updated_at: []
+					updated_at: [],
+					index: [],
+					data: []
 				}
 			]
 		});
@@ -49,7 +49,8 @@ describe('Sputnik > on_delete_many_docs', () => {
 		const doc = await set_doc(collection, key, {
 			data: mockData,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await del_many_docs([
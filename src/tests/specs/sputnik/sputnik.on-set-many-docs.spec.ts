@@ -53,7 +53,8 @@ describe('Sputnik > on_set_many_docs', () => {
 				{
 					data: mockData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				}
 			]
 		]);
@@ -95,7 +96,8 @@ describe('Sputnik > on_set_many_docs', () => {
 				{
 					data: mockData,
 					description: toNullable(),
-					version: toNullable()
+					version: toNullable(),
+					expires_at: toNullable()
 				}
 			]
 		]);
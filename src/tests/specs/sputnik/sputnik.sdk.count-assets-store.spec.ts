@@ -55,7 +55,8 @@ describe('Sputnik > sdk > countAssetsStore', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: Uint8Array.from([]),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
@@ -57,7 +57,8 @@ describe('Sputnik > sdk > countCollectionDocsStore', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: Uint8Array.from([]),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
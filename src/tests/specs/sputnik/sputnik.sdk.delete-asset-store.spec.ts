@@ -38,7 +38,8 @@ describe('Sputnik > sdk > deleteAssetStore', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: await toArray(fullPath),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
@@ -36,7 +36,8 @@ describe('Sputnik > sdk > deleteDocStore', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: await toArray(mockSputnikObj),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
@@ -45,7 +45,8 @@ describe('Sputnik > sdk > getAssetStore', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: await toArray(fullPath),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
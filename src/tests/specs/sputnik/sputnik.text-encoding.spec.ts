@@ -52,7 +52,8 @@ describe('Sputnik > text-encoding', () => {
 		await set_doc(TEST_COLLECTION, key, {
 			data: await toArray(data),
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
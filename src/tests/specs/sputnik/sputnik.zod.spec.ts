@@ -41,7 +41,8 @@ describe('Sputnik > assert_set_doc', () => {
 		await set_doc(TEST_COLLECTION, nanoid(), {
 			data,
 			description: toNullable(),
-			version: toNullable()
+			version: toNullable(),
+			expires_at: toNullable()
 		});
 
 		await waitServerlessFunction(pic);
@@ -21,7 +21,8 @@ export const createDoc = async ({
 	await set_doc(collection, key, {
 		data: mockData,
 		description: toNullable(),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	return key;
@@ -49,7 +50,8 @@ export const createUser = async <T extends ActorInterface<T>>({
 			provider: 'internet_identity'
 		}),
 		description: toNullable(),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	return { user: identity, doc };
@@ -84,7 +84,8 @@ export const initVersionMock = async (actor: Actor<SputnikActor>) => {
 	await set_doc(mockSputnikVersionCollection, mockSputnikVersionKey, {
 		data: await toArray(mockSputnikVersion),
 		description: toNullable(),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 };
 
@@ -110,7 +111,8 @@ export const addSomeDocsToBeListed = async ({
 			id: 1n
 		}),
 		description: toNullable('desc-match'),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	await set_doc(collection, KEY_2, {
@@ -119,7 +121,8 @@ export const addSomeDocsToBeListed = async ({
 			id: 2n
 		}),
 		description: toNullable('desc-match'),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	await tick(pic);
@@ -130,7 +133,8 @@ export const addSomeDocsToBeListed = async ({
 			id: 3n
 		}),
 		description: toNullable('excluded'),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	await set_doc(collection, KEY_4, {
@@ -139,7 +143,8 @@ export const addSomeDocsToBeListed = async ({
 			id: 4n
 		}),
 		description: toNullable('desc-match'),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	const user = Ed25519KeyIdentity.generate();
@@ -151,7 +156,8 @@ export const addSomeDocsToBeListed = async ({
 			id: 5n
 		}),
 		description: toNullable('desc-match'),
-		version: toNullable()
+		version: toNullable(),
+		expires_at: toNullable()
 	});
 
 	actor.setIdentity(controller);